@@ -1,7 +1,7 @@
 //! Tools to control view alignment.
 
 /// Specifies the alignment along both horizontal and vertical directions.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Align {
     /// Horizontal alignment policy
     pub h: HAlign,
@@ -37,7 +37,7 @@ impl Align {
 
     /// Creates a bottom-right alignment.
     pub fn bot_right() -> Self {
-        Align::new(HAlign::Right, VAlign::Top)
+        Align::new(HAlign::Right, VAlign::Bottom)
     }
 
     /// Creates a bottom-center alignment.
@@ -62,7 +62,7 @@ impl Align {
 }
 
 /// Horizontal alignment
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HAlign {
     /// Place the element to the left of available space
     Left,
@@ -73,7 +73,7 @@ pub enum HAlign {
 }
 
 /// Vertical alignment
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VAlign {
     /// Place the element at the top of available space
     Top,
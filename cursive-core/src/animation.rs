@@ -0,0 +1,254 @@
+//! Animated values and transitions.
+//!
+//! An [`Animation`] moves a value towards a target over some time, following
+//! an [`Easing`] curve. Views keep animated values (an offset, a size, a
+//! progress value...) as fields, and read them when drawing. While an
+//! animation runs, the view calls [`Printer::request_frame`]: the event loop
+//! then sends an [`Event::Refresh`] and draws the next frame, even without
+//! any input and without setting a global FPS.
+//!
+//! [`views::Animated`] uses this to bring a view in with a slide or a fade,
+//! for example when adding a layer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::animation::{Animation, Easing};
+//! use std::time::Duration;
+//!
+//! // The displayed progress catches up with the real one.
+//! let mut progress = Animation::new(0usize).easing(Easing::EaseOut);
+//! progress.animate_to(80, Duration::from_millis(300));
+//! assert!(progress.is_running());
+//! assert_eq!(*progress.target(), 80);
+//! ```
+//!
+//! [`Printer::request_frame`]: crate::Printer::request_frame
+//! [`Event::Refresh`]: crate::event::Event::Refresh
+//! [`views::Animated`]: crate::views::Animated
+use crate::direction::Absolute;
+use crate::theme::Color;
+use crate::Vec2;
+use crate::With;
+use std::time::{Duration, Instant};
+
+/// Curve followed by an animation, from start to end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Slow at first, then faster.
+    EaseIn,
+    /// Fast at first, slowing down near the end.
+    #[default]
+    EaseOut,
+    /// Slow at both ends.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the progress of the value when `t` of the time has elapsed.
+    ///
+    /// Both go from `0.0` to `1.0`; `t` is clamped to this range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::animation::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    /// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+    /// assert_eq!(Easing::EaseInOut.apply(1.0), 1.0);
+    /// ```
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t.powi(3),
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut if t < 0.5 => 4.0 * t.powi(3),
+            Easing::EaseInOut => 1.0 - (2.0 - 2.0 * t).powi(3) / 2.0,
+        }
+    }
+}
+
+/// Value that can be animated.
+pub trait Lerp: Clone {
+    /// Returns a value between `self` and `target`.
+    ///
+    /// `t` goes from `0.0` (returns `self`) to `1.0` (returns `target`).
+    fn lerp(&self, target: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, target: &Self, t: f32) -> Self {
+        self + (target - self) * t
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, target: &Self, t: f32) -> Self {
+        self + (target - self) * f64::from(t)
+    }
+}
+
+impl Lerp for usize {
+    fn lerp(&self, target: &Self, t: f32) -> Self {
+        let from = *self as f64;
+        (from + (*target as f64 - from) * f64::from(t)).round() as usize
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(&self, target: &Self, t: f32) -> Self {
+        self.zip_map(*target, |from, to| from.lerp(&to, t))
+    }
+}
+
+/// Colors are blended with [`Color::interpolate`].
+impl Lerp for Color {
+    fn lerp(&self, target: &Self, t: f32) -> Self {
+        self.interpolate(*target, t)
+    }
+}
+
+/// Value moving towards a target over time.
+///
+/// The value is computed from the current time whenever it is read, so it
+/// does not need to be updated on each frame.
+#[derive(Clone, Debug)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    start: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T: Lerp> Animation<T> {
+    /// Creates a new animation, resting on `value`.
+    pub fn new(value: T) -> Self {
+        Animation {
+            from: value.clone(),
+            to: value,
+            start: Instant::now(),
+            duration: Duration::from_secs(0),
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the curve followed by the next animations.
+    ///
+    /// Defaults to [`Easing::EaseOut`].
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.easing = easing;
+    }
+
+    /// Sets the curve followed by the next animations.
+    ///
+    /// Chainable variant.
+    pub fn easing(self, easing: Easing) -> Self {
+        self.with(|s| s.set_easing(easing))
+    }
+
+    /// Returns the curve followed by the animations.
+    pub fn get_easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Starts moving towards `target`, over `duration`.
+    ///
+    /// The animation starts from the current value, so a running animation
+    /// can be redirected without a jump.
+    pub fn animate_to(&mut self, target: T, duration: Duration) {
+        let now = Instant::now();
+        self.from = self.value_at(now);
+        self.to = target;
+        self.start = now;
+        self.duration = duration;
+    }
+
+    /// Sets the value right away, stopping any running animation.
+    pub fn set(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.duration = Duration::from_secs(0);
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> T {
+        self.value_at(Instant::now())
+    }
+
+    /// Returns the value at the given time.
+    pub fn value_at(&self, now: Instant) -> T {
+        if !self.is_running_at(now) {
+            return self.to.clone();
+        }
+        let elapsed = now.saturating_duration_since(self.start);
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        self.from.lerp(&self.to, self.easing.apply(t))
+    }
+
+    /// Returns the value this animation is going to.
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns `true` if the value is still moving.
+    ///
+    /// Views should request a new frame while this is the case.
+    pub fn is_running(&self) -> bool {
+        self.is_running_at(Instant::now())
+    }
+
+    /// Returns `true` if the value is still moving at the given time.
+    pub fn is_running_at(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.start) < self.duration
+    }
+}
+
+impl<T: Lerp + Default> Default for Animation<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+/// Effect used by [`views::Animated`] to bring a view in.
+///
+/// [`views::Animated`]: crate::views::Animated
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transition {
+    /// The view slides in from the given side.
+    ///
+    /// The view is clipped to its own area while moving.
+    Slide(Absolute),
+
+    /// The colors of the view fade in from the background.
+    ///
+    /// Colors can only be blended on backends supporting true colors.
+    Fade,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animation() {
+        let mut offset = Animation::new(Vec2::zero()).easing(Easing::Linear);
+        offset.animate_to(Vec2::new(10, 4), Duration::from_secs(10));
+        let start = offset.start;
+
+        let at = |secs| start + Duration::from_secs(secs);
+        assert_eq!(offset.value_at(at(0)), Vec2::zero());
+        assert_eq!(offset.value_at(at(5)), Vec2::new(5, 2));
+        assert!(offset.is_running_at(at(5)));
+        assert_eq!(offset.value_at(at(12)), Vec2::new(10, 4));
+        assert!(!offset.is_running_at(at(12)));
+
+        offset.set(Vec2::new(1, 1));
+        assert!(!offset.is_running());
+        assert_eq!(offset.value(), Vec2::new(1, 1));
+    }
+}
@@ -0,0 +1,224 @@
+//! Message-driven application harness.
+//!
+//! An [`App`] keeps the state of the application, and changes it only in
+//! response to messages. Its interface is described with [`Node`]s, from the
+//! current state, and an [`AppView`] applies each new description to the
+//! real views with [`virtual_dom`](crate::virtual_dom).
+//!
+//! Callbacks don't change the state directly: they send messages through a
+//! [`Dispatcher`]. Messages are processed before the next frame, so the
+//! interface is always up to date.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::app::{App, AppView, Dispatcher};
+//! use cursive_core::virtual_dom::Node;
+//!
+//! struct Counter(i32);
+//!
+//! #[derive(Clone)]
+//! enum Msg {
+//!     Increment,
+//!     Decrement,
+//! }
+//!
+//! impl App for Counter {
+//!     type Msg = Msg;
+//!
+//!     fn update(&mut self, msg: Msg) {
+//!         match msg {
+//!             Msg::Increment => self.0 += 1,
+//!             Msg::Decrement => self.0 -= 1,
+//!         }
+//!     }
+//!
+//!     fn view(&self, dispatch: &Dispatcher<Msg>) -> Node {
+//!         Node::horizontal(vec![
+//!             Node::button("-", dispatch.callback(Msg::Decrement)),
+//!             Node::text(self.0.to_string()),
+//!             Node::button("+", dispatch.callback(Msg::Increment)),
+//!         ])
+//!     }
+//! }
+//!
+//! let mut siv = cursive_core::Cursive::dummy();
+//! let view = AppView::new(Counter(0), siv.cb_sink().clone());
+//! siv.add_layer(view);
+//! ```
+use crate::view::{View, ViewWrapper};
+use crate::virtual_dom::{Node, VirtualView};
+use crate::{CbSink, Cursive, Vec2};
+use crossbeam_channel::{self, Receiver, Sender};
+
+/// Application state, updated by messages.
+pub trait App: 'static {
+    /// Type of the messages changing the state.
+    type Msg: 'static;
+
+    /// Changes the state in response to a message.
+    fn update(&mut self, msg: Self::Msg);
+
+    /// Describes the interface for the current state.
+    ///
+    /// Callbacks can send messages with `dispatch`.
+    fn view(&self, dispatch: &Dispatcher<Self::Msg>) -> Node;
+}
+
+/// Sends messages to an [`App`].
+///
+/// Dispatchers can be cloned, and sent to other threads when the messages
+/// can. Sending a message wakes up the event loop, which then updates the
+/// application.
+pub struct Dispatcher<M> {
+    sender: Sender<M>,
+    cb_sink: CbSink,
+}
+
+impl<M> Clone for Dispatcher<M> {
+    fn clone(&self) -> Self {
+        Dispatcher {
+            sender: self.sender.clone(),
+            cb_sink: self.cb_sink.clone(),
+        }
+    }
+}
+
+impl<M: 'static> Dispatcher<M> {
+    /// Sends a message to the application.
+    ///
+    /// Does nothing if the application is gone.
+    pub fn send(&self, msg: M) {
+        if self.sender.send(msg).is_ok() {
+            // An empty callback is enough to trigger a refresh.
+            self.cb_sink.send(Box::new(|_| ())).ok();
+        }
+    }
+
+    /// Returns a callback sending `msg` every time it is called.
+    pub fn callback(&self, msg: M) -> impl Fn(&mut Cursive) + 'static
+    where
+        M: Clone,
+    {
+        let dispatch = self.clone();
+        move |_| dispatch.send(msg.clone())
+    }
+
+    /// Returns a callback sending the message built by `f`.
+    ///
+    /// Useful for callbacks with an argument, like `Node::checkbox`:
+    /// `Node::checkbox(done, dispatch.map(Msg::Done))`.
+    pub fn map<A, F>(&self, f: F) -> impl Fn(&mut Cursive, A) + 'static
+    where
+        F: Fn(A) -> M + 'static,
+    {
+        let dispatch = self.clone();
+        move |_, arg| dispatch.send(f(arg))
+    }
+}
+
+/// View running an [`App`].
+///
+/// Pending messages are processed every time the view is laid out.
+pub struct AppView<A: App> {
+    app: A,
+    dispatcher: Dispatcher<A::Msg>,
+    receiver: Receiver<A::Msg>,
+    view: VirtualView,
+}
+
+impl<A: App> AppView<A> {
+    /// Creates a new view running `app`.
+    ///
+    /// `cb_sink` is used to wake up the event loop when a message is sent,
+    /// usually from `Cursive::cb_sink`.
+    pub fn new(app: A, cb_sink: CbSink) -> Self {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let dispatcher = Dispatcher { sender, cb_sink };
+        let view = VirtualView::new(app.view(&dispatcher));
+        AppView {
+            app,
+            dispatcher,
+            receiver,
+            view,
+        }
+    }
+
+    /// Returns a dispatcher sending messages to the application.
+    pub fn dispatcher(&self) -> Dispatcher<A::Msg> {
+        self.dispatcher.clone()
+    }
+
+    /// Returns the application.
+    pub fn app(&self) -> &A {
+        &self.app
+    }
+
+    /// Processes pending messages, and updates the view if needed.
+    fn process_messages(&mut self) {
+        let mut changed = false;
+        for msg in self.receiver.try_iter() {
+            self.app.update(msg);
+            changed = true;
+        }
+        if changed {
+            self.view.update(self.app.view(&self.dispatcher));
+        }
+    }
+}
+
+impl<A: App> ViewWrapper for AppView<A> {
+    wrap_impl!(self.view: VirtualView);
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        self.process_messages();
+        self.view.required_size(req)
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.process_messages();
+        self.view.layout(size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::event::{Event, EventResult, Key};
+
+    struct Counter(i32);
+
+    impl App for Counter {
+        type Msg = i32;
+
+        fn update(&mut self, msg: i32) {
+            self.0 += msg;
+        }
+
+        fn view(&self, dispatch: &Dispatcher<i32>) -> Node {
+            Node::horizontal(vec![
+                Node::button("+", dispatch.callback(1)),
+                Node::text(self.0.to_string()),
+            ])
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let mut siv = Cursive::dummy();
+        let mut view = AppView::new(Counter(0), siv.cb_sink().clone());
+
+        view.dispatcher().send(2);
+        assert_eq!(render_to_buffer(&mut view, (5, 1)).lines(), ["<+>2 "]);
+
+        // Button callbacks send messages too.
+        let cb = match view.on_event(Event::Key(Key::Enter)) {
+            EventResult::Consumed(Some(cb)) => cb,
+            _ => panic!("the button should be pressed"),
+        };
+        cb(&mut siv);
+        assert_eq!(render_to_buffer(&mut view, (5, 1)).lines(), ["<+>3 "]);
+        assert_eq!(view.app().0, 3);
+    }
+}
@@ -8,10 +8,42 @@
 //! corresonding feature to be enabled.
 
 use crate::event::Event;
+use crate::rect::Rect;
 use crate::theme;
+use crate::utils::lines::simple::{prefix, suffix};
 use crate::Vec2;
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Returns the OSC 8 sequence starting a hyperlink to `url`, or ending it.
+///
+/// Control characters in `url` are percent-encoded, so they can't end the
+/// sequence early and reach the terminal as other escape sequences.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::backend::hyperlink_sequence;
+///
+/// assert_eq!(
+///     hyperlink_sequence(Some("https://a.b/\x1b]0;title\x07")),
+///     "\x1b]8;;https://a.b/%1B]0;title%07\x1b\\"
+/// );
+/// assert_eq!(hyperlink_sequence(None), "\x1b]8;;\x1b\\");
+/// ```
+pub fn hyperlink_sequence(url: Option<&str>) -> String {
+    let mut sequence = String::from("\x1b]8;;");
+    for c in url.unwrap_or("").chars() {
+        if c.is_ascii_control() {
+            sequence.push_str(&format!("%{:02X}", c as u8));
+        } else {
+            sequence.push(c);
+        }
+    }
+    sequence.push_str("\x1b\\");
+    sequence
+}
+
 /// Trait defining the required methods to be a backend.
 ///
 /// A backend is the interface between the abstract view tree and the actual
@@ -53,6 +85,14 @@ pub trait Backend {
     /// Should return `true` if this backend supports colors.
     fn has_colors(&self) -> bool;
 
+    /// Should return `true` if this backend can print 24-bit colors.
+    ///
+    /// Backends that downgrade `Color::Rgb` to a smaller palette should
+    /// return `false`, the default.
+    fn has_true_colors(&self) -> bool {
+        false
+    }
+
     /// Returns the screen size.
     fn screen_size(&self) -> Vec2;
 
@@ -98,6 +138,33 @@ pub trait Backend {
     /// Disables the given effect.
     fn unset_effect(&self, effect: theme::Effect);
 
+    /// Starts or stops a hyperlink.
+    ///
+    /// Any call to `print_at` from now on should link to `url`, until this
+    /// is called again with `None`.
+    ///
+    /// Terminal backends usually emit OSC 8 sequences, see
+    /// [`hyperlink_sequence`]. The default does nothing, printing links as
+    /// plain text.
+    fn set_link(&self, url: Option<&str>) {
+        let _ = url;
+    }
+
+    /// Rings the terminal bell.
+    ///
+    /// The default does nothing.
+    fn beep(&self) {}
+
+    /// Starts or stops reporting mouse moves with no button pressed.
+    ///
+    /// Reporting every move is costly, so it is only enabled when
+    /// something uses `MouseEvent::Move`, like focus-follows-mouse.
+    ///
+    /// The default does nothing.
+    fn set_mouse_motion(&self, enabled: bool) {
+        let _ = enabled;
+    }
+
     /// Returns a name to identify the backend.
     ///
     /// Mostly used for debugging.
@@ -106,6 +173,160 @@ pub trait Backend {
     }
 }
 
+/// Backend printing on another one with front and back colors swapped.
+///
+/// Used for the visual bell.
+pub(crate) struct Inverted<'a>(pub &'a dyn Backend);
+
+impl Backend for Inverted<'_> {
+    fn poll_event(&mut self) -> Option<Event> {
+        None
+    }
+
+    fn finish(&mut self) {}
+
+    fn refresh(&mut self) {}
+
+    fn has_colors(&self) -> bool {
+        self.0.has_colors()
+    }
+
+    fn has_true_colors(&self) -> bool {
+        self.0.has_true_colors()
+    }
+
+    fn screen_size(&self) -> Vec2 {
+        self.0.screen_size()
+    }
+
+    fn print_at(&self, pos: Vec2, text: &str) {
+        self.0.print_at(pos, text);
+    }
+
+    fn print_at_rep(&self, pos: Vec2, repetitions: usize, text: &str) {
+        self.0.print_at_rep(pos, repetitions, text);
+    }
+
+    fn clear(&self, color: theme::Color) {
+        self.0.clear(color);
+    }
+
+    fn set_color(&self, colors: theme::ColorPair) -> theme::ColorPair {
+        self.0.set_color(colors.invert()).invert()
+    }
+
+    fn set_effect(&self, effect: theme::Effect) {
+        self.0.set_effect(effect);
+    }
+
+    fn unset_effect(&self, effect: theme::Effect) {
+        self.0.unset_effect(effect);
+    }
+
+    fn set_link(&self, url: Option<&str>) {
+        self.0.set_link(url);
+    }
+
+    fn beep(&self) {
+        self.0.beep();
+    }
+
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+}
+
+/// Backend printing on another one, only inside the given area.
+///
+/// Used to draw part of the screen again.
+pub(crate) struct Clipped<'a> {
+    pub backend: &'a dyn Backend,
+    pub rect: Rect,
+}
+
+impl Backend for Clipped<'_> {
+    fn poll_event(&mut self) -> Option<Event> {
+        None
+    }
+
+    fn finish(&mut self) {}
+
+    fn refresh(&mut self) {}
+
+    fn has_colors(&self) -> bool {
+        self.backend.has_colors()
+    }
+
+    fn has_true_colors(&self) -> bool {
+        self.backend.has_true_colors()
+    }
+
+    fn screen_size(&self) -> Vec2 {
+        self.backend.screen_size()
+    }
+
+    fn print_at(&self, pos: Vec2, text: &str) {
+        let rect = self.rect;
+        let width = text.width();
+        if pos.y < rect.top()
+            || pos.y > rect.bottom()
+            || pos.x > rect.right()
+            || pos.x + width <= rect.left()
+        {
+            return;
+        }
+
+        let mut text = text;
+        let mut x = pos.x;
+        if x < rect.left() {
+            // Keep what's right of the left edge.
+            let tail =
+                suffix(text.graphemes(true), width - (rect.left() - x), "");
+            x += width - tail.width;
+            text = &text[text.len() - tail.length..];
+        }
+
+        let room = rect.right() + 1 - x;
+        let head = prefix(text.graphemes(true), room, "");
+        self.backend
+            .print_at(Vec2::new(x, pos.y), &text[..head.length]);
+    }
+
+    fn clear(&self, color: theme::Color) {
+        // Only clear our area, with spaces.
+        let colors = theme::ColorPair {
+            front: color,
+            back: color,
+        };
+        let old = self.backend.set_color(colors);
+        for y in self.rect.top()..=self.rect.bottom() {
+            let pos = Vec2::new(self.rect.left(), y);
+            self.backend.print_at_rep(pos, self.rect.width(), " ");
+        }
+        self.backend.set_color(old);
+    }
+
+    fn set_color(&self, colors: theme::ColorPair) -> theme::ColorPair {
+        self.backend.set_color(colors)
+    }
+
+    fn set_effect(&self, effect: theme::Effect) {
+        self.backend.set_effect(effect);
+    }
+
+    fn unset_effect(&self, effect: theme::Effect) {
+        self.backend.unset_effect(effect);
+    }
+
+    fn set_link(&self, url: Option<&str>) {
+        self.backend.set_link(url);
+    }
+
+    fn name(&self) -> &str {
+        self.backend.name()
+    }
+}
+
 /// Dummy backend that does nothing and immediately exits.
 ///
 /// Mostly used for testing.
@@ -0,0 +1,685 @@
+//! Offscreen rendering of views.
+//!
+//! Views normally draw directly on the backend. Instead, they can be drawn on
+//! a [`CellBuffer`], independently of any live backend. This can be used to
+//! pre-render widgets, to export a screen to text, or to take screenshots for
+//! documentation.
+//!
+//! A buffer can then be exported as plain text, HTML or ANSI-colored text
+//! with [`CellBuffer::export`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::buffer::render_to_buffer;
+//! use cursive_core::views::TextView;
+//!
+//! let mut view = TextView::new("Hello!");
+//! let buffer = render_to_buffer(&mut view, (10, 1));
+//!
+//! assert_eq!(buffer.lines(), vec!["Hello!    "]);
+//! ```
+use crate::backend::{hyperlink_sequence, Backend};
+use crate::event::Event;
+use crate::theme::{
+    self, Color, ColorPair, ColorStyle, Effect, Link, PaletteColor, Style,
+    Theme,
+};
+use crate::view::View;
+use crate::{Printer, Vec2};
+use enumset::EnumSet;
+use std::cell::{Cell as StdCell, RefCell};
+use std::fmt::{self, Write};
+use std::fs;
+use std::io;
+use std::path::Path;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A single character cell in a `CellBuffer`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    /// Grapheme printed on this cell.
+    ///
+    /// Empty if this cell is the continuation of a wide grapheme.
+    pub text: String,
+
+    /// Colors used for this cell.
+    pub colors: ColorPair,
+
+    /// Effects applied to this cell.
+    pub effects: EnumSet<Effect>,
+
+    /// Hyperlink on this cell, if any.
+    pub link: Option<Link>,
+}
+
+impl Cell {
+    /// Returns the width of the grapheme starting on this cell.
+    ///
+    /// Returns 0 for the continuation of a wide grapheme.
+    pub fn width(&self) -> usize {
+        self.text.width()
+    }
+
+    /// Prints this cell at the given position.
+    pub fn print(&self, printer: &Printer<'_, '_>, pos: Vec2) {
+        if self.text.is_empty() {
+            return;
+        }
+
+        let style = Style {
+            color: Some(ColorStyle::new(self.colors.front, self.colors.back)),
+            effects: self.effects,
+            link: self.link.clone(),
+            name: None,
+        };
+        printer.with_style(style, |printer| printer.print(pos, &self.text));
+    }
+}
+
+/// A rectangular grid of styled cells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellBuffer {
+    size: Vec2,
+
+    /// Cells, row by row. `None` if nothing was printed there.
+    cells: Vec<Option<Cell>>,
+}
+
+/// A screen captured with [`Cursive::screenshot`].
+///
+/// [`Cursive::screenshot`]: crate::Cursive::screenshot
+pub type ObservedScreen = CellBuffer;
+
+impl CellBuffer {
+    /// Creates a new empty buffer with the given size.
+    pub fn new(size: Vec2) -> Self {
+        CellBuffer {
+            size,
+            cells: vec![None; size.x * size.y],
+        }
+    }
+
+    /// Returns the size of this buffer.
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// Returns the cell at the given position, if anything was printed there.
+    pub fn get(&self, pos: Vec2) -> Option<&Cell> {
+        if !pos.strictly_lt(self.size) {
+            return None;
+        }
+        self.cells[pos.y * self.size.x + pos.x].as_ref()
+    }
+
+    fn set(&mut self, pos: Vec2, cell: Cell) {
+        if pos.strictly_lt(self.size) {
+            self.cells[pos.y * self.size.x + pos.x] = Some(cell);
+        }
+    }
+
+    /// Iterates on the printed cells, with their position.
+    pub fn cells(&self) -> impl Iterator<Item = (Vec2, &Cell)> {
+        let width = self.size.x;
+        self.cells.iter().enumerate().filter_map(move |(i, cell)| {
+            cell.as_ref()
+                .map(|cell| (Vec2::new(i % width, i / width), cell))
+        })
+    }
+
+    /// Returns the text content of each row, without any style.
+    ///
+    /// Cells where nothing was printed appear as spaces.
+    pub fn lines(&self) -> Vec<String> {
+        (0..self.size.y)
+            .map(|y| {
+                let mut line = String::new();
+                for x in 0..self.size.x {
+                    match self.get(Vec2::new(x, y)) {
+                        None => line.push(' '),
+                        Some(cell) => line.push_str(&cell.text),
+                    }
+                }
+                line
+            })
+            .collect()
+    }
+
+    /// Prints the entire buffer with the given printer.
+    pub fn draw(&self, printer: &Printer<'_, '_>) {
+        for (pos, cell) in self.cells() {
+            cell.print(printer, pos);
+        }
+    }
+
+    /// Exports the content of this buffer in the given format.
+    ///
+    /// Each row ends with a newline.
+    pub fn export(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Text => self.to_string(),
+            ExportFormat::Html => self.to_html(),
+            ExportFormat::Ansi => self.to_ansi(),
+        }
+    }
+
+    /// Writes the content of this buffer to a file, in the given format.
+    ///
+    /// The file is created, or overwritten if it exists.
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        path: P,
+        format: ExportFormat,
+    ) -> io::Result<()> {
+        fs::write(path, self.export(format))
+    }
+
+    /// Returns the rows as runs of graphemes sharing the same style.
+    fn runs(&self) -> Vec<Vec<Run>> {
+        let blank = Cell {
+            text: String::from(" "),
+            colors: ColorPair {
+                front: Color::TerminalDefault,
+                back: Color::TerminalDefault,
+            },
+            effects: EnumSet::new(),
+            link: None,
+        };
+
+        (0..self.size.y)
+            .map(|y| {
+                let mut runs: Vec<Run> = Vec::new();
+                for x in 0..self.size.x {
+                    let cell = self.get(Vec2::new(x, y)).unwrap_or(&blank);
+                    match runs.last_mut() {
+                        Some(run)
+                            if run.0 == cell.colors
+                                && run.1 == cell.effects
+                                && run.2 == cell.link =>
+                        {
+                            run.3.push_str(&cell.text)
+                        }
+                        _ => runs.push((
+                            cell.colors,
+                            cell.effects,
+                            cell.link.clone(),
+                            cell.text.clone(),
+                        )),
+                    }
+                }
+                runs
+            })
+            .collect()
+    }
+
+    fn to_html(&self) -> String {
+        let mut html = String::from("<pre>");
+        for line in self.runs() {
+            for (colors, effects, link, text) in line {
+                let href = link
+                    .as_ref()
+                    .map(Link::url)
+                    .filter(|url| is_safe_href(url));
+                if let Some(href) = href {
+                    write!(html, "<a href=\"{}\">", escape_html(href))
+                        .unwrap();
+                }
+
+                let (front, back) = if effects.contains(Effect::Reverse) {
+                    (colors.back, colors.front)
+                } else {
+                    (colors.front, colors.back)
+                };
+
+                let mut css = String::new();
+                if let Some((r, g, b)) = front.to_rgb() {
+                    write!(css, "color:#{:02x}{:02x}{:02x};", r, g, b)
+                        .unwrap();
+                }
+                if let Some((r, g, b)) = back.to_rgb() {
+                    write!(
+                        css,
+                        "background-color:#{:02x}{:02x}{:02x};",
+                        r, g, b
+                    )
+                    .unwrap();
+                }
+                if effects.contains(Effect::Bold) {
+                    css.push_str("font-weight:bold;");
+                }
+                if effects.contains(Effect::Italic) {
+                    css.push_str("font-style:italic;");
+                }
+                match (
+                    effects.contains(Effect::Underline),
+                    effects.contains(Effect::Strikethrough),
+                ) {
+                    (true, true) => {
+                        css.push_str("text-decoration:underline line-through;")
+                    }
+                    (true, false) => {
+                        css.push_str("text-decoration:underline;")
+                    }
+                    (false, true) => {
+                        css.push_str("text-decoration:line-through;")
+                    }
+                    (false, false) => (),
+                }
+
+                if css.is_empty() {
+                    html.push_str(&escape_html(&text));
+                } else {
+                    write!(
+                        html,
+                        "<span style=\"{}\">{}</span>",
+                        css,
+                        escape_html(&text)
+                    )
+                    .unwrap();
+                }
+                if href.is_some() {
+                    html.push_str("</a>");
+                }
+            }
+            html.push('\n');
+        }
+        html.push_str("</pre>\n");
+        html
+    }
+
+    fn to_ansi(&self) -> String {
+        let mut ansi = String::new();
+        for line in self.runs() {
+            for (colors, effects, link, text) in line {
+                let mut codes = vec![String::from("0")];
+                codes.extend(
+                    effects.iter().filter_map(ansi_effect).map(String::from),
+                );
+                codes.push(ansi_color(colors.front, false));
+                codes.push(ansi_color(colors.back, true));
+                write!(ansi, "\x1b[{}m", codes.join(";")).unwrap();
+                // OSC 8 hyperlink around the text.
+                match link {
+                    Some(link) => {
+                        ansi.push_str(&hyperlink_sequence(Some(link.url())));
+                        ansi.push_str(&text);
+                        ansi.push_str(&hyperlink_sequence(None));
+                    }
+                    None => ansi.push_str(&text),
+                }
+            }
+            ansi.push_str("\x1b[0m\n");
+        }
+        ansi
+    }
+}
+
+// Colors, effects and link shared by some text.
+type Run = (ColorPair, EnumSet<Effect>, Option<Link>, String);
+
+/// Format used to export a [`CellBuffer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// Plain text, without any style.
+    Text,
+
+    /// A HTML `<pre>` block, with inline CSS styles.
+    ///
+    /// Terminal colors are approximated with the usual xterm palette.
+    Html,
+
+    /// Text with ANSI escape codes for colors and effects.
+    ///
+    /// It can be printed on most terminals.
+    Ansi,
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+// Returns `false` if following the URL could run scripts.
+fn is_safe_href(url: &str) -> bool {
+    // Browsers ignore whitespace and control characters in the scheme.
+    let scheme: String = url
+        .chars()
+        .take_while(|&c| c != ':')
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .flat_map(char::to_lowercase)
+        .collect();
+    !(url.contains(':')
+        && matches!(scheme.as_str(), "javascript" | "vbscript" | "data"))
+}
+
+fn ansi_effect(effect: Effect) -> Option<&'static str> {
+    match effect {
+        Effect::Simple => None,
+        Effect::Bold => Some("1"),
+        Effect::Italic => Some("3"),
+        Effect::Underline => Some("4"),
+        Effect::Reverse => Some("7"),
+        Effect::Strikethrough => Some("9"),
+    }
+}
+
+fn ansi_color(color: Color, background: bool) -> String {
+    let offset = if background { 10 } else { 0 };
+    match color {
+        Color::TerminalDefault => format!("{}", 39 + offset),
+        Color::Dark(base) => {
+            format!("{}", 30 + offset + base.index())
+        }
+        Color::Light(base) => {
+            format!("{}", 90 + offset + base.index())
+        }
+        Color::Rgb(r, g, b) => {
+            format!("{};2;{};{};{}", 38 + offset, r, g, b)
+        }
+        Color::RgbLowRes(r, g, b) => {
+            format!("{};5;{}", 38 + offset, 16 + 36 * r + 6 * g + b)
+        }
+    }
+}
+
+impl fmt::Display for CellBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Lays out and draws the given view on a new buffer, using the default theme.
+///
+/// See [`render_to_buffer_with_theme`] to use a custom theme.
+pub fn render_to_buffer<S: Into<Vec2>>(
+    view: &mut dyn View,
+    size: S,
+) -> CellBuffer {
+    render_to_buffer_with_theme(view, size, &theme::load_default())
+}
+
+/// Lays out and draws the given view on a new buffer.
+///
+/// The buffer is first cleared with the theme's background color, like a
+/// regular screen would be.
+pub fn render_to_buffer_with_theme<S: Into<Vec2>>(
+    view: &mut dyn View,
+    size: S,
+    theme: &Theme,
+) -> CellBuffer {
+    let size = size.into();
+    let backend = BufferBackend::new(size);
+    backend.clear(theme.palette[PaletteColor::Background]);
+
+    view.layout(size);
+    view.draw(&Printer::new(size, theme, &backend));
+
+    backend.into_buffer()
+}
+
+/// A backend printing into a `CellBuffer`.
+///
+/// It never produces any input event, and can be given to a `Printer` to draw
+/// views offscreen.
+pub struct BufferBackend {
+    buffer: RefCell<CellBuffer>,
+    colors: StdCell<ColorPair>,
+    effects: StdCell<EnumSet<Effect>>,
+    link: RefCell<Option<Link>>,
+}
+
+impl BufferBackend {
+    /// Creates a new backend with an empty buffer of the given size.
+    pub fn new(size: Vec2) -> Self {
+        BufferBackend {
+            buffer: RefCell::new(CellBuffer::new(size)),
+            colors: StdCell::new(ColorPair {
+                front: Color::TerminalDefault,
+                back: Color::TerminalDefault,
+            }),
+            effects: StdCell::new(EnumSet::new()),
+            link: RefCell::new(None),
+        }
+    }
+
+    /// Consumes this backend and returns the printed buffer.
+    pub fn into_buffer(self) -> CellBuffer {
+        self.buffer.into_inner()
+    }
+}
+
+impl Backend for BufferBackend {
+    fn name(&self) -> &str {
+        "buffer"
+    }
+
+    fn poll_event(&mut self) -> Option<Event> {
+        None
+    }
+
+    fn finish(&mut self) {}
+
+    fn refresh(&mut self) {}
+
+    fn has_colors(&self) -> bool {
+        true
+    }
+
+    fn has_true_colors(&self) -> bool {
+        true
+    }
+
+    fn screen_size(&self) -> Vec2 {
+        self.buffer.borrow().size()
+    }
+
+    fn print_at(&self, pos: Vec2, text: &str) {
+        let colors = self.colors.get();
+        let effects = self.effects.get();
+        let link = self.link.borrow().clone();
+        let mut buffer = self.buffer.borrow_mut();
+
+        let mut x = pos.x;
+        for grapheme in text.graphemes(true) {
+            buffer.set(
+                Vec2::new(x, pos.y),
+                Cell {
+                    text: grapheme.to_string(),
+                    colors,
+                    effects,
+                    link: link.clone(),
+                },
+            );
+            // Wide graphemes also cover the next cells.
+            for i in 1..grapheme.width() {
+                buffer.set(
+                    Vec2::new(x + i, pos.y),
+                    Cell {
+                        text: String::new(),
+                        colors,
+                        effects,
+                        link: link.clone(),
+                    },
+                );
+            }
+            x += grapheme.width();
+        }
+    }
+
+    fn clear(&self, color: Color) {
+        let colors = ColorPair {
+            front: color,
+            back: color,
+        };
+        let mut buffer = self.buffer.borrow_mut();
+        for cell in &mut buffer.cells {
+            *cell = Some(Cell {
+                text: String::from(" "),
+                colors,
+                effects: EnumSet::new(),
+                link: None,
+            });
+        }
+    }
+
+    fn set_color(&self, colors: ColorPair) -> ColorPair {
+        self.colors.replace(colors)
+    }
+
+    fn set_effect(&self, effect: Effect) {
+        let mut effects = self.effects.get();
+        effects.insert(effect);
+        self.effects.set(effects);
+    }
+
+    fn unset_effect(&self, effect: Effect) {
+        let mut effects = self.effects.get();
+        effects.remove(effect);
+        self.effects.set(effects);
+    }
+
+    fn set_link(&self, url: Option<&str>) {
+        *self.link.borrow_mut() = url.map(Link::new);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::BaseColor;
+    use crate::views::{LinearLayout, TextView};
+
+    #[test]
+    fn render_layout() {
+        let mut view = LinearLayout::vertical()
+            .child(TextView::new("abc"))
+            .child(TextView::new("日本"));
+        let buffer = render_to_buffer(&mut view, (5, 3));
+
+        assert_eq!(buffer.lines(), vec!["abc  ", "日本 ", "     "]);
+
+        // Wide characters span two cells.
+        assert_eq!(buffer.get(Vec2::new(0, 1)).unwrap().width(), 2);
+        assert_eq!(buffer.get(Vec2::new(1, 1)).unwrap().width(), 0);
+        assert!(buffer.get(Vec2::new(5, 0)).is_none());
+    }
+
+    #[test]
+    fn export_html() {
+        let backend = BufferBackend::new(Vec2::new(4, 1));
+        backend.set_color(ColorPair {
+            front: Color::Rgb(255, 0, 0),
+            back: Color::TerminalDefault,
+        });
+        backend.print_at(Vec2::zero(), "<a>");
+        let buffer = backend.into_buffer();
+
+        assert_eq!(
+            buffer.export(ExportFormat::Html),
+            "<pre><span style=\"color:#ff0000;\">&lt;a&gt;</span> \n</pre>\n"
+        );
+    }
+
+    #[test]
+    fn export_ansi() {
+        let backend = BufferBackend::new(Vec2::new(2, 1));
+        backend.set_color(ColorPair {
+            front: Color::Dark(BaseColor::Red),
+            back: Color::Light(BaseColor::Blue),
+        });
+        backend.set_effect(Effect::Bold);
+        backend.print_at(Vec2::zero(), "ab");
+        let buffer = backend.into_buffer();
+
+        assert_eq!(
+            buffer.export(ExportFormat::Ansi),
+            "\x1b[0;1;31;104mab\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn export_links() {
+        let backend = BufferBackend::new(Vec2::new(2, 1));
+        backend.print_at(Vec2::zero(), "a");
+        backend.set_link(Some("https://example.com"));
+        backend.print_at(Vec2::new(1, 0), "b");
+        let buffer = backend.into_buffer();
+
+        assert_eq!(
+            buffer.export(ExportFormat::Ansi),
+            "\x1b[0;39;49ma\x1b[0;39;49m\x1b]8;;https://example.com\x1b\\b\x1b]8;;\x1b\\\x1b[0m\n"
+        );
+        assert_eq!(
+            buffer.export(ExportFormat::Html),
+            "<pre>a<a href=\"https://example.com\">b</a>\n</pre>\n"
+        );
+    }
+
+    #[test]
+    fn nested_links() {
+        let backend = BufferBackend::new(Vec2::new(3, 1));
+        let theme = Theme::default();
+        let printer = Printer::new((3, 1), &theme, &backend);
+        printer.with_link(Link::new("outer"), |printer| {
+            printer.with_link(Link::new("inner"), |printer| {
+                printer.print((0, 0), "a");
+            });
+            printer.print((1, 0), "b");
+        });
+        printer.print((2, 0), "c");
+        let buffer = backend.into_buffer();
+
+        let link = |x| buffer.get(Vec2::new(x, 0)).unwrap().link.clone();
+        assert_eq!(link(0), Some(Link::new("inner")));
+        assert_eq!(link(1), Some(Link::new("outer")));
+        assert_eq!(link(2), None);
+    }
+
+    #[test]
+    fn export_hostile_links() {
+        let backend = BufferBackend::new(Vec2::new(2, 1));
+        backend.set_link(Some("x\"><script>"));
+        backend.print_at(Vec2::zero(), "a");
+        backend.set_link(Some(" JavaScript:alert(1)"));
+        backend.print_at(Vec2::new(1, 0), "b");
+        let buffer = backend.into_buffer();
+
+        assert_eq!(
+            buffer.export(ExportFormat::Html),
+            "<pre><a href=\"x&quot;&gt;&lt;script&gt;\">a</a>b\n</pre>\n"
+        );
+    }
+
+    #[test]
+    fn export_control_characters_in_links() {
+        let backend = BufferBackend::new(Vec2::new(1, 1));
+        backend.set_link(Some("https://a.b/\x1b\\\x1b[2J\x07\x7f"));
+        backend.print_at(Vec2::zero(), "a");
+        let buffer = backend.into_buffer();
+
+        let ansi = buffer.export(ExportFormat::Ansi);
+        assert!(ansi.contains("\x1b]8;;https://a.b/%1B\\%1B[2J%07%7F\x1b\\a"));
+        // Only our own sequences remain.
+        assert_eq!(ansi.matches('\x1b').count(), 6);
+    }
+
+    #[test]
+    fn screenshot() {
+        let mut siv = crate::Cursive::new(|| {
+            Box::new(BufferBackend::new(Vec2::new(8, 3)))
+        });
+        siv.add_layer(TextView::new("Hi"));
+
+        let screen = siv.screenshot();
+        assert_eq!(screen.size(), Vec2::new(8, 3));
+        assert_eq!(screen.lines(), vec!["        ", "   Hi   ", "        "]);
+    }
+}
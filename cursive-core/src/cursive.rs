@@ -1,22 +1,48 @@
 use std::any::Any;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::VecDeque;
 use std::num::NonZeroU32;
 #[cfg(feature = "toml")]
 use std::path::Path;
-use std::time::Duration;
+use std::rc::Rc;
+#[cfg(feature = "theme-watch")]
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+#[cfg(feature = "theme-watch")]
+use std::thread;
+use std::time::{Duration, Instant};
 
 use crossbeam_channel::{self, Receiver, Sender};
+use unicode_width::UnicodeWidthStr;
 
-use crate::backend;
+use crate::align::{Align, VAlign};
+use crate::backend::{self, Backend};
+use crate::buffer;
 use crate::direction;
-use crate::event::{Event, EventResult};
-use crate::printer::Printer;
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent, SwipeDetector,
+};
+use crate::printer::{Bindings, DropZones};
+use crate::printer::{Printer, ViewAreas};
+use crate::testing::{self, UiCommand, UiCommandError};
 use crate::theme;
-use crate::view::{self, Finder, IntoBoxedView, Position, View};
-use crate::views::{self, LayerPosition};
+use crate::utils::{
+    markup::StyledString, notifications::NotificationCenter,
+    timeline::Timeline, timer, undo::UndoManager,
+};
+use crate::view::{self, Finder, IntoBoxedView, Nameable, Position, View};
+use crate::views::{self, Drag, LayerPosition};
 use crate::Vec2;
 
 static DEBUG_VIEW_NAME: &str = "_cursive_debug_view";
 
+static CONFIRM_QUIT_NAME: &str = "_cursive_confirm_quit";
+
+static TIMELINE_VIEW_NAME: &str = "_cursive_timeline_view";
+
 // How long we wait between two empty input polls
 const INPUT_POLL_DELAY_MS: u64 = 30;
 
@@ -53,8 +79,180 @@ pub struct Cursive {
     // Handle auto-refresh when no event is received.
     fps: Option<NonZeroU32>,
     boring_frame_count: u32,
+
+    // Ongoing animated theme change, if any.
+    theme_transition: Option<ThemeTransition>,
+
+    // Stops the thread watching the theme file, if any.
+    #[cfg(feature = "theme-watch")]
+    theme_watch: Option<Arc<AtomicBool>>,
+
+    // Known terminal background, used for relative palette colors.
+    terminal_background: Option<theme::Color>,
+
+    // Minimum contrast ratio enforced when drawing, if any.
+    min_contrast: Option<f32>,
+
+    // If `true`, moving the mouse over a view focuses it.
+    focus_follows_mouse: bool,
+
+    // Turns quick drags into swipe events, if set.
+    swipe_detector: Option<SwipeDetector>,
+
+    // Changes or drops events before they are dispatched.
+    event_remapper: Option<Box<EventRemapper>>,
+
+    // Themes to pick from when the color scheme changes.
+    #[cfg(feature = "color-scheme")]
+    theme_pair: Option<theme::ThemePair>,
+    #[cfg(feature = "color-scheme")]
+    color_scheme: Option<theme::ColorScheme>,
+
+    // Where named views were drawn in the last frame.
+    view_areas: ViewAreas,
+
+    // Value dragged with the mouse, if any.
+    drag: Option<Drag>,
+
+    // Where drop targets were drawn in the last frame.
+    drop_zones: DropZones,
+
+    // Set when a view asked for another frame, during the last one.
+    frame_request: Cell<bool>,
+
+    // Bindings of the views drawn in the last frame.
+    bindings: Bindings,
+
+    // Callbacks run around each frame.
+    pre_frame_hook: Option<Box<FrameHook>>,
+    post_frame_hook: Option<Box<FrameHook>>,
+
+    // Number and start time of the last frame.
+    frame_count: u64,
+    last_frame: Option<Instant>,
+
+    // Application-wide undo history.
+    undo_manager: UndoManager,
+
+    // Application-wide notification history.
+    notifications: NotificationCenter,
+
+    bell_policy: BellPolicy,
+    // When the visual bell ends, if it's on.
+    flash_until: Option<Instant>,
+
+    // Events received since `start_recording`, if recording.
+    recording: Option<testing::Recording>,
+
+    // Last frames drawn, for time-travel debugging.
+    timeline: Option<Timeline>,
+
+    // Scripted commands, run one per step.
+    commands: VecDeque<UiCommand>,
+    command_error: Option<UiCommandError>,
+
+    // Tells if quitting needs a confirmation.
+    quit_guard: Option<Box<QuitGuard>>,
+
+    high_contrast: bool,
+
+    // Name of the focused view, as of the last frame.
+    focused_name: Option<String>,
+    focus_callbacks: Vec<Box<FocusCallback>>,
+
+    // Transient messages, oldest first.
+    toasts: Vec<Toast>,
+    next_toast_id: u64,
+
+    // Called when layers are added to or removed from the active screen.
+    on_layer_push: Option<Box<LayerHook>>,
+    on_layer_pop: Option<Box<LayerHook>>,
+
+    // Set while input is blocked, with the event still allowed through.
+    busy: Option<Busy>,
+    busy_cancel: Option<(Event, Callback)>,
+}
+
+type FrameHook = dyn FnMut(&mut Cursive, &FrameInfo);
+type QuitGuard = dyn Fn(&mut Cursive) -> bool;
+type FocusCallback = dyn FnMut(&mut Cursive, &str);
+type LayerHook = dyn FnMut(&mut Cursive);
+type EventRemapper = dyn FnMut(Event) -> Option<Event>;
+
+/// Timing information about a frame.
+///
+/// Given to the callbacks set with [`Cursive::set_pre_frame_hook`] and
+/// [`Cursive::set_post_frame_hook`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameInfo {
+    /// Number of this frame, starting at 0.
+    pub frame: u64,
+
+    /// When this frame started.
+    pub start: Instant,
+
+    /// Time elapsed since the start of the previous frame.
+    ///
+    /// This is zero for the first frame.
+    pub delta: Duration,
+}
+
+/// How [`Cursive::bell`] signals something to the user.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum BellPolicy {
+    /// Rings the terminal bell, with [`Cursive::beep`].
+    #[default]
+    Audible,
+    /// Briefly inverts the screen colors, with [`Cursive::flash`].
+    Visual,
+    /// Does both.
+    Both,
+    /// Does nothing.
+    Silent,
+}
+
+/// How often the file given to `Cursive::watch_theme_file` is checked.
+#[cfg(feature = "theme-watch")]
+const THEME_WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long the visual bell lasts.
+const FLASH_DURATION: Duration = Duration::from_millis(100);
+
+/// Animated change from one theme to another.
+struct ThemeTransition {
+    from: theme::Theme,
+    to: theme::Theme,
+    start: Instant,
+    duration: Duration,
+}
+
+/// Transient message drawn above a screen.
+struct Toast {
+    id: u64,
+    screen: ScreenId,
+    position: Align,
+    until: Instant,
+    view: views::Layer<views::Panel<views::TextView>>,
+    // Size given by the last layout.
+    size: Vec2,
 }
 
+/// Message shown while the UI is busy.
+struct Busy {
+    message: Option<String>,
+    start: Instant,
+}
+
+/// Frames of the spinner shown while busy.
+const BUSY_FRAMES: [&str; 10] =
+    ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// How long each frame of the busy spinner lasts.
+const BUSY_FRAME_DURATION: Duration = Duration::from_millis(80);
+
+/// How much the UI is blended into the background while busy.
+const BUSY_DIM: f32 = 0.5;
+
 /// Identifies a screen in the cursive root.
 pub type ScreenId = usize;
 
@@ -117,6 +315,45 @@ impl Cursive {
             backend,
             fps: None,
             boring_frame_count: 0,
+            theme_transition: None,
+            #[cfg(feature = "theme-watch")]
+            theme_watch: None,
+            terminal_background: None,
+            min_contrast: None,
+            focus_follows_mouse: false,
+            swipe_detector: None,
+            event_remapper: None,
+            #[cfg(feature = "color-scheme")]
+            theme_pair: None,
+            #[cfg(feature = "color-scheme")]
+            color_scheme: None,
+            view_areas: ViewAreas::default(),
+            drag: None,
+            drop_zones: DropZones::default(),
+            frame_request: Cell::new(false),
+            bindings: Bindings::default(),
+            pre_frame_hook: None,
+            quit_guard: None,
+            post_frame_hook: None,
+            frame_count: 0,
+            last_frame: None,
+            undo_manager: UndoManager::new(),
+            notifications: NotificationCenter::new(),
+            bell_policy: BellPolicy::default(),
+            flash_until: None,
+            recording: None,
+            timeline: None,
+            commands: VecDeque::new(),
+            command_error: None,
+            high_contrast: false,
+            focused_name: None,
+            focus_callbacks: Vec::new(),
+            toasts: Vec::new(),
+            next_toast_id: 0,
+            on_layer_push: None,
+            on_layer_pop: None,
+            busy: None,
+            busy_cancel: None,
             user_data: Box::new(()),
         };
         cursive.reset_default_callbacks();
@@ -373,8 +610,239 @@ impl Cursive {
     }
 
     /// Sets the current theme.
+    ///
+    /// This cancels any ongoing animated theme change.
     pub fn set_theme(&mut self, theme: theme::Theme) {
-        self.theme = theme;
+        self.theme_transition = None;
+        self.theme = self.with_terminal_background(theme);
+        self.clear();
+    }
+
+    /// Sets the terminal background color.
+    ///
+    /// Palette entries defined relative to the terminal (see
+    /// [`theme::RelativeColor`]) are computed again, for the current theme
+    /// and for any theme set later.
+    ///
+    /// The background can be found with `theme::query_terminal_background`
+    /// before starting cursive, or from any other source.
+    pub fn set_terminal_background(&mut self, color: theme::Color) {
+        self.terminal_background = Some(color);
+        if let Some(ref mut transition) = self.theme_transition {
+            transition.to.palette.set_terminal_background(color);
+        }
+        self.theme.palette.set_terminal_background(color);
+        self.clear();
+    }
+
+    /// Returns the terminal background color, if it was set.
+    pub fn terminal_background(&self) -> Option<theme::Color> {
+        self.terminal_background
+    }
+
+    /// Sets a minimum contrast ratio for all drawn text.
+    ///
+    /// Front colors with a lower contrast ratio against their back color
+    /// are made lighter or darker when drawing. This helps keep the
+    /// application readable with any user theme. WCAG recommends at least
+    /// `4.5`.
+    ///
+    /// `None` disables this check (the default). It can also be enabled for
+    /// a single view with [`views::MinContrastView`].
+    pub fn set_min_contrast(&mut self, ratio: Option<f32>) {
+        self.min_contrast = ratio;
+        self.clear();
+    }
+
+    /// Returns the minimum contrast ratio, if any.
+    pub fn min_contrast(&self) -> Option<f32> {
+        self.min_contrast
+    }
+
+    /// Enables or disables the high-contrast mode.
+    ///
+    /// When enabled, the basic colors of the current theme are replaced
+    /// when drawing by [`theme::Palette::high_contrast`]. The theme itself
+    /// is unchanged, and is used again when this mode is disabled. See
+    /// [`theme::Theme::high_contrast`].
+    ///
+    /// Disabled by default.
+    pub fn set_high_contrast(&mut self, enabled: bool) {
+        self.high_contrast = enabled;
+        self.clear();
+    }
+
+    /// Returns `true` if the high-contrast mode is enabled.
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast
+    }
+
+    // Returns the theme used for drawing.
+    fn drawn_theme(&self) -> Cow<'_, theme::Theme> {
+        if self.high_contrast {
+            Cow::Owned(self.theme.high_contrast())
+        } else {
+            Cow::Borrowed(&self.theme)
+        }
+    }
+
+    /// Applies the known terminal background to `theme`.
+    fn with_terminal_background(
+        &self,
+        mut theme: theme::Theme,
+    ) -> theme::Theme {
+        if let Some(color) = self.terminal_background {
+            theme.palette.set_terminal_background(color);
+        }
+        theme
+    }
+
+    /// Sets the current theme, gradually fading the colors over `duration`.
+    ///
+    /// Palette colors are interpolated over the next frames, which avoids a
+    /// jarring flash when switching between dark and light modes at runtime.
+    ///
+    /// The new theme is applied instantly if the backend cannot print 24-bit
+    /// colors (see [`Backend::has_true_colors`]).
+    ///
+    /// [`Backend::has_true_colors`]: crate::backend::Backend::has_true_colors
+    pub fn set_theme_animated(
+        &mut self,
+        theme: theme::Theme,
+        duration: Duration,
+    ) {
+        if duration == Duration::from_secs(0)
+            || !self.backend.has_true_colors()
+        {
+            self.set_theme(theme);
+            return;
+        }
+
+        self.theme_transition = Some(ThemeTransition {
+            from: self.theme.clone(),
+            to: self.with_terminal_background(theme),
+            start: Instant::now(),
+            duration,
+        });
+    }
+
+    /// Detects the color scheme preferred by the terminal or the OS.
+    ///
+    /// See [`theme::detect_color_scheme`] for the detection methods.
+    ///
+    /// Requires the `color-scheme` feature.
+    #[cfg(feature = "color-scheme")]
+    pub fn detect_color_scheme(&self) -> Option<theme::ColorScheme> {
+        theme::detect_color_scheme()
+    }
+
+    /// Uses the theme from `pair` matching the preferred color scheme.
+    ///
+    /// The scheme follows the terminal background, if it was given to
+    /// [`Cursive::set_terminal_background`], or the system preference.
+    /// The dark theme is used if no preference can be detected. Call
+    /// [`Cursive::update_color_scheme`] later (for example from a timer or a
+    /// global callback) to switch themes if the preference changed.
+    ///
+    /// Returns the color scheme used.
+    ///
+    /// Requires the `color-scheme` feature.
+    #[cfg(feature = "color-scheme")]
+    pub fn set_theme_pair(
+        &mut self,
+        pair: theme::ThemePair,
+    ) -> theme::ColorScheme {
+        self.theme_pair = Some(pair);
+        self.update_color_scheme()
+            .unwrap_or(theme::ColorScheme::Dark)
+    }
+
+    /// Detects the color scheme again, and switches to the matching theme
+    /// from the pair given to [`Cursive::set_theme_pair`].
+    ///
+    /// The theme change is animated when possible.
+    ///
+    /// Returns `None` if no theme pair was set.
+    ///
+    /// Requires the `color-scheme` feature.
+    #[cfg(feature = "color-scheme")]
+    pub fn update_color_scheme(&mut self) -> Option<theme::ColorScheme> {
+        // The terminal knows best.
+        let scheme = self
+            .terminal_background
+            .and_then(theme::ColorScheme::from_background)
+            .or_else(|| self.detect_color_scheme())
+            .unwrap_or(theme::ColorScheme::Dark);
+        self.set_color_scheme(scheme).then_some(scheme)
+    }
+
+    /// Switches to the theme for `scheme`, from the pair given to
+    /// [`Cursive::set_theme_pair`].
+    ///
+    /// This ignores the detected preference, for example to let users
+    /// toggle between light and dark themes. The theme change is animated
+    /// when possible.
+    ///
+    /// Returns `false` if no theme pair was set.
+    ///
+    /// Requires the `color-scheme` feature.
+    #[cfg(feature = "color-scheme")]
+    pub fn set_color_scheme(&mut self, scheme: theme::ColorScheme) -> bool {
+        let theme = match self.theme_pair {
+            Some(ref pair) => pair.get(scheme).clone(),
+            None => return false,
+        };
+        self.color_scheme = Some(scheme);
+        let theme = self.with_terminal_background(theme);
+
+        let target = match self.theme_transition {
+            Some(ref transition) => &transition.to,
+            None => &self.theme,
+        };
+        if *target != theme {
+            self.set_theme_animated(theme, Duration::from_millis(300));
+        }
+
+        true
+    }
+
+    /// Returns the color scheme of the current theme pair, if any.
+    ///
+    /// Requires the `color-scheme` feature.
+    #[cfg(feature = "color-scheme")]
+    pub fn color_scheme(&self) -> Option<theme::ColorScheme> {
+        self.color_scheme
+    }
+
+    /// Returns `true` if an animated theme change is in progress.
+    pub fn is_theme_animating(&self) -> bool {
+        self.theme_transition.is_some()
+    }
+
+    /// Moves the ongoing theme transition, if any, to the current time.
+    fn update_theme_transition(&mut self) {
+        let t = match self.theme_transition {
+            Some(ref transition) => {
+                transition.start.elapsed().as_secs_f32()
+                    / transition.duration.as_secs_f32()
+            }
+            None => return,
+        };
+
+        if t >= 1.0 {
+            let transition = self.theme_transition.take().unwrap();
+            self.theme = transition.to;
+        } else {
+            let transition = self.theme_transition.as_ref().unwrap();
+            let palette = transition
+                .from
+                .palette
+                .interpolate(&transition.to.palette, t);
+            self.theme = theme::Theme {
+                palette,
+                ..transition.to.clone()
+            };
+        }
         self.clear();
     }
 
@@ -391,9 +859,21 @@ impl Cursive {
     /// Clears the screen.
     ///
     /// Users rarely have to call this directly.
+    ///
+    /// The active screen uses its own background, if it has one.
     pub fn clear(&mut self) {
-        self.backend
-            .clear(self.theme.palette[theme::PaletteColor::Background]);
+        self.backend.clear(self.background_color());
+        self.screen().invalidate_background();
+    }
+
+    // Returns the color behind the layers of the active screen.
+    fn background_color(&self) -> theme::Color {
+        let theme = self.drawn_theme();
+        let palette = &theme.palette;
+        match self.screen().get_background() {
+            Some(background) => background.color_type().resolve(palette),
+            None => palette[theme::PaletteColor::Background],
+        }
     }
 
     /// Loads a theme from the given file.
@@ -409,6 +889,77 @@ impl Cursive {
         theme::load_theme_file(filename).map(|theme| self.set_theme(theme))
     }
 
+    /// Loads a theme from the given file, and reloads it when it changes.
+    ///
+    /// A thread checks the file twice per second. When it is modified, the
+    /// new theme is sent through the [`cb_sink`](#method.cb_sink) and
+    /// applied. Invalid changes are logged and ignored, keeping the current
+    /// theme.
+    ///
+    /// Watching another file stops watching the previous one.
+    ///
+    /// Returns an error if the theme can't be loaded in the first place.
+    ///
+    /// Must have the `theme-watch` feature enabled.
+    #[cfg(feature = "theme-watch")]
+    pub fn watch_theme_file<P: AsRef<Path>>(
+        &mut self,
+        filename: P,
+    ) -> Result<(), theme::Error> {
+        // Metadata tells if the file changed since the last check.
+        fn metadata(path: &Path) -> Option<(std::time::SystemTime, u64)> {
+            let metadata = std::fs::metadata(path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        }
+
+        let path = filename.as_ref().to_path_buf();
+        let mut last = metadata(&path);
+        self.load_theme_file(&path)?;
+        self.unwatch_theme_file();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        self.theme_watch = Some(Arc::clone(&stop));
+        let cb_sink = self.cb_sink.clone();
+        thread::spawn(move || {
+            while !stop.load(Ordering::Relaxed) {
+                thread::sleep(THEME_WATCH_INTERVAL);
+                let current = metadata(&path);
+                if current == last {
+                    continue;
+                }
+                last = current;
+
+                let theme = match theme::load_theme_file(&path) {
+                    Ok(theme) => theme,
+                    Err(err) => {
+                        log::warn!(
+                            "Could not reload theme `{}`: {:?}",
+                            path.display(),
+                            err
+                        );
+                        continue;
+                    }
+                };
+                if cb_sink.send(Box::new(|s| s.set_theme(theme))).is_err() {
+                    // The cursive root is gone.
+                    break;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Stops watching the theme file given to
+    /// [`watch_theme_file`](#method.watch_theme_file).
+    ///
+    /// Must have the `theme-watch` feature enabled.
+    #[cfg(feature = "theme-watch")]
+    pub fn unwatch_theme_file(&mut self) {
+        if let Some(stop) = self.theme_watch.take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+    }
+
     /// Loads a theme from the given string content.
     ///
     /// Content must be valid toml.
@@ -436,105 +987,488 @@ impl Cursive {
         self.set_fps(if autorefresh { 30 } else { 0 });
     }
 
-    /// Returns a reference to the currently active screen.
-    pub fn screen(&self) -> &views::StackView {
-        self.root.get_inner().screen().unwrap()
+    /// Sets how [`bell`](#method.bell) signals something to the user.
+    ///
+    /// Defaults to [`BellPolicy::Audible`].
+    pub fn set_bell_policy(&mut self, policy: BellPolicy) {
+        self.bell_policy = policy;
     }
 
-    /// Returns a mutable reference to the currently active screen.
-    pub fn screen_mut(&mut self) -> &mut views::StackView {
-        self.root.get_inner_mut().screen_mut().unwrap()
+    /// Returns how [`bell`](#method.bell) signals something to the user.
+    pub fn bell_policy(&self) -> BellPolicy {
+        self.bell_policy
     }
 
-    /// Returns the id of the currently active screen.
-    pub fn active_screen(&self) -> ScreenId {
-        self.root.get_inner().active_screen()
+    /// Signals something to the user, like an error, following the bell
+    /// policy.
+    ///
+    /// Applications can call this instead of [`beep`](#method.beep) or
+    /// [`flash`](#method.flash), and let users choose what suits them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::BellPolicy;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_bell_policy(BellPolicy::Visual);
+    /// siv.bell();
+    /// assert!(siv.is_flashing());
+    /// ```
+    pub fn bell(&mut self) {
+        match self.bell_policy {
+            BellPolicy::Audible => self.beep(),
+            BellPolicy::Visual => self.flash(),
+            BellPolicy::Both => {
+                self.beep();
+                self.flash();
+            }
+            BellPolicy::Silent => (),
+        }
     }
 
-    /// Adds a new screen, and returns its ID.
-    pub fn add_screen(&mut self) -> ScreenId {
-        self.root
-            .get_inner_mut()
-            .add_screen(views::StackView::new())
+    /// Rings the terminal bell, if the backend supports it.
+    pub fn beep(&self) {
+        self.backend.beep();
     }
 
-    /// Convenient method to create a new screen, and set it as active.
-    pub fn add_active_screen(&mut self) -> ScreenId {
-        let res = self.add_screen();
-        self.set_screen(res);
-        res
+    /// Briefly inverts the colors of the whole screen.
+    ///
+    /// This is a visual bell, for users who can't hear or disabled the
+    /// audible one.
+    pub fn flash(&mut self) {
+        self.flash_until = Some(Instant::now() + FLASH_DURATION);
     }
 
-    /// Sets the active screen. Panics if no such screen exist.
-    pub fn set_screen(&mut self, screen_id: ScreenId) {
-        self.root.get_inner_mut().set_active_screen(screen_id);
+    /// Returns `true` if the screen is inverted by [`flash`](#method.flash).
+    pub fn is_flashing(&self) -> bool {
+        self.flash_until.is_some()
     }
 
-    /// Tries to find the view pointed to by the given selector.
+    /// Enables or disables focus-follows-mouse.
     ///
-    /// Runs a closure on the view once it's found, and return the
-    /// result.
+    /// When enabled, moving the mouse over a focusable view gives it the
+    /// focus, without a click. Views can opt out with
+    /// [`NoHoverFocus`](views::NoHoverFocus).
     ///
-    /// If the view is not found, or if it is not of the asked type,
-    /// returns None.
+    /// This requires a backend reporting mouse motion, like ncurses,
+    /// pancurses or BearLibTerminal.
+    ///
+    /// Disabled by default.
+    pub fn set_focus_follows_mouse(&mut self, enabled: bool) {
+        self.focus_follows_mouse = enabled;
+        self.backend.set_mouse_motion(enabled);
+    }
+
+    /// Returns `true` if focus-follows-mouse is enabled.
+    pub fn focus_follows_mouse(&self) -> bool {
+        self.focus_follows_mouse
+    }
+
+    /// Adds a callback called when another named view gets the focus.
+    ///
+    /// The callback is given the name of the innermost focused view, set
+    /// with [`Nameable::with_name`]. Giving descriptive names to the
+    /// interactive views lets a screen reader or a speech synthesizer
+    /// announce them.
+    ///
+    /// Focus changes are noticed after drawing each frame. Focusing an
+    /// unnamed view, or the menubar, is not reported.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use cursive_core::{Cursive, views, view};
-    /// # use cursive_core::traits::*;
-    /// let mut siv = Cursive::dummy();
+    /// use cursive_core::view::Nameable;
+    /// use cursive_core::views::{Button, LinearLayout};
     ///
-    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
-    ///
-    /// siv.add_global_callback('p', |s| {
-    ///     s.call_on(
-    ///         &view::Selector::Id("text"),
-    ///         |view: &mut views::TextView| {
-    ///             view.set_content("Text #2");
-    ///         },
-    ///     );
-    /// });
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.on_focus_change(|_, name| eprintln!("Focused: {}", name));
+    /// siv.add_layer(
+    ///     LinearLayout::vertical()
+    ///         .child(Button::new("Ok", |_| ()).with_name("Ok button"))
+    ///         .child(Button::new("Cancel", |_| ()).with_name("Cancel button")),
+    /// );
     /// ```
-    pub fn call_on<V, F, R>(
-        &mut self,
-        sel: &view::Selector<'_>,
-        callback: F,
-    ) -> Option<R>
+    pub fn on_focus_change<F>(&mut self, cb: F)
     where
-        V: View,
-        F: FnOnce(&mut V) -> R,
+        F: FnMut(&mut Cursive, &str) + 'static,
     {
-        self.root.call_on(sel, callback)
+        self.focus_callbacks.push(Box::new(cb));
     }
 
-    /// Tries to find the view identified by the given id.
+    /// Removes all callbacks added with [`Cursive::on_focus_change`].
+    pub fn clear_focus_change_callbacks(&mut self) {
+        self.focus_callbacks.clear();
+    }
+
+    /// Returns the name of the focused view, as of the last frame.
     ///
-    /// Convenient method to use `call_on` with a `view::Selector::Id`.
+    /// See [`Cursive::on_focus_change`].
+    pub fn focused_name(&self) -> Option<&str> {
+        self.focused_name.as_deref()
+    }
+
+    // Calls the focus callbacks if the focused named view changed.
+    fn check_focus_change(&mut self) {
+        let focused = self
+            .view_areas
+            .borrow()
+            .iter()
+            .filter(|(_, area)| area.is_focused())
+            .filter_map(|(name, area)| Some((name, area.visible_rect()?)))
+            .min_by_key(|(_, rect)| rect.surface())
+            .map(|(name, _)| name.clone());
+        if focused == self.focused_name {
+            return;
+        }
+        self.focused_name = focused.clone();
+
+        let name = match focused {
+            Some(name) => name,
+            None => return,
+        };
+        let mut callbacks = std::mem::take(&mut self.focus_callbacks);
+        for cb in &mut callbacks {
+            cb(self, &name);
+        }
+        // Callbacks may have added new ones.
+        callbacks.append(&mut self.focus_callbacks);
+        self.focus_callbacks = callbacks;
+    }
+
+    /// Sets the detector turning quick mouse drags into swipes.
+    ///
+    /// When set, an [`Event::Swipe`] follows the mouse release ending a
+    /// swipe. This is mostly useful on touch screens.
+    ///
+    /// Disabled by default.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use cursive_core::{Cursive, views};
-    /// # use cursive_core::traits::*;
-    /// let mut siv = Cursive::dummy();
+    /// use cursive_core::event::SwipeDetector;
     ///
-    /// siv.add_layer(views::TextView::new("Text #1")
-    ///                               .with_name("text"));
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_swipe_detector(Some(SwipeDetector::new()));
+    /// ```
+    pub fn set_swipe_detector(&mut self, detector: Option<SwipeDetector>) {
+        self.swipe_detector = detector;
+    }
+
+    /// Sets a function changing events before they are dispatched.
     ///
-    /// siv.add_global_callback('p', |s| {
-    ///     s.call_on_name("text", |view: &mut views::TextView| {
-    ///         view.set_content("Text #2");
-    ///     });
+    /// Every event given to [`on_event`](#method.on_event) goes through
+    /// `remapper` first, before the menubar, the views or the global
+    /// callbacks see it. It can return a different event, or `None` to
+    /// drop it. This makes user-configurable key bindings possible in a
+    /// single place.
+    ///
+    /// Recordings keep the events as received, before remapping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::{Event, Key};
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    ///
+    /// // Vim-style navigation everywhere.
+    /// siv.set_event_remapper(|event| {
+    ///     Some(match event {
+    ///         Event::Char('h') => Event::Key(Key::Left),
+    ///         Event::Char('j') => Event::Key(Key::Down),
+    ///         Event::Char('k') => Event::Key(Key::Up),
+    ///         Event::Char('l') => Event::Key(Key::Right),
+    ///         event => event,
+    ///     })
     /// });
     /// ```
-    pub fn call_on_name<V, F, R>(
-        &mut self,
-        name: &str,
-        callback: F,
-    ) -> Option<R>
+    pub fn set_event_remapper<F>(&mut self, remapper: F)
     where
-        V: View,
+        F: FnMut(Event) -> Option<Event> + 'static,
+    {
+        self.event_remapper = Some(Box::new(remapper));
+    }
+
+    /// Removes the function set with `set_event_remapper`.
+    pub fn clear_event_remapper(&mut self) {
+        self.event_remapper = None;
+    }
+
+    /// Sets a callback to run before each frame is drawn.
+    ///
+    /// It is called on every refresh, before the views are laid out, with
+    /// timing information about the frame. This can be used to advance a
+    /// simulation or an animation; use `set_fps` to get regular frames.
+    ///
+    /// Replaces any previous pre-frame hook.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::Cursive;
+    /// let mut siv = Cursive::dummy();
+    ///
+    /// siv.set_fps(30);
+    /// siv.set_pre_frame_hook(|s, frame| {
+    ///     // Move things by `frame.delta`...
+    /// });
+    /// ```
+    pub fn set_pre_frame_hook<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut Cursive, &FrameInfo) + 'static,
+    {
+        self.pre_frame_hook = Some(Box::new(cb));
+    }
+
+    /// Sets a callback to run after each frame is drawn.
+    ///
+    /// It gets the same information as the pre-frame hook; the time spent
+    /// on the frame is `frame.start.elapsed()`.
+    ///
+    /// Replaces any previous post-frame hook.
+    pub fn set_post_frame_hook<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut Cursive, &FrameInfo) + 'static,
+    {
+        self.post_frame_hook = Some(Box::new(cb));
+    }
+
+    /// Sets a callback to run when a layer is added to the active screen.
+    ///
+    /// This includes layers added with [`screen_mut`](#method.screen_mut).
+    /// The callback runs before the next frame is drawn, once per layer.
+    ///
+    /// Replaces any previous callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut siv = cursive_core::Cursive::dummy();
+    ///
+    /// // Only refresh the main view when no popup is shown.
+    /// siv.set_on_layer_push(|s| {
+    ///     if s.screen().len() > 1 {
+    ///         s.set_autorefresh(false);
+    ///     }
+    /// });
+    /// siv.set_on_layer_pop(|s| {
+    ///     if s.screen().len() <= 1 {
+    ///         s.set_autorefresh(true);
+    ///     }
+    /// });
+    /// ```
+    pub fn set_on_layer_push<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static,
+    {
+        self.on_layer_push = Some(Box::new(cb));
+    }
+
+    /// Sets a callback to run when a layer is removed from the active screen.
+    ///
+    /// See [`set_on_layer_push`](#method.set_on_layer_push).
+    pub fn set_on_layer_pop<F>(&mut self, cb: F)
+    where
+        F: FnMut(&mut Cursive) + 'static,
+    {
+        self.on_layer_pop = Some(Box::new(cb));
+    }
+
+    // Runs the layer hooks for the changes since the last frame.
+    fn run_layer_hooks(&mut self) {
+        let (pushed, popped) = self.screen_mut().take_layer_changes();
+
+        if let Some(mut cb) = self.on_layer_pop.take() {
+            for _ in 0..popped {
+                cb(self);
+            }
+            // The callback may have set a new one.
+            self.on_layer_pop.get_or_insert(cb);
+        }
+        if let Some(mut cb) = self.on_layer_push.take() {
+            for _ in 0..pushed {
+                cb(self);
+            }
+            self.on_layer_push.get_or_insert(cb);
+        }
+    }
+
+    /// Returns a reference to the currently active screen.
+    pub fn screen(&self) -> &views::StackView {
+        self.root.get_inner().screen().unwrap()
+    }
+
+    /// Returns a mutable reference to the currently active screen.
+    pub fn screen_mut(&mut self) -> &mut views::StackView {
+        self.root.get_inner_mut().screen_mut().unwrap()
+    }
+
+    /// Returns the id of the currently active screen.
+    pub fn active_screen(&self) -> ScreenId {
+        self.root.get_inner().active_screen()
+    }
+
+    /// Adds a new screen, and returns its ID.
+    pub fn add_screen(&mut self) -> ScreenId {
+        self.root
+            .get_inner_mut()
+            .add_screen(views::StackView::new())
+    }
+
+    /// Convenient method to create a new screen, and set it as active.
+    pub fn add_active_screen(&mut self) -> ScreenId {
+        let res = self.add_screen();
+        self.set_screen(res);
+        res
+    }
+
+    /// Sets the active screen. Panics if no such screen exist.
+    pub fn set_screen(&mut self, screen_id: ScreenId) {
+        self.root.get_inner_mut().set_active_screen(screen_id);
+        self.clear();
+    }
+
+    /// Tries to find the view pointed to by the given selector.
+    ///
+    /// Runs a closure on the view once it's found, and return the
+    /// result.
+    ///
+    /// If the view is not found, or if it is not of the asked type,
+    /// returns None.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views, view};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::dummy();
+    ///
+    /// siv.add_layer(views::TextView::new("Text #1").with_name("text"));
+    ///
+    /// siv.add_global_callback('p', |s| {
+    ///     s.call_on(
+    ///         &view::Selector::Id("text"),
+    ///         |view: &mut views::TextView| {
+    ///             view.set_content("Text #2");
+    ///         },
+    ///     );
+    /// });
+    /// ```
+    pub fn call_on<V, F, R>(
+        &mut self,
+        sel: &view::Selector<'_>,
+        callback: F,
+    ) -> Option<R>
+    where
+        V: View,
+        F: FnOnce(&mut V) -> R,
+    {
+        self.root.call_on(sel, callback)
+    }
+
+    /// Draws a single view again, without redrawing the whole screen.
+    ///
+    /// The frame goes through the same drawing as
+    /// [`refresh`](Cursive::refresh), including the minimum contrast and
+    /// the layers above the view, but only the area where the view was
+    /// drawn during the last refresh is sent to the terminal. This is much
+    /// cheaper for views updated often, like a gauge in a process monitor.
+    ///
+    /// The views are laid out first, but this should only be used when the
+    /// change doesn't affect the size of the view.
+    ///
+    /// Named views remember where they were drawn. For other selectors, or
+    /// if the layers were resized since, the entire screen is drawn again.
+    /// Returns `false` if no view matches the selector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views, view};
+    /// # use cursive_core::buffer::BufferBackend;
+    /// # use cursive_core::traits::*;
+    /// let mut siv =
+    ///     Cursive::new(|| Box::new(BufferBackend::new((40, 10).into())));
+    ///
+    /// siv.add_layer(views::ProgressBar::new().with_name("cpu"));
+    /// siv.refresh();
+    ///
+    /// siv.call_on_name("cpu", |bar: &mut views::ProgressBar| {
+    ///     bar.set_value(42);
+    /// });
+    /// assert!(siv.redraw_view(&view::Selector::Name("cpu")));
+    /// ```
+    pub fn redraw_view(&mut self, sel: &view::Selector<'_>) -> bool {
+        let mut found = false;
+        self.root.call_on_any(sel, &mut |_| found = true);
+        if !found {
+            return false;
+        }
+
+        // The new content may need new rows, like in a `TextView`.
+        self.layout();
+
+        let rect = match *sel {
+            #[allow(deprecated)]
+            view::Selector::Name(name) | view::Selector::Id(name) => self
+                .view_areas
+                .borrow()
+                .get(name)
+                .and_then(|area| area.visible_rect()),
+            view::Selector::Path(_) => None,
+        };
+
+        match rect {
+            Some(rect) if self.screen().layer_sizes() == self.last_sizes => {
+                // What's left of the view may be covered by the background.
+                self.screen().invalidate_background();
+
+                let clipped = backend::Clipped {
+                    backend: &*self.backend,
+                    rect,
+                };
+                match self.flash_until {
+                    Some(end) if Instant::now() < end => {
+                        self.draw_frame(&backend::Inverted(&clipped));
+                    }
+                    _ => self.draw_frame(&clipped),
+                }
+            }
+            _ => self.draw(),
+        }
+
+        self.backend.refresh();
+        true
+    }
+
+    /// Tries to find the view identified by the given id.
+    ///
+    /// Convenient method to use `call_on` with a `view::Selector::Id`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::{Cursive, views};
+    /// # use cursive_core::traits::*;
+    /// let mut siv = Cursive::dummy();
+    ///
+    /// siv.add_layer(views::TextView::new("Text #1")
+    ///                               .with_name("text"));
+    ///
+    /// siv.add_global_callback('p', |s| {
+    ///     s.call_on_name("text", |view: &mut views::TextView| {
+    ///         view.set_content("Text #2");
+    ///     });
+    /// });
+    /// ```
+    pub fn call_on_name<V, F, R>(
+        &mut self,
+        name: &str,
+        callback: F,
+    ) -> Option<R>
+    where
+        V: View,
         F: FnOnce(&mut V) -> R,
     {
         self.call_on(&view::Selector::Name(name), callback)
@@ -611,6 +1545,36 @@ impl Cursive {
         self.find_name(id)
     }
 
+    /// Removes the view identified by `name` from the tree.
+    ///
+    /// The view is detached from the closest view group holding it, like a
+    /// `LinearLayout`, a `ListView` or a layer of the `StackView`. This
+    /// returns the removed child, which may still wrap the named view.
+    ///
+    /// Returns `None` if no view group holds a view with this name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::traits::*;
+    /// use cursive_core::views::{Button, LinearLayout, TextView};
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.add_layer(
+    ///     LinearLayout::vertical()
+    ///         .child(TextView::new("Working...").with_name("status"))
+    ///         .child(Button::new("Hide", |s| {
+    ///             s.remove_view("status");
+    ///         })),
+    /// );
+    ///
+    /// assert!(siv.remove_view("status").is_some());
+    /// assert!(siv.remove_view("status").is_none());
+    /// ```
+    pub fn remove_view(&mut self, name: &str) -> Option<Box<dyn View>> {
+        self.root.remove_view(&view::Selector::Name(name))
+    }
+
     /// Moves the focus to the view identified by `name`.
     ///
     /// Convenient method to call `focus` with a [`view::Selector::Name`].
@@ -625,8 +1589,14 @@ impl Cursive {
     }
 
     /// Moves the focus to the view identified by `sel`.
+    ///
+    /// Views losing and getting the focus receive `Event::FocusLost` and
+    /// `Event::FocusGained`.
     pub fn focus(&mut self, sel: &view::Selector<'_>) -> Result<(), ()> {
-        self.root.focus_view(sel)
+        View::on_event(&mut self.root, Event::FocusLost).process(self);
+        let result = self.root.focus_view(sel);
+        View::on_event(&mut self.root, Event::FocusGained).process(self);
+        result
     }
 
     /// Adds a global callback.
@@ -748,14 +1718,43 @@ impl Cursive {
 
     /// This resets the default callbacks.
     ///
-    /// Currently this mostly includes exiting on Ctrl-C.
+    /// Currently this mostly includes exiting on Ctrl-C. Ctrl-C only asks
+    /// for a confirmation once a guard is set with
+    /// [`set_confirm_quit`](#method.set_confirm_quit).
     pub fn reset_default_callbacks(&mut self) {
-        self.set_on_pre_event(Event::CtrlChar('c'), |s| s.quit());
+        self.set_on_pre_event(Event::CtrlChar('c'), |s| {
+            if s.quit_guard.is_some() {
+                s.request_quit();
+            } else {
+                s.quit();
+            }
+        });
         self.set_on_pre_event(Event::Exit, |s| s.quit());
 
         self.set_on_pre_event(Event::WindowResize, |s| s.clear());
     }
 
+    /// Binds `Ctrl+Z` and `Ctrl+Y` to [`undo`](#method.undo) and
+    /// [`redo`](#method.redo), or removes these bindings.
+    ///
+    /// Views with their own history, like `TextArea`, still handle these
+    /// keys first while they have the focus.
+    ///
+    /// Disabled by default, leaving `Ctrl+Z` to the application.
+    pub fn set_global_undo(&mut self, enabled: bool) {
+        if enabled {
+            self.set_global_callback(Event::CtrlChar('z'), |s| {
+                s.undo();
+            });
+            self.set_global_callback(Event::CtrlChar('y'), |s| {
+                s.redo();
+            });
+        } else {
+            self.clear_global_callbacks(Event::CtrlChar('z'));
+            self.clear_global_callbacks(Event::CtrlChar('y'));
+        }
+    }
+
     /// Add a layer to the current screen.
     ///
     /// # Examples
@@ -799,10 +1798,188 @@ impl Cursive {
 
     /// Processes an event.
     ///
+    /// * The event remapper, if any, can change or drop it.
     /// * If the menubar is active, it will be handled the event.
     /// * The view tree will be handled the event.
     /// * If ignored, global_callbacks will be checked for this event.
     pub fn on_event(&mut self, event: Event) {
+        if let Some(ref mut recording) = self.recording {
+            recording.push(event.clone());
+        }
+        if let Some(ref mut timeline) = self.timeline {
+            timeline.record_event(&event);
+        }
+
+        let event = match self.event_remapper {
+            Some(ref mut remapper) => match remapper(event) {
+                Some(event) => event,
+                None => return,
+            },
+            None => event,
+        };
+
+        if self.busy.is_some() {
+            match event {
+                // A stuck worker must not prevent quitting.
+                Event::WindowResize
+                | Event::Refresh
+                | Event::Exit
+                | Event::CtrlChar('c') => (),
+                event => {
+                    let cancel = match self.busy_cancel {
+                        Some((ref cancel, ref cb)) if *cancel == event => {
+                            Some(cb.clone())
+                        }
+                        _ => None,
+                    };
+                    if let Some(cb) = cancel {
+                        cb(self);
+                    }
+                    return;
+                }
+            }
+        }
+
+        let swipe = self
+            .swipe_detector
+            .as_mut()
+            .and_then(|detector| detector.on_event(&event));
+
+        self.dispatch_event(event);
+
+        if let Some(direction) = swipe {
+            self.dispatch_event(Event::Swipe(direction));
+        }
+    }
+
+    /// Returns `true` if a value is being dragged with the mouse.
+    ///
+    /// See [`DragSource`](views::DragSource).
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Stops the current drag, if any, without dropping anything.
+    pub fn cancel_drag(&mut self) {
+        self.drag = None;
+    }
+
+    // Starts dragging `payload`, with the mouse at `position`.
+    pub(crate) fn start_drag_at<T: Any>(
+        &mut self,
+        payload: T,
+        ghost: String,
+        position: Vec2,
+    ) {
+        self.drag = Some(Drag {
+            payload: Box::new(payload),
+            ghost,
+            position,
+        });
+    }
+
+    // Moves or drops the dragged value.
+    //
+    // Returns `true` if the event was used by the drag.
+    fn on_drag_event(&mut self, event: &Event) -> bool {
+        let drag = match self.drag {
+            Some(ref mut drag) => drag,
+            None => return false,
+        };
+
+        match *event {
+            Event::Key(Key::Esc) => {
+                self.drag = None;
+                true
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(_),
+                ..
+            } => {
+                // The release was missed.
+                self.drag = None;
+                false
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(_),
+                position,
+                ..
+            } => {
+                self.drop_at(position);
+                true
+            }
+            Event::Mouse { position, .. } => {
+                drag.position = position;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // Drops the dragged value on the topmost target accepting it.
+    fn drop_at(&mut self, position: Vec2) {
+        let drag = match self.drag.take() {
+            Some(drag) => drag,
+            None => return,
+        };
+
+        let target = self
+            .drop_zones
+            .borrow()
+            .iter()
+            .rev()
+            .find(|zone| {
+                zone.area.contains(position) && (zone.accept)(&*drag.payload)
+            })
+            .map(|zone| (Rc::clone(&zone.on_drop), zone.origin));
+
+        if let Some((on_drop, origin)) = target {
+            on_drop(self, drag.payload, position.saturating_sub(origin));
+        }
+    }
+
+    // Reads the bound values from the user data, for the views drawn in the
+    // last frame.
+    //
+    // Returns `true` if a value changed since the last read.
+    fn sync_bindings(&self) -> bool {
+        let mut changed = false;
+        for binding in self.bindings.borrow().iter() {
+            changed |= binding.read(&*self.user_data);
+        }
+        changed
+    }
+
+    // Sends an event to the menubar or to the views.
+    fn dispatch_event(&mut self, event: Event) {
+        if self.on_drag_event(&event) {
+            return;
+        }
+
+        // Menu accelerators work from anywhere.
+        let shortcut = self.menubar.on_shortcut(&event).or_else(|| {
+            event
+                .without_numpad()
+                .and_then(|event| self.menubar.on_shortcut(&event))
+        });
+        if let Some(result) = shortcut {
+            result.process(self);
+            return;
+        }
+
+        let event = match event {
+            Event::Mouse {
+                offset,
+                position,
+                event: MouseEvent::Move,
+            } if self.focus_follows_mouse => Event::Mouse {
+                offset,
+                position,
+                event: MouseEvent::Hover,
+            },
+            event => event,
+        };
+
         if let Event::Mouse {
             event, position, ..
         } = event
@@ -817,13 +1994,27 @@ impl Cursive {
         }
 
         if self.menubar.receive_events() {
-            self.menubar.on_event(event).process(self);
+            let mut result = self.menubar.on_event(event.clone());
+            if let (EventResult::Ignored, Some(event)) =
+                (&result, event.without_numpad())
+            {
+                result = self.menubar.on_event(event);
+            }
+            result.process(self);
         } else {
             let offset = if self.menubar.autohide { 0 } else { 1 };
 
-            let result =
+            let mut result =
                 View::on_event(&mut self.root, event.relativized((0, offset)));
 
+            // Keypad keys can be handled like regular keys.
+            // This includes global callbacks, which are part of the root.
+            if let (EventResult::Ignored, Some(event)) =
+                (&result, event.without_numpad())
+            {
+                result = View::on_event(&mut self.root, event);
+            }
+
             if let EventResult::Consumed(Some(cb)) = result {
                 cb(self);
             }
@@ -840,6 +2031,11 @@ impl Cursive {
         let offset = if self.menubar.autohide { 0 } else { 1 };
         let size = size.saturating_sub((0, offset));
         self.root.layout(size);
+
+        for toast in &mut self.toasts {
+            toast.size = toast.view.required_size(size).or_min(size);
+            toast.view.layout(toast.size);
+        }
     }
 
     fn draw(&mut self) {
@@ -852,15 +2048,50 @@ impl Cursive {
             self.last_sizes = sizes;
         }
 
-        let printer =
-            Printer::new(self.screen_size(), &self.theme, &*self.backend);
-
-        let selected = self.menubar.receive_events();
+        match self.flash_until {
+            Some(end) if Instant::now() < end => {
+                self.draw_frame(&backend::Inverted(&*self.backend));
+            }
+            _ => {
+                self.flash_until = None;
+                self.draw_frame(&*self.backend);
+            }
+        }
+    }
+
+    /// Draws the menubar and the current screen on the given backend.
+    fn draw_frame(&self, backend: &dyn backend::Backend) {
+        self.view_areas.borrow_mut().clear();
+        self.drop_zones.borrow_mut().clear();
+        self.bindings.borrow_mut().clear();
+        self.frame_request.set(false);
+
+        let theme = self.drawn_theme();
+        let top_printer = Printer::new(backend.screen_size(), &theme, backend)
+            .min_contrast(self.min_contrast)
+            .track_view_areas(&self.view_areas)
+            .track_drop_zones(&self.drop_zones)
+            .track_bindings(&self.bindings)
+            .track_frame_requests(&self.frame_request);
+
+        // The UI fades out while busy.
+        let dimmed = self.busy.as_ref().map(|_| theme::Theme {
+            palette: theme.palette.dimmed(BUSY_DIM),
+            ..theme.as_ref().clone()
+        });
+        let printer = match dimmed {
+            Some(ref dimmed) => top_printer.theme(dimmed),
+            None => top_printer.clone(),
+        };
+
+        let selected = self.menubar.receive_events();
 
         // Print the stackview background before the menubar
         let offset = if self.menubar.autohide { 0 } else { 1 };
 
-        let sv_printer = printer.offset((0, offset)).focused(!selected);
+        let sv_printer = printer
+            .offset((0, offset))
+            .focused(!selected && self.busy.is_none());
         self.root.draw(&sv_printer);
 
         self.root.get_inner().draw_bg(&sv_printer);
@@ -876,6 +2107,574 @@ impl Cursive {
         // finally draw stackview layers
         // using variables from above
         self.root.get_inner().draw_fg(&sv_printer);
+
+        // Toasts float above the layers, without ever taking the focus.
+        self.draw_toasts(&sv_printer);
+
+        // The dragged value goes above everything.
+        if let Some(ref drag) = self.drag {
+            printer.with_color(theme::ColorStyle::highlight(), |printer| {
+                printer.print(drag.position, &drag.ghost);
+            });
+        }
+
+        if let Some(ref busy) = self.busy {
+            self.draw_busy(busy, &top_printer);
+        }
+    }
+
+    /// Renders the current screen into an in-memory buffer.
+    ///
+    /// The frame is laid out and drawn offscreen, exactly as it would be
+    /// drawn on the terminal, whatever the backend. The result can be
+    /// inspected in tests, or saved for bug reports or documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::TextView;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.add_layer(TextView::new("Hello!"));
+    ///
+    /// let screen = siv.screenshot();
+    /// assert_eq!(screen.size(), siv.screen_size());
+    /// ```
+    pub fn screenshot(&mut self) -> buffer::ObservedScreen {
+        self.layout();
+
+        let backend = buffer::BufferBackend::new(self.screen_size());
+        backend.clear(self.background_color());
+        self.screen().invalidate_background();
+        self.draw_frame(&backend);
+
+        backend.into_buffer()
+    }
+
+    /// Exports the current screen content in the given format.
+    ///
+    /// This is a shortcut for `screenshot().export(format)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::buffer::ExportFormat;
+    /// use cursive_core::views::TextView;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.add_layer(TextView::new("Hello!"));
+    ///
+    /// let html = siv.export_screen(ExportFormat::Html);
+    /// assert!(html.starts_with("<pre>"));
+    /// ```
+    pub fn export_screen(&mut self, format: buffer::ExportFormat) -> String {
+        self.screenshot().export(format)
+    }
+
+    /// Returns the application-wide undo history.
+    ///
+    /// See the [`undo`](crate::utils::undo) module.
+    pub fn undo_manager(&self) -> &UndoManager {
+        &self.undo_manager
+    }
+
+    /// Returns the application-wide undo history.
+    pub fn undo_manager_mut(&mut self) -> &mut UndoManager {
+        &mut self.undo_manager
+    }
+
+    /// Undoes the last recorded action.
+    ///
+    /// [`set_global_undo`](#method.set_global_undo) binds this to `Ctrl+Z`.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo_manager.take_undo() {
+            Some(mut entry) => {
+                entry.undo(self);
+                self.undo_manager.undone(entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Redoes the last undone action.
+    ///
+    /// [`set_global_undo`](#method.set_global_undo) binds this to `Ctrl+Y`.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.undo_manager.take_redo() {
+            Some(mut entry) => {
+                entry.redo(self);
+                self.undo_manager.redone(entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shows the undo history in a dialog.
+    ///
+    /// Selecting an entry undoes all actions up to this one.
+    pub fn show_undo_history(&mut self) {
+        let mut select = views::SelectView::new();
+        for (i, label) in self.undo_manager.history().enumerate() {
+            select.add_item(label, i + 1);
+        }
+
+        let dialog = if select.is_empty() {
+            views::Dialog::info("Nothing to undo.")
+        } else {
+            views::Dialog::around(select.on_submit(|s, &count: &usize| {
+                s.pop_layer();
+                for _ in 0..count {
+                    s.undo();
+                }
+            }))
+            .dismiss_button("Cancel")
+        };
+        self.add_layer(dialog.title("Undo history"));
+    }
+
+    /// Returns the application-wide notification history.
+    ///
+    /// See the [`notifications`](crate::utils::notifications) module.
+    pub fn notifications(&self) -> &NotificationCenter {
+        &self.notifications
+    }
+
+    /// Returns the application-wide notification history.
+    pub fn notifications_mut(&mut self) -> &mut NotificationCenter {
+        &mut self.notifications
+    }
+
+    /// Records a notification in the history, and returns its identifier.
+    pub fn notify<S: Into<String>>(
+        &mut self,
+        level: views::AlertLevel,
+        message: S,
+    ) -> u64 {
+        self.notifications.push(level, message)
+    }
+
+    /// Shows the notification history in a dialog.
+    ///
+    /// Notifications are marked as read, and can be dismissed one by one or
+    /// all at once.
+    pub fn show_notifications(&mut self) {
+        self.notifications.mark_all_read();
+        if self.notifications.is_empty() {
+            self.add_layer(
+                views::Dialog::info("No notifications.")
+                    .title("Notifications"),
+            );
+            return;
+        }
+
+        let palette = &self.theme.palette;
+        let mut select = views::SelectView::new();
+        for notification in self.notifications.iter() {
+            let time =
+                chrono::DateTime::<chrono::Local>::from(notification.time());
+            let level = notification.level();
+            let mut label =
+                StyledString::plain(format!("{} ", time.format("%H:%M")));
+            label.append_styled(level.glyph(), level.color(palette));
+            label.append_plain(format!(" {}", notification.message()));
+            select.add_item(label, notification.id());
+        }
+
+        let dialog = views::Dialog::around(views::ScrollView::new(
+            select.with_name("notifications"),
+        ))
+        .title("Notifications")
+        .button("Dismiss", |s| {
+            let id = s.call_on_name(
+                "notifications",
+                |select: &mut views::SelectView<u64>| {
+                    let i = select.selected_id()?;
+                    let id = *select.get_item(i)?.1;
+                    select.remove_item(i);
+                    Some(id)
+                },
+            );
+            if let Some(id) = id.flatten() {
+                s.notifications.dismiss(id);
+                if s.notifications.is_empty() {
+                    s.pop_layer();
+                }
+            }
+        })
+        .button("Clear", |s| {
+            s.notifications.clear();
+            s.pop_layer();
+        })
+        .dismiss_button("Close");
+        self.add_layer(dialog);
+    }
+
+    /// Shows a transient message above the active screen.
+    ///
+    /// The toast never takes the focus, and goes away after `duration`.
+    /// Toasts sharing a position are stacked, the oldest one closest to the
+    /// edge.
+    ///
+    /// Returns an identifier, to dismiss the toast early.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::align::Align;
+    /// use std::time::Duration;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.show_toast("Saved.", Duration::from_secs(2), Align::bot_right());
+    /// ```
+    pub fn show_toast<S: Into<StyledString>>(
+        &mut self,
+        content: S,
+        duration: Duration,
+        position: Align,
+    ) -> u64 {
+        let id = self.next_toast_id;
+        self.next_toast_id += 1;
+
+        self.toasts.push(Toast {
+            id,
+            screen: self.active_screen(),
+            position,
+            until: Instant::now() + duration,
+            view: views::Layer::new(views::Panel::new(views::TextView::new(
+                content,
+            ))),
+            size: Vec2::zero(),
+        });
+        id
+    }
+
+    /// Removes a toast before the end of its duration.
+    ///
+    /// Returns `false` if it was already gone.
+    pub fn dismiss_toast(&mut self, id: u64) -> bool {
+        let len = self.toasts.len();
+        self.toasts.retain(|toast| toast.id != id);
+        self.toasts.len() != len
+    }
+
+    /// Removes all toasts, on every screen.
+    pub fn clear_toasts(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Blocks input while some work is going on.
+    ///
+    /// While busy, the UI is dimmed, with a spinner and the optional message
+    /// in the center of the screen. Events are not given to the views, except
+    /// the one set with [`set_busy_cancel`](#method.set_busy_cancel).
+    /// `Event::Exit` and `Ctrl-C` are still handled, so the application can
+    /// always quit.
+    ///
+    /// The screen is refreshed right away, so the spinner shows even if the
+    /// work then blocks the event loop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_busy(true, Some("Saving..."));
+    /// assert!(siv.is_busy());
+    ///
+    /// // Long work...
+    ///
+    /// siv.set_busy(false, None);
+    /// ```
+    pub fn set_busy(&mut self, busy: bool, message: Option<&str>) {
+        if !busy {
+            self.busy = None;
+            return;
+        }
+
+        let start = self
+            .busy
+            .as_ref()
+            .map_or_else(Instant::now, |busy| busy.start);
+        self.busy = Some(Busy {
+            message: message.map(String::from),
+            start,
+        });
+        self.refresh();
+    }
+
+    /// Returns `true` if input is blocked by [`set_busy`](#method.set_busy).
+    pub fn is_busy(&self) -> bool {
+        self.busy.is_some()
+    }
+
+    /// Sets the event still handled while busy, to cancel the work.
+    ///
+    /// The callback usually stops a worker, then calls `set_busy(false, _)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::event::Key;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_busy_cancel(Key::Esc, |s| s.set_busy(false, None));
+    /// ```
+    pub fn set_busy_cancel<E, F>(&mut self, event: E, cb: F)
+    where
+        E: Into<Event>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.busy_cancel = Some((event.into(), Callback::from_fn(cb)));
+    }
+
+    /// Removes the event set with `set_busy_cancel`.
+    pub fn clear_busy_cancel(&mut self) {
+        self.busy_cancel = None;
+    }
+
+    // Draws the spinner and message in the center of the screen.
+    fn draw_busy(&self, busy: &Busy, printer: &Printer<'_, '_>) {
+        let elapsed = busy.start.elapsed().as_millis();
+        let frame = (elapsed / BUSY_FRAME_DURATION.as_millis()) as usize;
+        let mut text = BUSY_FRAMES[frame % BUSY_FRAMES.len()].to_string();
+        if let Some(ref message) = busy.message {
+            text.push(' ');
+            text.push_str(message);
+        }
+
+        let size = Vec2::new(text.width() + 4, 3);
+        let offset = printer.size.saturating_sub(size) / 2;
+        let printer = printer.offset(offset).cropped(size);
+        printer.with_color(theme::ColorStyle::primary(), |printer| {
+            for y in 0..printer.size.y {
+                printer.print_hline((0, y), printer.size.x, " ");
+            }
+            printer.print((2, 1), &text);
+        });
+        printer.print_box((0, 0), size, false);
+
+        // Keep the spinner moving.
+        printer.request_frame();
+    }
+
+    // Removes the toasts whose time is over.
+    //
+    // Returns `true` if any was removed.
+    fn expire_toasts(&mut self) -> bool {
+        let now = Instant::now();
+        let len = self.toasts.len();
+        self.toasts.retain(|toast| toast.until > now);
+        self.toasts.len() != len
+    }
+
+    // Draws the toasts of the active screen, stacked by position.
+    fn draw_toasts(&self, printer: &Printer<'_, '_>) {
+        let screen = self.active_screen();
+        let toasts: Vec<&Toast> = self
+            .toasts
+            .iter()
+            .filter(|toast| toast.screen == screen)
+            .collect();
+
+        for (i, toast) in toasts.iter().enumerate() {
+            // Height taken by older toasts at the same position.
+            let stacked: usize = toasts[..i]
+                .iter()
+                .filter(|other| other.position == toast.position)
+                .map(|other| other.size.y)
+                .sum();
+
+            let size = toast.size;
+            let x = toast.position.h.get_offset(size.x, printer.size.x);
+            let y = match toast.position.v {
+                VAlign::Top => stacked,
+                VAlign::Center => {
+                    VAlign::Center.get_offset(size.y, printer.size.y) + stacked
+                }
+                VAlign::Bottom => {
+                    printer.size.y.saturating_sub(stacked + size.y)
+                }
+            };
+            toast
+                .view
+                .draw(&printer.offset((x, y)).cropped(size).focused(false));
+        }
+    }
+
+    /// Starts keeping the last `frames` frames drawn.
+    ///
+    /// At most `frames` screens are kept in memory, and unchanged frames
+    /// without events are skipped.
+    ///
+    /// Each frame is captured with the events received before it. Restarts
+    /// from scratch if the timeline was already enabled. See the
+    /// [`timeline`](crate::utils::timeline) module.
+    pub fn enable_timeline(&mut self, frames: usize) {
+        self.timeline = Some(Timeline::new(frames));
+    }
+
+    /// Stops keeping frames, and returns the frames kept so far.
+    pub fn disable_timeline(&mut self) -> Option<Timeline> {
+        self.timeline.take()
+    }
+
+    /// Returns the frames kept, if the timeline is enabled.
+    pub fn timeline(&self) -> Option<&Timeline> {
+        self.timeline.as_ref()
+    }
+
+    /// Shows the frames kept in a fullscreen overlay.
+    ///
+    /// Capture is paused until the overlay is closed, so the overlay itself
+    /// is not kept. See [`views::TimelineView`] for the keys.
+    pub fn show_timeline(&mut self) {
+        let timeline = match self.timeline {
+            Some(ref mut timeline) if !timeline.is_empty() => timeline,
+            _ => {
+                self.add_layer(
+                    views::Dialog::info("No frame captured.")
+                        .title("Timeline"),
+                );
+                return;
+            }
+        };
+        if timeline.is_paused() {
+            // Already shown.
+            return;
+        }
+
+        timeline.set_paused(true);
+        let frames = timeline.frames().cloned().collect();
+        self.add_fullscreen_layer(views::NamedView::new(
+            TIMELINE_VIEW_NAME,
+            views::TimelineView::new(frames),
+        ));
+    }
+
+    /// Resumes capture, after the timeline overlay is closed.
+    pub(crate) fn resume_timeline(&mut self) {
+        if let Some(ref mut timeline) = self.timeline {
+            timeline.set_paused(false);
+        }
+    }
+
+    // Captures the frame just drawn, if the timeline is enabled.
+    fn record_timeline_frame(&mut self, number: u64) {
+        if matches!(self.timeline, Some(ref timeline) if !timeline.is_paused())
+        {
+            let screen = self.screenshot();
+            if let Some(ref mut timeline) = self.timeline {
+                timeline.record_frame(number, screen);
+            }
+        }
+    }
+
+    /// Starts recording the events given to `on_event`.
+    ///
+    /// Restarts from scratch if a recording was ongoing. See the
+    /// [`testing`] module to replay them.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(testing::Recording::new());
+    }
+
+    /// Stops recording events, and returns the events received so far.
+    ///
+    /// Returns an empty recording if none was ongoing.
+    pub fn stop_recording(&mut self) -> testing::Recording {
+        self.recording.take().unwrap_or_default()
+    }
+
+    /// Returns `true` if events are being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Queues commands to run, one per step of the event loop.
+    ///
+    /// If a command fails, the remaining ones are dropped, and the error
+    /// can be retrieved with [`take_command_error`]. See
+    /// [`testing::run_commands`] to run them in tests.
+    ///
+    /// [`take_command_error`]: #method.take_command_error
+    pub fn queue_commands<I>(&mut self, commands: I)
+    where
+        I: IntoIterator<Item = UiCommand>,
+    {
+        self.commands.extend(commands);
+    }
+
+    /// Returns `true` if some queued commands did not run yet.
+    pub fn has_queued_commands(&self) -> bool {
+        !self.commands.is_empty()
+    }
+
+    /// Returns the last failure of a queued command, if any.
+    pub fn take_command_error(&mut self) -> Option<UiCommandError> {
+        self.command_error.take()
+    }
+
+    // Runs the next queued command, if any.
+    //
+    // Returns `true` if a command was run.
+    fn run_next_command(&mut self) -> bool {
+        let command = match self.commands.pop_front() {
+            Some(command) => command,
+            None => return false,
+        };
+
+        if let Err(message) = self.run_command(&command) {
+            self.commands.clear();
+            self.command_error = Some(UiCommandError { command, message });
+        }
+        true
+    }
+
+    fn run_command(&mut self, command: &UiCommand) -> Result<(), String> {
+        match *command {
+            UiCommand::FocusName(ref name) => self
+                .focus_name(name)
+                .map_err(|()| format!("no focusable view named {}", name)),
+            UiCommand::TypeText(ref text) => {
+                for c in text.chars() {
+                    self.on_event(Event::Char(c));
+                }
+                Ok(())
+            }
+            UiCommand::PressKey(key) => {
+                self.on_event(Event::Key(key));
+                Ok(())
+            }
+            UiCommand::ClickName(ref name) => {
+                let position = match self.view_areas.borrow().get(name) {
+                    Some(area) => area.center(),
+                    None => return Err(format!("no view named {}", name)),
+                };
+                for &event in &[
+                    MouseEvent::Press(MouseButton::Left),
+                    MouseEvent::Release(MouseButton::Left),
+                ] {
+                    self.on_event(Event::Mouse {
+                        offset: Vec2::zero(),
+                        position,
+                        event,
+                    });
+                }
+                Ok(())
+            }
+            UiCommand::AssertText(ref text) => {
+                if testing::screen(self).iter().any(|line| line.contains(text))
+                {
+                    Ok(())
+                } else {
+                    Err(String::from("text not found on the screen"))
+                }
+            }
+        }
     }
 
     /// Returns `true` until [`quit(&mut self)`] is called.
@@ -909,6 +2708,67 @@ impl Cursive {
         }
     }
 
+    /// Runs the event loop as a future.
+    ///
+    /// This is the same polling loop as [`run(&mut self)`]: it processes
+    /// pending input and callbacks, and waits a few milliseconds when there
+    /// was nothing to do. The only difference is that this wait is a timer
+    /// future, so other tasks on the executor can run in the meantime. A
+    /// background thread, started by this method, drives the timer.
+    ///
+    /// Input is still polled: backends don't provide awaitable input, so
+    /// there is no future for individual events, and no integration with a
+    /// specific async runtime.
+    ///
+    /// The future is not `Send`, since backends usually aren't. With
+    /// `tokio`, run it on a `LocalSet`, or with `block_on` on a
+    /// current-thread runtime.
+    ///
+    /// Tasks running elsewhere can update the UI by sending callbacks
+    /// through [`cb_sink`]; they will run on the UI thread.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut siv = cursive::default();
+    ///
+    /// let sink = siv.cb_sink().clone();
+    /// tokio::spawn(async move {
+    ///     let body = fetch_data().await;
+    ///     sink.send(Box::new(move |s| {
+    ///         s.add_layer(Dialog::info(body));
+    ///     }))
+    ///     .unwrap();
+    /// });
+    ///
+    /// tokio::task::LocalSet::new()
+    ///     .run_until(siv.run_async())
+    ///     .await;
+    /// ```
+    ///
+    /// [`run(&mut self)`]: #method.run
+    /// [`cb_sink`]: #method.cb_sink
+    pub async fn run_async(&mut self) {
+        let timer = timer::Timer::new();
+
+        self.running = true;
+        self.refresh();
+
+        while self.running {
+            let received_something = self.process_events();
+            self.refresh_if_needed(received_something);
+
+            if received_something {
+                timer::yield_now().await;
+            } else {
+                timer
+                    .sleep(Duration::from_millis(INPUT_POLL_DELAY_MS))
+                    .await;
+                self.boring_frame_count += 1;
+            }
+        }
+    }
+
     /// Performs a single step from the event loop.
     ///
     /// Useful if you need tighter control on the event loop.
@@ -963,6 +2823,16 @@ impl Cursive {
             }
         }
 
+        // Scripted commands run after the events they may depend on.
+        if self.run_next_command() {
+            boring = false;
+        }
+
+        // Expired toasts need to be cleared from the screen.
+        if self.expire_toasts() {
+            boring = false;
+        }
+
         !boring
     }
 
@@ -977,11 +2847,24 @@ impl Cursive {
     /// [2]: Cursive::step()
     /// [3]: Cursive::process_events()
     pub fn post_events(&mut self, received_something: bool) {
+        self.refresh_if_needed(received_something);
+
+        if !received_something {
+            std::thread::sleep(Duration::from_millis(INPUT_POLL_DELAY_MS));
+            self.boring_frame_count += 1;
+        }
+    }
+
+    /// Refreshes the screen if something happened, or for the next frame.
+    fn refresh_if_needed(&mut self, received_something: bool) {
         let boring = !received_something;
         // How many times should we try if it's still boring?
         // Total duration will be INPUT_POLL_DELAY_MS * repeats
         // So effectively fps = 1000 / INPUT_POLL_DELAY_MS / repeats
         if !boring
+            || self.theme_transition.is_some()
+            || self.flash_until.is_some()
+            || self.frame_request.get()
             || self
                 .fps
                 .map(|fps| 1000 / INPUT_POLL_DELAY_MS as u32 / fps.get())
@@ -997,19 +2880,39 @@ impl Cursive {
 
             self.refresh();
         }
-
-        if boring {
-            std::thread::sleep(Duration::from_millis(INPUT_POLL_DELAY_MS));
-            self.boring_frame_count += 1;
-        }
     }
 
     /// Refresh the screen with the current view tree state.
     pub fn refresh(&mut self) {
         self.boring_frame_count = 0;
 
+        let start = Instant::now();
+        let frame = FrameInfo {
+            frame: self.frame_count,
+            start,
+            delta: self
+                .last_frame
+                .map(|last| start - last)
+                .unwrap_or_default(),
+        };
+        self.frame_count += 1;
+        self.last_frame = Some(start);
+
+        if let Some(mut hook) = self.pre_frame_hook.take() {
+            hook(self, &frame);
+            // The hook may have set a new one.
+            self.pre_frame_hook.get_or_insert(hook);
+        }
+
+        self.run_layer_hooks();
+
         // Do we need to redraw everytime?
         // Probably, actually.
+        self.update_theme_transition();
+
+        // Bound views apply the values from the user data on layout.
+        self.sync_bindings();
+
         // TODO: Do we need to re-layout everytime?
         self.layout();
 
@@ -1017,13 +2920,97 @@ impl Cursive {
         // (Is this getting repetitive? :p)
         self.draw();
         self.backend.refresh();
+
+        // Views drawn for the first time catch up on the next frame.
+        if self.sync_bindings() {
+            self.frame_request.set(true);
+        }
+        self.record_timeline_frame(frame.frame);
+
+        self.check_focus_change();
+
+        if let Some(mut hook) = self.post_frame_hook.take() {
+            hook(self, &frame);
+            self.post_frame_hook.get_or_insert(hook);
+        }
     }
 
     /// Stops the event loop.
+    ///
+    /// This never asks for confirmation. See
+    /// [`request_quit`](#method.request_quit) for user-initiated exits.
     pub fn quit(&mut self) {
         self.running = false;
     }
 
+    /// Asks to quit, with a confirmation if needed.
+    ///
+    /// If the guard set with [`set_confirm_quit`](#method.set_confirm_quit)
+    /// returns `true`, a dialog asks the user to confirm first. Otherwise,
+    /// or if the dialog is already shown, this quits immediately.
+    ///
+    /// This is what `Ctrl-C` does once a guard is set. Use it for other
+    /// quit paths triggered by the user, like a "Quit" menu entry.
+    pub fn request_quit(&mut self) {
+        let confirm = match self.quit_guard.take() {
+            Some(guard) => {
+                let confirm = guard(self);
+                self.quit_guard.get_or_insert(guard);
+                confirm
+            }
+            None => false,
+        };
+
+        if !confirm || self.debug_name(CONFIRM_QUIT_NAME).is_some() {
+            self.quit();
+            return;
+        }
+
+        self.add_layer(
+            views::Dialog::text("Quit anyway?")
+                .title("Unsaved changes")
+                .with_buttons(views::ButtonSet::YesNo)
+                .on_result(|s, result| {
+                    if result == views::DialogResult::Yes {
+                        s.quit();
+                    }
+                })
+                .with_name(CONFIRM_QUIT_NAME),
+        );
+    }
+
+    /// Sets a guard asking for a confirmation before quitting.
+    ///
+    /// `guard` is called when the user asks to quit, like with `Ctrl-C` or
+    /// [`request_quit`](#method.request_quit). If it returns `true`, for
+    /// example when there are unsaved changes, a dialog asks the user to
+    /// confirm. Pressing `Ctrl-C` again while it is shown quits anyway.
+    ///
+    /// Without a guard, `Ctrl-C` simply calls [`quit`](#method.quit), which
+    /// is never affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_user_data(false);
+    /// siv.set_confirm_quit(|s| {
+    ///     // Ask only when there are unsaved changes.
+    ///     s.user_data::<bool>().copied().unwrap_or(false)
+    /// });
+    /// ```
+    pub fn set_confirm_quit<F>(&mut self, guard: F)
+    where
+        F: Fn(&mut Cursive) -> bool + 'static,
+    {
+        self.quit_guard = Some(Box::new(guard));
+    }
+
+    /// Removes the guard set with `set_confirm_quit`.
+    pub fn clear_confirm_quit(&mut self) {
+        self.quit_guard = None;
+    }
+
     /// Does not do anything.
     pub fn noop(&mut self) {
         // foo
@@ -1039,6 +3026,469 @@ impl Cursive {
 
 impl Drop for Cursive {
     fn drop(&mut self) {
+        #[cfg(feature = "theme-watch")]
+        self.unwatch_theme_file();
         self.backend.finish();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Lets tests look at the screen without drawing it again.
+    struct Shared(Rc<buffer::BufferBackend>);
+
+    impl Backend for Shared {
+        fn poll_event(&mut self) -> Option<Event> {
+            None
+        }
+
+        fn finish(&mut self) {}
+
+        fn refresh(&mut self) {}
+
+        fn has_colors(&self) -> bool {
+            true
+        }
+
+        fn screen_size(&self) -> Vec2 {
+            self.0.screen_size()
+        }
+
+        fn print_at(&self, pos: Vec2, text: &str) {
+            self.0.print_at(pos, text);
+        }
+
+        fn clear(&self, color: theme::Color) {
+            self.0.clear(color);
+        }
+
+        fn set_color(&self, colors: theme::ColorPair) -> theme::ColorPair {
+            self.0.set_color(colors)
+        }
+
+        fn set_effect(&self, effect: theme::Effect) {
+            self.0.set_effect(effect);
+        }
+
+        fn unset_effect(&self, effect: theme::Effect) {
+            self.0.unset_effect(effect);
+        }
+    }
+
+    #[test]
+    fn redraw_view() {
+        let backend = Rc::new(buffer::BufferBackend::new(Vec2::new(6, 2)));
+        let shared = Rc::clone(&backend);
+        let mut siv = Cursive::new(move || Box::new(Shared(shared)));
+        siv.set_min_contrast(Some(4.5));
+        siv.add_fullscreen_layer(
+            views::LinearLayout::vertical()
+                .child(views::TextView::new("a").with_name("a"))
+                .child(views::TextView::new("b").with_name("b")),
+        );
+        siv.refresh();
+
+        for name in &["a", "b"] {
+            siv.call_on_name(name, |view: &mut views::TextView| {
+                view.set_content("x");
+            });
+        }
+        assert!(siv.redraw_view(&view::Selector::Name("a")));
+        assert!(!siv.redraw_view(&view::Selector::Name("c")));
+
+        // Only the first line was drawn again, like a full refresh would.
+        let expected = siv.screenshot();
+        drop(siv);
+        let screen = Rc::try_unwrap(backend).ok().unwrap().into_buffer();
+        assert_eq!(screen.lines(), ["x     ", "b     "]);
+        for x in 0..6 {
+            let pos = Vec2::new(x, 0);
+            assert_eq!(screen.get(pos), expected.get(pos));
+        }
+    }
+
+    #[test]
+    fn confirm_quit() {
+        let mut siv = testing::dummy((40, 10));
+        siv.on_event(Event::CtrlChar('c'));
+        assert!(!siv.is_running());
+
+        let mut siv = testing::dummy((40, 10));
+        siv.set_confirm_quit(|_| true);
+
+        siv.on_event(Event::CtrlChar('c'));
+        siv.refresh();
+        assert!(siv.is_running());
+        assert!(siv.debug_name(CONFIRM_QUIT_NAME).is_some());
+
+        // Answering "No" keeps the application running.
+        siv.on_event(Event::Char('n'));
+        assert!(siv.is_running());
+        assert!(siv.debug_name(CONFIRM_QUIT_NAME).is_none());
+
+        siv.on_event(Event::CtrlChar('c'));
+        siv.refresh();
+        siv.on_event(Event::Char('y'));
+        assert!(!siv.is_running());
+    }
+
+    #[test]
+    fn global_undo() {
+        let mut siv = testing::dummy((10, 3));
+        siv.set_user_data(1);
+        siv.undo_manager_mut().push_fn(
+            "Set",
+            |s| s.set_user_data(0),
+            |s| s.set_user_data(1),
+        );
+
+        // Ctrl+Z is left to the application by default.
+        siv.on_event(Event::CtrlChar('z'));
+        assert_eq!(siv.user_data::<i32>(), Some(&mut 1));
+
+        siv.set_global_undo(true);
+        siv.on_event(Event::CtrlChar('z'));
+        assert_eq!(siv.user_data::<i32>(), Some(&mut 0));
+        siv.on_event(Event::CtrlChar('y'));
+        assert_eq!(siv.user_data::<i32>(), Some(&mut 1));
+
+        siv.set_global_undo(false);
+        siv.on_event(Event::CtrlChar('z'));
+        assert_eq!(siv.user_data::<i32>(), Some(&mut 1));
+    }
+
+    #[test]
+    fn numpad_fallback() {
+        let mut siv = testing::dummy((10, 3));
+        siv.set_user_data(Vec::<&str>::new());
+        siv.add_global_callback(Key::Enter, |s| {
+            s.with_user_data(|v: &mut Vec<&str>| v.push("enter"));
+        });
+        siv.set_on_pre_event(Key::Home, |s| {
+            s.with_user_data(|v: &mut Vec<&str>| v.push("home"));
+        });
+        siv.add_global_callback(Key::NumpadDel, |s| {
+            s.with_user_data(|v: &mut Vec<&str>| v.push("numpad del"));
+        });
+        siv.add_layer(views::TextView::new("Hello"));
+
+        siv.on_event(Event::Key(Key::NumpadEnter));
+        siv.on_event(Event::Key(Key::NumpadHome));
+        // Callbacks bound to the keypad key itself come first.
+        siv.on_event(Event::Key(Key::NumpadDel));
+
+        assert_eq!(
+            siv.take_user_data::<Vec<&str>>().unwrap(),
+            ["enter", "home", "numpad del"]
+        );
+    }
+
+    #[test]
+    fn event_remapper() {
+        let mut siv = testing::dummy((10, 3));
+        siv.add_layer(views::EditView::new().with_name("edit"));
+        siv.set_event_remapper(|event| match event {
+            Event::Char('x') => None,
+            Event::Char('1') => Some(Event::Char('a')),
+            event => Some(event),
+        });
+        siv.start_recording();
+
+        for c in "1x1".chars() {
+            siv.on_event(Event::Char(c));
+        }
+        assert_eq!(siv.stop_recording().events().len(), 3);
+
+        let content = siv
+            .call_on_name("edit", |view: &mut views::EditView| {
+                view.get_content()
+            })
+            .unwrap();
+        assert_eq!(&*content, "aa");
+    }
+
+    #[test]
+    fn bell_policy() {
+        let mut siv = testing::dummy((10, 3));
+        siv.set_bell_policy(BellPolicy::Silent);
+        siv.bell();
+        assert!(!siv.is_flashing());
+
+        siv.set_bell_policy(BellPolicy::Visual);
+        siv.bell();
+        assert!(siv.is_flashing());
+        // The flash ends with the next frame after a while.
+        siv.flash_until = Some(Instant::now());
+        siv.refresh();
+        assert!(!siv.is_flashing());
+
+        // Colors are swapped while flashing.
+        siv.add_layer(views::TextView::new("Hi"));
+        let screen = siv.screenshot();
+        let inverted = buffer::BufferBackend::new(Vec2::new(10, 3));
+        siv.draw_frame(&backend::Inverted(&inverted));
+        let inverted = inverted.into_buffer();
+        let colors = |buffer: &buffer::CellBuffer| {
+            buffer.get(Vec2::new(4, 1)).unwrap().colors
+        };
+        assert_eq!(colors(&inverted), colors(&screen).invert());
+    }
+
+    #[cfg(feature = "theme-watch")]
+    #[test]
+    fn watch_theme_file() {
+        let path = std::env::temp_dir()
+            .join(format!("cursive-theme-{}.toml", std::process::id()));
+        std::fs::write(&path, "shadow = false").unwrap();
+
+        let mut siv = testing::dummy((10, 3));
+        siv.watch_theme_file(&path).unwrap();
+        assert!(!siv.current_theme().shadow);
+
+        std::fs::write(&path, "shadow = true").unwrap();
+        for _ in 0..50 {
+            if siv.current_theme().shadow {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+            siv.process_events();
+        }
+        siv.unwatch_theme_file();
+        std::fs::remove_file(&path).unwrap();
+        assert!(siv.current_theme().shadow);
+    }
+
+    #[cfg(feature = "color-scheme")]
+    #[test]
+    fn adaptive_theme() {
+        use theme::{Color, ColorScheme, Theme};
+
+        // Themes change with an animation.
+        fn shadow(siv: &Cursive) -> bool {
+            match siv.theme_transition {
+                Some(ref transition) => transition.to.shadow,
+                None => siv.theme.shadow,
+            }
+        }
+
+        let light = Theme {
+            shadow: false,
+            ..Theme::default()
+        };
+        let mut siv = testing::dummy((10, 3));
+        siv.set_terminal_background(Color::Rgb(250, 250, 250));
+        let scheme =
+            siv.set_theme_pair(Theme::adaptive(Theme::default(), light));
+        assert_eq!(scheme, ColorScheme::Light);
+        assert!(!shadow(&siv));
+
+        assert!(siv.set_color_scheme(ColorScheme::Dark));
+        assert_eq!(siv.color_scheme(), Some(ColorScheme::Dark));
+        assert!(shadow(&siv));
+    }
+
+    #[test]
+    fn accessibility() {
+        use crate::view::Nameable;
+        use theme::{BaseColor, Color};
+
+        let mut siv = testing::dummy((20, 4));
+        siv.set_user_data(Vec::<String>::new());
+        siv.on_focus_change(|s, name| {
+            s.with_user_data(|log: &mut Vec<String>| log.push(name.into()));
+        });
+        siv.add_fullscreen_layer(
+            views::LinearLayout::vertical()
+                .child(views::Button::new("a", |_| ()).with_name("first"))
+                .child(views::Button::new("b", |_| ()).with_name("second")),
+        );
+        siv.refresh();
+        siv.on_event(Event::Key(Key::Down));
+        siv.refresh();
+        siv.refresh();
+        assert_eq!(siv.focused_name(), Some("second"));
+        assert_eq!(
+            siv.take_user_data::<Vec<String>>().unwrap(),
+            ["first", "second"]
+        );
+
+        siv.set_high_contrast(true);
+        let screen = siv.screenshot();
+        let colors = screen.get(Vec2::new(0, 0)).unwrap().colors;
+        assert_eq!(colors.back, Color::Dark(BaseColor::Black));
+        // The theme itself is kept.
+        siv.set_high_contrast(false);
+        assert_eq!(siv.current_theme(), &theme::load_default());
+    }
+
+    #[test]
+    fn toasts() {
+        let mut siv = testing::dummy((20, 8));
+        let button = views::Button::new("ok", |_| ());
+        siv.add_fullscreen_layer(button.with_name("button"));
+
+        let corner = Align::bot_right();
+        siv.show_toast("Saved", Duration::from_secs(3600), corner);
+        let sent = siv.show_toast("Sent", Duration::from_secs(3600), corner);
+        siv.show_toast("Gone", Duration::from_secs(0), Align::top_left());
+        assert!(siv.process_events());
+
+        let lines = siv.screenshot().lines();
+        assert_eq!(lines[2], "              ┌────┐");
+        assert_eq!(lines[3], "              │Sent│");
+        assert_eq!(lines[6], "             │Saved│");
+        assert!(lines.iter().all(|line| !line.contains("Gone")));
+
+        // The screen keeps the focus.
+        assert!(siv.focus_name("button").is_ok());
+        assert!(siv.dismiss_toast(sent));
+        assert!(!siv.dismiss_toast(sent));
+    }
+
+    #[test]
+    fn menu_shortcuts() {
+        let mut siv = testing::dummy((30, 8));
+        siv.menubar().set_mnemonics(true).add_subtree(
+            "&File",
+            crate::menu::MenuTree::with_mnemonics()
+                .accelerated_leaf("&Save", Event::CtrlChar('s'), |s| {
+                    s.set_user_data("saved")
+                })
+                .leaf("&Quit", |s| s.set_user_data("quit")),
+        );
+        siv.menubar().add_subtree(
+            "&Edit",
+            crate::menu::MenuTree::new().leaf("Cut & paste", |_| ()),
+        );
+
+        // Accelerators work without opening the menu.
+        siv.on_event(Event::CtrlChar('s'));
+        assert_eq!(siv.take_user_data(), Some("saved"));
+
+        // Alt and a mnemonic open the menubar item.
+        siv.on_event(Event::AltChar('f'));
+        let lines = siv.screenshot().lines();
+        assert_eq!(lines[0].trim_end(), "  File  Edit");
+        assert_eq!(lines[2], "│ Save  Ctrl+S │              ");
+
+        // Mnemonics need `Alt`.
+        siv.on_event(Event::Char('q'));
+        assert_eq!(siv.take_user_data::<&str>(), None);
+        siv.on_event(Event::AltChar('q'));
+        assert_eq!(siv.take_user_data(), Some("quit"));
+        assert_eq!(siv.screen().len(), 0);
+
+        // Without mnemonics, `&` is printed as is.
+        siv.on_event(Event::AltChar('e'));
+        let lines = siv.screenshot().lines();
+        assert_eq!(lines[2].trim_end(), "      │ Cut & paste │");
+    }
+
+    #[test]
+    fn bindings() {
+        use crate::traits::{Nameable, Resizable};
+
+        struct Form {
+            agree: bool,
+            name: String,
+        }
+
+        let mut siv = testing::dummy((30, 5));
+        siv.set_user_data(Form {
+            agree: false,
+            name: "Ann".into(),
+        });
+        siv.add_layer(
+            views::LinearLayout::vertical()
+                .child(
+                    views::Checkbox::bound(
+                        |form: &Form| form.agree,
+                        |form, agree| form.agree = agree,
+                    )
+                    .with_name("agree"),
+                )
+                .child(
+                    views::EditView::bound(
+                        |form: &Form| form.name.clone(),
+                        |form, name| form.name = name,
+                    )
+                    .with_name("name")
+                    .fixed_width(10),
+                ),
+        );
+        siv.refresh();
+        siv.refresh();
+        let name = siv
+            .call_on_name("name", |v: &mut views::EditView| v.get_content());
+        assert_eq!(name.as_deref().map(String::as_str), Some("Ann"));
+
+        // Changes to the user data reach the views on the next frame.
+        siv.with_user_data(|form: &mut Form| form.agree = true);
+        siv.refresh();
+        let agree = siv.find_name::<views::Checkbox>("agree").unwrap();
+        assert!(agree.is_checked());
+        drop(agree);
+
+        // Edits are written back.
+        siv.focus_name("name").unwrap();
+        siv.on_event(Event::Char('e'));
+        siv.refresh();
+        let form = siv.user_data::<Form>().unwrap();
+        assert_eq!(form.name, "Anne");
+        assert!(form.agree);
+    }
+
+    #[test]
+    fn busy() {
+        let mut siv = testing::dummy((20, 5));
+        siv.add_layer(views::EditView::new().with_name("edit"));
+        siv.set_busy_cancel(Key::Esc, |s| s.set_busy(false, None));
+        siv.set_busy(true, Some("Saving"));
+
+        let lines = siv.screenshot().lines();
+        assert!(lines[2].contains("Saving"));
+
+        // Input is blocked, except the cancel binding.
+        siv.on_event(Event::Char('a'));
+        assert!(siv.is_busy());
+        siv.on_event(Event::Key(Key::Esc));
+        assert!(!siv.is_busy());
+        siv.on_event(Event::Char('b'));
+
+        let content = siv
+            .call_on_name("edit", |edit: &mut views::EditView| {
+                edit.get_content()
+            })
+            .unwrap();
+        assert_eq!(&*content, "b");
+
+        // Quitting still works.
+        siv.set_busy(true, None);
+        siv.on_event(Event::CtrlChar('c'));
+        assert!(!siv.is_running());
+    }
+
+    #[test]
+    fn frame_hooks() {
+        let mut siv = testing::dummy((10, 5));
+        siv.set_user_data(Vec::<(&str, u64)>::new());
+        siv.set_pre_frame_hook(|s, frame| {
+            if frame.frame == 0 {
+                assert_eq!(frame.delta, Duration::from_secs(0));
+            }
+            s.with_user_data(|v: &mut Vec<_>| v.push(("pre", frame.frame)));
+        });
+        siv.set_post_frame_hook(|s, frame| {
+            s.with_user_data(|v: &mut Vec<_>| v.push(("post", frame.frame)));
+        });
+
+        siv.refresh();
+        siv.refresh();
+        assert_eq!(
+            siv.user_data::<Vec<(&str, u64)>>().unwrap(),
+            &vec![("pre", 0), ("post", 0), ("pre", 1), ("post", 1)]
+        );
+    }
+}
@@ -217,7 +217,7 @@ impl Relative {
 }
 
 /// Absolute direction (up, down, left, right).
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Absolute {
     /// Left
     Left,
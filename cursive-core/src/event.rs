@@ -12,11 +12,13 @@
 //! [`on_event`]: crate::View::on_event
 //! [global callback]: crate::Cursive::add_global_callback
 
+use crate::direction::Absolute;
 use crate::Cursive;
 use crate::Vec2;
 use std::any::Any;
 use std::ops::Deref;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 /// Callback is a function that can be triggered by an event.
 /// It has a mutable access to the cursive root.
@@ -39,8 +41,18 @@ pub struct EventTrigger {
     tag: Box<dyn AnyTag>,
 }
 
-trait AnyTag: Any + std::fmt::Debug {}
-impl<T> AnyTag for T where T: Any + std::fmt::Debug {}
+trait AnyTag: Any + std::fmt::Debug {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T> AnyTag for T
+where
+    T: Any + std::fmt::Debug,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
 impl EventTrigger {
     /// Create a new `EventTrigger` using the given function as filter.
@@ -65,7 +77,8 @@ impl EventTrigger {
 
     /// Check if this trigger has the given tag.
     pub fn has_tag<T: PartialEq + 'static>(&self, tag: &T) -> bool {
-        Any::downcast_ref::<T>(&self.tag).map_or(false, |t| tag == t)
+        // Deref the box first: the box itself is also an `AnyTag`.
+        (*self.tag).as_any().downcast_ref::<T>() == Some(tag)
     }
 
     /// Checks if this trigger applies to the given `Event`.
@@ -181,6 +194,11 @@ impl Callback {
     pub fn dummy() -> Self {
         Callback::from_fn(|_| ())
     }
+
+    /// Returns `true` if both callbacks are clones of the same one.
+    pub(crate) fn ptr_eq(&self, other: &Callback) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl Deref for Callback {
@@ -280,7 +298,9 @@ impl EventResult {
 /// A non-character key on the keyboard
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
 pub enum Key {
-    /// Both Enter (or Return) and numpad Enter
+    /// Enter (or Return) key
+    ///
+    /// This is also the numpad Enter when the backend can't tell them apart.
     Enter,
     /// Tabulation key
     Tab,
@@ -317,6 +337,60 @@ pub enum Key {
     /// The 5 in the center of the keypad, when numlock is disabled.
     NumpadCenter,
 
+    /// Enter key on the keypad
+    NumpadEnter,
+    /// Up arrow on the keypad (8 when numlock is disabled)
+    NumpadUp,
+    /// Down arrow on the keypad (2 when numlock is disabled)
+    NumpadDown,
+    /// Left arrow on the keypad (4 when numlock is disabled)
+    NumpadLeft,
+    /// Right arrow on the keypad (6 when numlock is disabled)
+    NumpadRight,
+    /// Home key on the keypad (7 when numlock is disabled)
+    NumpadHome,
+    /// End key on the keypad (1 when numlock is disabled)
+    NumpadEnd,
+    /// Page Up key on the keypad (9 when numlock is disabled)
+    NumpadPageUp,
+    /// Page Down key on the keypad (3 when numlock is disabled)
+    NumpadPageDown,
+    /// Insert key on the keypad (0 when numlock is disabled)
+    NumpadIns,
+    /// Delete key on the keypad (. when numlock is disabled)
+    NumpadDel,
+
+    /// 0 on the keypad, when numlock is enabled
+    Numpad0,
+    /// 1 on the keypad, when numlock is enabled
+    Numpad1,
+    /// 2 on the keypad, when numlock is enabled
+    Numpad2,
+    /// 3 on the keypad, when numlock is enabled
+    Numpad3,
+    /// 4 on the keypad, when numlock is enabled
+    Numpad4,
+    /// 5 on the keypad, when numlock is enabled
+    Numpad5,
+    /// 6 on the keypad, when numlock is enabled
+    Numpad6,
+    /// 7 on the keypad, when numlock is enabled
+    Numpad7,
+    /// 8 on the keypad, when numlock is enabled
+    Numpad8,
+    /// 9 on the keypad, when numlock is enabled
+    Numpad9,
+    /// Decimal point on the keypad, when numlock is enabled
+    NumpadDecimal,
+    /// `+` key on the keypad
+    NumpadAdd,
+    /// `-` key on the keypad
+    NumpadSubtract,
+    /// `*` key on the keypad
+    NumpadMultiply,
+    /// `/` key on the keypad
+    NumpadDivide,
+
     /// F0 key
     F0,
     /// F1 key
@@ -343,6 +417,30 @@ pub enum Key {
     F11,
     /// F12 key
     F12,
+    /// F13 key
+    F13,
+    /// F14 key
+    F14,
+    /// F15 key
+    F15,
+    /// F16 key
+    F16,
+    /// F17 key
+    F17,
+    /// F18 key
+    F18,
+    /// F19 key
+    F19,
+    /// F20 key
+    F20,
+    /// F21 key
+    F21,
+    /// F22 key
+    F22,
+    /// F23 key
+    F23,
+    /// F24 key
+    F24,
 }
 
 impl Key {
@@ -352,7 +450,7 @@ impl Key {
     ///
     /// # Panics
     ///
-    /// If `n == 0 || n > 12`
+    /// If `n > 24`
     pub fn from_f(n: u8) -> Key {
         match n {
             0 => Key::F0,
@@ -368,9 +466,90 @@ impl Key {
             10 => Key::F10,
             11 => Key::F11,
             12 => Key::F12,
+            13 => Key::F13,
+            14 => Key::F14,
+            15 => Key::F15,
+            16 => Key::F16,
+            17 => Key::F17,
+            18 => Key::F18,
+            19 => Key::F19,
+            20 => Key::F20,
+            21 => Key::F21,
+            22 => Key::F22,
+            23 => Key::F23,
+            24 => Key::F24,
             _ => panic!("unknown function key: F{}", n),
         }
     }
+
+    /// Returns the digit key of the keypad for the given number.
+    ///
+    /// 0 -> Numpad0, etc...
+    ///
+    /// # Panics
+    ///
+    /// If `n > 9`
+    pub fn from_numpad(n: u8) -> Key {
+        match n {
+            0 => Key::Numpad0,
+            1 => Key::Numpad1,
+            2 => Key::Numpad2,
+            3 => Key::Numpad3,
+            4 => Key::Numpad4,
+            5 => Key::Numpad5,
+            6 => Key::Numpad6,
+            7 => Key::Numpad7,
+            8 => Key::Numpad8,
+            9 => Key::Numpad9,
+            _ => panic!("unknown numpad digit: {}", n),
+        }
+    }
+
+    /// Returns the regular key matching this keypad key.
+    ///
+    /// For example, `NumpadLeft` gives `Left`, and `NumpadEnter` gives
+    /// `Enter`. Returns `None` for other keys, including digits and
+    /// operators (see [`Key::numpad_char`]).
+    pub fn without_numpad(self) -> Option<Key> {
+        Some(match self {
+            Key::NumpadEnter => Key::Enter,
+            Key::NumpadUp => Key::Up,
+            Key::NumpadDown => Key::Down,
+            Key::NumpadLeft => Key::Left,
+            Key::NumpadRight => Key::Right,
+            Key::NumpadHome => Key::Home,
+            Key::NumpadEnd => Key::End,
+            Key::NumpadPageUp => Key::PageUp,
+            Key::NumpadPageDown => Key::PageDown,
+            Key::NumpadIns => Key::Ins,
+            Key::NumpadDel => Key::Del,
+            _ => return None,
+        })
+    }
+
+    /// Returns the character typed by this keypad key.
+    ///
+    /// Returns `None` for keys not typing a character.
+    pub fn numpad_char(self) -> Option<char> {
+        Some(match self {
+            Key::Numpad0 => '0',
+            Key::Numpad1 => '1',
+            Key::Numpad2 => '2',
+            Key::Numpad3 => '3',
+            Key::Numpad4 => '4',
+            Key::Numpad5 => '5',
+            Key::Numpad6 => '6',
+            Key::Numpad7 => '7',
+            Key::Numpad8 => '8',
+            Key::Numpad9 => '9',
+            Key::NumpadDecimal => '.',
+            Key::NumpadAdd => '+',
+            Key::NumpadSubtract => '-',
+            Key::NumpadMultiply => '*',
+            Key::NumpadDivide => '/',
+            _ => return None,
+        })
+    }
 }
 
 /// One of the buttons present on the mouse
@@ -406,6 +585,18 @@ pub enum MouseEvent {
     WheelUp,
     /// The wheel was moved down.
     WheelDown,
+    /// The mouse moved while no button was pressed.
+    ///
+    /// Not every backend reports these.
+    Move,
+    /// The mouse moved while no button was pressed, and should move the
+    /// focus.
+    ///
+    /// Sent instead of `Move` when focus-follows-mouse is enabled.
+    /// See [`Cursive::set_focus_follows_mouse`].
+    ///
+    /// [`Cursive::set_focus_follows_mouse`]: crate::Cursive::set_focus_follows_mouse
+    Hover,
 }
 
 impl MouseEvent {
@@ -423,17 +614,137 @@ impl MouseEvent {
 
     /// Returns `true` if `self` is an event that can grab focus.
     ///
-    /// This includes `Press`, `WheelUp` and `WheelDown`.
+    /// This includes `Press`, `WheelUp`, `WheelDown` and `Hover`.
     pub fn grabs_focus(self) -> bool {
         match self {
             MouseEvent::Press(_)
             | MouseEvent::WheelUp
-            | MouseEvent::WheelDown => true,
+            | MouseEvent::WheelDown
+            | MouseEvent::Hover => true,
             _ => false,
         }
     }
 }
 
+/// Recognizes swipes from mouse events.
+///
+/// A swipe is a quick drag with the left button, mostly in one direction,
+/// like a finger sliding on a touch screen. Terminals on touch devices
+/// usually report these as mouse events.
+///
+/// Enable it with [`Cursive::set_swipe_detector`] to receive
+/// [`Event::Swipe`] events.
+///
+/// [`Cursive::set_swipe_detector`]: crate::Cursive::set_swipe_detector
+#[derive(Debug, Clone)]
+pub struct SwipeDetector {
+    min_distance: Vec2,
+    max_duration: Duration,
+    // Position and time of the last press.
+    start: Option<(Vec2, Instant)>,
+}
+
+impl Default for SwipeDetector {
+    fn default() -> Self {
+        SwipeDetector {
+            min_distance: Vec2::new(8, 4),
+            max_duration: Duration::from_millis(500),
+            start: None,
+        }
+    }
+}
+
+impl SwipeDetector {
+    /// Creates a new detector with default thresholds.
+    ///
+    /// Swipes must cover 8 columns or 4 rows, in less than 500ms.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum distance covered by a swipe, on each axis.
+    pub fn set_min_distance<V: Into<Vec2>>(&mut self, min_distance: V) {
+        self.min_distance = min_distance.into();
+    }
+
+    /// Sets the minimum distance covered by a swipe, on each axis.
+    ///
+    /// Chainable variant.
+    pub fn min_distance<V: Into<Vec2>>(mut self, min_distance: V) -> Self {
+        self.set_min_distance(min_distance);
+        self
+    }
+
+    /// Sets the maximum time between the press and the release.
+    pub fn set_max_duration(&mut self, max_duration: Duration) {
+        self.max_duration = max_duration;
+    }
+
+    /// Sets the maximum time between the press and the release.
+    ///
+    /// Chainable variant.
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.set_max_duration(max_duration);
+        self
+    }
+
+    /// Feeds an event to the detector.
+    ///
+    /// Returns the direction of the swipe if `event` completes one.
+    pub fn on_event(&mut self, event: &Event) -> Option<Absolute> {
+        self.on_event_at(event, Instant::now())
+    }
+
+    fn on_event_at(
+        &mut self,
+        event: &Event,
+        now: Instant,
+    ) -> Option<Absolute> {
+        let (position, event) = match *event {
+            Event::Mouse {
+                position, event, ..
+            } => (position, event),
+            _ => return None,
+        };
+
+        match event {
+            MouseEvent::Press(MouseButton::Left) => {
+                self.start = Some((position, now));
+                None
+            }
+            MouseEvent::Release(MouseButton::Left) => {
+                let (start, time) = self.start.take()?;
+                if now.duration_since(time) > self.max_duration {
+                    return None;
+                }
+                self.direction(start, position)
+            }
+            _ => None,
+        }
+    }
+
+    // Direction from `start` to `end`, if it is a swipe.
+    fn direction(&self, start: Vec2, end: Vec2) -> Option<Absolute> {
+        let dx = end.x as isize - start.x as isize;
+        let dy = end.y as isize - start.y as isize;
+
+        // Rows are about twice as tall as columns are wide.
+        let horizontal = dx.abs() >= 2 * dy.abs();
+
+        if horizontal && dx.unsigned_abs() >= self.min_distance.x {
+            Some(if dx < 0 {
+                Absolute::Left
+            } else {
+                Absolute::Right
+            })
+        } else if !horizontal && dy.unsigned_abs() >= self.min_distance.y {
+            Some(if dy < 0 { Absolute::Up } else { Absolute::Down })
+        } else {
+            None
+        }
+    }
+}
+
 /// Represents an event as seen by the application.
 #[derive(PartialEq, Eq, Clone, Hash, Debug)]
 pub enum Event {
@@ -476,6 +787,28 @@ pub enum Event {
         event: MouseEvent,
     },
 
+    /// The mouse was swiped in the given direction.
+    ///
+    /// The direction is the one of the movement: swiping to the left
+    /// usually shows what is on the right.
+    ///
+    /// Only sent when a [`SwipeDetector`] is set.
+    Swipe(Absolute),
+
+    /// The view got the focus.
+    ///
+    /// Sent by view groups to the child they move the focus to, when the
+    /// focus moves from one child to another. Views forward it to their own
+    /// focused child.
+    FocusGained,
+
+    /// The view lost the focus.
+    ///
+    /// Sent by view groups to the child they move the focus away from, when
+    /// the focus moves from one child to another. Views forward it to their
+    /// own focused child.
+    FocusLost,
+
     // TODO: use a backend-dependent type for the unknown values?
     /// An unknown event was received.
     Unknown(Vec<u8>),
@@ -538,6 +871,45 @@ impl Event {
         result.relativize(top_left);
         result
     }
+
+    /// Returns the same event, without keypad keys.
+    ///
+    /// Keypad digits and operators become the characters they type, and
+    /// other keypad keys become the matching regular key, keeping any
+    /// modifier. Returns `None` if `self` doesn't involve a keypad key.
+    ///
+    /// Cursive uses this to send ignored keypad events again, so views only
+    /// need to handle regular keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::event::{Event, Key};
+    ///
+    /// let event = Event::Ctrl(Key::NumpadLeft);
+    /// assert_eq!(event.without_numpad(), Some(Event::Ctrl(Key::Left)));
+    ///
+    /// let event = Event::Key(Key::Numpad7);
+    /// assert_eq!(event.without_numpad(), Some(Event::Char('7')));
+    /// ```
+    pub fn without_numpad(&self) -> Option<Event> {
+        if let Event::Key(key) = *self {
+            if let Some(c) = key.numpad_char() {
+                return Some(Event::Char(c));
+            }
+        }
+
+        Some(match *self {
+            Event::Key(key) => Event::Key(key.without_numpad()?),
+            Event::Shift(key) => Event::Shift(key.without_numpad()?),
+            Event::Alt(key) => Event::Alt(key.without_numpad()?),
+            Event::AltShift(key) => Event::AltShift(key.without_numpad()?),
+            Event::Ctrl(key) => Event::Ctrl(key.without_numpad()?),
+            Event::CtrlShift(key) => Event::CtrlShift(key.without_numpad()?),
+            Event::CtrlAlt(key) => Event::CtrlAlt(key.without_numpad()?),
+            _ => return None,
+        })
+    }
 }
 
 impl From<char> for Event {
@@ -551,3 +923,55 @@ impl From<Key> for Event {
         Event::Key(k)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mouse(x: usize, y: usize, event: MouseEvent) -> Event {
+        Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, y),
+            event,
+        }
+    }
+
+    #[test]
+    fn swipe() {
+        let mut detector = SwipeDetector::new();
+        let start = Instant::now();
+        let press = mouse(20, 5, MouseEvent::Press(MouseButton::Left));
+        let release = mouse(5, 6, MouseEvent::Release(MouseButton::Left));
+
+        detector.on_event_at(&press, start);
+        let end = start + Duration::from_millis(100);
+        assert_eq!(detector.on_event_at(&release, end), Some(Absolute::Left));
+
+        // Slow drags are not swipes.
+        detector.on_event_at(&press, start);
+        let end = start + Duration::from_secs(1);
+        assert_eq!(detector.on_event_at(&release, end), None);
+    }
+
+    #[test]
+    fn without_numpad() {
+        assert_eq!(Key::from_numpad(7), Key::Numpad7);
+
+        let plain = |event: Event| event.without_numpad();
+        assert_eq!(plain(Event::Key(Key::Numpad7)), Some(Event::Char('7')));
+        assert_eq!(
+            plain(Event::Key(Key::NumpadDivide)),
+            Some(Event::Char('/'))
+        );
+        assert_eq!(
+            plain(Event::Ctrl(Key::NumpadLeft)),
+            Some(Event::Ctrl(Key::Left))
+        );
+        assert_eq!(
+            plain(Event::Key(Key::NumpadEnter)),
+            Some(Event::Key(Key::Enter))
+        );
+        assert_eq!(plain(Event::Key(Key::Left)), None);
+        assert_eq!(plain(Event::Char('7')), None);
+    }
+}
@@ -32,14 +32,21 @@ pub mod view;
 pub mod views;
 
 pub mod align;
+pub mod animation;
+pub mod app;
 pub mod backend;
+pub mod buffer;
 pub mod direction;
 pub mod event;
 pub mod logger;
 pub mod menu;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+pub mod testing;
 pub mod theme;
 pub mod traits;
 pub mod vec;
+pub mod virtual_dom;
 
 mod cursive;
 mod printer;
@@ -49,7 +56,7 @@ mod xy;
 
 mod div;
 
-pub use self::cursive::{CbSink, Cursive, ScreenId};
+pub use self::cursive::{BellPolicy, CbSink, Cursive, FrameInfo, ScreenId};
 pub use self::printer::Printer;
 pub use self::rect::Rect;
 pub use self::vec::Vec2;
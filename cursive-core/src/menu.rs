@@ -11,17 +11,83 @@
 //! The [menubar] is the main way to show menus.
 //!
 //! [menubar]: crate::Cursive::menubar
+//!
+//! # Shortcuts
+//!
+//! Leaves can have an accelerator: an event triggering them from anywhere,
+//! shown next to their label. See [`MenuTree::add_accelerated_leaf`].
+//!
+//! Trees created with [`MenuTree::with_mnemonics`] can also have mnemonics:
+//! a `&` in a label marks the next letter, which is underlined. Pressing
+//! `Alt` and this letter selects the item, in an open menu or on the
+//! menubar (see [`Menubar::set_mnemonics`]). Use `&&` for a literal `&`.
+//!
+//! ```rust
+//! use cursive_core::event::Event;
+//! use cursive_core::menu::MenuTree;
+//!
+//! let mut siv = cursive_core::Cursive::dummy();
+//! siv.menubar().set_mnemonics(true);
+//! siv.menubar().add_subtree(
+//!     "&File",
+//!     MenuTree::with_mnemonics()
+//!         .accelerated_leaf("&Save", Event::CtrlChar('s'), |_| ())
+//!         .leaf("&Quit", |s| s.quit()),
+//! );
+//! ```
+//!
+//! [`Menubar::set_mnemonics`]: crate::views::Menubar::set_mnemonics
+//!
+//! # Loading menus from a file
+//!
+//! With the `toml` feature, menus can also be described in a configuration
+//! file, so users can customize them. Leaves refer to named actions,
+//! registered in [`Actions`]:
+//!
+//! ```toml
+//! [[items]]
+//! label = "File"
+//!
+//!     [[items.items]]
+//!     label = "Open"
+//!     action = "open"
+//!
+//!     [[items.items]]
+//!     delimiter = true
+//!
+//!     [[items.items]]
+//!     label = "Quit"
+//!     action = "quit"
+//! ```
+//!
+//! See [`MenuTree::from_toml`].
 
-use crate::event::Callback;
+use crate::event::{Callback, Event, Key};
+use crate::theme::Effect;
 use crate::Cursive;
+use crate::Printer;
+use crate::Vec2;
 use crate::With;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
 use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
+
+#[cfg(feature = "toml")]
+use std::path::Path;
 
 /// Root of a menu tree.
 #[derive(Default, Clone)]
 pub struct MenuTree {
     /// Menu items
     pub children: Vec<MenuItem>,
+
+    // Accelerators of leaves, identified by their callback.
+    accelerators: Vec<(Callback, Event)>,
+
+    // Whether `&` marks mnemonics in labels.
+    mnemonics: bool,
 }
 
 /// Node in the menu tree.
@@ -89,6 +155,25 @@ impl MenuTree {
         Self::default()
     }
 
+    /// Creates a new, empty tree, where `&` marks mnemonics in labels.
+    ///
+    /// See the [module documentation](self#shortcuts).
+    pub fn with_mnemonics() -> Self {
+        Self::new().with(|tree| tree.set_mnemonics(true))
+    }
+
+    /// Sets whether `&` marks mnemonics in the labels of this tree.
+    ///
+    /// Subtrees have their own setting.
+    pub fn set_mnemonics(&mut self, mnemonics: bool) {
+        self.mnemonics = mnemonics;
+    }
+
+    /// Returns `true` if `&` marks mnemonics in the labels of this tree.
+    pub fn has_mnemonics(&self) -> bool {
+        self.mnemonics
+    }
+
     /// Remove every children from this tree.
     pub fn clear(&mut self) {
         self.children.clear();
@@ -144,6 +229,115 @@ impl MenuTree {
         self.with(|menu| menu.add_leaf(title, cb))
     }
 
+    /// Adds a leaf triggered by `accelerator` from anywhere.
+    ///
+    /// The accelerator is shown next to the label.
+    pub fn add_accelerated_leaf<S, E, F>(
+        &mut self,
+        title: S,
+        accelerator: E,
+        cb: F,
+    ) where
+        S: Into<String>,
+        E: Into<Event>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        let i = self.children.len();
+        self.insert_accelerated_leaf(i, title, accelerator, cb);
+    }
+
+    /// Inserts a leaf triggered by `accelerator` at the given position.
+    pub fn insert_accelerated_leaf<S, E, F>(
+        &mut self,
+        i: usize,
+        title: S,
+        accelerator: E,
+        cb: F,
+    ) where
+        S: Into<String>,
+        E: Into<Event>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        let cb = Callback::from_fn(cb);
+        self.insert(i, MenuItem::Leaf(title.into(), cb.clone()));
+        self.accelerators.push((cb, accelerator.into()));
+    }
+
+    /// Adds a leaf triggered by `accelerator` from anywhere - chainable
+    /// variant.
+    pub fn accelerated_leaf<S, E, F>(
+        self,
+        title: S,
+        accelerator: E,
+        cb: F,
+    ) -> Self
+    where
+        S: Into<String>,
+        E: Into<Event>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.with(|menu| menu.add_accelerated_leaf(title, accelerator, cb))
+    }
+
+    /// Adds a leaf carrying some data to the end of this tree.
+    ///
+    /// The callback receives a reference to `data` when the leaf is
+    /// selected. This is useful for generated menus, where each entry would
+    /// otherwise need its own clone of the data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::menu::MenuTree;
+    /// use std::path::PathBuf;
+    ///
+    /// let recent = vec![PathBuf::from("a.txt"), PathBuf::from("b.txt")];
+    ///
+    /// let mut menu = MenuTree::new();
+    /// for path in recent {
+    ///     let label = path.display().to_string();
+    ///     menu.add_leaf_with(label, path, |s, path| {
+    ///         s.set_user_data(path.clone());
+    ///     });
+    /// }
+    /// assert_eq!(menu.len(), 2);
+    /// ```
+    pub fn add_leaf_with<S, T, F>(&mut self, title: S, data: T, cb: F)
+    where
+        S: Into<String>,
+        T: 'static,
+        F: 'static + Fn(&mut Cursive, &T),
+    {
+        let i = self.children.len();
+        self.insert_leaf_with(i, title, data, cb);
+    }
+
+    /// Inserts a leaf carrying some data at the given position.
+    pub fn insert_leaf_with<S, T, F>(
+        &mut self,
+        i: usize,
+        title: S,
+        data: T,
+        cb: F,
+    ) where
+        S: Into<String>,
+        T: 'static,
+        F: 'static + Fn(&mut Cursive, &T),
+    {
+        self.insert_leaf(i, title, move |s| cb(s, &data));
+    }
+
+    /// Adds a leaf carrying some data to the end of this tree - chainable
+    /// variant.
+    pub fn leaf_with<S, T, F>(self, title: S, data: T, cb: F) -> Self
+    where
+        S: Into<String>,
+        T: 'static,
+        F: 'static + Fn(&mut Cursive, &T),
+    {
+        self.with(|menu| menu.add_leaf_with(title, data, cb))
+    }
+
     /// Inserts a subtree at the given position.
     pub fn insert_subtree<S>(&mut self, i: usize, title: S, tree: MenuTree)
     where
@@ -187,29 +381,117 @@ impl MenuTree {
 
     /// Looks for a child with the given title.
     ///
+    /// With mnemonics, the title can omit the mnemonic marker.
+    ///
     /// Returns `None` if no such label was found.
     pub fn find_item(&mut self, title: &str) -> Option<&mut MenuItem> {
-        self.children
-            .iter_mut()
-            .find(|child| child.label() == title)
+        let i = self.find_position(title)?;
+        self.children.get_mut(i)
     }
 
     /// Looks for a subtree with the given title.
     pub fn find_subtree(&mut self, title: &str) -> Option<&mut MenuTree> {
-        self.children
-            .iter_mut()
-            .filter(|child| child.label() == title)
-            .filter_map(MenuItem::as_subtree)
-            .next()
+        self.find_item(title).and_then(MenuItem::as_subtree)
     }
 
     /// Returns the position of a child with the given label.
     ///
     /// Returns `None` if no such label was found.
     pub fn find_position(&mut self, title: &str) -> Option<usize> {
+        self.children.iter().position(|child| {
+            child.label() == title || self.text(child) == title
+        })
+    }
+
+    /// Returns the accelerator of the leaf at the given position, if any.
+    pub fn accelerator(&self, i: usize) -> Option<&Event> {
+        match self.children.get(i)? {
+            MenuItem::Leaf(_, cb) => self
+                .accelerators
+                .iter()
+                .find(|(other, _)| other.ptr_eq(cb))
+                .map(|(_, event)| event),
+            _ => None,
+        }
+    }
+
+    /// Sets the accelerator of the leaf at the given position.
+    ///
+    /// Does nothing if the item is not a leaf.
+    pub fn set_accelerator(&mut self, i: usize, accelerator: Option<Event>) {
+        let cb = match self.children.get(i) {
+            Some(MenuItem::Leaf(_, cb)) => cb.clone(),
+            _ => return,
+        };
+        self.accelerators.retain(|(other, _)| !other.ptr_eq(&cb));
+        if let Some(accelerator) = accelerator {
+            self.accelerators.push((cb, accelerator));
+        }
+    }
+
+    /// Returns the label of `item` as shown, without the mnemonic marker.
+    pub(crate) fn text<'a>(&self, item: &'a MenuItem) -> Cow<'a, str> {
+        if self.mnemonics {
+            split_mnemonic(item.label()).0
+        } else {
+            Cow::Borrowed(item.label())
+        }
+    }
+
+    /// Prints the label of `item`, underlining its mnemonic.
+    pub(crate) fn print_label(
+        &self,
+        printer: &Printer<'_, '_>,
+        start: Vec2,
+        item: &MenuItem,
+    ) {
+        if !self.mnemonics {
+            printer.print(start, item.label());
+            return;
+        }
+
+        let (text, mnemonic) = split_mnemonic(item.label());
+        printer.print(start, &text);
+
+        let i = match mnemonic {
+            Some(i) => i,
+            None => return,
+        };
+        if let Some(c) = text[i..].chars().next() {
+            let x = start.x + text[..i].width();
+            printer.with_effect(Effect::Underline, |printer| {
+                printer.print((x, start.y), &text[i..i + c.len_utf8()]);
+            });
+        }
+    }
+
+    /// Returns the position of the child with the given mnemonic.
+    pub(crate) fn find_mnemonic(&self, c: char) -> Option<usize> {
+        if !self.mnemonics {
+            return None;
+        }
+        let c = c.to_lowercase().next()?;
+        self.children.iter().position(|child| {
+            let (text, i) = split_mnemonic(child.label());
+            let mnemonic = i.and_then(|i| text[i..].chars().next());
+            mnemonic.and_then(|m| m.to_lowercase().next()) == Some(c)
+        })
+    }
+
+    /// Looks for a leaf with the given accelerator, in this tree or below.
+    pub(crate) fn find_accelerator(&self, event: &Event) -> Option<Callback> {
         self.children
             .iter()
-            .position(|child| child.label() == title)
+            .enumerate()
+            .find_map(|(i, child)| match *child {
+                MenuItem::Leaf(_, ref cb)
+                    if self.accelerator(i) == Some(event) =>
+                {
+                    Some(cb.clone())
+                }
+                MenuItem::Subtree(_, ref tree) => tree.find_accelerator(event),
+                _ => None,
+            })
     }
 
     /// Removes the item at the given position.
@@ -230,3 +512,298 @@ impl MenuTree {
         self.children.is_empty()
     }
 }
+
+/// Splits a label into the text to show, and the byte position of its
+/// mnemonic in that text.
+fn split_mnemonic(label: &str) -> (Cow<'_, str>, Option<usize>) {
+    if !label.contains('&') {
+        return (Cow::Borrowed(label), None);
+    }
+
+    let mut text = String::with_capacity(label.len());
+    let mut mnemonic = None;
+    let mut chars = label.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('&', Some('&')) => {
+                chars.next();
+                text.push('&');
+            }
+            ('&', Some(next))
+                if mnemonic.is_none() && next.is_alphanumeric() =>
+            {
+                mnemonic = Some(text.len());
+            }
+            (c, _) => text.push(c),
+        }
+    }
+    (Cow::Owned(text), mnemonic)
+}
+
+/// Describes an accelerator, like `Ctrl+S`.
+///
+/// Returns `None` for events without a key, like mouse events.
+pub(crate) fn describe_accelerator(event: &Event) -> Option<String> {
+    // Keys are named after their variant, like `PageUp` or `F5`.
+    let key = |key: &Key| format!("{:?}", key);
+    let letter = |c: char| c.to_uppercase().collect::<String>();
+
+    Some(match *event {
+        Event::Char(c) => c.to_string(),
+        Event::CtrlChar(c) => format!("Ctrl+{}", letter(c)),
+        Event::AltChar(c) => format!("Alt+{}", letter(c)),
+        Event::Key(ref k) => key(k),
+        Event::Shift(ref k) => format!("Shift+{}", key(k)),
+        Event::Alt(ref k) => format!("Alt+{}", key(k)),
+        Event::AltShift(ref k) => format!("Alt+Shift+{}", key(k)),
+        Event::Ctrl(ref k) => format!("Ctrl+{}", key(k)),
+        Event::CtrlShift(ref k) => format!("Ctrl+Shift+{}", key(k)),
+        Event::CtrlAlt(ref k) => format!("Ctrl+Alt+{}", key(k)),
+        _ => return None,
+    })
+}
+
+/// Registry of callbacks, identified by name.
+///
+/// This lets configuration files refer to application actions, for
+/// instance in [`MenuTree::from_toml`].
+#[derive(Default, Clone)]
+pub struct Actions {
+    callbacks: HashMap<String, Callback>,
+}
+
+impl Actions {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an action with the given name.
+    ///
+    /// Replaces any previous action with the same name.
+    pub fn add<S, F>(&mut self, name: S, cb: F)
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.callbacks.insert(name.into(), Callback::from_fn(cb));
+    }
+
+    /// Registers an action with the given name.
+    ///
+    /// Chainable variant.
+    pub fn action<S, F>(self, name: S, cb: F) -> Self
+    where
+        S: Into<String>,
+        F: 'static + Fn(&mut Cursive),
+    {
+        self.with(|actions| actions.add(name, cb))
+    }
+
+    /// Returns the action with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&Callback> {
+        self.callbacks.get(name)
+    }
+
+    /// Returns the names of the registered actions, in arbitrary order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.callbacks.keys().map(String::as_str)
+    }
+}
+
+/// Error returned when loading a menu description.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read.
+    Io(std::io::Error),
+
+    /// The content is not valid toml.
+    #[cfg(feature = "toml")]
+    Parse(toml::de::Error),
+
+    /// An item refers to an action missing from the registry.
+    UnknownAction(String),
+
+    /// An item is neither a leaf, a subtree nor a delimiter.
+    InvalidItem(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LoadError::Io(ref err) => {
+                write!(f, "could not read menu: {}", err)
+            }
+            #[cfg(feature = "toml")]
+            LoadError::Parse(ref err) => {
+                write!(f, "could not parse menu: {}", err)
+            }
+            LoadError::UnknownAction(ref name) => {
+                write!(f, "unknown action: {}", name)
+            }
+            LoadError::InvalidItem(ref item) => {
+                write!(f, "invalid menu item: {}", item)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<std::io::Error> for LoadError {
+    fn from(err: std::io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl From<toml::de::Error> for LoadError {
+    fn from(err: toml::de::Error) -> Self {
+        LoadError::Parse(err)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl MenuTree {
+    /// Builds a menu from a toml description.
+    ///
+    /// The description has an `items` array. Each item is one of:
+    ///
+    /// * A leaf, with a `label` and the name of an `action` from `actions`.
+    /// * A subtree, with a `label` and its own `items` array.
+    /// * A delimiter, with `delimiter = true`.
+    ///
+    /// See the [module documentation](self) for an example.
+    ///
+    /// Must have the `toml` feature enabled.
+    pub fn from_toml(
+        content: &str,
+        actions: &Actions,
+    ) -> Result<Self, LoadError> {
+        let table: toml::value::Table = toml::de::from_str(content)?;
+        Self::from_toml_table(&table, actions)
+    }
+
+    /// Builds a menu from a toml file.
+    ///
+    /// See [`MenuTree::from_toml`].
+    ///
+    /// Must have the `toml` feature enabled.
+    pub fn from_toml_file<P: AsRef<Path>>(
+        path: P,
+        actions: &Actions,
+    ) -> Result<Self, LoadError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_toml(&content, actions)
+    }
+
+    fn from_toml_table(
+        table: &toml::value::Table,
+        actions: &Actions,
+    ) -> Result<Self, LoadError> {
+        let items = match table.get("items") {
+            None => return Ok(MenuTree::new()),
+            Some(toml::Value::Array(items)) => items,
+            Some(other) => {
+                return Err(LoadError::InvalidItem(other.to_string()))
+            }
+        };
+
+        let mut tree = MenuTree::new();
+        for item in items {
+            let invalid = || LoadError::InvalidItem(item.to_string());
+            let item = item.as_table().ok_or_else(invalid)?;
+
+            if item.get("delimiter").and_then(toml::Value::as_bool)
+                == Some(true)
+            {
+                tree.add_delimiter();
+                continue;
+            }
+
+            let label = item
+                .get("label")
+                .and_then(toml::Value::as_str)
+                .ok_or_else(invalid)?;
+
+            match item.get("action") {
+                Some(toml::Value::String(name)) => {
+                    let cb = actions
+                        .get(name)
+                        .ok_or_else(|| LoadError::UnknownAction(name.clone()))?
+                        .clone();
+                    tree.children.push(MenuItem::Leaf(label.to_string(), cb));
+                }
+                Some(_) => return Err(invalid()),
+                None if item.contains_key("items") => {
+                    let subtree = Self::from_toml_table(item, actions)?;
+                    tree.add_subtree(label, subtree);
+                }
+                None => return Err(invalid()),
+            }
+        }
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn from_toml() {
+        let actions = Actions::new()
+            .action("open", |_| ())
+            .action("quit", Cursive::quit);
+
+        let content = r#"
+            [[items]]
+            label = "File"
+
+                [[items.items]]
+                label = "Open"
+                action = "open"
+
+                [[items.items]]
+                delimiter = true
+
+                [[items.items]]
+                label = "Quit"
+                action = "quit"
+        "#;
+
+        let mut tree = MenuTree::from_toml(content, &actions).unwrap();
+        let file = tree.find_subtree("File").unwrap();
+        assert_eq!(file.len(), 3);
+        assert!(file.children[1].is_delimiter());
+        assert!(file.children[2].is_leaf());
+
+        let content = r#"
+            [[items]]
+            label = "Save"
+            action = "save"
+        "#;
+        match MenuTree::from_toml(content, &actions) {
+            Err(LoadError::UnknownAction(name)) => assert_eq!(name, "save"),
+            _ => panic!("unknown actions should be rejected"),
+        }
+    }
+
+    #[test]
+    fn leaf_with() {
+        let mut tree = MenuTree::new()
+            .leaf_with("One", 1, |s, &n: &i32| s.set_user_data(n))
+            .leaf_with("Three", 3, |s, &n: &i32| s.set_user_data(n));
+        tree.insert_leaf_with(1, "Two", 2, |s, &n: &i32| s.set_user_data(n));
+
+        let mut siv = crate::testing::dummy((10, 5));
+        for (i, &expected) in [1, 2, 3].iter().enumerate() {
+            match tree.get_mut(i) {
+                Some(MenuItem::Leaf(_, cb)) => cb(&mut siv),
+                _ => panic!("expected a leaf"),
+            }
+            assert_eq!(siv.user_data::<i32>().copied(), Some(expected));
+        }
+    }
+}
@@ -2,17 +2,92 @@
 
 use crate::backend::Backend;
 use crate::direction::Orientation;
+use crate::rect::Rect;
 use crate::theme::{
-    BorderStyle, ColorStyle, Effect, PaletteColor, Style, Theme,
+    BorderStyle, ColorStyle, Effect, Link, PaletteColor, Style, Theme,
 };
 use crate::utils::lines::simple::{prefix, suffix};
 use crate::with::With;
+use crate::Cursive;
 use crate::Vec2;
+use crate::XY;
 use enumset::EnumSet;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::cmp::min;
+use std::collections::HashMap;
+use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Areas where named views were last drawn, by name.
+pub(crate) type ViewAreas = RefCell<HashMap<String, PrinterArea>>;
+
+/// Ends of the separator lines drawn by layouts, on the screen.
+///
+/// Each end is given with the orientation of its line.
+pub(crate) type SeparatorEnds = RefCell<Vec<(XY<isize>, Orientation)>>;
+
+/// Drop targets drawn in the last frame, in drawing order.
+pub(crate) type DropZones = RefCell<Vec<DropZone>>;
+
+type AnyAccept = dyn Fn(&dyn Any) -> bool;
+type AnyOnDrop = dyn Fn(&mut Cursive, Box<dyn Any>, Vec2);
+
+/// Area accepting drops, recorded when a `DropTarget` is drawn.
+pub(crate) struct DropZone {
+    /// Visible area, in screen coordinates.
+    pub area: Rect,
+    /// Offset of the view content, in screen coordinates.
+    pub origin: Vec2,
+    pub accept: Rc<AnyAccept>,
+    pub on_drop: Rc<AnyOnDrop>,
+}
+
+/// Bindings of the views drawn in the last frame.
+pub(crate) type Bindings = RefCell<Vec<Rc<dyn AnyBinding>>>;
+
+/// Binding reading its value from the user data.
+pub(crate) trait AnyBinding {
+    /// Reads the bound value from the user data.
+    ///
+    /// Returns `true` if it changed since the last read.
+    fn read(&self, user_data: &dyn Any) -> bool;
+}
+
+/// Where a printer draws on the screen.
+///
+/// Used to draw a view again where it was last drawn.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PrinterArea {
+    offset: Vec2,
+    output_size: Vec2,
+    size: Vec2,
+    focused: bool,
+}
+
+impl PrinterArea {
+    /// Returns the middle of the visible part of the area.
+    pub(crate) fn center(&self) -> Vec2 {
+        self.offset + Vec2::min(self.output_size, self.size) / 2
+    }
+
+    /// Returns the visible part of the area, if any.
+    pub(crate) fn visible_rect(&self) -> Option<Rect> {
+        let size = Vec2::min(self.output_size, self.size);
+        if size.x == 0 || size.y == 0 {
+            None
+        } else {
+            Some(Rect::from_size(self.offset, size))
+        }
+    }
+
+    /// Returns `true` if the area was drawn focused.
+    pub(crate) fn is_focused(&self) -> bool {
+        self.focused
+    }
+}
+
 /// Convenient interface to draw on a subset of the screen.
 ///
 /// The area it can print on is defined by `offset` and `size`.
@@ -57,8 +132,34 @@ pub struct Printer<'a, 'b> {
     /// Currently used theme
     pub theme: &'a Theme,
 
+    /// Minimum contrast ratio between front and back colors.
+    ///
+    /// When set, front colors less readable than this are adjusted when
+    /// drawing. See [`Color::contrast_ratio`].
+    ///
+    /// [`Color::contrast_ratio`]: crate::theme::Color::contrast_ratio
+    pub min_contrast: Option<f32>,
+
     /// Backend used to actually draw things
     backend: &'b dyn Backend,
+
+    /// Where named views record their area, if anywhere.
+    view_areas: Option<&'b ViewAreas>,
+
+    /// Where drop targets record their area, if anywhere.
+    drop_zones: Option<&'b DropZones>,
+
+    /// Set when a view asks for another frame, if anywhere.
+    frame_request: Option<&'b Cell<bool>>,
+
+    /// Where layouts record the ends of their separators, if anywhere.
+    separator_ends: Option<&'b SeparatorEnds>,
+
+    /// Where bound views record their bindings, if anywhere.
+    bindings: Option<&'b Bindings>,
+
+    /// Link applied to prints, if any.
+    link: Option<Link>,
 }
 
 impl<'a, 'b> Printer<'a, 'b> {
@@ -80,10 +181,25 @@ impl<'a, 'b> Printer<'a, 'b> {
             focused: true,
             enabled: true,
             theme,
+            min_contrast: None,
             backend,
+            view_areas: None,
+            drop_zones: None,
+            frame_request: None,
+            separator_ends: None,
+            bindings: None,
+            link: None,
         }
     }
 
+    /// Returns `true` if the backend can print 24-bit colors.
+    ///
+    /// Views can use this to draw finer colors, like gradients, and fall
+    /// back to palette colors otherwise.
+    pub fn has_true_colors(&self) -> bool {
+        self.backend.has_true_colors()
+    }
+
     /// Clear the screen.
     ///
     /// It will discard anything drawn before.
@@ -104,7 +220,7 @@ impl<'a, 'b> Printer<'a, 'b> {
     {
         let Vec2 { mut x, y } = start.into();
         for span in text.spans() {
-            self.with_style(*span.attr, |printer| {
+            self.with_style(span.attr.clone(), |printer| {
                 printer.print_with_width((x, y), span.content, |_| span.width);
                 x += span.width;
             });
@@ -304,7 +420,11 @@ impl<'a, 'b> Printer<'a, 'b> {
     where
         F: FnOnce(&Printer<'_, '_>),
     {
-        let old = self.backend.set_color(c.resolve(&self.theme.palette));
+        let mut colors = c.resolve(&self.theme.palette);
+        if let Some(ratio) = self.min_contrast {
+            colors = colors.with_min_contrast(ratio);
+        }
+        let old = self.backend.set_color(colors);
         f(self);
         self.backend.set_color(old);
     }
@@ -321,7 +441,30 @@ impl<'a, 'b> Printer<'a, 'b> {
         let color = style.color;
         let effects = style.effects;
 
-        if let Some(color) = color {
+        if let Some(name) = style.name {
+            // The named style is below the other fields.
+            let named = match self.theme.styles.get(name) {
+                Some(named) => Style {
+                    name: None,
+                    ..named.clone()
+                },
+                None => Style::none(),
+            };
+            let style = Style::merge(&[
+                named,
+                Style {
+                    name: None,
+                    ..style
+                },
+            ]);
+            self.with_style(style, f);
+        } else if let Some(link) = style.link {
+            let style = Style {
+                link: None,
+                ..style
+            };
+            self.with_link(link, |printer| printer.with_style(style, f));
+        } else if let Some(color) = color {
             self.with_color(color, |printer| {
                 printer.with_effects(effects, f);
             });
@@ -330,6 +473,20 @@ impl<'a, 'b> Printer<'a, 'b> {
         }
     }
 
+    /// Call the given closure with a modified printer
+    /// that will link prints to the given URL.
+    ///
+    /// Inside `f`, this link replaces any outer one, which is restored
+    /// when `f` returns.
+    pub fn with_link<F>(&self, link: Link, f: F)
+    where
+        F: FnOnce(&Printer<'_, '_>),
+    {
+        self.backend.set_link(Some(link.url()));
+        f(&self.clone().with(|s| s.link = Some(link)));
+        self.backend.set_link(self.link.as_ref().map(Link::url));
+    }
+
     /// Call the given closure with a modified printer
     /// that will apply the given effect on prints.
     pub fn with_effect<F>(&self, effect: Effect, f: F)
@@ -498,6 +655,211 @@ impl<'a, 'b> Printer<'a, 'b> {
         self.print(start + (len.saturating_sub(1), 0), "┤");
     }
 
+    /// Returns a printer recording named view areas in `view_areas`.
+    pub(crate) fn track_view_areas(&self, view_areas: &'b ViewAreas) -> Self {
+        self.clone().with(|s| s.view_areas = Some(view_areas))
+    }
+
+    /// Records the area of this printer for the view named `name`.
+    ///
+    /// Does nothing unless areas are tracked.
+    pub(crate) fn record_area(&self, name: &str) {
+        let view_areas = match self.view_areas {
+            Some(view_areas) => view_areas,
+            None => return,
+        };
+
+        let area = PrinterArea {
+            offset: self.offset,
+            output_size: self.output_size,
+            size: self.size,
+            focused: self.focused,
+        };
+
+        let mut view_areas = view_areas.borrow_mut();
+        match view_areas.get_mut(name) {
+            Some(old) => *old = area,
+            None => {
+                view_areas.insert(name.to_owned(), area);
+            }
+        }
+    }
+
+    /// Records areas tracked separately, as if they were recorded here.
+    ///
+    /// Does nothing unless areas are tracked.
+    pub(crate) fn record_areas(&self, areas: &ViewAreas) {
+        if let Some(view_areas) = self.view_areas {
+            let areas = areas.borrow();
+            let areas = areas.iter().map(|(name, &area)| (name.clone(), area));
+            view_areas.borrow_mut().extend(areas);
+        }
+    }
+
+    /// Returns a printer recording drop targets in `drop_zones`.
+    pub(crate) fn track_drop_zones(&self, drop_zones: &'b DropZones) -> Self {
+        self.clone().with(|s| s.drop_zones = Some(drop_zones))
+    }
+
+    /// Records a drop target, built by `zone`.
+    ///
+    /// Does nothing unless drop targets are tracked.
+    pub(crate) fn record_drop_zone<F>(&self, zone: F)
+    where
+        F: FnOnce() -> DropZone,
+    {
+        if let Some(drop_zones) = self.drop_zones {
+            drop_zones.borrow_mut().push(zone());
+        }
+    }
+
+    /// Returns a printer setting `frame_request` when a view asks for
+    /// another frame.
+    pub(crate) fn track_frame_requests(
+        &self,
+        frame_request: &'b Cell<bool>,
+    ) -> Self {
+        self.clone().with(|s| s.frame_request = Some(frame_request))
+    }
+
+    /// Asks for another frame soon, even without any input.
+    ///
+    /// Animated views call this while drawing, until the animation is
+    /// over. Does nothing when drawing outside of the event loop.
+    pub fn request_frame(&self) {
+        if let Some(frame_request) = self.frame_request {
+            frame_request.set(true);
+        }
+    }
+
+    /// Returns a printer recording the bindings of views in `bindings`.
+    pub(crate) fn track_bindings(&self, bindings: &'b Bindings) -> Self {
+        self.clone().with(|s| s.bindings = Some(bindings))
+    }
+
+    /// Records the binding of a view, so Cursive keeps it in sync.
+    ///
+    /// Does nothing unless bindings are tracked.
+    pub(crate) fn record_binding<F>(&self, binding: F)
+    where
+        F: FnOnce() -> Rc<dyn AnyBinding>,
+    {
+        if let Some(bindings) = self.bindings {
+            bindings.borrow_mut().push(binding());
+        }
+    }
+
+    /// Returns a printer recording separator ends in `separator_ends`.
+    pub(crate) fn track_separator_ends(
+        &self,
+        separator_ends: &'b SeparatorEnds,
+    ) -> Self {
+        self.clone()
+            .with(|s| s.separator_ends = Some(separator_ends))
+    }
+
+    /// Records the end of a separator line, drawn at `pos`.
+    ///
+    /// Does nothing unless separator ends are tracked.
+    pub(crate) fn record_separator_end(
+        &self,
+        pos: Vec2,
+        orientation: Orientation,
+    ) {
+        if let Some(separator_ends) = self.separator_ends {
+            let end = (self.screen_position(pos), orientation);
+            separator_ends.borrow_mut().push(end);
+        }
+    }
+
+    /// Records separator ends tracked separately, as if they were
+    /// recorded here.
+    pub(crate) fn record_separator_ends(&self, ends: &SeparatorEnds) {
+        if let Some(separator_ends) = self.separator_ends {
+            separator_ends.borrow_mut().extend(ends.borrow().iter());
+        }
+    }
+
+    /// Returns where `pos` would be printed on the screen.
+    ///
+    /// The result may be outside of the screen.
+    pub(crate) fn screen_position(&self, pos: Vec2) -> XY<isize> {
+        (pos + self.offset).signed() - self.content_offset.signed()
+    }
+
+    /// Returns `true` if any part of the given area can be seen.
+    ///
+    /// `start` and `size` use the same coordinates as print requests.
+    ///
+    /// Views can use this to skip drawing content outside of the visible
+    /// area, for example in a long layout inside a `ScrollView`.
+    pub fn is_visible<S, T>(&self, start: S, size: T) -> bool
+    where
+        S: Into<Vec2>,
+        T: Into<Vec2>,
+    {
+        let start = start.into();
+        let end = start + size.into();
+
+        // Empty areas are never visible.
+        start.strictly_lt(end)
+            && start.strictly_lt(self.content_offset + self.output_size)
+            && self.content_offset.strictly_lt(end)
+    }
+
+    /// Returns the area that can be seen, in the coordinates of print
+    /// requests.
+    ///
+    /// Inside a `ScrollView`, this is the part of the content in view.
+    pub fn visible_area(&self) -> Rect {
+        Rect::from_size(self.content_offset, self.output_size)
+    }
+
+    /// Returns a sub-printer only drawing inside the given area.
+    ///
+    /// `area` uses the same coordinates as print requests, and they are left
+    /// unchanged: anything printed outside of `area` is discarded, and
+    /// `is_visible` returns `false` for it. Views skipping invisible content
+    /// then only redraw the clipped part, for example a damaged region.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::buffer::render_to_buffer;
+    /// use cursive_core::Rect;
+    /// use cursive_core::views::Canvas;
+    ///
+    /// let mut canvas = Canvas::new(()).with_draw(|_, printer| {
+    ///     // Only redraw the first two columns of the second row.
+    ///     let printer = printer.clipped(Rect::from_size((0, 1), (2, 1)));
+    ///     for y in 0..printer.size.y {
+    ///         if printer.is_visible((0, y), (printer.size.x, 1)) {
+    ///             printer.print((0, y), "abcd");
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// let buffer = render_to_buffer(&mut canvas, (4, 2));
+    /// assert_eq!(buffer.lines(), vec!["    ", "ab  "]);
+    /// ```
+    pub fn clipped<R: Into<Rect>>(&self, area: R) -> Self {
+        let area = area.into();
+        self.clone().with(|s| {
+            if s.output_size.x == 0 || s.output_size.y == 0 {
+                return;
+            }
+            match s.visible_area().intersection(area) {
+                Some(area) => {
+                    // Skip the hidden part, like when scrolling.
+                    s.offset = s.offset + (area.top_left() - s.content_offset);
+                    s.content_offset = area.top_left();
+                    s.output_size = area.size();
+                }
+                None => s.output_size = Vec2::zero(),
+            }
+        })
+    }
+
     /// Returns a sub-printer with the given offset.
     ///
     /// It will print in an area slightly to the bottom/right.
@@ -541,6 +903,13 @@ impl<'a, 'b> Printer<'a, 'b> {
         self.clone().with(|s| s.enabled &= enabled)
     }
 
+    /// Returns a new sub-printer enforcing the given minimum contrast.
+    ///
+    /// `None` disables the contrast check.
+    pub fn min_contrast(&self, ratio: Option<f32>) -> Self {
+        self.clone().with(|s| s.min_contrast = ratio)
+    }
+
     /// Returns a new sub-printer with a cropped area.
     ///
     /// The new printer size will be the minimum of `size` and its current size.
@@ -180,4 +180,19 @@ impl Rect {
     pub fn contains(self, point: Vec2) -> bool {
         point.fits(self.top_left) && point.fits_in(self.bottom_right)
     }
+
+    /// Returns the part of `self` also covered by `other`, if any.
+    pub fn intersection(self, other: Rect) -> Option<Rect> {
+        let top_left = Vec2::max(self.top_left, other.top_left);
+        let bottom_right = Vec2::min(self.bottom_right, other.bottom_right);
+
+        if top_left.fits_in(bottom_right) {
+            Some(Rect {
+                top_left,
+                bottom_right,
+            })
+        } else {
+            None
+        }
+    }
 }
@@ -0,0 +1,486 @@
+//! Scripting bridge, to extend applications with [Rhai] scripts.
+//!
+//! A [`ScriptEngine`] runs scripts that can manipulate the UI through a
+//! small, safe set of functions:
+//!
+//! * `add_layer(blueprint)` adds a layer built from a blueprint (see below).
+//! * `pop_layer()` removes the top layer.
+//! * `set_text(name, text)` sets the content of a named `TextView` or
+//!   `EditView`.
+//! * `bind_key(key, function)` calls a script function when a key is
+//!   pressed. Keys are a single character like `"q"`, `"Ctrl+s"`,
+//!   `"Alt+x"`, or a key name like `"F5"` or `"Enter"`.
+//! * `quit()` stops the application.
+//!
+//! Scripts cannot access anything else. Their functions stay available to
+//! key bindings, buttons, and to [`ScriptEngine::call`].
+//!
+//! The output of `print` and `debug` goes to the [`log`] crate rather than
+//! to the terminal, where it would mess up the UI.
+//!
+//! # Blueprints
+//!
+//! A blueprint describes a view with a Rhai object map. The `type` field
+//! gives the kind of view, and the optional `name` field gives it a name
+//! usable with `set_text` or [`Cursive::call_on_name`]:
+//!
+//! * `#{ type: "TextView", text: "..." }`
+//! * `#{ type: "EditView", content: "..." }`
+//! * `#{ type: "Button", label: "...", on_press: "function" }`
+//! * `#{ type: "LinearLayout", orientation: "vertical", children: [...] }`
+//!   (the orientation can also be `"horizontal"`)
+//! * `#{ type: "Panel", title: "...", content: #{...} }`
+//! * `#{ type: "Dialog", title: "...", content: #{...}, buttons: [...],
+//!   dismiss: "Close" }`, where each button is
+//!   `#{ label: "...", on_press: "function" }`
+//!
+//! Requires the `scripting` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::scripting::ScriptEngine;
+//!
+//! let mut siv = cursive_core::Cursive::dummy();
+//! let scripts = ScriptEngine::new();
+//! scripts
+//!     .run(
+//!         &mut siv,
+//!         r#"
+//!             fn greet() {
+//!                 set_text("greeting", "Hello from a script!");
+//!             }
+//!
+//!             add_layer(#{
+//!                 type: "Dialog",
+//!                 title: "Plugin",
+//!                 content: #{ type: "TextView", name: "greeting", text: "" },
+//!                 buttons: [#{ label: "Greet", on_press: "greet" }],
+//!                 dismiss: "Close",
+//!             });
+//!             bind_key("F2", "greet");
+//!         "#,
+//!     )
+//!     .unwrap();
+//! ```
+//!
+//! [Rhai]: https://rhai.rs
+//! [`Cursive::call_on_name`]: crate::Cursive::call_on_name
+use crate::direction::Orientation;
+use crate::event::Event;
+use crate::view::{Nameable, View};
+use crate::views::{
+    BoxedView, Button, Dialog, EditView, LinearLayout, Panel, TextView,
+};
+use crate::Cursive;
+use rhai::{Array, Dynamic, EvalAltResult, Map, Scope, AST};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Error returned when running a script.
+#[derive(Debug)]
+pub enum Error {
+    /// The script could not be parsed, or failed while running.
+    Script(Box<EvalAltResult>),
+
+    /// The script asked for something invalid, like an unknown view.
+    Ui(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Script(err) => write!(f, "script error: {}", err),
+            Error::Ui(message) => {
+                write!(f, "invalid UI operation: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Box<EvalAltResult>> for Error {
+    fn from(err: Box<EvalAltResult>) -> Self {
+        Error::Script(err)
+    }
+}
+
+// Operation requested by a script, applied once it returns.
+enum Command {
+    AddLayer(Map),
+    PopLayer,
+    SetText(String, String),
+    BindKey(Event, String),
+    Quit,
+}
+
+struct Inner {
+    engine: rhai::Engine,
+    commands: Rc<RefCell<Vec<Command>>>,
+
+    // Functions of all the scripts run so far.
+    functions: RefCell<AST>,
+}
+
+/// Runs scripts manipulating the UI.
+///
+/// Cloning a `ScriptEngine` is cheap, and gives a handle to the same
+/// engine. See the [module documentation](self) for the functions
+/// available to scripts.
+#[derive(Clone)]
+pub struct ScriptEngine {
+    inner: Rc<Inner>,
+}
+
+impl ScriptEngine {
+    /// Creates a new engine, with the default Rhai engine.
+    ///
+    /// `print` and `debug` are sent to the log.
+    pub fn new() -> Self {
+        let mut engine = rhai::Engine::new();
+        engine.on_print(|text| log::info!("{}", text));
+        engine.on_debug(|text, source, pos| match source {
+            Some(source) => log::debug!("{} @ {:?}: {}", source, pos, text),
+            None => log::debug!("{:?}: {}", pos, text),
+        });
+        Self::with_engine(engine)
+    }
+
+    /// Creates a new engine from a Rhai engine.
+    ///
+    /// This lets applications register their own functions or limits
+    /// before adding the UI functions.
+    ///
+    /// Unless changed with `on_print` and `on_debug`, the engine prints to
+    /// the standard output, which messes up the UI.
+    pub fn with_engine(mut engine: rhai::Engine) -> Self {
+        let commands = Rc::new(RefCell::new(Vec::new()));
+        register_functions(&mut engine, &commands);
+
+        ScriptEngine {
+            inner: Rc::new(Inner {
+                engine,
+                commands,
+                functions: RefCell::new(AST::empty()),
+            }),
+        }
+    }
+
+    /// Runs a script, then applies the UI operations it asked for.
+    ///
+    /// The functions defined by the script stay available, and replace
+    /// previous ones with the same name.
+    pub fn run(&self, siv: &mut Cursive, script: &str) -> Result<(), Error> {
+        let ast = self.inner.engine.compile(script).map_err(Box::from)?;
+        self.inner
+            .functions
+            .borrow_mut()
+            .combine(ast.clone_functions_only());
+
+        let result = self
+            .inner
+            .engine
+            .run_ast_with_scope(&mut Scope::new(), &ast);
+        // Operations requested before a failure are still applied.
+        let applied = self.apply(siv);
+        result?;
+        applied
+    }
+
+    /// Calls a function defined by a script, without arguments.
+    pub fn call(
+        &self,
+        siv: &mut Cursive,
+        function: &str,
+    ) -> Result<(), Error> {
+        let result = {
+            let functions = self.inner.functions.borrow();
+            self.inner.engine.call_fn::<Dynamic>(
+                &mut Scope::new(),
+                &functions,
+                function,
+                (),
+            )
+        };
+        // The returned value, if any, is ignored.
+        let result = result.map(drop);
+        let applied = self.apply(siv);
+        result?;
+        applied
+    }
+
+    // Returns a callback calling a script function, logging any error.
+    fn callback(&self, function: String) -> impl Fn(&mut Cursive) + 'static {
+        let engine = self.clone();
+        move |siv| {
+            if let Err(err) = engine.call(siv, &function) {
+                log::warn!("Script function `{}` failed: {}", function, err);
+            }
+        }
+    }
+
+    // Applies the operations requested by scripts so far.
+    fn apply(&self, siv: &mut Cursive) -> Result<(), Error> {
+        let commands = std::mem::take(&mut *self.inner.commands.borrow_mut());
+        for command in commands {
+            match command {
+                Command::AddLayer(blueprint) => {
+                    let view = self.build(&blueprint)?;
+                    siv.add_layer(view);
+                }
+                Command::PopLayer => {
+                    siv.pop_layer();
+                }
+                Command::SetText(name, text) => {
+                    let found = siv
+                        .call_on_name(&name, |view: &mut TextView| {
+                            view.set_content(text.clone())
+                        })
+                        .is_some()
+                        || siv
+                            .call_on_name(&name, |view: &mut EditView| {
+                                view.set_content(text.clone());
+                            })
+                            .is_some();
+                    if !found {
+                        return Err(Error::Ui(format!(
+                            "no text view named `{}`",
+                            name
+                        )));
+                    }
+                }
+                Command::BindKey(event, function) => {
+                    siv.add_global_callback(event, self.callback(function));
+                }
+                Command::Quit => siv.quit(),
+            }
+        }
+        Ok(())
+    }
+
+    // Builds a view from a blueprint.
+    fn build(&self, blueprint: &Map) -> Result<BoxedView, Error> {
+        let kind = get_string(blueprint, "type")?;
+        let name = match blueprint.get("name") {
+            Some(_) => Some(get_string(blueprint, "name")?),
+            None => None,
+        };
+        Ok(match kind.as_str() {
+            "TextView" => {
+                named(name, TextView::new(get_string_or(blueprint, "text")?))
+            }
+            "EditView" => named(
+                name,
+                EditView::new().content(get_string_or(blueprint, "content")?),
+            ),
+            "Button" => named(
+                name,
+                Button::new(
+                    get_string(blueprint, "label")?,
+                    self.callback(get_string(blueprint, "on_press")?),
+                ),
+            ),
+            "LinearLayout" => {
+                let orientation =
+                    match get_string_or(blueprint, "orientation")?.as_str() {
+                        "" | "vertical" => Orientation::Vertical,
+                        "horizontal" => Orientation::Horizontal,
+                        other => {
+                            return Err(Error::Ui(format!(
+                                "unknown orientation `{}`",
+                                other
+                            )))
+                        }
+                    };
+                let mut layout = LinearLayout::new(orientation);
+                for child in get_array(blueprint, "children")? {
+                    layout.add_child(self.build(&to_map(child)?)?);
+                }
+                named(name, layout)
+            }
+            "Panel" => named(
+                name,
+                Panel::new(self.build(&get_map(blueprint, "content")?)?)
+                    .title(get_string_or(blueprint, "title")?),
+            ),
+            "Dialog" => {
+                let mut dialog = match blueprint.get("content") {
+                    Some(content) => {
+                        Dialog::around(self.build(&to_map(content.clone())?)?)
+                    }
+                    None => Dialog::new(),
+                };
+                dialog.set_title(get_string_or(blueprint, "title")?);
+                for button in get_array(blueprint, "buttons")? {
+                    let button = to_map(button)?;
+                    dialog.add_button(
+                        get_string(&button, "label")?,
+                        self.callback(get_string(&button, "on_press")?),
+                    );
+                }
+                if blueprint.contains_key("dismiss") {
+                    dialog = dialog
+                        .dismiss_button(get_string(blueprint, "dismiss")?);
+                }
+                named(name, dialog)
+            }
+            other => {
+                return Err(Error::Ui(format!(
+                    "unknown view type `{}`",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+new_default!(ScriptEngine);
+
+// Boxes a view built from a blueprint, with its name if any.
+fn named<V: View>(name: Option<String>, view: V) -> BoxedView {
+    match name {
+        Some(name) => BoxedView::boxed(view.with_name(name)),
+        None => BoxedView::boxed(view),
+    }
+}
+
+// Adds the UI functions to `engine`.
+fn register_functions(
+    engine: &mut rhai::Engine,
+    commands: &Rc<RefCell<Vec<Command>>>,
+) {
+    let push = |commands: &Rc<RefCell<Vec<Command>>>| {
+        let commands = Rc::clone(commands);
+        move |command| commands.borrow_mut().push(command)
+    };
+
+    let add_layer = push(commands);
+    engine.register_fn("add_layer", move |blueprint: Map| {
+        add_layer(Command::AddLayer(blueprint))
+    });
+
+    let pop_layer = push(commands);
+    engine.register_fn("pop_layer", move || pop_layer(Command::PopLayer));
+
+    let set_text = push(commands);
+    engine.register_fn("set_text", move |name: &str, text: &str| {
+        set_text(Command::SetText(name.into(), text.into()))
+    });
+
+    let bind_key = push(commands);
+    engine.register_fn(
+        "bind_key",
+        move |key: &str, function: &str| -> Result<(), Box<EvalAltResult>> {
+            let event = parse_event(key)
+                .ok_or_else(|| format!("unknown key `{}`", key))?;
+            bind_key(Command::BindKey(event, function.into()));
+            Ok(())
+        },
+    );
+
+    let quit = push(commands);
+    engine.register_fn("quit", move || quit(Command::Quit));
+}
+
+// Parses a key like `q`, `Ctrl+s`, `Alt+x` or `F5`.
+fn parse_event(key: &str) -> Option<Event> {
+    let single = |c: &str| {
+        let mut chars = c.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    };
+
+    if let Some(c) = key.strip_prefix("Ctrl+").and_then(single) {
+        Some(Event::CtrlChar(c))
+    } else if let Some(c) = key.strip_prefix("Alt+").and_then(single) {
+        Some(Event::AltChar(c))
+    } else if let Some(c) = single(key) {
+        Some(Event::Char(c))
+    } else {
+        crate::testing::parse_key(key).map(Event::Key)
+    }
+}
+
+fn to_map(value: Dynamic) -> Result<Map, Error> {
+    let type_name = value.type_name();
+    value.try_cast::<Map>().ok_or_else(|| {
+        Error::Ui(format!("expected a blueprint, got {}", type_name))
+    })
+}
+
+fn get_map(blueprint: &Map, key: &str) -> Result<Map, Error> {
+    match blueprint.get(key) {
+        Some(value) => to_map(value.clone()),
+        None => Err(Error::Ui(format!("missing field `{}`", key))),
+    }
+}
+
+fn get_array(blueprint: &Map, key: &str) -> Result<Array, Error> {
+    match blueprint.get(key) {
+        Some(value) => value.clone().try_cast::<Array>().ok_or_else(|| {
+            Error::Ui(format!("field `{}` should be an array", key))
+        }),
+        None => Ok(Array::new()),
+    }
+}
+
+fn get_string(blueprint: &Map, key: &str) -> Result<String, Error> {
+    match blueprint.get(key) {
+        Some(value) => value.clone().into_string().map_err(|_| {
+            Error::Ui(format!("field `{}` should be a string", key))
+        }),
+        None => Err(Error::Ui(format!("missing field `{}`", key))),
+    }
+}
+
+// Returns an empty string if the field is missing.
+fn get_string_or(blueprint: &Map, key: &str) -> Result<String, Error> {
+    if blueprint.contains_key(key) {
+        get_string(blueprint, key)
+    } else {
+        Ok(String::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    #[test]
+    fn run_script() {
+        let mut siv = testing::dummy((40, 10));
+        let scripts = ScriptEngine::new();
+        scripts
+            .run(
+                &mut siv,
+                r#"
+                    fn hello() { set_text("out", "Hello"); }
+                    add_layer(#{ type: "TextView", name: "out", text: "-" });
+                    bind_key("Ctrl+h", "hello");
+                "#,
+            )
+            .unwrap();
+
+        siv.on_event(Event::CtrlChar('h'));
+        let content = siv
+            .call_on_name("out", |view: &mut TextView| {
+                view.get_content().source().to_string()
+            })
+            .unwrap();
+        assert_eq!(content, "Hello");
+
+        assert!(matches!(
+            scripts.run(&mut siv, r#"add_layer(#{ type: "Nope" });"#),
+            Err(Error::Ui(_))
+        ));
+        assert!(matches!(
+            scripts.run(&mut siv, r#"bind_key("Shift+Nope", "hello");"#),
+            Err(Error::Script(_))
+        ));
+    }
+}
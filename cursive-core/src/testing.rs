@@ -0,0 +1,442 @@
+//! Tools for end-to-end UI tests.
+//!
+//! Events given to [`Cursive::on_event`] can be recorded with
+//! [`Cursive::start_recording`], then replayed on another instance, usually
+//! created with [`dummy`]. The rendered screen can be captured with
+//! [`screen`] to check the result, without any terminal.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::event::Event;
+//! use cursive_core::testing::{self, Recording};
+//! use cursive_core::view::Resizable;
+//! use cursive_core::views::EditView;
+//!
+//! let mut siv = testing::dummy((10, 3));
+//! siv.add_layer(EditView::new().fixed_width(6));
+//!
+//! let recording = Recording::from(vec![Event::Char('h'), Event::Char('i')]);
+//! recording.replay(&mut siv);
+//!
+//! assert_eq!(testing::screen(&mut siv)[1], "  hi____  ");
+//! ```
+//!
+//! Tests can also be written as a list of [`UiCommand`], more readable than
+//! raw events, and run with [`run_commands`]:
+//!
+//! ```rust
+//! use cursive_core::testing::{self, UiCommand};
+//! use cursive_core::view::{Nameable, Resizable};
+//! use cursive_core::views::EditView;
+//!
+//! let mut siv = testing::dummy((10, 3));
+//! siv.add_layer(EditView::new().with_name("name").fixed_width(6));
+//!
+//! let script = "focus name\ntype Bob\nassert Bob";
+//! let commands = UiCommand::parse_script(script).unwrap();
+//! testing::run_commands(&mut siv, commands).unwrap();
+//! ```
+//!
+//! With the `test-helpers` feature, screens can be compared with
+//! [`assert_screen_matches!`](crate::assert_screen_matches), or with golden
+//! files using [`assert_screen_snapshot!`](crate::assert_screen_snapshot).
+use crate::buffer::BufferBackend;
+use crate::event::{Event, Key};
+use crate::{Cursive, Vec2};
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "test-helpers")]
+mod snapshot;
+
+#[cfg(feature = "test-helpers")]
+pub use self::snapshot::{
+    check_screen, check_snapshot, normalize_screen, ScreenMismatch,
+    SnapshotError, UPDATE_SNAPSHOTS_VAR,
+};
+
+// Keys that can be named in a `UiCommand::PressKey`.
+const KEYS: &[Key] = &[
+    Key::Enter,
+    Key::Tab,
+    Key::Backspace,
+    Key::Esc,
+    Key::Left,
+    Key::Right,
+    Key::Up,
+    Key::Down,
+    Key::Ins,
+    Key::Del,
+    Key::Home,
+    Key::End,
+    Key::PageUp,
+    Key::PageDown,
+    Key::PauseBreak,
+    Key::NumpadCenter,
+    Key::NumpadEnter,
+    Key::NumpadUp,
+    Key::NumpadDown,
+    Key::NumpadLeft,
+    Key::NumpadRight,
+    Key::NumpadHome,
+    Key::NumpadEnd,
+    Key::NumpadPageUp,
+    Key::NumpadPageDown,
+    Key::NumpadIns,
+    Key::NumpadDel,
+    Key::Numpad0,
+    Key::Numpad1,
+    Key::Numpad2,
+    Key::Numpad3,
+    Key::Numpad4,
+    Key::Numpad5,
+    Key::Numpad6,
+    Key::Numpad7,
+    Key::Numpad8,
+    Key::Numpad9,
+    Key::NumpadDecimal,
+    Key::NumpadAdd,
+    Key::NumpadSubtract,
+    Key::NumpadMultiply,
+    Key::NumpadDivide,
+    Key::F0,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+    Key::F21,
+    Key::F22,
+    Key::F23,
+    Key::F24,
+];
+
+/// Events received by a `Cursive` root.
+///
+/// Returned by [`Cursive::stop_recording`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Recording {
+    events: Vec<Event>,
+}
+
+impl Recording {
+    /// Creates an empty recording.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded events, in order.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Appends an event to the recording.
+    pub fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// Sends the recorded events to `siv`.
+    ///
+    /// See [`replay`].
+    pub fn replay(&self, siv: &mut Cursive) {
+        replay(siv, self.events.iter().cloned());
+    }
+}
+
+impl From<Vec<Event>> for Recording {
+    fn from(events: Vec<Event>) -> Self {
+        Recording { events }
+    }
+}
+
+impl IntoIterator for Recording {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.events.into_iter()
+    }
+}
+
+/// Step of a scripted UI session.
+///
+/// Commands are queued with [`Cursive::queue_commands`], and run one per
+/// step of the event loop. They can be written as text, one per line:
+///
+/// ```text
+/// focus <name>
+/// type <text>
+/// key <key>
+/// click <name>
+/// assert <text>
+/// ```
+///
+/// Keys are written as in `Key`, for example `Enter` or `F5`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UiCommand {
+    /// Focuses the view with the given name.
+    FocusName(String),
+    /// Types each character of the text.
+    TypeText(String),
+    /// Presses a key.
+    PressKey(Key),
+    /// Clicks in the middle of the view with the given name.
+    ///
+    /// The view must have been drawn in the last frame.
+    ClickName(String),
+    /// Checks that the text is on the screen.
+    AssertText(String),
+}
+
+impl UiCommand {
+    /// Parses commands written one per line.
+    ///
+    /// Empty lines and lines starting with `#` are ignored. Returns the
+    /// number of the first invalid line, starting at 1, if any.
+    pub fn parse_script(script: &str) -> Result<Vec<UiCommand>, usize> {
+        script
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with('#')
+            })
+            .map(|(i, line)| line.parse().map_err(|()| i + 1))
+            .collect()
+    }
+}
+
+impl fmt::Display for UiCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UiCommand::FocusName(ref name) => write!(f, "focus {}", name),
+            UiCommand::TypeText(ref text) => write!(f, "type {}", text),
+            UiCommand::PressKey(key) => write!(f, "key {:?}", key),
+            UiCommand::ClickName(ref name) => write!(f, "click {}", name),
+            UiCommand::AssertText(ref text) => write!(f, "assert {}", text),
+        }
+    }
+}
+
+// Returns the key with the given name, like `Enter` or `F5`.
+pub(crate) fn parse_key(name: &str) -> Option<Key> {
+    KEYS.iter()
+        .copied()
+        .find(|key| format!("{:?}", key) == name)
+}
+
+impl FromStr for UiCommand {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let s = s.trim_start();
+        let (command, arg) = match s.find(' ') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => (s, ""),
+        };
+        let arg = arg.to_string();
+
+        Ok(match command {
+            "focus" => UiCommand::FocusName(arg),
+            "type" => UiCommand::TypeText(arg),
+            "key" => UiCommand::PressKey(parse_key(arg.trim()).ok_or(())?),
+            "click" => UiCommand::ClickName(arg),
+            "assert" => UiCommand::AssertText(arg),
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Error returned when a `UiCommand` fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UiCommandError {
+    /// Command that failed.
+    pub command: UiCommand,
+    /// What went wrong.
+    pub message: String,
+}
+
+impl fmt::Display for UiCommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` failed: {}", self.command, self.message)
+    }
+}
+
+impl std::error::Error for UiCommandError {}
+
+/// Runs `commands` on `siv`, one per step, until they are all done.
+///
+/// Stops at the first failure, or if `siv` quits.
+pub fn run_commands<I>(
+    siv: &mut Cursive,
+    commands: I,
+) -> Result<(), UiCommandError>
+where
+    I: IntoIterator<Item = UiCommand>,
+{
+    siv.queue_commands(commands);
+    siv.refresh();
+    while siv.has_queued_commands() && siv.is_running() {
+        siv.step();
+    }
+
+    match siv.take_command_error() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Creates a `Cursive` root with an offscreen backend of the given size.
+///
+/// Unlike [`Cursive::dummy`], which has a single cell, its screen can be
+/// captured with [`screen`].
+pub fn dummy<S: Into<Vec2>>(size: S) -> Cursive {
+    let size = size.into();
+    Cursive::new(move || Box::new(BufferBackend::new(size)))
+}
+
+/// Sends `events` to `siv`, one after the other.
+///
+/// As in the event loop, callbacks sent to the `cb_sink` are run and the
+/// screen is refreshed after each event. Stops early if `siv` quits.
+pub fn replay<I>(siv: &mut Cursive, events: I)
+where
+    I: IntoIterator<Item = Event>,
+{
+    for event in events {
+        if !siv.is_running() {
+            break;
+        }
+        siv.on_event(event);
+        siv.process_events();
+        siv.refresh();
+    }
+}
+
+/// Renders the screen of `siv`, and returns it as lines of text.
+pub fn screen(siv: &mut Cursive) -> Vec<String> {
+    siv.screenshot().lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Nameable;
+    use crate::views::{Button, Dialog, EditView, LinearLayout};
+
+    fn build() -> Cursive {
+        let mut siv = dummy((20, 5));
+        siv.add_fullscreen_layer(Dialog::around(EditView::new()).button(
+            "Ok",
+            |s| {
+                s.pop_layer();
+            },
+        ));
+        siv
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut siv = build();
+        siv.start_recording();
+        for c in "abc".chars() {
+            siv.on_event(Event::Char(c));
+        }
+        siv.on_event(Event::Key(Key::Backspace));
+        let recording = siv.stop_recording();
+        assert_eq!(recording.events().len(), 4);
+
+        // Events after the recording stopped are not recorded.
+        siv.on_event(Event::Char('d'));
+        assert_eq!(siv.stop_recording(), Recording::new());
+
+        let mut other = build();
+        recording.replay(&mut other);
+
+        let lines = screen(&mut other);
+        assert_eq!(lines.len(), 5);
+        assert!(lines.iter().any(|line| line.contains("ab_")));
+    }
+
+    #[test]
+    fn commands() {
+        let mut siv = dummy((20, 5));
+        siv.add_layer(
+            LinearLayout::vertical()
+                .child(EditView::new().with_name("edit"))
+                .child(
+                    Button::new("Ok", |s| s.set_user_data(())).with_name("ok"),
+                ),
+        );
+
+        let script = "
+            # Edit the text.
+            type abc
+            key Backspace
+            assert ab
+            click ok
+        ";
+        let commands = UiCommand::parse_script(script).unwrap();
+        assert_eq!(commands[1], UiCommand::PressKey(Key::Backspace));
+        for command in &commands {
+            assert_eq!(command.to_string().parse(), Ok(command.clone()));
+        }
+
+        run_commands(&mut siv, commands).unwrap();
+        assert_eq!(siv.take_user_data(), Some(()));
+
+        // Failures stop the remaining commands.
+        let error = run_commands(
+            &mut siv,
+            vec![
+                UiCommand::AssertText("xyz".into()),
+                UiCommand::TypeText("d".into()),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(error.command, UiCommand::AssertText("xyz".into()));
+        assert!(!siv.has_queued_commands());
+        assert_eq!(UiCommand::parse_script("type a\nkey Nope"), Err(2));
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[test]
+    fn snapshots() {
+        use crate::views::TextView;
+
+        let mut siv = dummy((20, 5));
+        siv.add_fullscreen_layer(TextView::new("abc"));
+        crate::assert_screen_matches!(siv, "\x1b[1mabc\x1b[0m");
+
+        let mismatch = check_screen(&mut siv, "abd").unwrap_err();
+        assert_eq!(
+            mismatch.to_string(),
+            "screen does not match (-expected +actual):\n-|abd\n+|abc\n"
+        );
+
+        let path = std::env::temp_dir()
+            .join(format!("cursive-snapshot-{}", std::process::id()))
+            .join("edit.txt");
+        // Missing golden files are written.
+        check_snapshot(&mut siv, &path).unwrap();
+        crate::assert_screen_snapshot!(siv, &path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "abc\n");
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,239 @@
+use super::screen;
+use crate::Cursive;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Environment variable overwriting golden files with the current screen.
+///
+/// When set to any value, [`check_snapshot`] writes the screen to the file
+/// instead of comparing it.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "CURSIVE_UPDATE_SNAPSHOTS";
+
+/// Normalizes a screen capture, to compare it with another one.
+///
+/// ANSI escape codes are removed, trailing whitespace is trimmed on each
+/// line, and empty lines are dropped at the start and the end.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::testing::normalize_screen;
+///
+/// let screen = "\n\x1b[1mHello\x1b[0m   \n  \x1b]8;;https://example.com\x1b\\world\x1b]8;;\x1b\\\n\n";
+/// assert_eq!(normalize_screen(screen), "Hello\n  world");
+/// ```
+pub fn normalize_screen(screen: &str) -> String {
+    let screen = strip_ansi(screen);
+    let lines: Vec<&str> = screen.lines().map(str::trim_end).collect();
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => String::new(),
+    }
+}
+
+// Removes ANSI escape codes, like `\x1b[1;31m` or OSC 8 hyperlinks.
+fn strip_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                // Parameters, until the final byte.
+                for c in &mut chars {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            Some(']') => {
+                // Commands like hyperlinks, until the string terminator.
+                while let Some(c) = chars.next() {
+                    if c == '\x07'
+                        || (c == '\x1b' && chars.next() == Some('\\'))
+                    {
+                        break;
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    result
+}
+
+/// Difference between the screen and the expected one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScreenMismatch {
+    /// Expected screen, normalized.
+    pub expected: String,
+    /// Actual screen, normalized.
+    pub actual: String,
+}
+
+impl fmt::Display for ScreenMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "screen does not match (-expected +actual):")?;
+        let expected: Vec<&str> = self.expected.lines().collect();
+        let actual: Vec<&str> = self.actual.lines().collect();
+        for i in 0..expected.len().max(actual.len()) {
+            match (expected.get(i), actual.get(i)) {
+                (Some(e), Some(a)) if e == a => writeln!(f, " |{}", e)?,
+                (e, a) => {
+                    if let Some(e) = e {
+                        writeln!(f, "-|{}", e)?;
+                    }
+                    if let Some(a) = a {
+                        writeln!(f, "+|{}", a)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ScreenMismatch {}
+
+/// Error returned by [`check_snapshot`].
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The golden file could not be read or written.
+    Io(io::Error),
+    /// The screen does not match the golden file.
+    Mismatch(ScreenMismatch),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(err) => {
+                write!(f, "cannot use snapshot: {}", err)
+            }
+            SnapshotError::Mismatch(mismatch) => mismatch.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapshotError::Io(err) => Some(err),
+            SnapshotError::Mismatch(mismatch) => Some(mismatch),
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        SnapshotError::Io(err)
+    }
+}
+
+impl From<ScreenMismatch> for SnapshotError {
+    fn from(mismatch: ScreenMismatch) -> Self {
+        SnapshotError::Mismatch(mismatch)
+    }
+}
+
+/// Renders the screen of `siv`, and compares it with `expected`.
+///
+/// Both are normalized with [`normalize_screen`] first. See also
+/// [`assert_screen_matches!`](crate::assert_screen_matches).
+pub fn check_screen(
+    siv: &mut Cursive,
+    expected: &str,
+) -> Result<(), ScreenMismatch> {
+    let actual = normalize_screen(&screen(siv).join("\n"));
+    let expected = normalize_screen(expected);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ScreenMismatch { expected, actual })
+    }
+}
+
+/// Renders the screen of `siv`, and compares it with a golden file.
+///
+/// If the file doesn't exist, or if the [`UPDATE_SNAPSHOTS_VAR`]
+/// environment variable is set, the screen is written to it instead. See
+/// also [`assert_screen_snapshot!`](crate::assert_screen_snapshot).
+pub fn check_snapshot<P: AsRef<Path>>(
+    siv: &mut Cursive,
+    path: P,
+) -> Result<(), SnapshotError> {
+    let path = path.as_ref();
+    if path.exists() && std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_none() {
+        let expected = fs::read_to_string(path)?;
+        return Ok(check_screen(siv, &expected)?);
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let actual = normalize_screen(&screen(siv).join("\n"));
+    fs::write(path, actual + "\n")?;
+    Ok(())
+}
+
+/// Asserts that the screen of a `Cursive` root matches the given text.
+///
+/// Both are normalized with [`normalize_screen`] first. On failure, the
+/// differing lines are shown.
+///
+/// Requires the `test-helpers` feature.
+///
+/// [`normalize_screen`]: crate::testing::normalize_screen
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::assert_screen_matches;
+/// use cursive_core::views::TextView;
+///
+/// let mut siv = cursive_core::testing::dummy((11, 3));
+/// siv.add_fullscreen_layer(TextView::new("Hello\nworld"));
+/// assert_screen_matches!(siv, "Hello\nworld");
+/// ```
+#[macro_export]
+macro_rules! assert_screen_matches {
+    ($siv:expr, $expected:expr $(,)?) => {
+        if let Err(err) = $crate::testing::check_screen(&mut $siv, &$expected)
+        {
+            panic!("{}", err);
+        }
+    };
+}
+
+/// Asserts that the screen of a `Cursive` root matches a golden file.
+///
+/// The file is written if it doesn't exist yet, or if the
+/// `CURSIVE_UPDATE_SNAPSHOTS` environment variable is set.
+///
+/// Requires the `test-helpers` feature.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use cursive_core::assert_screen_snapshot;
+/// use cursive_core::views::Dialog;
+///
+/// let mut siv = cursive_core::testing::dummy((30, 8));
+/// siv.add_layer(Dialog::info("Saved."));
+/// assert_screen_snapshot!(siv, "tests/snapshots/saved.txt");
+/// ```
+#[macro_export]
+macro_rules! assert_screen_snapshot {
+    ($siv:expr, $path:expr $(,)?) => {
+        if let Err(err) = $crate::testing::check_snapshot(&mut $siv, $path) {
+            panic!("{}", err);
+        }
+    };
+}
@@ -47,6 +47,13 @@ impl BaseColor {
     }
 }
 
+impl BaseColor {
+    /// Returns the index of this color, between 0 and 7.
+    pub fn index(self) -> u8 {
+        self as u8
+    }
+}
+
 impl From<u8> for BaseColor {
     fn from(n: u8) -> Self {
         match n % 8 {
@@ -151,8 +158,9 @@ impl Color {
     /// Examples:
     /// * `"red"` becomes `Color::Dark(BaseColor::Red)`
     /// * `"light green"` becomes `Color::Light(BaseColor::Green)`
-    /// * `"default"` becomes `Color::TerminalDefault`
+    /// * `"default"` or `"terminal default"` becomes `Color::TerminalDefault`
     /// * `"#123456"` becomes `Color::Rgb(0x12, 0x34, 0x56)`
+    /// * `"color255(208)"` becomes `Color::from_256colors(208)`
     pub fn parse(value: &str) -> Option<Self> {
         Some(match value {
             "dark black" | "black" => Color::Dark(BaseColor::Black),
@@ -171,7 +179,9 @@ impl Color {
             "light magenta" => Color::Light(BaseColor::Magenta),
             "light cyan" => Color::Light(BaseColor::Cyan),
             "light white" => Color::Light(BaseColor::White),
-            "default" => Color::TerminalDefault,
+            "default" | "terminal default" | "terminal_default" => {
+                Color::TerminalDefault
+            }
             value => {
                 return parse_special(value).or_else(|| {
                     log::warn!("Could not parse color `{}`.", value);
@@ -180,10 +190,188 @@ impl Color {
             }
         })
     }
+
+    /// Returns the red, green and blue components of this color.
+    ///
+    /// Base colors depend on the terminal configuration; the usual xterm
+    /// values are returned for them.
+    ///
+    /// Returns `None` for `Color::TerminalDefault`.
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        const DARK: [(u8, u8, u8); 8] = [
+            (0, 0, 0),
+            (205, 0, 0),
+            (0, 205, 0),
+            (205, 205, 0),
+            (0, 0, 238),
+            (205, 0, 205),
+            (0, 205, 205),
+            (229, 229, 229),
+        ];
+        const LIGHT: [(u8, u8, u8); 8] = [
+            (127, 127, 127),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (92, 92, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+        // Channel values of the 6x6x6 color cube.
+        const LOW_RES: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        match self {
+            Color::TerminalDefault => None,
+            Color::Dark(base) => Some(DARK[base.index() as usize]),
+            Color::Light(base) => Some(LIGHT[base.index() as usize]),
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            Color::RgbLowRes(r, g, b) => Some((
+                LOW_RES[r as usize],
+                LOW_RES[g as usize],
+                LOW_RES[b as usize],
+            )),
+        }
+    }
+
+    /// Returns the relative luminance of this color.
+    ///
+    /// This goes from `0.0` for black to `1.0` for white, as defined by the
+    /// WCAG contrast guidelines.
+    ///
+    /// Returns `None` for `Color::TerminalDefault`.
+    pub fn luminance(self) -> Option<f32> {
+        // Converts a sRGB channel to linear light.
+        fn linear(c: u8) -> f32 {
+            let c = f32::from(c) / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        self.to_rgb().map(|(r, g, b)| {
+            0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+        })
+    }
+
+    /// Returns the contrast ratio between `self` and `other`.
+    ///
+    /// This goes from `1.0` for colors with the same luminance, to `21.0`
+    /// for black on white. WCAG recommends at least `4.5` for normal text.
+    ///
+    /// Returns `None` if either color is `Color::TerminalDefault`.
+    pub fn contrast_ratio(self, other: Color) -> Option<f32> {
+        let a = self.luminance()?;
+        let b = other.luminance()?;
+        let (light, dark) = if a > b { (a, b) } else { (b, a) };
+        Some((light + 0.05) / (dark + 0.05))
+    }
+
+    /// Returns a color close to `self`, readable on `background`.
+    ///
+    /// If the contrast ratio between `self` and `background` is below
+    /// `ratio`, `self` is moved towards black or white (whichever contrasts
+    /// more with `background`) until the ratio is reached. If it cannot be
+    /// reached, black or white is returned.
+    ///
+    /// `self` is returned unchanged if either color is
+    /// `Color::TerminalDefault`.
+    pub fn with_min_contrast(self, background: Color, ratio: f32) -> Color {
+        match self.contrast_ratio(background) {
+            Some(current) if current < ratio => (),
+            _ => return self,
+        }
+
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        let target = if white.contrast_ratio(background)
+            > black.contrast_ratio(background)
+        {
+            white
+        } else {
+            black
+        };
+
+        // Find the smallest step towards the target that is enough.
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..8 {
+            let t = (low + high) / 2.0;
+            let enough = self
+                .interpolate(target, t)
+                .contrast_ratio(background)
+                .map(|c| c >= ratio)
+                .unwrap_or(false);
+            if enough {
+                high = t;
+            } else {
+                low = t;
+            }
+        }
+
+        self.interpolate(target, high)
+    }
+
+    /// Returns a color between `self` and `target`.
+    ///
+    /// `t` goes from `0.0` (returns `self`) to `1.0` (returns `target`).
+    ///
+    /// The result is a `Color::Rgb` when both colors have RGB components (see
+    /// [`Color::to_rgb`]); otherwise, `target` is returned right away.
+    pub fn interpolate(self, target: Color, t: f32) -> Color {
+        if t <= 0.0 {
+            return self;
+        }
+        if t >= 1.0 || self == target {
+            return target;
+        }
+
+        match (self.to_rgb(), target.to_rgb()) {
+            (Some((r1, g1, b1)), Some((r2, g2, b2))) => {
+                let mix = |a: u8, b: u8| {
+                    (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round()
+                        as u8
+                };
+                Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+            }
+            _ => target,
+        }
+    }
+
+    /// Returns `steps` colors going from `self` to `target`, both included.
+    ///
+    /// This is useful to color each cell of a bar; see
+    /// [`interpolate`](#method.interpolate) for the color between each step.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use cursive_core::theme::Color;
+    /// let colors: Vec<_> =
+    ///     Color::Rgb(0, 0, 0).gradient(Color::Rgb(200, 0, 0), 3).collect();
+    /// assert_eq!(
+    ///     colors,
+    ///     [Color::Rgb(0, 0, 0), Color::Rgb(100, 0, 0), Color::Rgb(200, 0, 0)]
+    /// );
+    /// ```
+    pub fn gradient(
+        self,
+        target: Color,
+        steps: usize,
+    ) -> impl Iterator<Item = Color> {
+        let last = steps.saturating_sub(1).max(1) as f32;
+        (0..steps).map(move |i| self.interpolate(target, i as f32 / last))
+    }
 }
 
 fn parse_special(value: &str) -> Option<Color> {
-    if value.starts_with('#') {
+    if let Some(index) = value
+        .strip_prefix("color255(")
+        .and_then(|value| value.strip_suffix(')'))
+    {
+        index.trim().parse().ok().map(Color::from_256colors)
+    } else if value.starts_with('#') {
         parse_hex(&value[1..])
     } else if value.starts_with("0x") {
         parse_hex(&value[2..])
@@ -246,7 +434,46 @@ fn load_hex(s: &str) -> u16 {
 
 #[cfg(test)]
 mod tests {
-    use super::Color;
+    use super::{BaseColor, Color};
+
+    #[test]
+    fn test_interpolate() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(black.interpolate(white, 0.0), black);
+        assert_eq!(black.interpolate(white, 0.5), Color::Rgb(128, 128, 128));
+        assert_eq!(black.interpolate(white, 1.0), white);
+        assert_eq!(
+            Color::Dark(BaseColor::Black).interpolate(white, 0.5),
+            Color::Rgb(128, 128, 128)
+        );
+
+        // Terminal default colors cannot be blended.
+        assert_eq!(Color::TerminalDefault.interpolate(white, 0.1), white);
+    }
+
+    #[test]
+    fn test_min_contrast() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        let grey = Color::Rgb(100, 100, 100);
+        assert!((black.contrast_ratio(white).unwrap() - 21.0).abs() < 0.01);
+
+        // Already readable: unchanged.
+        assert_eq!(white.with_min_contrast(grey, 4.5), white);
+
+        // Grey on black gets lighter until it's readable.
+        let fixed = grey.with_min_contrast(black, 7.0);
+        assert!(fixed.contrast_ratio(black).unwrap() >= 7.0);
+        assert!(fixed.luminance() > grey.luminance());
+
+        // Impossible ratios go all the way.
+        assert_eq!(grey.with_min_contrast(black, 30.0), white);
+        assert_eq!(
+            Color::TerminalDefault.with_min_contrast(black, 4.5),
+            Color::TerminalDefault
+        );
+    }
 
     #[test]
     fn test_256_colors() {
@@ -270,6 +497,20 @@ mod tests {
             Color::parse("0xFEDCBA"),
             Some(Color::Rgb(0xfe, 0xdc, 0xba))
         );
+
+        assert_eq!(
+            Color::parse("color255(208)"),
+            Some(Color::from_256colors(208))
+        );
+        assert_eq!(
+            Color::parse("color255( 9 )"),
+            Some(Color::Light(BaseColor::Red))
+        );
+        assert_eq!(Color::parse("color255(256)"), None);
+        assert_eq!(
+            Color::parse("terminal default"),
+            Some(Color::TerminalDefault)
+        );
     }
 
     #[test]
@@ -21,6 +21,17 @@ impl ColorPair {
         }
     }
 
+    /// Returns a color pair with a readable front color.
+    ///
+    /// The front color is adjusted to reach at least the given contrast
+    /// ratio with the back color. See [`Color::with_min_contrast`].
+    pub fn with_min_contrast(self, ratio: f32) -> Self {
+        ColorPair {
+            front: self.front.with_min_contrast(self.back, ratio),
+            back: self.back,
+        }
+    }
+
     /// Creates a new color pair from color IDs.
     pub fn from_256colors(front: u8, back: u8) -> Self {
         Self {
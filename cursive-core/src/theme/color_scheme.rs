@@ -0,0 +1,262 @@
+use super::{Color, Theme};
+
+/// Preferred color scheme of the terminal or operating system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorScheme {
+    /// Light text on a dark background.
+    Dark,
+    /// Dark text on a light background.
+    Light,
+}
+
+impl ColorScheme {
+    /// Guesses the color scheme from the background color.
+    ///
+    /// Returns `None` for `Color::TerminalDefault`.
+    pub fn from_background(background: Color) -> Option<Self> {
+        background.luminance().map(|luminance| {
+            // Below this, white text contrasts better than black text.
+            if luminance < 0.179 {
+                ColorScheme::Dark
+            } else {
+                ColorScheme::Light
+            }
+        })
+    }
+
+    /// Parses the `COLORFGBG` environment variable format.
+    ///
+    /// This variable is set by some terminals (rxvt, konsole, ...) to
+    /// `"foreground;background"`, using color indexes.
+    #[cfg(any(test, feature = "color-scheme"))]
+    fn from_colorfgbg(value: &str) -> Option<Self> {
+        let background = value.rsplit(';').next()?.parse().ok()?;
+        Self::from_background(Color::from_256colors(background))
+    }
+}
+
+/// A pair of themes, for dark and light color schemes.
+///
+/// Used with `Cursive::set_theme_pair` to pick the theme matching the
+/// preferred color scheme.
+#[derive(Clone, Debug, Default)]
+pub struct ThemePair {
+    /// Theme to use with a dark color scheme.
+    pub dark: Theme,
+    /// Theme to use with a light color scheme.
+    pub light: Theme,
+}
+
+impl ThemePair {
+    /// Creates a new pair of themes.
+    pub fn new(dark: Theme, light: Theme) -> Self {
+        ThemePair { dark, light }
+    }
+
+    /// Returns the theme for the given color scheme.
+    pub fn get(&self, scheme: ColorScheme) -> &Theme {
+        match scheme {
+            ColorScheme::Dark => &self.dark,
+            ColorScheme::Light => &self.light,
+        }
+    }
+}
+
+/// Detects the preferred color scheme.
+///
+/// This looks, in order, at:
+/// * The `COLORFGBG` environment variable set by some terminals.
+/// * The desktop settings: `AppleInterfaceStyle` on macOS,
+///   `AppsUseLightTheme` on Windows, and the GNOME `color-scheme` setting
+///   on other platforms.
+///
+/// This does not query the terminal itself, as the running backend would
+/// receive the answer. See [`query_terminal_background`] to do that before
+/// starting cursive.
+///
+/// Returns `None` if no preference could be found.
+///
+/// Requires the `color-scheme` feature.
+#[cfg(feature = "color-scheme")]
+pub fn detect_color_scheme() -> Option<ColorScheme> {
+    std::env::var("COLORFGBG")
+        .ok()
+        .and_then(|value| ColorScheme::from_colorfgbg(&value))
+        .or_else(platform_color_scheme)
+}
+
+#[cfg(all(feature = "color-scheme", target_os = "macos"))]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    // The key is only defined when dark mode is enabled.
+    let output = std::process::Command::new("defaults")
+        .args(["read", "-g", "AppleInterfaceStyle"])
+        .output()
+        .ok()?;
+    let value = String::from_utf8_lossy(&output.stdout);
+    if value.trim() == "Dark" {
+        Some(ColorScheme::Dark)
+    } else {
+        Some(ColorScheme::Light)
+    }
+}
+
+#[cfg(all(feature = "color-scheme", windows))]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    let output = std::process::Command::new("reg")
+        .args([
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "/v",
+            "AppsUseLightTheme",
+        ])
+        .output()
+        .ok()?;
+    let value = String::from_utf8_lossy(&output.stdout);
+    if value.contains("0x0") {
+        Some(ColorScheme::Dark)
+    } else if value.contains("0x1") {
+        Some(ColorScheme::Light)
+    } else {
+        None
+    }
+}
+
+#[cfg(all(feature = "color-scheme", not(any(target_os = "macos", windows))))]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    let output = std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "color-scheme"])
+        .output()
+        .ok()?;
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "'prefer-dark'" => Some(ColorScheme::Dark),
+        "'prefer-light'" => Some(ColorScheme::Light),
+        _ => None,
+    }
+}
+
+/// Asks the terminal for its background color.
+///
+/// This sends an `OSC 11` query to `/dev/tty` and waits briefly for the
+/// answer. Terminals that don't support it will make this return `None`
+/// after a short timeout.
+///
+/// This must be called _before_ initializing the backend, since a running
+/// backend would intercept the answer.
+///
+/// Requires the `color-scheme` feature. Always returns `None` on non-unix
+/// platforms.
+#[cfg(feature = "color-scheme")]
+pub fn query_terminal_background() -> Option<Color> {
+    #[cfg(unix)]
+    {
+        query_osc11().and_then(|response| parse_osc11(&response))
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+#[cfg(all(feature = "color-scheme", unix))]
+fn query_osc11() -> Option<String> {
+    use std::fs::OpenOptions;
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let fd = tty.as_raw_fd();
+
+    // Disable line buffering and echo, and time out reads after 100ms.
+    let mut termios: libc::termios = unsafe { std::mem::zeroed() };
+    if unsafe { libc::tcgetattr(fd, &mut termios) } != 0 {
+        return None;
+    }
+    let original = termios;
+    termios.c_lflag &= !(libc::ICANON | libc::ECHO);
+    termios.c_cc[libc::VMIN] = 0;
+    termios.c_cc[libc::VTIME] = 1;
+    if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &termios) } != 0 {
+        return None;
+    }
+
+    let mut response = Vec::new();
+    if tty
+        .write_all(b"\x1b]11;?\x07")
+        .and_then(|_| tty.flush())
+        .is_ok()
+    {
+        let mut buf = [0u8; 64];
+        // The answer ends with BEL or ST (`ESC \`).
+        while !response.ends_with(b"\x07")
+            && !response.ends_with(b"\x1b\\")
+            && response.len() < 256
+        {
+            match tty.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => response.extend_from_slice(&buf[..n]),
+            }
+        }
+    }
+
+    unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+
+    String::from_utf8(response).ok()
+}
+
+/// Parses an answer like `ESC ] 11 ; rgb:RRRR/GGGG/BBBB BEL`.
+#[cfg(any(test, all(feature = "color-scheme", unix)))]
+fn parse_osc11(response: &str) -> Option<Color> {
+    let start = response.find("rgb:")? + 4;
+    let mut channels = response[start..]
+        .trim_end_matches(&['\x07', '\\', '\x1b'][..])
+        .split('/')
+        .map(|channel| {
+            // Each channel has 1 to 4 hex digits.
+            if channel.is_empty() || channel.len() > 4 {
+                return None;
+            }
+            let value = u32::from_str_radix(channel, 16).ok()?;
+            let max = (1u32 << (4 * channel.len())) - 1;
+            Some(((value * 255 + max / 2) / max) as u8)
+        });
+
+    let r = channels.next()??;
+    let g = channels.next()??;
+    let b = channels.next()??;
+    Some(Color::Rgb(r, g, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colorfgbg() {
+        assert_eq!(
+            ColorScheme::from_colorfgbg("15;0"),
+            Some(ColorScheme::Dark)
+        );
+        assert_eq!(
+            ColorScheme::from_colorfgbg("0;default;15"),
+            Some(ColorScheme::Light)
+        );
+        assert_eq!(ColorScheme::from_colorfgbg("15;default"), None);
+    }
+
+    #[test]
+    fn osc11() {
+        assert_eq!(
+            parse_osc11("\x1b]11;rgb:ffff/8080/0000\x07"),
+            Some(Color::Rgb(255, 128, 0))
+        );
+        assert_eq!(
+            parse_osc11("\x1b]11;rgb:ff/ff/ff\x1b\\"),
+            Some(Color::Rgb(255, 255, 255))
+        );
+        assert_eq!(parse_osc11("garbage"), None);
+    }
+}
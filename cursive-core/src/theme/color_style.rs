@@ -127,6 +127,30 @@ pub enum ColorType {
 
     /// Uses a direct color, independent of the current palette.
     Color(Color),
+
+    /// Uses a custom color from the application palette, by name.
+    ///
+    /// Custom colors can be defined with [`Palette::set_color`], or with any
+    /// extra key in the `[colors]` section of a theme file.
+    ///
+    /// Resolves to `Color::TerminalDefault` if the palette doesn't define
+    /// this color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::{Color, ColorType, Palette};
+    /// use cursive_core::utils::markup::StyledString;
+    ///
+    /// let mut palette = Palette::default();
+    /// palette.set_color("accent", Color::Rgb(255, 136, 0));
+    ///
+    /// let accent = ColorType::Custom("accent");
+    /// assert_eq!(accent.resolve(&palette), Color::Rgb(255, 136, 0));
+    ///
+    /// let text = StyledString::styled("Important", accent);
+    /// ```
+    Custom(&'static str),
 }
 
 impl ColorType {
@@ -135,6 +159,10 @@ impl ColorType {
         match self {
             ColorType::Color(color) => color,
             ColorType::Palette(color) => color.resolve(palette),
+            ColorType::Custom(key) => palette
+                .custom(key)
+                .cloned()
+                .unwrap_or(Color::TerminalDefault),
         }
     }
 }
@@ -0,0 +1,206 @@
+//! Reduce colors to what a terminal can display.
+//!
+//! Terminals support either true colors, the 256-color palette, or only the
+//! 16 (sometimes 8) base colors. The functions here find the closest color
+//! in these smaller sets, and are used by the backends.
+//!
+//! Base colors depend on the terminal configuration; the usual xterm values
+//! are assumed for them (see [`Color::to_rgb`]).
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::theme::downsample::{self, Strategy};
+//! use cursive_core::theme::{BaseColor, Color};
+//!
+//! let orange = Color::Rgb(255, 135, 0);
+//! assert_eq!(downsample::to_256(orange, Strategy::Nearest), Some(208));
+//! assert_eq!(
+//!     downsample::downsample(orange, 16, Strategy::Perceptual),
+//!     Color::Dark(BaseColor::Red),
+//! );
+//! ```
+use super::{BaseColor, Color};
+
+/// How the distance between two colors is measured.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Strategy {
+    /// Euclidean distance between the red, green and blue components.
+    ///
+    /// Fast, but can pick colors of a visibly different hue.
+    #[default]
+    Nearest,
+
+    /// Euclidean distance in the CIE Lab color space.
+    ///
+    /// Closer to how differences are perceived.
+    Perceptual,
+}
+
+impl Strategy {
+    /// Returns the distance between two colors.
+    ///
+    /// Only the ordering of distances is meaningful.
+    pub fn distance(self, a: (u8, u8, u8), b: (u8, u8, u8)) -> f32 {
+        let (a, b) = match self {
+            Strategy::Nearest => (rgb(a), rgb(b)),
+            Strategy::Perceptual => (lab(a), lab(b)),
+        };
+        (0..3).map(|i| (a[i] - b[i]).powi(2)).sum()
+    }
+}
+
+/// Returns the closest color in the 256-color palette, as an index.
+///
+/// Base colors keep their index (0 to 15), and `Color::RgbLowRes` maps to
+/// its place in the color cube. True colors are matched against the color
+/// cube and the grayscale ramp (16 to 255), which don't depend on the
+/// terminal configuration.
+///
+/// Returns `None` for `Color::TerminalDefault`.
+pub fn to_256(color: Color, strategy: Strategy) -> Option<u8> {
+    match color {
+        Color::TerminalDefault => None,
+        Color::Dark(base) => Some(base.index()),
+        Color::Light(base) => Some(8 + base.index()),
+        Color::RgbLowRes(r, g, b) => Some(16 + 36 * r + 6 * g + b),
+        Color::Rgb(..) => closest(color, 16..=255, strategy),
+    }
+}
+
+/// Returns the closest of the 16 base colors, as an index.
+///
+/// Returns `None` for `Color::TerminalDefault`.
+pub fn to_16(color: Color, strategy: Strategy) -> Option<u8> {
+    match color {
+        Color::TerminalDefault => None,
+        Color::Dark(base) => Some(base.index()),
+        Color::Light(base) => Some(8 + base.index()),
+        _ => closest(color, 0..=15, strategy),
+    }
+}
+
+/// Returns the closest of the 8 dark base colors, as an index.
+///
+/// Light colors use their dark version.
+///
+/// Returns `None` for `Color::TerminalDefault`.
+pub fn to_8(color: Color, strategy: Strategy) -> Option<u8> {
+    match color {
+        Color::TerminalDefault => None,
+        Color::Dark(base) | Color::Light(base) => Some(base.index()),
+        _ => closest(color, 0..=7, strategy),
+    }
+}
+
+/// Returns the closest color for a terminal supporting `max_colors` colors.
+///
+/// True colors are kept if `max_colors` is at least `1 << 24`. Otherwise,
+/// the result is one of the first `max_colors` colors of the 256-color
+/// palette (grays are returned as `Color::Rgb`, see
+/// [`Color::from_256colors`]).
+pub fn downsample(color: Color, max_colors: u32, strategy: Strategy) -> Color {
+    let index = if max_colors >= 1 << 24 {
+        return color;
+    } else if max_colors >= 256 {
+        to_256(color, strategy)
+    } else if max_colors >= 16 {
+        to_16(color, strategy)
+    } else {
+        to_8(color, strategy)
+    };
+
+    index.map_or(color, Color::from_256colors)
+}
+
+// Index of the palette color closest to `color`.
+fn closest(
+    color: Color,
+    candidates: std::ops::RangeInclusive<u8>,
+    strategy: Strategy,
+) -> Option<u8> {
+    let target = color.to_rgb()?;
+    candidates
+        .map(|index| {
+            let candidate = palette(index);
+            (strategy.distance(target, candidate), index)
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, index)| index)
+}
+
+// Red, green and blue components of a palette color.
+fn palette(index: u8) -> (u8, u8, u8) {
+    let color = match index {
+        0..=7 => Color::Dark(BaseColor::from(index)),
+        8..=15 => Color::Light(BaseColor::from(index)),
+        _ => Color::from_256colors(index),
+    };
+    // Only `TerminalDefault` has no components.
+    color.to_rgb().unwrap()
+}
+
+fn rgb((r, g, b): (u8, u8, u8)) -> [f32; 3] {
+    [f32::from(r), f32::from(g), f32::from(b)]
+}
+
+// Converts sRGB components to CIE Lab, with a D65 white point.
+fn lab((r, g, b): (u8, u8, u8)) -> [f32; 3] {
+    fn linear(c: u8) -> f32 {
+        let c = f32::from(c) / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    fn f(t: f32) -> f32 {
+        if t > 0.008856 {
+            t.cbrt()
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    }
+
+    let (r, g, b) = (linear(r), linear(g), linear(b));
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palettes() {
+        let strategies = [Strategy::Nearest, Strategy::Perceptual];
+        for strategy in strategies {
+            // Palette colors map to themselves.
+            assert_eq!(to_256(Color::Rgb(95, 135, 175), strategy), Some(67));
+            assert_eq!(to_256(Color::Rgb(128, 128, 128), strategy), Some(244));
+            assert_eq!(to_16(Color::Rgb(255, 0, 0), strategy), Some(9));
+            assert_eq!(to_8(Color::Light(BaseColor::Cyan), strategy), Some(6));
+            assert_eq!(to_256(Color::TerminalDefault, strategy), None);
+        }
+
+        assert_eq!(
+            downsample(Color::Rgb(10, 10, 10), 256, Strategy::Nearest),
+            Color::Rgb(8, 8, 8)
+        );
+
+        // Strategies can disagree.
+        let lavender = Color::Rgb(90, 90, 200);
+        assert_eq!(
+            downsample(lavender, 8, Strategy::Nearest),
+            Color::Dark(BaseColor::Blue)
+        );
+        assert_eq!(
+            downsample(lavender, 8, Strategy::Perceptual),
+            Color::Dark(BaseColor::Magenta)
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use enumset::EnumSetType;
+use std::str::FromStr;
 
 /// Text effect
 #[derive(EnumSetType, Debug)]
@@ -16,3 +17,21 @@ pub enum Effect {
     /// Prints foreground with underline
     Underline,
 }
+
+impl FromStr for Effect {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use Effect::*;
+
+        Ok(match s {
+            "Simple" | "simple" => Simple,
+            "Reverse" | "reverse" => Reverse,
+            "Bold" | "bold" => Bold,
+            "Italic" | "italic" => Italic,
+            "Strikethrough" | "strikethrough" => Strikethrough,
+            "Underline" | "underline" => Underline,
+            _ => return Err(()),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+/// Target of a hyperlink, to use in a [`Style`](super::Style).
+///
+/// The URL is reference-counted, so cloning a link is cheap.
+///
+/// Backends supporting OSC 8 sequences print linked text as clickable
+/// hyperlinks; others print it as plain text. Screen exports keep links,
+/// and `markdown::parse_with_links` parses markdown links into this style.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::theme::Link;
+/// use cursive_core::utils::markup::StyledString;
+///
+/// let mut text = StyledString::plain("See ");
+/// text.append_styled("the docs", Link::new("https://docs.rs/cursive"));
+///
+/// assert_eq!(Link::new("https://docs.rs/cursive").url(), "https://docs.rs/cursive");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Link(Arc<str>);
+
+impl Link {
+    /// Returns a link to the given URL.
+    pub fn new(url: &str) -> Self {
+        Link(Arc::from(url))
+    }
+
+    /// Returns the URL for this link.
+    pub fn url(&self) -> &str {
+        &self.0
+    }
+}
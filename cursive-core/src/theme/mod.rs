@@ -47,8 +47,10 @@
 //! * An exact [`Color`] can be given directly
 //! * A [`PaletteColor`] entry can be given, which will fetch whatever color
 //!   is currently defined for this.
+//! * A custom palette entry can be given by name, for colors beyond the
+//!   fixed `PaletteColor` set (like an accent color for branded apps).
 //!
-//! The [`ColorType`] enum abstract over these two choices.
+//! The [`ColorType`] enum abstract over these choices.
 //!
 //! # Color Styles
 //!
@@ -144,22 +146,57 @@
 //!     # Lower precision values can use only 3 digits.
 //!     highlight          = "#F00"
 //!     highlight_inactive = "#5555FF"
+//!
+//!     # Colors of the 256-color palette use their index,
+//!     # and the terminal's own colors are "terminal default".
+//!     # Terminals with fewer colors show the closest one they have.
+//!     highlight_text = "color255(231)"
+//!
+//!     # Colors can also be relative to the terminal background, or to
+//!     # another entry. See `RelativeColor` for details.
+//!     tertiary = "terminal +60%"
+//!
+//!     # Any other key defines a custom color.
+//!     # Views can use it with `ColorType::Custom("accent")`.
+//!     accent = "#ff8800"
+//!
+//! # Named styles, used with `Style::named("warning")`.
+//! [styles.warning]
+//!     # Palette entries, custom colors or direct colors.
+//!     front = "accent"
+//!     back = "view"
+//!     effects = ["bold"]
+//!
+//! # Styles can inherit from another one, and override some fields.
+//! [styles.error]
+//!     inherits = "warning"
+//!     front = "red"
 //! ```
 mod border_style;
 mod color;
 mod color_pair;
+mod color_scheme;
 mod color_style;
+pub mod downsample;
 mod effect;
+mod link;
 mod palette;
+mod relative_color;
 mod style;
 
 pub use self::border_style::BorderStyle;
 pub use self::color::{BaseColor, Color};
 pub use self::color_pair::ColorPair;
+#[cfg(feature = "color-scheme")]
+pub use self::color_scheme::{detect_color_scheme, query_terminal_background};
+pub use self::color_scheme::{ColorScheme, ThemePair};
 pub use self::color_style::{ColorStyle, ColorType};
 pub use self::effect::Effect;
+pub use self::link::Link;
 pub use self::palette::{Palette, PaletteColor};
+pub use self::relative_color::RelativeColor;
 pub use self::style::Style;
+use std::collections::HashMap;
 #[cfg(feature = "toml")]
 use std::fs::File;
 use std::io;
@@ -169,7 +206,7 @@ use std::io::Read;
 use std::path::Path;
 
 /// Represents the style a Cursive application will use.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Theme {
     /// Whether views in a StackView should have shadows.
     pub shadow: bool,
@@ -177,6 +214,8 @@ pub struct Theme {
     pub borders: BorderStyle,
     /// What colors should be used through the application?
     pub palette: Palette,
+    // Styles defined by the application, used with `Style::named`.
+    pub(crate) styles: HashMap<String, Style>,
 }
 
 impl Default for Theme {
@@ -185,11 +224,60 @@ impl Default for Theme {
             shadow: true,
             borders: BorderStyle::Simple,
             palette: Palette::default(),
+            styles: HashMap::new(),
         }
     }
 }
 
 impl Theme {
+    /// Pairs a dark and a light theme, to follow the terminal's colors.
+    ///
+    /// Give the result to `Cursive::set_theme_pair`, which picks the theme
+    /// matching the terminal background or the system preference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::{BaseColor, Color, PaletteColor, Theme};
+    ///
+    /// let mut light = Theme::default();
+    /// light.palette[PaletteColor::View] = Color::Light(BaseColor::White);
+    /// let pair = Theme::adaptive(Theme::default(), light);
+    /// ```
+    pub fn adaptive(dark: Theme, light: Theme) -> ThemePair {
+        ThemePair::new(dark, light)
+    }
+
+    /// Defines a named style, used with [`Style::named`].
+    ///
+    /// Replaces any style previously defined with this name.
+    pub fn set_style<S: Into<String>>(&mut self, name: S, style: Style) {
+        self.styles.insert(name.into(), style);
+    }
+
+    /// Returns the named style, if it is defined.
+    pub fn get_style(&self, name: &str) -> Option<&Style> {
+        self.styles.get(name)
+    }
+
+    /// Removes a named style, returning it if it was defined.
+    pub fn remove_style(&mut self, name: &str) -> Option<Style> {
+        self.styles.remove(name)
+    }
+
+    /// Returns a copy of this theme using the high-contrast palette.
+    ///
+    /// The basic colors come from [`Palette::high_contrast`], and shadows
+    /// are disabled. Custom colors and named styles are kept.
+    pub fn high_contrast(&self) -> Theme {
+        let mut theme = self.clone();
+        theme
+            .palette
+            .extend(Palette::high_contrast().basic_colors());
+        theme.shadow = false;
+        theme
+    }
+
     #[cfg(feature = "toml")]
     fn load_toml(&mut self, table: &toml::value::Table) {
         if let Some(&toml::Value::Boolean(shadow)) = table.get("shadow") {
@@ -203,6 +291,10 @@ impl Theme {
         if let Some(&toml::Value::Table(ref table)) = table.get("colors") {
             palette::load_toml(&mut self.palette, table);
         }
+
+        if let Some(&toml::Value::Table(ref table)) = table.get("styles") {
+            style::load_toml(&mut self.styles, table, &self.palette);
+        }
     }
 }
 
@@ -263,3 +355,139 @@ pub fn load_toml(content: &str) -> Result<Theme, Error> {
 pub fn load_default() -> Theme {
     Theme::default()
 }
+
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_colors() {
+        let theme = load_toml(
+            r##"
+            [colors]
+            primary = "black"
+            accent = "#ff8800"
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            ColorType::Custom("accent").resolve(&theme.palette),
+            Color::Rgb(0xff, 0x88, 0x00)
+        );
+        assert_eq!(
+            ColorType::Custom("missing").resolve(&theme.palette),
+            Color::TerminalDefault
+        );
+    }
+
+    #[test]
+    fn palette_colors() {
+        let theme = load_toml(
+            r##"
+            [colors]
+            highlight_text = "color255(208)"
+            background = "terminal default"
+            view = "#336699"
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            theme.palette[PaletteColor::HighlightText],
+            Color::from_256colors(208)
+        );
+        assert_eq!(
+            theme.palette[PaletteColor::Background],
+            Color::TerminalDefault
+        );
+        assert_eq!(
+            theme.palette[PaletteColor::View],
+            Color::Rgb(0x33, 0x66, 0x99)
+        );
+    }
+
+    #[test]
+    fn relative_colors() {
+        let mut theme = load_toml(
+            r##"
+            [colors]
+            view = "#202020"
+            background = "terminal +0%"
+            shadow = "view -100%"
+            "##,
+        )
+        .unwrap();
+
+        // Unknown terminal background: keep the default.
+        assert_eq!(
+            theme.palette[PaletteColor::Background],
+            Palette::default()[PaletteColor::Background]
+        );
+        assert_eq!(theme.palette[PaletteColor::Shadow], Color::Rgb(0, 0, 0));
+
+        theme
+            .palette
+            .set_terminal_background(Color::Rgb(250, 250, 250));
+        assert_eq!(
+            theme.palette[PaletteColor::Background],
+            Color::Rgb(250, 250, 250)
+        );
+    }
+
+    #[test]
+    fn named_styles() {
+        use crate::buffer::render_to_buffer_with_theme;
+        use crate::utils::markup::StyledString;
+        use crate::views::TextView;
+        use crate::Vec2;
+
+        let theme = load_toml(
+            r##"
+            [colors]
+            accent = "#ff8800"
+
+            [styles.warning]
+            front = "accent"
+            effects = ["bold"]
+
+            [styles.error]
+            inherits = "warning"
+            back = "highlight"
+            effects = ["underline"]
+
+            [styles.loop]
+            inherits = "loop"
+            front = "red"
+            "##,
+        )
+        .unwrap();
+
+        let error = theme.get_style("error").unwrap();
+        assert_eq!(
+            error.color,
+            Some(ColorStyle::new(
+                Color::Rgb(0xff, 0x88, 0x00),
+                PaletteColor::Highlight
+            ))
+        );
+        assert_eq!(error.effects, Effect::Bold | Effect::Underline);
+        assert!(theme.get_style("loop").is_some());
+
+        // Named styles are looked up when printing, below other fields.
+        let text = StyledString::styled(
+            "x",
+            Style::named("error").combine(Effect::Italic),
+        );
+        let buffer = render_to_buffer_with_theme(
+            &mut TextView::new(text),
+            (1, 1),
+            &theme,
+        );
+        let cell = buffer.get(Vec2::zero()).unwrap();
+        assert_eq!(cell.colors.front, Color::Rgb(0xff, 0x88, 0x00));
+        assert!(cell
+            .effects
+            .is_superset(Effect::Bold | Effect::Underline | Effect::Italic));
+    }
+}
@@ -1,4 +1,4 @@
-use super::Color;
+use super::{Color, RelativeColor};
 use enum_map::{enum_map, Enum, EnumMap};
 #[cfg(feature = "toml")]
 use log::warn;
@@ -39,6 +39,12 @@ type HashMap<K, V> = std::collections::HashMap<K, V, ahash::RandomState>;
 pub struct Palette {
     basic: EnumMap<PaletteColor, Color>,
     custom: HashMap<String, PaletteNode>,
+
+    // Entries computed from other colors, in definition order.
+    relative: Vec<(String, RelativeColor)>,
+
+    // Used as base for relative colors.
+    terminal_background: Color,
 }
 
 /// A node in the palette tree.
@@ -73,6 +79,61 @@ impl IndexMut<PaletteColor> for Palette {
 }
 
 impl Palette {
+    /// Returns the built-in high-contrast palette.
+    ///
+    /// Bright white and yellow text on a black background:
+    ///
+    /// * `Background`, `Shadow` and `View` => `Dark(Black)`
+    /// * `Primary` and `Secondary` => `Light(White)`
+    /// * `Tertiary` => `Dark(White)`
+    /// * `TitlePrimary` and `Highlight` => `Light(Yellow)`
+    /// * `TitleSecondary` => `Light(Cyan)`
+    /// * `HighlightInactive` => `Light(White)`
+    /// * `HighlightText` => `Dark(Black)`
+    pub fn high_contrast() -> Palette {
+        use self::PaletteColor::*;
+        use crate::theme::BaseColor::*;
+        use crate::theme::Color::*;
+
+        Palette {
+            basic: enum_map! {
+                Background => Dark(Black),
+                Shadow => Dark(Black),
+                View => Dark(Black),
+                Primary => Light(White),
+                Secondary => Light(White),
+                Tertiary => Dark(White),
+                TitlePrimary => Light(Yellow),
+                TitleSecondary => Light(Cyan),
+                Highlight => Light(Yellow),
+                HighlightInactive => Light(White),
+                HighlightText => Dark(Black),
+            },
+            ..Palette::default()
+        }
+    }
+
+    /// Returns the basic colors of this palette.
+    pub(crate) fn basic_colors(
+        &self,
+    ) -> impl Iterator<Item = (PaletteColor, Color)> + '_ {
+        self.basic.iter().map(|(key, &color)| (key, color))
+    }
+
+    /// Returns this palette, with colors blended towards the background.
+    ///
+    /// Colors that cannot be blended are kept as they are.
+    pub(crate) fn dimmed(&self, amount: f32) -> Palette {
+        let background = self.basic[PaletteColor::Background];
+        let mut result = self.clone();
+        for color in result.basic.values_mut() {
+            if color.to_rgb().is_some() && background.to_rgb().is_some() {
+                *color = color.interpolate(background, amount);
+            }
+        }
+        result
+    }
+
     /// Returns a custom color from this palette.
     ///
     /// Returns `None` if the given key was not found.
@@ -130,6 +191,95 @@ impl Palette {
         PaletteColor::from_str(key).map(|c| self.basic[c] = color)
     }
 
+    /// Returns a palette between `self` and `target`.
+    ///
+    /// `t` goes from `0.0` (returns `self`) to `1.0` (returns `target`).
+    ///
+    /// Each color is blended with [`Color::interpolate`]. Custom colors are
+    /// only blended when they are defined in both palettes; other custom
+    /// values are taken from `target`.
+    pub fn interpolate(&self, target: &Palette, t: f32) -> Palette {
+        let mut result = target.clone();
+
+        for (key, color) in result.basic.iter_mut() {
+            *color = self.basic[key].interpolate(*color, t);
+        }
+
+        for (key, node) in result.custom.iter_mut() {
+            if let (
+                PaletteNode::Color(ref mut color),
+                Some(&PaletteNode::Color(from)),
+            ) = (node, self.custom.get(key))
+            {
+                *color = from.interpolate(*color, t);
+            }
+        }
+
+        result
+    }
+
+    /// Defines the given entry relative to another color.
+    ///
+    /// The color is computed right away if possible, and again whenever the
+    /// terminal background changes. Relative entries are computed in the
+    /// order they were defined, so they can depend on each other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::{Color, Palette, PaletteColor, RelativeColor};
+    ///
+    /// let mut palette = Palette::default();
+    /// palette.set_relative_color("view", RelativeColor::new("terminal", 10));
+    ///
+    /// // Dark terminal: the view is a bit lighter.
+    /// palette.set_terminal_background(Color::Rgb(0, 0, 0));
+    /// assert_eq!(palette[PaletteColor::View], Color::Rgb(26, 26, 26));
+    ///
+    /// // Light terminal: the view is a bit darker.
+    /// palette.set_terminal_background(Color::Rgb(255, 255, 255));
+    /// assert_eq!(palette[PaletteColor::View], Color::Rgb(230, 230, 230));
+    /// ```
+    pub fn set_relative_color(&mut self, key: &str, color: RelativeColor) {
+        self.relative.retain(|(k, _)| k != key);
+        self.relative.push((key.to_string(), color));
+        self.update_relative_colors();
+    }
+
+    /// Returns the terminal background color used for relative colors.
+    ///
+    /// This is `Color::TerminalDefault` until it is set.
+    pub fn terminal_background(&self) -> Color {
+        self.terminal_background
+    }
+
+    /// Sets the terminal background, and updates relative colors.
+    pub fn set_terminal_background(&mut self, color: Color) {
+        self.terminal_background = color;
+        self.update_relative_colors();
+    }
+
+    /// Computes again all relative colors.
+    ///
+    /// Entries whose base color is unknown keep their current value.
+    pub fn update_relative_colors(&mut self) {
+        for (key, relative) in self.relative.clone() {
+            let base = if relative.base == "terminal" {
+                self.terminal_background
+            } else if let Ok(color) = PaletteColor::from_str(&relative.base) {
+                self[color]
+            } else {
+                self.custom(&relative.base)
+                    .cloned()
+                    .unwrap_or(Color::TerminalDefault)
+            };
+
+            if let Some(color) = relative.apply(base) {
+                self.set_color(&key, color);
+            }
+        }
+    }
+
     /// Adds a color namespace to this palette.
     pub fn add_namespace(
         &mut self,
@@ -186,6 +336,8 @@ impl Default for Palette {
                 HighlightText => Dark(White),
             },
             custom: HashMap::default(),
+            relative: Vec::new(),
+            terminal_background: Color::TerminalDefault,
         }
     }
 }
@@ -216,6 +368,12 @@ fn iterate_toml<'a>(
                     .map(PaletteNode::Color)
                     .next()
             }
+            toml::Value::String(color)
+                if RelativeColor::parse(color).is_some() =>
+            {
+                // Relative colors are handled separately.
+                None
+            }
             toml::Value::String(color) => {
                 // This describe a new color - easy!
                 Color::parse(color).map(PaletteNode::Color)
@@ -246,6 +404,13 @@ pub(crate) fn load_toml(palette: &mut Palette, table: &toml::value::Table) {
             PaletteNode::Namespace(map) => palette.add_namespace(key, map),
         }
     }
+
+    // Relative colors may depend on any other entry, so add them last.
+    for (key, value) in table {
+        if let Some(relative) = value.as_str().and_then(RelativeColor::parse) {
+            palette.set_relative_color(key, relative);
+        }
+    }
 }
 
 /// Color entry in a palette.
@@ -0,0 +1,128 @@
+use super::Color;
+
+/// A color defined relative to another one.
+///
+/// This lets a single theme look right on both dark and light terminals: a
+/// palette entry can follow the terminal background, with a bit more or less
+/// contrast.
+///
+/// In a theme file, it is written as `"<base> <+/-amount>%"`, where `base`
+/// is either `terminal` for the terminal background, or the name of another
+/// palette entry:
+///
+/// ```toml
+/// [colors]
+///     background = "terminal +0%"
+///     view = "terminal +10%"
+///     shadow = "terminal -20%"
+///     highlight_inactive = "view +30%"
+/// ```
+///
+/// The terminal background is usually unknown, and must be given with
+/// [`Palette::set_terminal_background`] or
+/// `Cursive::set_terminal_background`. Until then, entries relative to it
+/// keep their previous color.
+///
+/// [`Palette::set_terminal_background`]: super::Palette::set_terminal_background
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RelativeColor {
+    /// Name of the base color.
+    ///
+    /// Either `"terminal"` for the terminal background, or a palette entry.
+    pub base: String,
+
+    /// Change in contrast with the base color, in percent.
+    ///
+    /// Positive values move away from the base luminance: lighter on a dark
+    /// base, and darker on a light base. Negative values go the other way.
+    pub contrast: i8,
+}
+
+impl RelativeColor {
+    /// Creates a new relative color.
+    ///
+    /// `contrast` is clamped between -100 and 100.
+    pub fn new<S: Into<String>>(base: S, contrast: i8) -> Self {
+        RelativeColor {
+            base: base.into(),
+            contrast: contrast.clamp(-100, 100),
+        }
+    }
+
+    /// Parses a relative color like `"terminal +10%"`.
+    ///
+    /// Returns `None` if `value` doesn't follow this format.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut tokens = value.split_whitespace();
+        let base = tokens.next()?;
+        let amount = tokens.next()?;
+        if tokens.next().is_some() {
+            return None;
+        }
+
+        let amount = amount.strip_suffix('%')?;
+        let amount = amount.strip_prefix('+').unwrap_or(amount);
+        let contrast: i8 = amount.parse().ok()?;
+        if !(-100..=100).contains(&contrast) {
+            return None;
+        }
+
+        Some(RelativeColor::new(base, contrast))
+    }
+
+    /// Computes the actual color, given the base color.
+    ///
+    /// Returns `None` if the base is `Color::TerminalDefault`, since its
+    /// luminance is unknown.
+    pub fn apply(&self, base: Color) -> Option<Color> {
+        let luminance = base.luminance()?;
+
+        // Below this, the base is considered dark.
+        let dark = luminance < 0.179;
+        let lighten = dark == (self.contrast >= 0);
+        let target = if lighten {
+            Color::Rgb(255, 255, 255)
+        } else {
+            Color::Rgb(0, 0, 0)
+        };
+
+        let t = f32::from(self.contrast).abs() / 100.0;
+        Some(base.interpolate(target, t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            RelativeColor::parse("terminal +10%"),
+            Some(RelativeColor::new("terminal", 10))
+        );
+        assert_eq!(
+            RelativeColor::parse("view -5%"),
+            Some(RelativeColor::new("view", -5))
+        );
+        assert_eq!(RelativeColor::parse("view 5"), None);
+        assert_eq!(RelativeColor::parse("#ff0000"), None);
+        assert_eq!(RelativeColor::parse("view +200%"), None);
+    }
+
+    #[test]
+    fn apply() {
+        let more = RelativeColor::new("terminal", 50);
+        let less = RelativeColor::new("terminal", -50);
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+
+        // More contrast goes lighter on dark backgrounds, darker on light.
+        assert_eq!(more.apply(black), Some(Color::Rgb(128, 128, 128)));
+        assert_eq!(more.apply(white), Some(Color::Rgb(128, 128, 128)));
+        assert_eq!(less.apply(black), Some(black));
+        assert_eq!(less.apply(white), Some(white));
+
+        assert_eq!(more.apply(Color::TerminalDefault), None);
+    }
+}
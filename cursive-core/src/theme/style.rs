@@ -1,10 +1,14 @@
-use super::{Color, ColorStyle, ColorType, Effect, PaletteColor};
+#[cfg(feature = "toml")]
+use super::Palette;
+use super::{Color, ColorStyle, ColorType, Effect, Link, PaletteColor};
 use enumset::{enum_set, EnumSet};
+#[cfg(feature = "toml")]
+use std::collections::HashMap;
 
 /// Combine a color and an effect.
 ///
 /// Represents any transformation that can be applied to text.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Style {
     /// Effect to apply.
     ///
@@ -15,6 +19,14 @@ pub struct Style {
     ///
     /// `None` to keep using the previous colors.
     pub color: Option<ColorStyle>,
+
+    // Hyperlink target.
+    //
+    // `None` to keep using the previous link, if any.
+    pub(crate) link: Option<Link>,
+
+    // Named style from the theme, applied below the other fields.
+    pub(crate) name: Option<&'static str>,
 }
 
 impl Default for Style {
@@ -29,25 +41,81 @@ impl Style {
         Style {
             effects: EnumSet::new(),
             color: None,
+            link: None,
+            name: None,
         }
     }
 
+    /// Returns a style defined in the theme, by name.
+    ///
+    /// Named styles are defined in the `[styles]` section of a theme file,
+    /// or with [`Theme::set_style`](super::Theme::set_style). They are looked up
+    /// when printing, so they follow theme changes. Unknown names don't
+    /// apply anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::{BaseColor, Effect, Style, Theme};
+    /// use cursive_core::utils::markup::StyledString;
+    ///
+    /// let mut theme = Theme::default();
+    /// theme.set_style(
+    ///     "error",
+    ///     Style::from(BaseColor::Red.light()).combine(Effect::Bold),
+    /// );
+    ///
+    /// let text = StyledString::styled("Disk full", Style::named("error"));
+    /// ```
+    pub fn named(name: &'static str) -> Self {
+        Style {
+            name: Some(name),
+            ..Style::none()
+        }
+    }
+
+    /// Returns the hyperlink target of this style, if any.
+    pub fn get_link(&self) -> Option<&Link> {
+        self.link.as_ref()
+    }
+
+    /// Returns the name of the theme style used by this style, if any.
+    ///
+    /// See [`Style::named`].
+    pub fn get_name(&self) -> Option<&'static str> {
+        self.name
+    }
+
     /// Returns a new `Style` by merging all given styles.
     ///
-    /// Will use the last non-`None` color, and will combine all effects.
+    /// Will use the last non-`None` color and link, and will combine all
+    /// effects.
     pub fn merge(styles: &[Style]) -> Self {
         let mut color = None;
+        let mut link = None;
+        let mut name = None;
         let mut effects = EnumSet::new();
 
         for style in styles {
             if style.color.is_some() {
                 color = style.color;
             }
+            if style.link.is_some() {
+                link = style.link.clone();
+            }
+            if style.name.is_some() {
+                name = style.name;
+            }
 
             effects.insert_all(style.effects);
         }
 
-        Style { color, effects }
+        Style {
+            color,
+            effects,
+            link,
+            name,
+        }
     }
 
     /// Returns a combination of `self` and `other`.
@@ -64,6 +132,8 @@ impl From<Effect> for Style {
         Style {
             effects: enum_set!(effect),
             color: None,
+            link: None,
+            name: None,
         }
     }
 }
@@ -73,6 +143,19 @@ impl From<ColorStyle> for Style {
         Style {
             effects: EnumSet::new(),
             color: Some(color),
+            link: None,
+            name: None,
+        }
+    }
+}
+
+impl From<Link> for Style {
+    fn from(link: Link) -> Self {
+        Style {
+            effects: EnumSet::new(),
+            color: None,
+            link: Some(link),
+            name: None,
         }
     }
 }
@@ -94,3 +177,95 @@ impl From<ColorType> for Style {
         ColorStyle::from(color).into()
     }
 }
+
+/// Loads named styles from the `[styles]` section of a theme file.
+#[cfg(feature = "toml")]
+pub(crate) fn load_toml(
+    styles: &mut HashMap<String, Style>,
+    table: &toml::value::Table,
+    palette: &Palette,
+) {
+    for name in table.keys() {
+        if let Some(entry) = read_entry(table, name, palette, 0) {
+            styles.insert(name.clone(), entry.build());
+        }
+    }
+}
+
+/// Fields of a named style, after inheritance.
+#[cfg(feature = "toml")]
+#[derive(Clone, Copy, Default)]
+struct Entry {
+    front: Option<ColorType>,
+    back: Option<ColorType>,
+    effects: EnumSet<Effect>,
+}
+
+#[cfg(feature = "toml")]
+impl Entry {
+    fn build(self) -> Style {
+        // Missing colors use the usual ones for text.
+        let color = match (self.front, self.back) {
+            (None, None) => None,
+            (front, back) => Some(ColorStyle::new(
+                front.unwrap_or(ColorType::Palette(PaletteColor::Primary)),
+                back.unwrap_or(ColorType::Palette(PaletteColor::View)),
+            )),
+        };
+        Style {
+            effects: self.effects,
+            color,
+            ..Style::none()
+        }
+    }
+}
+
+/// Reads the style `name`, on top of the one it inherits from.
+///
+/// `depth` stops inheritance loops.
+#[cfg(feature = "toml")]
+fn read_entry(
+    table: &toml::value::Table,
+    name: &str,
+    palette: &Palette,
+    depth: usize,
+) -> Option<Entry> {
+    let fields = table.get(name)?.as_table()?;
+
+    let mut entry = match fields.get("inherits").and_then(|v| v.as_str()) {
+        Some(parent) if depth < table.len() => {
+            read_entry(table, parent, palette, depth + 1).unwrap_or_default()
+        }
+        _ => Entry::default(),
+    };
+
+    let color = |key| {
+        let value = fields.get(key)?.as_str()?;
+        // Palette entries follow the theme; custom colors are copied.
+        if let Ok(color) = value.parse() {
+            return Some(ColorType::Palette(color));
+        }
+        palette
+            .custom(value)
+            .copied()
+            .or_else(|| Color::parse(value))
+            .map(ColorType::Color)
+    };
+    if let Some(front) = color("front") {
+        entry.front = Some(front);
+    }
+    if let Some(back) = color("back") {
+        entry.back = Some(back);
+    }
+
+    if let Some(effects) = fields.get("effects").and_then(|v| v.as_array()) {
+        let effects = effects.iter().filter_map(|effect| effect.as_str());
+        for effect in effects {
+            if let Ok(effect) = effect.parse() {
+                entry.effects.insert(effect);
+            }
+        }
+    }
+
+    Some(entry)
+}
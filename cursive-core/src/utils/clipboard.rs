@@ -0,0 +1,205 @@
+//! Clipboard shared by the editable views.
+//!
+//! Text is always kept in an application-wide buffer. With the `clipboard`
+//! feature, it also goes to the system clipboard, using the usual
+//! command-line tools (`pbcopy`, `wl-copy`, `xclip`, `xsel` or `clip`).
+//! If none of them works, only the application buffer is used.
+//!
+//! These tools run in a background thread, so they never block the UI.
+//! Unit tests only use the application buffer, to leave the system
+//! clipboard alone.
+
+use crossbeam_channel::{self, Receiver};
+use lazy_static::lazy_static;
+use std::sync::Mutex;
+
+lazy_static! {
+    static ref BUFFER: Mutex<String> = Mutex::new(String::new());
+}
+
+/// Puts `text` in the clipboard.
+///
+/// The system clipboard is updated in the background.
+pub fn set_text(text: &str) {
+    #[cfg(all(feature = "clipboard", not(test)))]
+    system::send(system::Request::Copy(text.to_string()));
+
+    let mut buffer = BUFFER.lock().unwrap();
+    buffer.clear();
+    buffer.push_str(text);
+}
+
+/// Asks for the content of the clipboard, without blocking.
+///
+/// The returned receiver gets the text once it is known, or `None` if the
+/// clipboard is empty. Without the `clipboard` feature, it is known
+/// right away.
+pub fn request_text() -> Receiver<Option<String>> {
+    let (sender, receiver) = crossbeam_channel::bounded(1);
+
+    #[cfg(all(feature = "clipboard", not(test)))]
+    system::send(system::Request::Paste(sender));
+
+    #[cfg(any(not(feature = "clipboard"), test))]
+    sender.send(buffer_text()).ok();
+
+    receiver
+}
+
+/// Returns the content of the clipboard.
+///
+/// Returns `None` if the clipboard is empty.
+///
+/// This waits for the system clipboard, so views should prefer
+/// [`request_text`].
+pub fn get_text() -> Option<String> {
+    request_text().recv().unwrap_or_else(|_| buffer_text())
+}
+
+// Returns the content of the application buffer.
+fn buffer_text() -> Option<String> {
+    let buffer = BUFFER.lock().unwrap();
+    Some(buffer.clone()).filter(|text| !text.is_empty())
+}
+
+// Returns the content of the system clipboard, or of the buffer.
+#[cfg(all(feature = "clipboard", not(test)))]
+fn system_text() -> Option<String> {
+    match system::get_text() {
+        Ok(text) => Some(text).filter(|text| !text.is_empty()),
+        Err(err) => {
+            log::debug!("Could not use the system clipboard: {}", err);
+            buffer_text()
+        }
+    }
+}
+
+#[cfg(all(feature = "clipboard", not(test)))]
+mod system {
+    use crossbeam_channel::Sender;
+    use lazy_static::lazy_static;
+    use std::io::{self, Write};
+    use std::process::{Command, Stdio};
+    use std::sync::Mutex;
+    use std::thread;
+
+    /// Work for the clipboard thread.
+    pub enum Request {
+        Copy(String),
+        Paste(Sender<Option<String>>),
+    }
+
+    lazy_static! {
+        // Requests are handled in order, so a paste sees the last copy.
+        static ref REQUESTS: Mutex<Sender<Request>> = {
+            let (sender, receiver) = crossbeam_channel::unbounded();
+            thread::spawn(move || {
+                for request in receiver {
+                    match request {
+                        Request::Copy(text) => {
+                            if let Err(err) = set_text(&text) {
+                                log::debug!(
+                                    "Could not use the system clipboard: {}",
+                                    err
+                                );
+                            }
+                        }
+                        Request::Paste(sender) => {
+                            sender.send(super::system_text()).ok();
+                        }
+                    }
+                }
+            });
+            Mutex::new(sender)
+        };
+    }
+
+    /// Sends a request to the clipboard thread.
+    pub fn send(request: Request) {
+        REQUESTS.lock().unwrap().send(request).ok();
+    }
+
+    // Commands to try in order, with their arguments.
+    #[cfg(target_os = "macos")]
+    const COPY: &[&[&str]] = &[&["pbcopy"]];
+    #[cfg(target_os = "macos")]
+    const PASTE: &[&[&str]] = &[&["pbpaste"]];
+
+    #[cfg(windows)]
+    const COPY: &[&[&str]] = &[&["clip"]];
+    #[cfg(windows)]
+    const PASTE: &[&[&str]] =
+        &[&["powershell", "-NoProfile", "-Command", "Get-Clipboard"]];
+
+    #[cfg(not(any(target_os = "macos", windows)))]
+    const COPY: &[&[&str]] = &[
+        &["wl-copy"],
+        &["xclip", "-selection", "clipboard"],
+        &["xsel", "--clipboard", "--input"],
+    ];
+    #[cfg(not(any(target_os = "macos", windows)))]
+    const PASTE: &[&[&str]] = &[
+        &["wl-paste", "--no-newline"],
+        &["xclip", "-selection", "clipboard", "-o"],
+        &["xsel", "--clipboard", "--output"],
+    ];
+
+    fn command(args: &[&str]) -> Command {
+        let mut command = Command::new(args[0]);
+        command.args(&args[1..]).stderr(Stdio::null());
+        command
+    }
+
+    fn copy_with(args: &[&str], text: &str) -> io::Result<()> {
+        let mut child = command(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(text.as_bytes())?;
+        check(child.wait()?.success())
+    }
+
+    fn paste_with(args: &[&str]) -> io::Result<String> {
+        let output = command(args).stdin(Stdio::null()).output()?;
+        check(output.status.success())?;
+        String::from_utf8(output.stdout)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    fn check(success: bool) -> io::Result<()> {
+        if success {
+            Ok(())
+        } else {
+            Err(io::Error::other("command failed"))
+        }
+    }
+
+    pub fn set_text(text: &str) -> io::Result<()> {
+        let mut result = Ok(());
+        for args in COPY {
+            result = copy_with(args, text);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+
+    pub fn get_text() -> io::Result<String> {
+        let mut result = Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "no clipboard command",
+        ));
+        for args in PASTE {
+            result = paste_with(args);
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    }
+}
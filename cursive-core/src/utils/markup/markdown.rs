@@ -4,7 +4,7 @@
 
 use std::borrow::Cow;
 
-use crate::theme::{Effect, Style};
+use crate::theme::{Effect, Link, Style};
 use crate::utils::markup::{StyledIndexedSpan, StyledString};
 use crate::utils::span::IndexedCow;
 
@@ -23,9 +23,24 @@ where
     StyledString::with_spans(input, spans)
 }
 
+/// Parses the given string as markdown text, with links as [`Link`] styles.
+///
+/// [`parse`] keeps links as `[text](url)` instead.
+pub fn parse_with_links<S>(input: S) -> StyledString
+where
+    S: Into<String>,
+{
+    let input = input.into();
+
+    let spans = Parser::new(&input).links(true).collect();
+
+    StyledString::with_spans(input, spans)
+}
+
 /// Iterator that parse a markdown text and outputs styled spans.
 pub struct Parser<'a> {
     first: bool,
+    links: bool,
     stack: Vec<Style>,
     input: &'a str,
     parser: pulldown_cmark::Parser<'a>,
@@ -37,11 +52,20 @@ impl<'a> Parser<'a> {
         Parser {
             input,
             first: true,
+            links: false,
             parser: pulldown_cmark::Parser::new(input),
             stack: Vec::new(),
         }
     }
 
+    /// Parses links as [`Link`] styles, instead of `[text](url)`.
+    ///
+    /// Defaults to `false`.
+    pub fn links(mut self, links: bool) -> Self {
+        self.links = links;
+        self
+    }
+
     /// Creates a new span with the given value
     fn literal<S>(&self, text: S) -> StyledIndexedSpan
     where
@@ -80,6 +104,10 @@ impl<'a> Iterator for Parser<'a> {
                         )
                     }
                     Tag::BlockQuote => return Some(self.literal("> ")),
+                    Tag::Link(_, url, _) if self.links => self.stack.push(
+                        Style::from(Link::new(&url))
+                            .combine(Effect::Underline),
+                    ),
                     Tag::Link(_, _, _) => return Some(self.literal("[")),
                     Tag::CodeBlock(_) => return Some(self.literal("```")),
                     Tag::Strong => self.stack.push(Style::from(Effect::Bold)),
@@ -92,11 +120,11 @@ impl<'a> Iterator for Parser<'a> {
                     // Remove from stack!
                     Tag::Paragraph if self.first => self.first = false,
                     Tag::Heading(_) => return Some(self.literal("\n\n")),
-                    Tag::Link(_, link, _) => {
+                    Tag::CodeBlock(_) => return Some(self.literal("```")),
+                    Tag::Link(_, link, _) if !self.links => {
                         return Some(self.literal(format!("]({})", link)))
                     }
-                    Tag::CodeBlock(_) => return Some(self.literal("```")),
-                    Tag::Emphasis | Tag::Strong => {
+                    Tag::Emphasis | Tag::Strong | Tag::Link(_, _, _) => {
                         self.stack.pop().unwrap();
                     }
                     _ => (),
@@ -200,4 +228,23 @@ I *really* love __Cursive__!";
             ]
         );
     }
+
+    #[test]
+    fn links() {
+        let input = "See [the docs](https://docs.rs/cursive).";
+        // Links are kept as text unless requested.
+        let plain: String = parse(input).spans().map(|s| s.content).collect();
+        assert_eq!(plain, input);
+
+        let text = parse_with_links(input);
+        assert_eq!(text.source(), input);
+
+        let spans: Vec<_> = text.spans().collect();
+        assert_eq!(spans[1].content, "the docs");
+        assert_eq!(
+            spans[1].attr.link.as_ref().map(Link::url),
+            Some("https://docs.rs/cursive")
+        );
+        assert!(spans[1].attr.effects.contains(Effect::Underline));
+    }
 }
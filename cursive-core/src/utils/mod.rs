@@ -1,12 +1,31 @@
 //! Toolbox to make text layout easier.
 
+pub mod clipboard;
 mod counter;
 #[macro_use]
 mod immutify;
 pub mod lines;
 pub mod markup;
+pub mod notifications;
 mod reader;
+pub mod search;
 pub mod span;
+pub mod spell;
+pub mod timeline;
+pub(crate) mod timer;
+pub mod undo;
+mod writer;
 
 pub use self::counter::Counter;
 pub use self::reader::ProgressReader;
+pub use self::writer::ProgressWriter;
+
+/// Returns the average number of bytes per second since `started`.
+fn throughput(bytes: usize, started: std::time::Instant) -> f64 {
+    let elapsed = started.elapsed().as_secs_f64();
+    if elapsed > 0.0 {
+        bytes as f64 / elapsed
+    } else {
+        0.0
+    }
+}
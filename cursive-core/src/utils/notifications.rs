@@ -0,0 +1,223 @@
+//! Application-wide notification history.
+//!
+//! Every `Cursive` root has a [`NotificationCenter`], available with
+//! [`Cursive::notifications_mut`]. Notifications are recorded with a level
+//! and a timestamp, and stay in the history until dismissed. The number of
+//! unread ones can be shown in a status bar, and
+//! [`Cursive::show_notifications`] opens a panel to review and dismiss them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::views::AlertLevel;
+//!
+//! let mut siv = cursive_core::Cursive::dummy();
+//! siv.notify(AlertLevel::Warning, "Disk almost full");
+//! siv.notify(AlertLevel::Success, "Backup done");
+//! assert_eq!(siv.notifications().unread_count(), 2);
+//!
+//! // Reviewing them marks them as read.
+//! siv.show_notifications();
+//! assert_eq!(siv.notifications().unread_count(), 0);
+//! ```
+//!
+//! [`Cursive::notifications_mut`]: crate::Cursive::notifications_mut
+//! [`Cursive::show_notifications`]: crate::Cursive::show_notifications
+use crate::views::AlertLevel;
+use std::collections::VecDeque;
+use std::time::SystemTime;
+
+/// Default maximum number of notifications in the history.
+const DEFAULT_LIMIT: usize = 100;
+
+/// Message recorded in a [`NotificationCenter`].
+#[derive(Clone, Debug)]
+pub struct Notification {
+    id: u64,
+    level: AlertLevel,
+    message: String,
+    time: SystemTime,
+    read: bool,
+}
+
+impl Notification {
+    /// Returns the identifier of this notification.
+    ///
+    /// Identifiers are unique in a given notification center.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the level of this notification.
+    pub fn level(&self) -> AlertLevel {
+        self.level
+    }
+
+    /// Returns the message of this notification.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns when this notification was recorded.
+    pub fn time(&self) -> SystemTime {
+        self.time
+    }
+
+    /// Returns `true` if this notification was reviewed.
+    pub fn is_read(&self) -> bool {
+        self.read
+    }
+}
+
+/// History of notifications.
+pub struct NotificationCenter {
+    // Oldest first.
+    notifications: VecDeque<Notification>,
+    next_id: u64,
+    limit: usize,
+}
+
+impl Default for NotificationCenter {
+    fn default() -> Self {
+        NotificationCenter {
+            notifications: VecDeque::new(),
+            next_id: 0,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl NotificationCenter {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a notification, and returns its identifier.
+    ///
+    /// The oldest notification is dropped if the history is full.
+    pub fn push<S: Into<String>>(
+        &mut self,
+        level: AlertLevel,
+        message: S,
+    ) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        self.notifications.push_back(Notification {
+            id,
+            level,
+            message: message.into(),
+            time: SystemTime::now(),
+            read: false,
+        });
+        if self.notifications.len() > self.limit {
+            self.notifications.pop_front();
+        }
+        id
+    }
+
+    /// Removes a notification.
+    ///
+    /// Returns `false` if it was not in the history.
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let len = self.notifications.len();
+        self.notifications
+            .retain(|notification| notification.id != id);
+        self.notifications.len() != len
+    }
+
+    /// Removes all notifications.
+    pub fn clear(&mut self) {
+        self.notifications.clear();
+    }
+
+    /// Returns a notification from its identifier.
+    pub fn get(&self, id: u64) -> Option<&Notification> {
+        self.notifications
+            .iter()
+            .find(|notification| notification.id == id)
+    }
+
+    /// Returns the notifications, most recent first.
+    pub fn iter(&self) -> impl Iterator<Item = &Notification> {
+        self.notifications.iter().rev()
+    }
+
+    /// Returns the number of notifications.
+    pub fn len(&self) -> usize {
+        self.notifications.len()
+    }
+
+    /// Returns `true` if there is no notification.
+    pub fn is_empty(&self) -> bool {
+        self.notifications.is_empty()
+    }
+
+    /// Returns the number of notifications not reviewed yet.
+    ///
+    /// This is usually shown as a badge, in a status bar.
+    pub fn unread_count(&self) -> usize {
+        self.notifications
+            .iter()
+            .filter(|notification| !notification.read)
+            .count()
+    }
+
+    /// Returns the highest level among unread notifications, if any.
+    pub fn unread_level(&self) -> Option<AlertLevel> {
+        self.notifications
+            .iter()
+            .filter(|notification| !notification.read)
+            .map(|notification| notification.level)
+            .max_by_key(|&level| level as u8)
+    }
+
+    /// Marks all notifications as read.
+    pub fn mark_all_read(&mut self) {
+        for notification in &mut self.notifications {
+            notification.read = true;
+        }
+    }
+
+    /// Sets the maximum number of notifications kept in the history.
+    ///
+    /// Defaults to 100.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        let excess = self.notifications.len().saturating_sub(limit);
+        self.notifications.drain(..excess);
+    }
+
+    /// Returns the maximum number of notifications kept in the history.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history() {
+        let mut center = NotificationCenter::new();
+        center.set_limit(2);
+        center.push(AlertLevel::Info, "one");
+        let two = center.push(AlertLevel::Error, "two");
+        center.push(AlertLevel::Warning, "three");
+
+        let messages: Vec<_> = center.iter().map(|n| n.message()).collect();
+        assert_eq!(messages, ["three", "two"]);
+        assert_eq!(center.unread_count(), 2);
+        assert_eq!(center.unread_level(), Some(AlertLevel::Error));
+
+        assert!(center.dismiss(two));
+        assert!(!center.dismiss(two));
+        assert_eq!(center.unread_level(), Some(AlertLevel::Warning));
+
+        center.mark_all_read();
+        assert_eq!(center.unread_count(), 0);
+        assert_eq!(center.unread_level(), None);
+    }
+}
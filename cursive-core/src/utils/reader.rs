@@ -1,10 +1,11 @@
 use crate::utils::Counter;
 use std::io::{self, Read};
+use std::time::Instant;
 
 /// Wrapper around a `Read` that reports the progress made.
 ///
 /// Used to monitor a file downloading or other slow IO task
-/// in a progress bar.
+/// in a progress bar. See also [`ProgressWriter`](super::ProgressWriter).
 ///
 /// # Examples
 ///
@@ -34,6 +35,8 @@ use std::io::{self, Read};
 pub struct ProgressReader<R: Read> {
     reader: R,
     counter: Counter,
+    bytes: usize,
+    started: Instant,
 }
 
 impl<R: Read> ProgressReader<R> {
@@ -44,7 +47,30 @@ impl<R: Read> ProgressReader<R> {
     /// You should make sure the progress bar knows how
     /// many bytes should be received.
     pub fn new(counter: Counter, reader: R) -> Self {
-        ProgressReader { reader, counter }
+        ProgressReader {
+            reader,
+            counter,
+            bytes: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the number of bytes read through this reader.
+    ///
+    /// Unlike the counter, this doesn't include its initial value.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the average number of bytes read per second, since this
+    /// reader was created.
+    pub fn throughput(&self) -> f64 {
+        super::throughput(self.bytes, self.started)
+    }
+
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
     }
 
     /// Unwraps this `ProgressReader`, returning the reader and counter.
@@ -56,6 +82,7 @@ impl<R: Read> ProgressReader<R> {
 impl<R: Read> Read for ProgressReader<R> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let result = self.reader.read(buf)?;
+        self.bytes += result;
         self.counter.tick(result);
         Ok(result)
     }
@@ -0,0 +1,303 @@
+//! Text search on a worker thread.
+//!
+//! Searching a large buffer can take a while. A [`Search`] runs on its own
+//! thread, and streams the matches back to the UI thread through the
+//! `cb_sink`, along with its progress. The matches are byte ranges in the
+//! source text, ready for [`TextView::add_highlights`].
+//!
+//! Any [`Matcher`] can be used: [`Plain`] comes with cursive, and closures
+//! returning ranges can wrap a regex engine.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::utils::search::{Plain, Search};
+//! use cursive_core::views::{TextContent, TextView};
+//! use cursive_core::view::Nameable;
+//!
+//! let mut siv = cursive_core::Cursive::dummy();
+//!
+//! let content = TextContent::new("error: disk full\nok\nerror: timeout");
+//! siv.add_layer(TextView::new_with_content(content.clone()).with_name("log"));
+//!
+//! let search = Search::in_content(
+//!     &content,
+//!     Plain::new("error"),
+//!     siv.cb_sink().clone(),
+//!     |s, update| {
+//!         s.call_on_name("log", |view: &mut TextView| {
+//!             view.add_highlights(update.matches);
+//!         });
+//!     },
+//! );
+//!
+//! // Keep `search` around: dropping it cancels the search.
+//! # search.wait();
+//! ```
+//!
+//! [`TextView::add_highlights`]: crate::views::TextView::add_highlights
+use crate::utils::markup::StyledString;
+use crate::views::TextContent;
+use crate::{CbSink, Cursive};
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+/// Number of bytes searched between two updates.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// Finds matches in a line of text.
+pub trait Matcher: Send + 'static {
+    /// Returns the byte ranges of the matches in `line`.
+    ///
+    /// `line` does not include the line break.
+    fn find_matches(&self, line: &str) -> Vec<Range<usize>>;
+}
+
+impl<F> Matcher for F
+where
+    F: Fn(&str) -> Vec<Range<usize>> + Send + 'static,
+{
+    fn find_matches(&self, line: &str) -> Vec<Range<usize>> {
+        self(line)
+    }
+}
+
+/// Plain text matcher.
+///
+/// Like the search in views, it ignores case unless the query contains an
+/// uppercase character.
+#[derive(Clone, Debug)]
+pub struct Plain {
+    query: String,
+}
+
+impl Plain {
+    /// Creates a matcher looking for `query`.
+    pub fn new<S: Into<String>>(query: S) -> Self {
+        Plain {
+            query: query.into(),
+        }
+    }
+}
+
+impl Matcher for Plain {
+    fn find_matches(&self, line: &str) -> Vec<Range<usize>> {
+        crate::view::find_matches(line, &self.query)
+    }
+}
+
+/// Progress made by a [`Search`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchUpdate {
+    /// Matches found since the last update, as byte ranges in the text.
+    pub matches: Vec<Range<usize>>,
+
+    /// Number of bytes searched so far.
+    pub searched: usize,
+
+    /// Total number of bytes to search.
+    pub total: usize,
+}
+
+impl SearchUpdate {
+    /// Returns `true` if this is the last update of the search.
+    pub fn is_done(&self) -> bool {
+        self.searched == self.total
+    }
+}
+
+// Shares the content of a `TextContent` without copying it.
+struct Snapshot(Arc<StyledString>);
+
+impl AsRef<str> for Snapshot {
+    fn as_ref(&self) -> &str {
+        self.0.source()
+    }
+}
+
+/// Search running on a worker thread.
+///
+/// The search is stopped when this is dropped.
+#[must_use = "the search is stopped when dropped"]
+pub struct Search {
+    // Set when the search is over or cancelled.
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Search {
+    /// Starts searching `text` with `matcher`.
+    ///
+    /// The text is searched line by line, so matches never span several
+    /// lines. `on_update` is called through `cb_sink` with the matches
+    /// found, about every megabyte searched, and once at the end.
+    pub fn new<S, M, F>(
+        text: S,
+        matcher: M,
+        cb_sink: CbSink,
+        on_update: F,
+    ) -> Self
+    where
+        S: AsRef<str> + Send + 'static,
+        M: Matcher,
+        F: Fn(&mut Cursive, SearchUpdate) + Send + Sync + 'static,
+    {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let on_update = Arc::new(on_update);
+
+        let handle = {
+            let stopped = Arc::clone(&stopped);
+            thread::spawn(move || {
+                let text = text.as_ref();
+                run(text, &matcher, &stopped, |update| {
+                    let on_update = Arc::clone(&on_update);
+                    cb_sink
+                        .send(Box::new(move |s| on_update(s, update)))
+                        .is_ok()
+                });
+            })
+        };
+
+        Search {
+            stopped,
+            handle: Some(handle),
+        }
+    }
+
+    /// Starts searching the text of a `TextContent`.
+    ///
+    /// The search works on the text at the time of the call: later changes
+    /// are ignored. The matches are byte ranges in the source of the
+    /// content. See [`Search::new`].
+    pub fn in_content<M, F>(
+        content: &TextContent,
+        matcher: M,
+        cb_sink: CbSink,
+        on_update: F,
+    ) -> Self
+    where
+        M: Matcher,
+        F: Fn(&mut Cursive, SearchUpdate) + Send + Sync + 'static,
+    {
+        let snapshot = Snapshot(content.snapshot());
+        Search::new(snapshot, matcher, cb_sink, on_update)
+    }
+
+    /// Stops the search.
+    ///
+    /// Updates already sent will still be received.
+    pub fn cancel(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the search is over, or was stopped.
+    pub fn is_finished(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Blocks until the search is over.
+    ///
+    /// All updates are then in the `cb_sink`.
+    pub fn wait(mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+    }
+}
+
+impl Drop for Search {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+// Searches `text`, calling `send` with each update.
+//
+// Stops early if `send` returns `false` or if the search is stopped.
+fn run<M, F>(text: &str, matcher: &M, stopped: &AtomicBool, mut send: F)
+where
+    M: Matcher,
+    F: FnMut(SearchUpdate) -> bool,
+{
+    let total = text.len();
+    let mut matches = Vec::new();
+    let mut chunk_start = 0;
+    let mut start = 0;
+
+    for line in text.split('\n') {
+        if stopped.load(Ordering::Relaxed) {
+            return;
+        }
+
+        matches.extend(
+            matcher
+                .find_matches(line)
+                .into_iter()
+                .map(|range| range.start + start..range.end + start),
+        );
+        start = (start + line.len() + 1).min(total);
+
+        if start - chunk_start >= CHUNK_SIZE && start < total {
+            chunk_start = start;
+            let update = SearchUpdate {
+                matches: std::mem::take(&mut matches),
+                searched: start,
+                total,
+            };
+            if !send(update) {
+                return;
+            }
+        }
+    }
+
+    send(SearchUpdate {
+        matches,
+        searched: total,
+        total,
+    });
+    stopped.store(true, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search() {
+        let mut siv = crate::testing::dummy((10, 2));
+        siv.set_user_data(Vec::<SearchUpdate>::new());
+
+        let text = "Error\nok\nan error, another ERROR".to_string();
+        let search = Search::new(
+            text,
+            Plain::new("error"),
+            siv.cb_sink().clone(),
+            |s, update| {
+                s.with_user_data(|updates: &mut Vec<_>| updates.push(update));
+            },
+        );
+        search.wait();
+        siv.process_events();
+
+        let updates = siv.take_user_data::<Vec<SearchUpdate>>().unwrap();
+        assert_eq!(updates.len(), 1);
+        assert!(updates[0].is_done());
+        assert_eq!(updates[0].matches, vec![0..5, 12..17, 27..32]);
+
+        // Closures can be used as matchers.
+        let mut updates = Vec::new();
+        let digits = |line: &str| {
+            line.char_indices()
+                .filter(|(_, c)| c.is_ascii_digit())
+                .map(|(i, _)| i..i + 1)
+                .collect()
+        };
+        run("a1\nb2", &digits, &AtomicBool::new(false), |update| {
+            updates.push(update);
+            true
+        });
+        assert_eq!(updates[0].matches, vec![1..2, 4..5]);
+    }
+}
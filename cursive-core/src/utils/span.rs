@@ -2,6 +2,7 @@
 //!
 //! This module defines various structs describing a span of text from a
 //! larger string.
+use crate::utils::lines::spans::LinesIterator;
 use std::borrow::Cow;
 use unicode_width::UnicodeWidthStr;
 
@@ -252,6 +253,37 @@ impl<T> SpannedString<T> {
     pub fn width(&self) -> usize {
         self.spans().map(|s| s.width).sum()
     }
+
+    /// Returns the width needed to display this string without wrapping.
+    ///
+    /// Unlike `width()`, this accounts for line breaks: this is the width
+    /// of the longest line.
+    pub fn display_width(&self) -> usize {
+        LinesIterator::new(self, usize::MAX)
+            .map(|row| row.width)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of rows needed to display this string, when
+    /// wrapped to fit in `width` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::utils::markup::StyledString;
+    ///
+    /// let text = StyledString::plain("Hello world!\nBye");
+    /// assert_eq!(text.display_width(), 12);
+    /// assert_eq!(text.height_for_width(12), 2);
+    /// assert_eq!(text.height_for_width(6), 3);
+    /// ```
+    pub fn height_for_width(&self, width: usize) -> usize {
+        if width == 0 {
+            return 0;
+        }
+        LinesIterator::new(self, width).count()
+    }
 }
 
 impl<'a, T> From<&'a SpannedString<T>> for SpannedStr<'a, T> {
@@ -0,0 +1,247 @@
+//! Spell-checking support for text views.
+//!
+//! A [`SpellChecker`] finds misspelled words and suggests replacements. Any
+//! `Fn(&str) -> Vec<Range<usize>>` closure can be used as a checker without
+//! suggestions.
+//!
+//! [`Ispell`] uses an external `ispell`, `hunspell` or `aspell` process, in
+//! a background thread.
+
+use std::ops::Range;
+
+/// Finds misspelled words in some text.
+pub trait SpellChecker {
+    /// Returns the byte ranges of misspelled words in `text`.
+    ///
+    /// `text` is a single line.
+    fn check(&self, text: &str) -> Vec<Range<usize>>;
+
+    /// Returns possible replacements for a misspelled `word`.
+    ///
+    /// The default implementation has no suggestion.
+    fn suggestions(&self, word: &str) -> Vec<String> {
+        let _ = word;
+        Vec::new()
+    }
+
+    /// Returns `true` while some text is being checked in the background.
+    ///
+    /// Views showing the results draw again until this returns `false`.
+    /// The default implementation always returns `false`.
+    fn is_pending(&self) -> bool {
+        false
+    }
+}
+
+impl<F> SpellChecker for F
+where
+    F: Fn(&str) -> Vec<Range<usize>>,
+{
+    fn check(&self, text: &str) -> Vec<Range<usize>> {
+        self(text)
+    }
+}
+
+pub use self::ispell::Ispell;
+
+mod ispell {
+    use super::SpellChecker;
+    use crossbeam_channel::Sender;
+    use std::collections::{HashMap, HashSet};
+    use std::io::{self, BufRead, BufReader, Write};
+    use std::ops::Range;
+    use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    // Maximum number of lines to remember.
+    const CACHE_SIZE: usize = 1000;
+
+    struct Pipe {
+        child: Child,
+        stdin: ChildStdin,
+        stdout: BufReader<ChildStdout>,
+    }
+
+    // Results from the background thread.
+    #[derive(Default)]
+    struct Results {
+        // Misspelled words, by line.
+        lines: HashMap<String, Vec<Range<usize>>>,
+        // Replacements, by misspelled word.
+        suggestions: HashMap<String, Vec<String>>,
+        // Lines being checked.
+        pending: HashSet<String>,
+    }
+
+    /// Spell checker using the `ispell` pipe protocol.
+    ///
+    /// This works with `ispell`, `hunspell` and `aspell`, started with the
+    /// `-a` flag. Lines are checked in a background thread: until a line
+    /// is checked, it has no misspelled word.
+    pub struct Ispell {
+        requests: Sender<String>,
+        results: Arc<Mutex<Results>>,
+    }
+
+    impl Ispell {
+        /// Starts `command -a`, with the default dictionary.
+        ///
+        /// `command` can be `"ispell"`, `"hunspell"` or `"aspell"`.
+        pub fn new(command: &str) -> io::Result<Self> {
+            Self::with_args(command, &[])
+        }
+
+        /// Starts `command -a`, followed by `args`.
+        ///
+        /// For example, use `&["-d", "fr_FR"]` to select a dictionary.
+        pub fn with_args(command: &str, args: &[&str]) -> io::Result<Self> {
+            let mut child = Command::new(command)
+                .arg("-a")
+                .args(args)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()?;
+            let stdin = child.stdin.take().expect("stdin is piped");
+            let mut stdout =
+                BufReader::new(child.stdout.take().expect("stdout is piped"));
+
+            // The first line is a version banner.
+            let mut banner = String::new();
+            if stdout.read_line(&mut banner)? == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "spell checker exited",
+                ));
+            }
+
+            let mut pipe = Pipe {
+                child,
+                stdin,
+                stdout,
+            };
+            let results = Arc::new(Mutex::new(Results::default()));
+            let (requests, receiver) =
+                crossbeam_channel::unbounded::<String>();
+
+            let shared = Arc::clone(&results);
+            thread::spawn(move || {
+                for line in receiver {
+                    let words = pipe.misspelled(&line).unwrap_or_else(|err| {
+                        log::warn!("Could not check spelling: {}", err);
+                        Vec::new()
+                    });
+
+                    let mut results = shared.lock().unwrap();
+                    results.pending.remove(&line);
+                    if results.lines.len() >= CACHE_SIZE {
+                        results.lines.clear();
+                        results.suggestions.clear();
+                    }
+                    let mut ranges = Vec::new();
+                    for (range, suggestions) in words {
+                        let word = line[range.clone()].to_string();
+                        results.suggestions.insert(word, suggestions);
+                        ranges.push(range);
+                    }
+                    results.lines.insert(line, ranges);
+                }
+
+                // The `Ispell` was dropped.
+                pipe.child.kill().ok();
+                pipe.child.wait().ok();
+            });
+
+            Ok(Ispell { requests, results })
+        }
+    }
+
+    impl Pipe {
+        /// Sends a line, and returns the result for each word.
+        fn query(&mut self, line: &str) -> io::Result<Vec<String>> {
+            // The `^` prefix prevents the line from being read as a command.
+            writeln!(self.stdin, "^{}", line.replace('\n', " "))?;
+            self.stdin.flush()?;
+
+            // Results end with an empty line.
+            let mut results = Vec::new();
+            loop {
+                let mut result = String::new();
+                if self.stdout.read_line(&mut result)? == 0 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "spell checker exited",
+                    ));
+                }
+                let result = result.trim_end();
+                if result.is_empty() {
+                    return Ok(results);
+                }
+                results.push(result.to_string());
+            }
+        }
+
+        /// Returns the misspelled words, with their replacements.
+        fn misspelled(
+            &mut self,
+            text: &str,
+        ) -> io::Result<Vec<(Range<usize>, Vec<String>)>> {
+            let mut words = Vec::new();
+            let mut start = 0;
+            for result in self.query(text)? {
+                // Misspelled words are reported as `& word ...`, `? word ...`
+                // or `# word ...`.
+                let mut parts = result.split(' ');
+                match parts.next() {
+                    Some("&") | Some("?") | Some("#") => (),
+                    _ => continue,
+                }
+                let word = match parts.next() {
+                    Some(word) => word,
+                    None => continue,
+                };
+
+                // Replacements come after the colon: `& word 3 0: a, b, c`
+                let suggestions = match result.split_once(": ") {
+                    Some((_, suggestions)) if result.starts_with('&') => {
+                        suggestions.split(", ").map(String::from).collect()
+                    }
+                    _ => Vec::new(),
+                };
+
+                // Words are reported in order, so we look for them after
+                // the previous one.
+                if let Some(i) = text[start..].find(word) {
+                    let word_start = start + i;
+                    start = word_start + word.len();
+                    words.push((word_start..start, suggestions));
+                }
+            }
+            Ok(words)
+        }
+    }
+
+    impl SpellChecker for Ispell {
+        fn check(&self, text: &str) -> Vec<Range<usize>> {
+            let mut results = self.results.lock().unwrap();
+            if let Some(ranges) = results.lines.get(text) {
+                return ranges.clone();
+            }
+
+            if results.pending.insert(text.to_string()) {
+                self.requests.send(text.to_string()).ok();
+            }
+            Vec::new()
+        }
+
+        fn suggestions(&self, word: &str) -> Vec<String> {
+            let results = self.results.lock().unwrap();
+            results.suggestions.get(word).cloned().unwrap_or_default()
+        }
+
+        fn is_pending(&self) -> bool {
+            !self.results.lock().unwrap().pending.is_empty()
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! History of rendered frames, for time-travel debugging.
+//!
+//! When enabled with [`Cursive::enable_timeline`], each frame drawn is also
+//! captured offscreen, along with the events received since the previous
+//! one. Only the last frames are kept, and frames identical to the
+//! previous one are only kept if some event came in between, sharing the
+//! same screen. [`Cursive::show_timeline`] opens an
+//! overlay to step backwards and forwards through this visual history, to
+//! see when and after which event the screen went wrong.
+//!
+//! Capturing each frame has a cost: this is meant for debugging.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::event::Key;
+//!
+//! let mut siv = cursive_core::Cursive::dummy();
+//! siv.enable_timeline(100);
+//! siv.add_global_callback(Key::F12, |s| s.show_timeline());
+//! ```
+//!
+//! [`Cursive::enable_timeline`]: crate::Cursive::enable_timeline
+//! [`Cursive::show_timeline`]: crate::Cursive::show_timeline
+use crate::buffer::CellBuffer;
+use crate::event::Event;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Frame captured in a [`Timeline`].
+#[derive(Clone, Debug)]
+pub struct TimelineFrame {
+    number: u64,
+    // Shared with the previous frame when nothing changed.
+    screen: Rc<CellBuffer>,
+    events: Vec<Event>,
+}
+
+impl TimelineFrame {
+    /// Returns the number of this frame, counted since the start.
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// Returns the screen, as drawn on this frame.
+    pub fn screen(&self) -> &CellBuffer {
+        &self.screen
+    }
+
+    /// Returns the events received since the previous frame.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+}
+
+/// Last frames drawn, with the events between them.
+#[derive(Clone, Debug)]
+pub struct Timeline {
+    // Oldest first.
+    frames: VecDeque<TimelineFrame>,
+    capacity: usize,
+    // Events received since the last frame.
+    pending: Vec<Event>,
+    paused: bool,
+}
+
+impl Timeline {
+    /// Creates an empty timeline, keeping up to `capacity` frames.
+    pub fn new(capacity: usize) -> Self {
+        Timeline {
+            frames: VecDeque::new(),
+            capacity,
+            pending: Vec::new(),
+            paused: false,
+        }
+    }
+
+    /// Returns the maximum number of frames kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of frames kept.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns `true` if no frame was captured yet.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns a frame, `0` being the oldest one kept.
+    pub fn get(&self, i: usize) -> Option<&TimelineFrame> {
+        self.frames.get(i)
+    }
+
+    /// Returns the frames, oldest first.
+    pub fn frames(&self) -> impl Iterator<Item = &TimelineFrame> {
+        self.frames.iter()
+    }
+
+    /// Removes all frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.pending.clear();
+    }
+
+    /// Returns `true` if capture is paused, while the timeline is shown.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub(crate) fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    /// Keeps an event, for the next frame.
+    pub(crate) fn record_event(&mut self, event: &Event) {
+        if !self.paused {
+            self.pending.push(event.clone());
+        }
+    }
+
+    /// Adds a frame, dropping the oldest one if the timeline is full.
+    ///
+    /// Frames identical to the previous one are skipped, unless events came
+    /// in between.
+    pub(crate) fn record_frame(&mut self, number: u64, screen: CellBuffer) {
+        if self.paused || self.capacity == 0 {
+            return;
+        }
+
+        let screen = match self.frames.back() {
+            Some(last) if *last.screen == screen => {
+                if self.pending.is_empty() {
+                    return;
+                }
+                Rc::clone(&last.screen)
+            }
+            _ => Rc::new(screen),
+        };
+
+        self.frames.push_back(TimelineFrame {
+            number,
+            screen,
+            events: std::mem::take(&mut self.pending),
+        });
+        while self.frames.len() > self.capacity {
+            self.frames.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::{Event, Key};
+    use crate::views::TextView;
+
+    #[test]
+    fn timeline() {
+        let mut siv = crate::testing::dummy((16, 3));
+        siv.enable_timeline(2);
+        siv.add_layer(TextView::new("Hello"));
+        for _ in 0..3 {
+            siv.refresh();
+        }
+        // Unchanged frames are skipped.
+        assert_eq!(siv.timeline().unwrap().len(), 1);
+
+        siv.on_event(Event::Char('x'));
+        siv.refresh();
+
+        let timeline = siv.timeline().unwrap();
+        assert_eq!(timeline.len(), 2);
+        let last = timeline.get(1).unwrap();
+        assert_eq!(last.number(), 3);
+        assert_eq!(last.events(), [Event::Char('x')]);
+        assert_eq!(last.screen().lines()[1], "     Hello      ");
+
+        // Stepping back shows the previous frame.
+        siv.show_timeline();
+        assert!(siv.timeline().unwrap().is_paused());
+        siv.on_event(Event::Key(Key::Left));
+        let screen = siv.screenshot().lines();
+        assert_eq!(screen[1], "     Hello      ");
+        assert!(screen[2].contains("1/2"));
+
+        siv.on_event(Event::Key(Key::Esc));
+        assert_eq!(siv.screen().len(), 1);
+        assert!(!siv.timeline().unwrap().is_paused());
+    }
+}
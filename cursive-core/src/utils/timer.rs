@@ -0,0 +1,206 @@
+use crossbeam_channel::{self, RecvTimeoutError, Sender};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// State shared between a `Sleep` and the timer thread.
+struct SleepState {
+    done: AtomicBool,
+    // Task to wake, from the last poll.
+    waker: Mutex<Waker>,
+}
+
+impl SleepState {
+    fn wake(&self) {
+        self.done.store(true, Ordering::Release);
+        self.waker.lock().unwrap().wake_by_ref();
+    }
+}
+
+/// Wakes futures after a delay, without depending on a specific runtime.
+///
+/// A single background thread waits for all the delays at once. It stops
+/// when the timer is dropped.
+pub(crate) struct Timer {
+    sender: Sender<(Instant, Arc<SleepState>)>,
+}
+
+impl Timer {
+    /// Starts a new timer thread.
+    pub fn new() -> Self {
+        let (sender, receiver) =
+            crossbeam_channel::unbounded::<(Instant, Arc<SleepState>)>();
+        thread::spawn(move || {
+            let mut pending: Vec<(Instant, Arc<SleepState>)> = Vec::new();
+            loop {
+                let now = Instant::now();
+                pending.retain(|(deadline, state)| {
+                    if *deadline <= now {
+                        state.wake();
+                    }
+                    *deadline > now
+                });
+
+                // Wait for a new sleep, or until the next deadline.
+                let next = pending.iter().map(|&(deadline, _)| deadline).min();
+                let request = match next {
+                    Some(deadline) => {
+                        match receiver.recv_timeout(deadline - now) {
+                            Ok(request) => request,
+                            Err(RecvTimeoutError::Timeout) => continue,
+                            Err(RecvTimeoutError::Disconnected) => break,
+                        }
+                    }
+                    None => match receiver.recv() {
+                        Ok(request) => request,
+                        Err(_) => break,
+                    },
+                };
+                pending.push(request);
+            }
+        });
+        Timer { sender }
+    }
+
+    /// Returns a future completing after `duration`.
+    pub fn sleep(&self, duration: Duration) -> Sleep<'_> {
+        Sleep {
+            timer: self,
+            duration,
+            state: None,
+        }
+    }
+}
+
+/// Future returned by `Timer::sleep`.
+pub(crate) struct Sleep<'a> {
+    timer: &'a Timer,
+    duration: Duration,
+    state: Option<Arc<SleepState>>,
+}
+
+impl Future for Sleep<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if let Some(ref state) = self.state {
+            {
+                // The task may have moved since the last poll.
+                let mut waker = state.waker.lock().unwrap();
+                if !waker.will_wake(cx.waker()) {
+                    *waker = cx.waker().clone();
+                }
+            }
+            // Checked after updating the waker, so no wake-up is lost.
+            if state.done.load(Ordering::Acquire) {
+                return Poll::Ready(());
+            }
+            return Poll::Pending;
+        }
+
+        let state = Arc::new(SleepState {
+            done: AtomicBool::new(false),
+            waker: Mutex::new(cx.waker().clone()),
+        });
+        let deadline = Instant::now() + self.duration;
+        if self
+            .timer
+            .sender
+            .send((deadline, Arc::clone(&state)))
+            .is_err()
+        {
+            // The timer thread is gone; don't wait forever.
+            return Poll::Ready(());
+        }
+        self.state = Some(state);
+        Poll::Pending
+    }
+}
+
+/// Returns a future letting other tasks run before completing.
+pub(crate) fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// Future returned by `yield_now`.
+pub(crate) struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+        self.yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cursive;
+    use std::task::Wake;
+    use std::thread::Thread;
+    use std::time::Instant;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    #[test]
+    fn sleep() {
+        let timer = Timer::new();
+        let start = Instant::now();
+        block_on(timer.sleep(Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn concurrent_sleeps() {
+        let timer = Timer::new();
+        let start = Instant::now();
+
+        // Start a long sleep, then wait for a short one.
+        let mut long = Box::pin(timer.sleep(Duration::from_secs(10)));
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        assert!(long.as_mut().poll(&mut cx).is_pending());
+        block_on(timer.sleep(Duration::from_millis(20)));
+
+        assert!(start.elapsed() < Duration::from_secs(10));
+        assert!(long.as_mut().poll(&mut cx).is_pending());
+    }
+
+    #[test]
+    fn run_async() {
+        let mut siv = Cursive::dummy();
+        siv.cb_sink().send(Box::new(Cursive::quit)).unwrap();
+        block_on(siv.run_async());
+        assert!(!siv.is_running());
+    }
+}
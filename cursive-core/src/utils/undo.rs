@@ -0,0 +1,325 @@
+//! Application-wide undo history.
+//!
+//! Every `Cursive` root has an [`UndoManager`], available with
+//! [`Cursive::undo_manager_mut`]. Callbacks record reversible changes with
+//! [`UndoManager::push`], either as a pair of closures or as an [`Action`].
+//! They are then undone with [`Cursive::undo`] and redone with
+//! [`Cursive::redo`], which [`Cursive::set_global_undo`] binds to `Ctrl+Z`
+//! and `Ctrl+Y`.
+//!
+//! Views with their own history, like `TextArea`, handle these keys
+//! themselves while they have the focus.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::views::Checkbox;
+//!
+//! let checkbox = Checkbox::new().on_change(|s, checked| {
+//!     s.undo_manager_mut().push_fn(
+//!         "Toggle option",
+//!         move |s| set_option(s, !checked),
+//!         move |s| set_option(s, checked),
+//!     );
+//! });
+//!
+//! fn set_option(s: &mut cursive_core::Cursive, checked: bool) {
+//!     s.call_on_name("option", |c: &mut Checkbox| c.set_checked(checked));
+//! }
+//! ```
+//!
+//! [`Cursive::undo_manager_mut`]: crate::Cursive::undo_manager_mut
+//! [`Cursive::undo`]: crate::Cursive::undo
+//! [`Cursive::redo`]: crate::Cursive::redo
+//! [`Cursive::set_global_undo`]: crate::Cursive::set_global_undo
+use crate::Cursive;
+
+/// Default maximum number of entries in the history.
+const DEFAULT_LIMIT: usize = 100;
+
+/// A reversible change.
+pub trait Action {
+    /// Reverts the change.
+    fn undo(&mut self, siv: &mut Cursive);
+
+    /// Applies the change again, after it was undone.
+    fn redo(&mut self, siv: &mut Cursive);
+}
+
+/// Action made of two closures.
+struct FnAction<U, R> {
+    undo: U,
+    redo: R,
+}
+
+impl<U, R> Action for FnAction<U, R>
+where
+    U: FnMut(&mut Cursive),
+    R: FnMut(&mut Cursive),
+{
+    fn undo(&mut self, siv: &mut Cursive) {
+        (self.undo)(siv);
+    }
+
+    fn redo(&mut self, siv: &mut Cursive) {
+        (self.redo)(siv);
+    }
+}
+
+/// Group of actions undone together.
+pub(crate) struct Entry {
+    label: String,
+    // Entries with the same key are merged together.
+    key: Option<String>,
+    actions: Vec<Box<dyn Action>>,
+}
+
+impl Entry {
+    pub(crate) fn undo(&mut self, siv: &mut Cursive) {
+        for action in self.actions.iter_mut().rev() {
+            action.undo(siv);
+        }
+    }
+
+    pub(crate) fn redo(&mut self, siv: &mut Cursive) {
+        for action in &mut self.actions {
+            action.redo(siv);
+        }
+    }
+}
+
+/// History of reversible actions.
+pub struct UndoManager {
+    undo_stack: Vec<Entry>,
+    redo_stack: Vec<Entry>,
+    limit: usize,
+    // Set while an action runs, so it doesn't record itself again.
+    applying: bool,
+    // Set when the next action should not be merged with the last one.
+    boundary: bool,
+}
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        UndoManager {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            limit: DEFAULT_LIMIT,
+            applying: false,
+            boundary: false,
+        }
+    }
+}
+
+impl UndoManager {
+    /// Creates an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an action, already applied.
+    ///
+    /// `label` describes the action in the history. This clears the actions
+    /// that could be redone.
+    ///
+    /// Does nothing while an action is being undone or redone.
+    pub fn push<S, A>(&mut self, label: S, action: A)
+    where
+        S: Into<String>,
+        A: Action + 'static,
+    {
+        self.push_entry(label.into(), None, Box::new(action));
+    }
+
+    /// Records an action made of two closures.
+    ///
+    /// `undo` reverts the change, and `redo` applies it again.
+    pub fn push_fn<S, U, R>(&mut self, label: S, undo: U, redo: R)
+    where
+        S: Into<String>,
+        U: FnMut(&mut Cursive) + 'static,
+        R: FnMut(&mut Cursive) + 'static,
+    {
+        self.push(label, FnAction { undo, redo });
+    }
+
+    /// Records an action, merged with the previous one if it has the same
+    /// `key`.
+    ///
+    /// Merged actions are undone together, under the label of the latest.
+    /// This is useful for a series of small changes, like typing in a field.
+    ///
+    /// See also [`break_merge`](#method.break_merge).
+    pub fn push_merged<K, S, A>(&mut self, key: K, label: S, action: A)
+    where
+        K: Into<String>,
+        S: Into<String>,
+        A: Action + 'static,
+    {
+        self.push_entry(label.into(), Some(key.into()), Box::new(action));
+    }
+
+    /// Prevents the next action from being merged with the previous one.
+    pub fn break_merge(&mut self) {
+        self.boundary = true;
+    }
+
+    fn push_entry(
+        &mut self,
+        label: String,
+        key: Option<String>,
+        action: Box<dyn Action>,
+    ) {
+        if self.applying {
+            return;
+        }
+        self.redo_stack.clear();
+
+        let boundary = std::mem::replace(&mut self.boundary, false);
+        if let Some(last) = self.undo_stack.last_mut() {
+            if !boundary && key.is_some() && last.key == key {
+                last.label = label;
+                last.actions.push(action);
+                return;
+            }
+        }
+
+        self.undo_stack.push(Entry {
+            label,
+            key,
+            actions: vec![action],
+        });
+        if self.undo_stack.len() > self.limit {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    /// Sets the maximum number of entries kept in the history.
+    ///
+    /// Defaults to 100.
+    pub fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        let excess = self.undo_stack.len().saturating_sub(limit);
+        self.undo_stack.drain(..excess);
+    }
+
+    /// Returns the maximum number of entries kept in the history.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns `true` if an action can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns `true` if an action can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Returns the label of the next action to undo, if any.
+    pub fn undo_label(&self) -> Option<&str> {
+        self.undo_stack.last().map(|entry| &entry.label[..])
+    }
+
+    /// Returns the label of the next action to redo, if any.
+    pub fn redo_label(&self) -> Option<&str> {
+        self.redo_stack.last().map(|entry| &entry.label[..])
+    }
+
+    /// Returns the labels of the actions that can be undone.
+    ///
+    /// The most recent action comes first.
+    pub fn history(&self) -> impl Iterator<Item = &str> {
+        self.undo_stack.iter().rev().map(|entry| &entry.label[..])
+    }
+
+    /// Forgets all actions.
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    /// Takes the next entry to undo, ignoring new actions until
+    /// `undone` is called.
+    pub(crate) fn take_undo(&mut self) -> Option<Entry> {
+        let entry = self.undo_stack.pop()?;
+        self.applying = true;
+        Some(entry)
+    }
+
+    /// Takes the next entry to redo, ignoring new actions until
+    /// `redone` is called.
+    pub(crate) fn take_redo(&mut self) -> Option<Entry> {
+        let entry = self.redo_stack.pop()?;
+        self.applying = true;
+        Some(entry)
+    }
+
+    pub(crate) fn undone(&mut self, entry: Entry) {
+        self.applying = false;
+        self.boundary = true;
+        self.redo_stack.push(entry);
+    }
+
+    pub(crate) fn redone(&mut self, entry: Entry) {
+        self.applying = false;
+        self.boundary = true;
+        self.undo_stack.push(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_add(siv: &mut Cursive, key: Option<&str>, n: i32) {
+        let undo = move |s: &mut Cursive| {
+            s.with_user_data(|v: &mut i32| *v -= n);
+        };
+        let redo = move |s: &mut Cursive| {
+            s.with_user_data(|v: &mut i32| *v += n);
+        };
+        redo(siv);
+        let action = FnAction { undo, redo };
+        match key {
+            Some(key) => {
+                siv.undo_manager_mut().push_merged(key, "Add", action)
+            }
+            None => siv.undo_manager_mut().push("Add", action),
+        }
+    }
+
+    fn value(siv: &mut Cursive) -> i32 {
+        *siv.user_data::<i32>().unwrap()
+    }
+
+    #[test]
+    fn undo_redo_merge() {
+        let mut siv = Cursive::dummy();
+        siv.set_user_data(0i32);
+
+        push_add(&mut siv, None, 1);
+        push_add(&mut siv, Some("typing"), 2);
+        push_add(&mut siv, Some("typing"), 3);
+        assert_eq!(value(&mut siv), 6);
+        assert_eq!(siv.undo_manager().history().count(), 2);
+
+        // Merged actions are undone together.
+        assert!(siv.undo());
+        assert_eq!(value(&mut siv), 1);
+        assert!(siv.redo());
+        assert_eq!(value(&mut siv), 6);
+        assert!(!siv.redo());
+
+        assert!(siv.undo());
+        assert!(siv.undo());
+        assert_eq!(value(&mut siv), 0);
+        assert!(!siv.undo());
+
+        // A new action clears the redo stack.
+        push_add(&mut siv, None, 5);
+        assert!(!siv.undo_manager().can_redo());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::utils::Counter;
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// Wrapper around a `Write` that reports the progress made.
+///
+/// This is the counterpart of [`ProgressReader`](super::ProgressReader),
+/// for uploads or file copies.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use cursive_core::utils::ProgressWriter;
+/// use cursive_core::views::ProgressBar;
+///
+/// let mut source = std::fs::File::open("large_file").unwrap();
+/// let len = source.metadata().unwrap().len() as usize;
+///
+/// let bar = ProgressBar::new().max(len);
+/// let file = std::fs::File::create("copy").unwrap();
+/// let mut writer = ProgressWriter::new(bar.counter(), file);
+///
+/// std::thread::spawn(move || {
+///     std::io::copy(&mut source, &mut writer).unwrap();
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct ProgressWriter<W: Write> {
+    writer: W,
+    counter: Counter,
+    bytes: usize,
+    started: Instant,
+}
+
+impl<W: Write> ProgressWriter<W> {
+    /// Creates a new `ProgressWriter` around `writer`.
+    ///
+    /// `counter` will be updated with the number of bytes written.
+    pub fn new(counter: Counter, writer: W) -> Self {
+        ProgressWriter {
+            writer,
+            counter,
+            bytes: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the number of bytes written through this writer.
+    ///
+    /// Unlike the counter, this doesn't include its initial value.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns the average number of bytes written per second, since this
+    /// writer was created.
+    pub fn throughput(&self) -> f64 {
+        super::throughput(self.bytes, self.started)
+    }
+
+    /// Returns a reference to the wrapped writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Unwraps this `ProgressWriter`, returning the writer and counter.
+    pub fn deconstruct(self) -> (W, Counter) {
+        (self.writer, self.counter)
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let result = self.writer.write(buf)?;
+        self.bytes += result;
+        self.counter.tick(result);
+        Ok(result)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ProgressReader;
+
+    #[test]
+    fn copy() {
+        let read = Counter::new(0);
+        let written = Counter::new(10);
+
+        let mut reader = ProgressReader::new(read.clone(), &b"hello"[..]);
+        let mut writer = ProgressWriter::new(written.clone(), Vec::new());
+        io::copy(&mut reader, &mut writer).unwrap();
+
+        assert_eq!(read.get(), 5);
+        assert_eq!(written.get(), 15);
+        assert_eq!(writer.bytes(), 5);
+        assert_eq!(writer.get_ref(), b"hello");
+    }
+}
@@ -93,6 +93,15 @@ impl<T: View> Finder for T {
     }
 }
 
+/// Returns `true` if `view`, or one of its children, matches `selector`.
+///
+/// This is useful to implement [`View::remove_view`].
+pub fn contains(view: &mut dyn View, selector: &Selector<'_>) -> bool {
+    let mut found = false;
+    view.call_on_any(selector, &mut |_| found = true);
+    found
+}
+
 /// Selects a single view (if any) in the tree.
 pub enum Selector<'a> {
     /// Same as [`Selector::Name`].
@@ -52,6 +52,15 @@
 //!   next child. If no child accept the focus, then it will ignore the event
 //!   as well.
 //!
+//! When a view group moves the focus from one child to another, it sends
+//! `Event::FocusLost` to the first one, then `Event::FocusGained` to the
+//! other. Wrap a view in a [`FocusTracker`](crate::views::FocusTracker) to
+//! react to these.
+//!
+//! If the top layer ignores `Tab` or `Shift+Tab`, the focus wraps around
+//! to its first or last focusable view. A
+//! [`TabOrder`](crate::views::TabOrder) can also give an explicit order.
+//!
 //! # Scrolling
 //!
 //! Most views do not scroll by themselves; instead, they should be wrapped in
@@ -98,21 +107,28 @@ mod resizable;
 #[macro_use]
 pub mod scroll;
 
+mod scroll_aware;
 mod scroll_base;
 mod scrollable;
+mod searchable;
 
 mod into_boxed_view;
 
 pub use self::any::AnyView;
-pub use self::finder::{Finder, Selector};
+pub use self::finder::{contains, Finder, Selector};
 pub use self::into_boxed_view::IntoBoxedView;
 pub use self::margins::Margins;
 pub use self::nameable::Nameable;
 pub use self::position::{Offset, Position};
 pub use self::resizable::Resizable;
-pub use self::scroll::ScrollStrategy;
+pub use self::scroll::{
+    ScrollStrategy, ScrollbarGlyphs, ScrollbarStyle, ScrollbarVisibility,
+};
+pub use self::scroll_aware::ScrollAware;
 pub use self::scroll_base::ScrollBase;
 pub use self::scrollable::Scrollable;
+pub(crate) use self::searchable::{find_matches, is_match, search_order};
+pub use self::searchable::{SearchStep, Searchable};
 pub use self::size_cache::SizeCache;
 pub use self::size_constraint::SizeConstraint;
 pub use self::view_path::ViewPath;
@@ -1,15 +1,24 @@
 use std::cmp::min;
+use std::time::{Duration, Instant};
 
+use crate::align::{Align, HAlign, VAlign};
+use crate::animation::{Easing, Lerp};
 use crate::direction::Orientation;
 use crate::event::{AnyCb, Event, EventResult, Key, MouseButton, MouseEvent};
 use crate::printer::Printer;
 use crate::rect::Rect;
 use crate::theme::ColorStyle;
-use crate::view::{ScrollStrategy, Selector, SizeCache};
+use crate::view::{
+    ScrollStrategy, ScrollbarGlyphs, ScrollbarStyle, ScrollbarVisibility,
+    Selector, SizeCache,
+};
 use crate::with::With;
 use crate::Vec2;
 use crate::XY;
 
+/// How long an animated scroll takes.
+const SMOOTH_SCROLL_DURATION: Duration = Duration::from_millis(150);
+
 /// Describes an item with a scroll core.
 ///
 /// This trait is used to represent "something that can scroll".
@@ -79,14 +88,20 @@ pub struct Core {
     /// Are we scrollable in each direction?
     enabled: XY<bool>,
 
-    /// Should we show scrollbars?
-    ///
-    /// Even if this is true, no scrollbar will be printed if we don't need to
-    /// scroll.
+    /// When should we show scrollbars?
+    scrollbar_visibility: ScrollbarVisibility,
+
+    /// Where should we show scrollbars?
     ///
-    /// TODO: have an option to always show the scrollbar.
-    /// TODO: have an option to show scrollbar on top/left.
-    show_scrollbars: bool,
+    /// `scrollbar_align.h` places the vertical scrollbar, and
+    /// `scrollbar_align.v` the horizontal one.
+    scrollbar_align: Align,
+
+    /// Characters used to draw the scrollbars.
+    scrollbar_glyphs: ScrollbarGlyphs,
+
+    /// Colors used to draw the scrollbars.
+    scrollbar_style: ScrollbarStyle,
 
     /// How much padding should be between content and scrollbar?
     ///
@@ -101,6 +116,25 @@ pub struct Core {
 
     /// Defines how to update the offset when the view size changes.
     scroll_strategy: ScrollStrategy,
+
+    /// Width of the minimap gutter replacing the vertical scrollbar.
+    ///
+    /// 0 means no minimap.
+    minimap_width: usize,
+
+    /// Weight of each content row, drawn in the minimap.
+    minimap_samples: Vec<usize>,
+
+    /// Are we jumping around with the minimap?
+    minimap_grab: bool,
+
+    /// Should jumps be animated?
+    smooth_scrolling: bool,
+
+    /// Offset shown when the current animation started, and when.
+    ///
+    /// The animation goes from there to `offset`.
+    animation: Option<(Vec2, Instant)>,
 }
 
 impl Default for Core {
@@ -117,11 +151,19 @@ impl Core {
             offset: Vec2::zero(),
             last_size: Vec2::zero(),
             enabled: XY::new(false, true),
-            show_scrollbars: true,
+            scrollbar_visibility: ScrollbarVisibility::Auto,
+            scrollbar_align: Align::bot_right(),
+            scrollbar_glyphs: ScrollbarGlyphs::default(),
+            scrollbar_style: ScrollbarStyle::default(),
             scrollbar_padding: Vec2::new(1, 0),
             thumb_grab: None,
             size_cache: None,
             scroll_strategy: ScrollStrategy::KeepRow,
+            minimap_width: 0,
+            minimap_samples: Vec::new(),
+            minimap_grab: false,
+            smooth_scrolling: false,
+            animation: None,
         }
     }
 
@@ -130,70 +172,186 @@ impl Core {
         &self,
         printer: &Printer<'a, 'b>,
     ) -> Printer<'a, 'b> {
-        // Draw scrollbar?
-
         let size = self.available_size();
+        let origin = self.content_origin();
+        let shown = self.shown_scrollbars();
+        let content_offset = self.shown_offset(Instant::now());
 
-        // Draw the scrollbars
-        if self.get_show_scrollbars() {
-            let scrolling = self.is_scrolling();
+        if self.is_animating() {
+            printer.request_frame();
+        }
 
+        // Draw the scrollbars
+        if shown.any() {
             let lengths = self.scrollbar_thumb_lengths();
-            let offsets = self.scrollbar_thumb_offsets(lengths);
+            let offsets =
+                self.scrollbar_thumb_offsets(lengths, content_offset);
+            let position = self.scrollbar_position();
 
-            let line_c = XY::new("-", "|");
+            let glyphs = self.scrollbar_glyphs;
+            let line_c =
+                XY::new(glyphs.horizontal_track, glyphs.vertical_track);
 
             let color = if printer.focused {
-                ColorStyle::highlight()
+                self.scrollbar_style.thumb
             } else {
-                ColorStyle::highlight_inactive()
+                self.scrollbar_style.thumb_inactive
             };
 
+            // The minimap replaces the vertical scrollbar.
+            let minimap = self.has_minimap();
+            let bars = shown.and(XY::new(true, !minimap));
+
             XY::zip5(lengths, offsets, size, line_c, Orientation::pair())
-                .run_if(
-                    scrolling,
-                    |(length, offset, size, c, orientation)| {
-                        let start = printer
-                            .size
-                            .saturating_sub((1, 1))
-                            .with_axis(orientation, 0);
-                        let offset = orientation.make_vec(offset, 0);
+                .run_if(bars, |(length, offset, size, c, orientation)| {
+                    let start = position
+                        .with_axis(orientation, *origin.get(orientation));
+                    let offset = orientation.make_vec(offset, 0);
 
+                    self.with_track_color(printer, |printer| {
                         printer.print_line(orientation, start, size, c);
+                    });
 
-                        let thumb_c = if self
-                            .thumb_grab
-                            .map(|(o, _)| o == orientation)
-                            .unwrap_or(false)
-                        {
-                            " "
-                        } else {
-                            "▒"
-                        };
-                        printer.with_color(color, |printer| {
-                            printer.print_line(
-                                orientation,
-                                start + offset,
-                                length,
-                                thumb_c,
-                            );
-                        });
-                    },
-                );
-
-            // Draw the X between the two scrollbars.
-            if scrolling.both() {
-                printer.print(printer.size.saturating_sub((1, 1)), "╳");
+                    let thumb_c = if self
+                        .thumb_grab
+                        .is_some_and(|(o, _)| o == orientation)
+                    {
+                        glyphs.grabbed_thumb
+                    } else {
+                        glyphs.thumb
+                    };
+                    printer.with_color(color, |printer| {
+                        printer.print_line(
+                            orientation,
+                            start + offset,
+                            length,
+                            thumb_c,
+                        );
+                    });
+                });
+
+            if minimap {
+                self.draw_minimap(printer, color, content_offset.y);
+            }
+
+            // Draw the corner between the two scrollbars.
+            if shown.both() {
+                self.with_track_color(printer, |printer| {
+                    printer.print(position, glyphs.corner);
+                });
             }
         }
 
         // Draw content
         printer
+            .offset(origin)
             .cropped(size)
-            .content_offset(self.offset)
+            .content_offset(content_offset)
             .inner_size(self.inner_size)
     }
 
+    /// Runs `f` with the color of the scrollbar track.
+    fn with_track_color<F>(&self, printer: &Printer<'_, '_>, f: F)
+    where
+        F: FnOnce(&Printer<'_, '_>),
+    {
+        match self.scrollbar_style.track {
+            Some(color) => printer.with_color(color, f),
+            None => f(printer),
+        }
+    }
+
+    /// Draws the minimap gutter in place of the vertical scrollbar.
+    ///
+    /// Each row of the gutter covers a range of content rows, with a bar as
+    /// long as their average weight. Rows covering the viewport, starting
+    /// at row `top_row`, use `color`.
+    fn draw_minimap(
+        &self,
+        printer: &Printer<'_, '_>,
+        color: ColorStyle,
+        top_row: usize,
+    ) {
+        let height = self.available_size().y;
+        let x = self.scrollbar_position().x;
+        let top = self.content_origin().y;
+
+        let densities: Vec<usize> =
+            (0..height).map(|row| self.minimap_density(row)).collect();
+        let max = densities.iter().copied().max().unwrap_or(0).max(1);
+
+        let viewport = self.minimap_rows(top_row, height);
+
+        for (row, density) in densities.into_iter().enumerate() {
+            let bar = (density * self.minimap_width).div_ceil(max);
+            let draw = |printer: &Printer<'_, '_>| {
+                printer.print_hline((x, top + row), self.minimap_width, " ");
+                printer.print_hline((x, top + row), bar, "█");
+            };
+            if viewport.contains(&row) {
+                printer.with_color(color, draw);
+            } else {
+                draw(printer);
+            }
+        }
+    }
+
+    /// Returns the range of content rows covered by a row of the minimap.
+    fn minimap_bucket(&self, row: usize) -> std::ops::Range<usize> {
+        let height = self.available_size().y.max(1);
+        let start = row * self.inner_size.y / height;
+        let end = ((row + 1) * self.inner_size.y / height).max(start + 1);
+        start..end
+    }
+
+    /// Returns the rows of the minimap covering `len` content rows from
+    /// `start`.
+    fn minimap_rows(
+        &self,
+        start: usize,
+        len: usize,
+    ) -> std::ops::Range<usize> {
+        let height = self.available_size().y;
+        let inner = self.inner_size.y.max(1);
+        let first = start * height / inner;
+        let last = ((start + len) * height).div_ceil(inner);
+        first..last.max(first + 1)
+    }
+
+    /// Returns the average weight of the content rows under a minimap row.
+    fn minimap_density(&self, row: usize) -> usize {
+        let bucket = self.minimap_bucket(row);
+        let len = bucket.len();
+        let total: usize =
+            bucket.filter_map(|y| self.minimap_samples.get(y)).sum();
+        total / len
+    }
+
+    /// Returns `true` if the minimap is currently shown.
+    fn has_minimap(&self) -> bool {
+        self.minimap_width > 0 && self.shown_scrollbars().y
+    }
+
+    /// Returns `true` if `position` is on the minimap.
+    fn is_on_minimap(&self, position: Vec2) -> bool {
+        let x = self.scrollbar_position().x;
+        let top = self.content_origin().y;
+        self.has_minimap()
+            && position.y >= top
+            && position.y < top + self.available_size().y
+            && position.x >= x
+            && position.x < x + self.minimap_width
+    }
+
+    /// Centers the viewport on the content under the given minimap row.
+    fn jump_to_minimap_row(&mut self, row: usize) {
+        let height = self.available_size().y;
+        let bucket = self.minimap_bucket(row.min(height.saturating_sub(1)));
+        let center = (bucket.start + bucket.end) / 2;
+        let max_offset = self.inner_size.y.saturating_sub(height);
+        self.offset.y = min(center.saturating_sub(height / 2), max_offset);
+    }
+
     /// Returns `true` if `event` should be processed by the content.
     ///
     /// This also updates `event` so that it is relative to the content.
@@ -205,11 +363,12 @@ impl Core {
         } = event
         {
             // For mouse events, check if it falls inside the available area
+            let origin = self.content_origin();
             let inside = position
                 .checked_sub(offset)
-                .map(|p| p.fits_in(self.available_size()))
-                .unwrap_or(false);
-            *position = *position + self.offset;
+                .and_then(|p| p.checked_sub(origin))
+                .is_some_and(|p| p.fits_in(self.available_size()));
+            *position = (*position + self.offset).saturating_sub(origin);
             inside
         } else {
             // For key events, assume it's inside by default.
@@ -228,6 +387,10 @@ impl Core {
             EventResult::Ignored => {
                 // The view ignored the event, so we're free to use it.
 
+                // Scrolling again cancels any animation.
+                let shown = self.shown_offset(Instant::now());
+                let animation = self.animation.take();
+
                 // If it's an arrow, try to scroll in the given direction.
                 // If it's a mouse scroll, try to scroll as well.
                 // Also allow Ctrl+arrow to move the view,
@@ -257,7 +420,22 @@ impl Core {
                         event: MouseEvent::Press(MouseButton::Left),
                         position,
                         offset,
-                    } if self.show_scrollbars
+                    } if position
+                        .checked_sub(offset)
+                        .map(|position| self.is_on_minimap(position))
+                        .unwrap_or(false) =>
+                    {
+                        self.minimap_grab = true;
+                        let top = self.content_origin().y;
+                        self.jump_to_minimap_row(
+                            (position.y - offset.y).saturating_sub(top),
+                        );
+                    }
+                    Event::Mouse {
+                        event: MouseEvent::Press(MouseButton::Left),
+                        position,
+                        offset,
+                    } if self.get_show_scrollbars()
                         && position
                             .checked_sub(offset)
                             .map(|position| self.start_drag(position))
@@ -269,7 +447,7 @@ impl Core {
                         event: MouseEvent::Hold(MouseButton::Left),
                         position,
                         offset,
-                    } if self.show_scrollbars => {
+                    } if self.get_show_scrollbars() => {
                         let position = position.saturating_sub(offset);
                         self.drag(position);
                     }
@@ -326,12 +504,22 @@ impl Core {
                     {
                         self.offset.x += 1;
                     }
-                    _ => return EventResult::Ignored,
+                    _ => {
+                        self.animation = animation;
+                        return EventResult::Ignored;
+                    }
                 };
 
+                // Page jumps are animated from where we were.
+                if let Event::Key(
+                    Key::PageUp | Key::PageDown | Key::Home | Key::End,
+                ) = event
+                {
+                    self.animate_from(shown);
+                }
+
                 // We just scrolled manually, so reset the scroll strategy.
                 self.scroll_strategy = ScrollStrategy::KeepRow;
-                // TODO: return callback on_scroll?
                 EventResult::Consumed(None)
             }
             other => {
@@ -373,6 +561,10 @@ impl Core {
 
         // Possibly update the offset if we're following a specific strategy.
         self.adjust_scroll();
+
+        if !self.is_animating() {
+            self.animation = None;
+        }
     }
 
     /// Returns `true` if we should relayout, no matter the content.
@@ -453,6 +645,39 @@ impl Core {
         self.scrollbar_padding
     }
 
+    /// Replaces the vertical scrollbar with a minimap of the given width.
+    ///
+    /// The minimap shows the weight of the content rows, given with
+    /// [`set_minimap_samples`](#method.set_minimap_samples), compressed to
+    /// the height of the view. Clicking it jumps to the matching content.
+    ///
+    /// Like the scrollbar, it is only shown when scrolling vertically.
+    /// A width of 0 disables the minimap, which is the default.
+    pub fn set_minimap_width(&mut self, width: usize) {
+        self.minimap_width = width;
+        self.invalidate_cache();
+    }
+
+    /// Replaces the vertical scrollbar with a minimap of the given width.
+    ///
+    /// Chainable variant.
+    pub fn minimap_width(self, width: usize) -> Self {
+        self.with(|s| s.set_minimap_width(width))
+    }
+
+    /// Returns the width of the minimap, or 0 if it is disabled.
+    pub fn get_minimap_width(&self) -> usize {
+        self.minimap_width
+    }
+
+    /// Sets the weight of each content row, drawn in the minimap.
+    ///
+    /// Usually the number of non-blank cells in the row. Missing rows have
+    /// a weight of 0.
+    pub fn set_minimap_samples(&mut self, samples: Vec<usize>) {
+        self.minimap_samples = samples;
+    }
+
     /// For each axis, returns `true` if this view can scroll.
     ///
     /// For example, a vertically-scrolling view will return
@@ -464,8 +689,14 @@ impl Core {
     /// Control whether scroll bars are visibile.
     ///
     /// Defaults to `true`.
+    ///
+    /// `true` shows them when needed, like `ScrollbarVisibility::Auto`.
     pub fn set_show_scrollbars(&mut self, show_scrollbars: bool) {
-        self.show_scrollbars = show_scrollbars;
+        self.set_scrollbar_visibility(if show_scrollbars {
+            ScrollbarVisibility::Auto
+        } else {
+            ScrollbarVisibility::Never
+        });
     }
 
     /// Control whether scroll bars are visibile.
@@ -476,10 +707,91 @@ impl Core {
     }
 
     /// Returns `true` if we will show scrollbars when needed.
-    ///
-    /// Scrollbars are always hidden when not needed.
     pub fn get_show_scrollbars(&self) -> bool {
-        self.show_scrollbars
+        self.scrollbar_visibility != ScrollbarVisibility::Never
+    }
+
+    /// Defines when scrollbars are shown.
+    ///
+    /// Defaults to `ScrollbarVisibility::Auto`.
+    pub fn set_scrollbar_visibility(
+        &mut self,
+        visibility: ScrollbarVisibility,
+    ) {
+        self.scrollbar_visibility = visibility;
+        self.invalidate_cache();
+    }
+
+    /// Defines when scrollbars are shown.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_visibility(
+        self,
+        visibility: ScrollbarVisibility,
+    ) -> Self {
+        self.with(|s| s.set_scrollbar_visibility(visibility))
+    }
+
+    /// Returns when scrollbars are shown.
+    pub fn get_scrollbar_visibility(&self) -> ScrollbarVisibility {
+        self.scrollbar_visibility
+    }
+
+    /// Defines on which sides scrollbars are drawn.
+    ///
+    /// `align.h` places the vertical scrollbar on the left or on the right,
+    /// and `align.v` places the horizontal scrollbar on the top or on the
+    /// bottom. Centered alignments are treated as right and bottom.
+    ///
+    /// Defaults to `Align::bot_right()`.
+    pub fn set_scrollbar_align(&mut self, align: Align) {
+        self.scrollbar_align = align;
+    }
+
+    /// Defines on which sides scrollbars are drawn.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_align(self, align: Align) -> Self {
+        self.with(|s| s.set_scrollbar_align(align))
+    }
+
+    /// Returns on which sides scrollbars are drawn.
+    pub fn get_scrollbar_align(&self) -> Align {
+        self.scrollbar_align
+    }
+
+    /// Sets the characters used to draw scrollbars.
+    pub fn set_scrollbar_glyphs(&mut self, glyphs: ScrollbarGlyphs) {
+        self.scrollbar_glyphs = glyphs;
+    }
+
+    /// Sets the characters used to draw scrollbars.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_glyphs(self, glyphs: ScrollbarGlyphs) -> Self {
+        self.with(|s| s.set_scrollbar_glyphs(glyphs))
+    }
+
+    /// Returns the characters used to draw scrollbars.
+    pub fn get_scrollbar_glyphs(&self) -> ScrollbarGlyphs {
+        self.scrollbar_glyphs
+    }
+
+    /// Sets the colors used to draw scrollbars.
+    pub fn set_scrollbar_style(&mut self, style: ScrollbarStyle) {
+        self.scrollbar_style = style;
+    }
+
+    /// Sets the colors used to draw scrollbars.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_style(self, style: ScrollbarStyle) -> Self {
+        self.with(|s| s.set_scrollbar_style(style))
+    }
+
+    /// Returns the colors used to draw scrollbars.
+    pub fn get_scrollbar_style(&self) -> ScrollbarStyle {
+        self.scrollbar_style
     }
 
     /// Returns the size given to the content on the last layout phase.
@@ -541,6 +853,11 @@ impl Core {
 
     /// Scrolls until the given rect is in view.
     pub fn scroll_to_rect(&mut self, important_area: Rect) {
+        self.jump(|s| s.show_rect(important_area));
+    }
+
+    /// Moves the offset until the given rect is in view.
+    fn show_rect(&mut self, important_area: Rect) {
         // The furthest top-left we can go
         let top_left = (important_area.bottom_right() + (1, 1))
             .saturating_sub(self.available_size());
@@ -562,51 +879,136 @@ impl Core {
         // How far to the bottom-right we can go
         let max = pos;
 
-        self.offset = self.offset.or_min(max).or_max(min);
+        self.jump(|s| s.offset = s.offset.or_min(max).or_max(min));
     }
 
     /// Scroll until the given column is visible.
     pub fn scroll_to_x(&mut self, x: usize) {
-        if x >= self.offset.x + self.last_size.x {
-            self.offset.x = 1 + x - self.last_size.x;
-        } else if x < self.offset.x {
-            self.offset.x = x;
-        }
+        self.jump(|s| {
+            if x >= s.offset.x + s.last_size.x {
+                s.offset.x = 1 + x - s.last_size.x;
+            } else if x < s.offset.x {
+                s.offset.x = x;
+            }
+        });
     }
 
     /// Scroll until the given row is visible.
     pub fn scroll_to_y(&mut self, y: usize) {
-        if y >= self.offset.y + self.last_size.y {
-            self.offset.y = 1 + y - self.last_size.y;
-        } else if y < self.offset.y {
-            self.offset.y = y;
-        }
+        self.jump(|s| {
+            if y >= s.offset.y + s.last_size.y {
+                s.offset.y = 1 + y - s.last_size.y;
+            } else if y < s.offset.y {
+                s.offset.y = y;
+            }
+        });
     }
 
     /// Programmatically scroll to the top of the view.
     pub fn scroll_to_top(&mut self) {
         let curr_x = self.offset.x;
-        self.set_offset((curr_x, 0));
+        self.jump(|s| s.set_offset((curr_x, 0)));
     }
 
     /// Programmatically scroll to the bottom of the view.
     pub fn scroll_to_bottom(&mut self) {
         let max_y = self.inner_size.saturating_sub(self.available_size()).y;
         let curr_x = self.offset.x;
-        self.set_offset((curr_x, max_y));
+        self.jump(|s| s.set_offset((curr_x, max_y)));
     }
 
     /// Programmatically scroll to the leftmost side of the view.
     pub fn scroll_to_left(&mut self) {
         let curr_y = self.offset.y;
-        self.set_offset((0, curr_y));
+        self.jump(|s| s.set_offset((0, curr_y)));
     }
 
     /// Programmatically scroll to the rightmost side of the view.
     pub fn scroll_to_right(&mut self) {
         let max_x = self.inner_size.saturating_sub(self.available_size()).x;
         let curr_y = self.offset.y;
-        self.set_offset((max_x, curr_y));
+        self.jump(|s| s.set_offset((max_x, curr_y)));
+    }
+
+    /// Animates page jumps and programmatic scrolling.
+    ///
+    /// When enabled, the content moves to the new offset over a few
+    /// frames, which makes it easier to follow in long documents. Moves
+    /// of a single row or column are never animated, and scrolling again
+    /// cancels the current animation.
+    ///
+    /// Defaults to `false`.
+    pub fn set_smooth_scrolling(&mut self, smooth_scrolling: bool) {
+        self.smooth_scrolling = smooth_scrolling;
+        if !smooth_scrolling {
+            self.animation = None;
+        }
+    }
+
+    /// Animates page jumps and programmatic scrolling.
+    ///
+    /// Chainable variant.
+    pub fn smooth_scrolling(self, smooth_scrolling: bool) -> Self {
+        self.with(|s| s.set_smooth_scrolling(smooth_scrolling))
+    }
+
+    /// Returns `true` if page jumps and programmatic scrolling are
+    /// animated.
+    pub fn get_smooth_scrolling(&self) -> bool {
+        self.smooth_scrolling
+    }
+
+    /// Returns `true` if the content is still moving to the offset.
+    pub fn is_animating(&self) -> bool {
+        self.animation
+            .is_some_and(|(_, start)| start.elapsed() < SMOOTH_SCROLL_DURATION)
+    }
+
+    /// Moves the current animation forward, as if `elapsed` went by.
+    #[cfg(test)]
+    pub(crate) fn step_animation(&mut self, elapsed: Duration) {
+        if let Some((_, ref mut start)) = self.animation {
+            *start -= elapsed;
+        }
+    }
+
+    /// Runs `f`, and animates the offset change, if any.
+    fn jump<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let offset = self.offset;
+        let shown = self.shown_offset(Instant::now());
+        f(self);
+        if self.offset != offset {
+            self.animate_from(shown);
+        }
+    }
+
+    /// Starts animating from `shown` to the offset, when enabled.
+    fn animate_from(&mut self, shown: Vec2) {
+        let distance = shown
+            .zip_map(self.offset, |a, b| a.abs_diff(b))
+            .fold(usize::max);
+        self.animation = (self.smooth_scrolling && distance > 1)
+            .then(|| (shown, Instant::now()));
+    }
+
+    /// Returns the offset to draw at the given time.
+    ///
+    /// This is the offset, unless an animation is still running.
+    fn shown_offset(&self, now: Instant) -> Vec2 {
+        let (from, start) = match self.animation {
+            Some(animation) => animation,
+            None => return self.offset,
+        };
+        let t = now.saturating_duration_since(start).as_secs_f64()
+            / SMOOTH_SCROLL_DURATION.as_secs_f64();
+        if t >= 1.0 {
+            return self.offset;
+        }
+
+        from.lerp(&self.offset, Easing::EaseOut.apply(t as f32))
     }
 
     /// Clears the cache.
@@ -619,29 +1021,88 @@ impl Core {
         self.inner_size.zip_map(self.last_size, |i, s| i > s)
     }
 
+    /// Returns for each axis if a scrollbar is shown.
+    ///
+    /// `shown_scrollbars().y` is `true` if the vertical scrollbar is shown.
+    pub fn shown_scrollbars(&self) -> XY<bool> {
+        match self.scrollbar_visibility {
+            ScrollbarVisibility::Auto => self.is_scrolling(),
+            ScrollbarVisibility::Always => {
+                self.is_scrolling().or(self.enabled)
+            }
+            ScrollbarVisibility::Never => XY::new(false, false),
+        }
+    }
+
+    /// Returns for each axis if a scrollbar is shown, even without scrolling.
+    pub(crate) fn forced_scrollbars(&self) -> XY<bool> {
+        match self.scrollbar_visibility {
+            ScrollbarVisibility::Always => self.enabled,
+            _ => XY::new(false, false),
+        }
+    }
+
+    /// Returns the column of the vertical scrollbar, and the row of the
+    /// horizontal scrollbar.
+    fn scrollbar_position(&self) -> Vec2 {
+        let end = self.last_size.saturating_sub(self.thickness());
+        Vec2::new(
+            if self.scrollbar_align.h == HAlign::Left {
+                0
+            } else {
+                end.x
+            },
+            if self.scrollbar_align.v == VAlign::Top {
+                0
+            } else {
+                end.y
+            },
+        )
+    }
+
+    /// Returns the position of the content, after scrollbars on the top or
+    /// on the left.
+    fn content_origin(&self) -> Vec2 {
+        XY::new(
+            self.scrollbar_align.h == HAlign::Left,
+            self.scrollbar_align.v == VAlign::Top,
+        )
+        .select_or(self.scrollbar_size(), Vec2::zero())
+    }
+
+    /// Returns `true` if the scrollbar or the minimap is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.thumb_grab.is_some() || self.minimap_grab
+    }
+
     /// Stops grabbing the scrollbar.
     fn release_grab(&mut self) {
         self.thumb_grab = None;
+        self.minimap_grab = false;
     }
 
     /// Returns the size taken by the scrollbars.
     ///
-    /// Will be zero in axis where we're not scrolling.
+    /// Will be zero in axis where no scrollbar is shown.
     ///
     /// The scrollbar_size().x will be the horizontal space taken by the vertical scrollbar.
     pub fn scrollbar_size(&self) -> Vec2 {
-        self.is_scrolling()
+        self.shown_scrollbars()
             .swap()
-            .select_or(self.scrollbar_padding + (1, 1), Vec2::zero())
+            .select_or(self.scrollbar_padding + self.thickness(), Vec2::zero())
+    }
+
+    /// Returns the thickness of the scrollbars.
+    ///
+    /// `thickness().x` is the width of the vertical scrollbar, or of the
+    /// minimap.
+    pub fn thickness(&self) -> Vec2 {
+        Vec2::new(self.minimap_width.max(1), 1)
     }
 
     /// Returns the size available for the child view.
     fn available_size(&self) -> Vec2 {
-        if self.show_scrollbars {
-            self.last_size.saturating_sub(self.scrollbar_size())
-        } else {
-            self.last_size
-        }
+        self.last_size.saturating_sub(self.scrollbar_size())
     }
 
     /// Starts scrolling from the cursor position.
@@ -649,24 +1110,30 @@ impl Core {
     /// Returns `true` if the event was consumed.
     fn start_drag(&mut self, position: Vec2) -> bool {
         // For each scrollbar, how far it is.
-        let scrollbar_pos = self.last_size.saturating_sub((1, 1));
+        let scrollbar_pos = self.scrollbar_position();
+        let origin = self.content_origin();
         let lengths = self.scrollbar_thumb_lengths();
-        let offsets = self.scrollbar_thumb_offsets(lengths);
+        let offsets = self.scrollbar_thumb_offsets(lengths, self.offset);
         let available = self.available_size();
 
         // This is true for Y if we grabbed the vertical scrollbar
         // More specifically, we need both (for instance for the vertical bar):
         // * To be in the right column: X == scrollbar_pos
-        // * To be in the right range: Y < available
+        // * To be in the right range: origin <= Y < origin + available
         let grabbed = position
             .zip_map(scrollbar_pos, |p, s| p == s)
             .swap()
-            .and(position.zip_map(available, |p, a| p < a));
+            .and(position.zip_map(origin.zip(available), |p, (o, a)| {
+                p >= o && p < o + a
+            }));
+
+        // Position along the scrollbars.
+        let relative = position.saturating_sub(origin);
 
         // Iterate on axises, and keep the one we grabbed.
         if let Some((orientation, pos, length, offset)) =
-            XY::zip4(Orientation::pair(), position, lengths, offsets)
-                .keep(grabbed.and(self.enabled))
+            XY::zip4(Orientation::pair(), relative, lengths, offsets)
+                .keep(grabbed.and(self.shown_scrollbars()))
                 .into_iter()
                 .filter_map(|x| x)
                 .next()
@@ -689,6 +1156,13 @@ impl Core {
 
     /// Called when a mouse drag is detected.
     fn drag(&mut self, position: Vec2) {
+        let position = position.saturating_sub(self.content_origin());
+
+        if self.minimap_grab {
+            self.jump_to_minimap_row(position.y);
+            return;
+        }
+
         // Only do something if we grabbed something before.
         if let Some((orientation, grab)) = self.thumb_grab {
             self.scroll_to_thumb(
@@ -742,20 +1216,24 @@ impl Core {
         (available * available / self.inner_size.or_max((1, 1))).or_max((1, 1))
     }
 
-    fn scrollbar_thumb_offsets(&self, lengths: Vec2) -> Vec2 {
+    fn scrollbar_thumb_offsets(&self, lengths: Vec2, offset: Vec2) -> Vec2 {
         let available = self.available_size();
         // The number of steps is 1 + the "extra space"
         let steps = (available + (1, 1)).saturating_sub(lengths);
         let max_offset = self.inner_size.saturating_sub(available) + (1, 1);
 
-        steps * self.offset / max_offset
+        steps * offset / max_offset
     }
 
     /// Apply the scrolling strategy to the current scroll position.
     fn adjust_scroll(&mut self) {
         match self.scroll_strategy {
-            ScrollStrategy::StickToTop => self.scroll_to_top(),
-            ScrollStrategy::StickToBottom => self.scroll_to_bottom(),
+            // Following the content is never animated.
+            ScrollStrategy::StickToTop => self.set_offset((self.offset.x, 0)),
+            ScrollStrategy::StickToBottom => {
+                // `set_offset` stops at the bottom.
+                self.set_offset((self.offset.x, usize::MAX))
+            }
             ScrollStrategy::KeepRow => (),
         }
     }
@@ -25,7 +25,10 @@ mod raw;
 
 pub use self::core::{Core, Scroller};
 
+use crate::buffer::BufferBackend;
 use crate::event::{Event, EventResult};
+use crate::theme;
+use crate::view::View;
 use crate::{Printer, Rect, Vec2};
 
 /// Defines the scrolling behaviour on content or size change
@@ -45,6 +48,88 @@ impl Default for ScrollStrategy {
     }
 }
 
+/// Defines when scrollbars are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollbarVisibility {
+    /// Shows the scrollbars only when the content is larger than the view.
+    #[default]
+    Auto,
+    /// Always shows the scrollbars on the axes that can scroll.
+    ///
+    /// The content does not move when it starts scrolling.
+    Always,
+    /// Never shows the scrollbars.
+    Never,
+}
+
+/// Characters used to draw scrollbars.
+///
+/// Each glyph should be one cell wide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollbarGlyphs {
+    /// Track of the horizontal scrollbar.
+    pub horizontal_track: &'static str,
+    /// Track of the vertical scrollbar.
+    pub vertical_track: &'static str,
+    /// Thumb showing the visible part of the content.
+    pub thumb: &'static str,
+    /// Thumb while it is dragged with the mouse.
+    pub grabbed_thumb: &'static str,
+    /// Corner between the two scrollbars.
+    pub corner: &'static str,
+}
+
+impl Default for ScrollbarGlyphs {
+    fn default() -> Self {
+        ScrollbarGlyphs {
+            horizontal_track: "-",
+            vertical_track: "|",
+            thumb: "▒",
+            grabbed_thumb: " ",
+            corner: "╳",
+        }
+    }
+}
+
+/// Colors used to draw scrollbars.
+///
+/// Custom palette colors can be used here, to theme scrollbars from a theme
+/// file:
+///
+/// ```rust
+/// use cursive_core::theme::{ColorStyle, ColorType, PaletteColor};
+/// use cursive_core::view::ScrollbarStyle;
+///
+/// let style = ScrollbarStyle {
+///     thumb: ColorStyle::new(
+///         PaletteColor::View,
+///         ColorType::Custom("scrollbar"),
+///     ),
+///     ..ScrollbarStyle::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollbarStyle {
+    /// Color of the track and the corner.
+    ///
+    /// `None` keeps the color of the view.
+    pub track: Option<theme::ColorStyle>,
+    /// Color of the thumb when the view is focused.
+    pub thumb: theme::ColorStyle,
+    /// Color of the thumb when the view is not focused.
+    pub thumb_inactive: theme::ColorStyle,
+}
+
+impl Default for ScrollbarStyle {
+    fn default() -> Self {
+        ScrollbarStyle {
+            track: None,
+            thumb: theme::ColorStyle::highlight(),
+            thumb_inactive: theme::ColorStyle::highlight_inactive(),
+        }
+    }
+}
+
 /// Performs `View::on_event` on a `scroll::Scroller`.
 ///
 /// Example:
@@ -190,6 +275,26 @@ pub fn draw_lines<T, LineDrawer>(
     });
 }
 
+/// Counts the non-blank cells in each row of `view`, drawn at `size`.
+///
+/// The view is drawn offscreen, with the default theme. This is the default
+/// way to sample the content of a minimap.
+///
+/// See [`Core::set_minimap_samples`].
+pub fn sample_rows(view: &dyn View, size: Vec2) -> Vec<usize> {
+    let backend = BufferBackend::new(size);
+    view.draw(&Printer::new(size, &theme::load_default(), &backend));
+
+    let buffer = backend.into_buffer();
+    let mut rows = vec![0; size.y];
+    for (pos, cell) in buffer.cells() {
+        if !cell.text.trim().is_empty() {
+            rows[pos.y] += 1;
+        }
+    }
+    rows
+}
+
 /// Draws a frame around the scrollable content.
 ///
 /// `left_border` will be called for each row to draw the left border for the given line number.
@@ -101,18 +101,22 @@ where
         }
     }
 
+    // Scrollbars always shown take some space from the start.
+    let forced = get_scroller(model).forced_scrollbars();
+
     // Attempt 1: try without scrollbars
     let (inner_size, size, scrolling) = sizes_when_scrolling(
         constraint,
-        XY::new(false, false),
+        forced,
         strict,
         model,
         get_scroller,
         required_size,
     );
+    let scrolling = scrolling.or(forced);
 
     // If we need to add scrollbars, the available size will change.
-    if scrolling.any() && get_scroller(model).get_show_scrollbars() {
+    if scrolling != forced && get_scroller(model).get_show_scrollbars() {
         // Attempt 2: he wants to scroll? Sure!
         // Try again with some space for the scrollbar.
         let (inner_size, size, new_scrolling) = sizes_when_scrolling(
@@ -123,6 +127,7 @@ where
             get_scroller,
             required_size,
         );
+        let new_scrolling = new_scrolling.or(forced);
         if scrolling == new_scrolling {
             // Yup, scrolling did it. We're good to go now.
             (inner_size, size)
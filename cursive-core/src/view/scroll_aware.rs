@@ -0,0 +1,20 @@
+use crate::view::View;
+use crate::Rect;
+
+/// A view that needs to know which part of it is visible.
+///
+/// When wrapped with [`ScrollView::aware`], the view is told its viewport
+/// after every layout, before being drawn. Large views, like maps or
+/// timelines, can then prepare only the visible region.
+///
+/// Views that only need the visible region while drawing can use
+/// [`Printer::visible_area`] instead.
+///
+/// [`ScrollView::aware`]: crate::views::ScrollView::aware
+/// [`Printer::visible_area`]: crate::Printer::visible_area
+pub trait ScrollAware: View {
+    /// Called with the part of this view currently visible.
+    ///
+    /// `viewport` is in the coordinates of this view.
+    fn set_viewport(&mut self, viewport: Rect);
+}
@@ -0,0 +1,123 @@
+use crate::event::EventResult;
+use crate::view::View;
+use std::ops::Range;
+
+/// Which match to go to when searching a [`Searchable`] view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SearchStep {
+    /// Stay on the current match if it still matches.
+    ///
+    /// This is used when the query changes, for example while it's typed.
+    Current,
+
+    /// Go to the next match, wrapping around at the end.
+    Next,
+
+    /// Go to the previous match, wrapping around at the start.
+    Previous,
+}
+
+/// A view whose content can be searched.
+///
+/// This is used by [`SearchWrapper`] to provide the same search experience
+/// across views.
+///
+/// Matching is case-insensitive, unless the query contains an uppercase
+/// character.
+///
+/// [`SearchWrapper`]: crate::views::SearchWrapper
+pub trait Searchable: View {
+    /// Looks for `query` in this view, and moves to a match.
+    ///
+    /// Depending on the view, this can focus the matching item, or highlight
+    /// the matching text.
+    ///
+    /// Returns `EventResult::Ignored` if nothing matches.
+    fn search(&mut self, query: &str, step: SearchStep) -> EventResult;
+
+    /// Clears any highlight left by a previous search.
+    ///
+    /// Default implementation does nothing.
+    fn clear_search(&mut self) {}
+}
+
+/// Returns the order in which items should be tried for a search.
+///
+/// Starts from `focus` (or right after/before, depending on `step`), and
+/// wraps around.
+pub(crate) fn search_order(
+    len: usize,
+    focus: usize,
+    step: SearchStep,
+) -> impl Iterator<Item = usize> {
+    (0..len).map(move |i| match step {
+        SearchStep::Current => (focus + i) % len,
+        SearchStep::Next => (focus + i + 1) % len,
+        SearchStep::Previous => (focus + 2 * len - i - 1) % len,
+    })
+}
+
+/// Returns `true` if `text` contains `query`.
+///
+/// See [`find_matches`] for the matching rules.
+pub(crate) fn is_match(text: &str, query: &str) -> bool {
+    !query.is_empty() && !find_matches(text, query).is_empty()
+}
+
+/// Finds non-overlapping occurences of `query` in `text`.
+///
+/// Returns byte ranges in `text`. The search ignores case, unless `query`
+/// contains an uppercase character.
+pub(crate) fn find_matches(text: &str, query: &str) -> Vec<Range<usize>> {
+    let mut result = Vec::new();
+    if query.is_empty() {
+        return result;
+    }
+
+    let ignore_case = !query.chars().any(char::is_uppercase);
+    let same = |a: char, b: char| {
+        if ignore_case {
+            a.to_lowercase().eq(b.to_lowercase())
+        } else {
+            a == b
+        }
+    };
+
+    let mut start = 0;
+    while start < text.len() {
+        let mut rest = text[start..].char_indices();
+        let matched = query
+            .chars()
+            .all(|q| rest.next().map(|(_, c)| same(c, q)).unwrap_or(false));
+
+        if matched {
+            let end =
+                rest.next().map(|(i, _)| start + i).unwrap_or(text.len());
+            result.push(start..end);
+            start = end;
+        } else {
+            // Try again from the next character.
+            start += text[start..]
+                .chars()
+                .next()
+                .map(char::len_utf8)
+                .unwrap_or(1);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches() {
+        assert_eq!(find_matches("Hello hello", "hello"), vec![0..5, 6..11]);
+        assert_eq!(find_matches("Hello hello", "Hello"), vec![0..5]);
+        assert_eq!(find_matches("aaa", "aa"), vec![0..2]);
+        assert_eq!(find_matches("élan Élan", "élan"), vec![0..5, 6..11]);
+        assert!(find_matches("abc", "").is_empty());
+    }
+}
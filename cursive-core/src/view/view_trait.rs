@@ -57,6 +57,19 @@ pub trait View: Any + AnyView {
         Vec2::new(1, 1)
     }
 
+    /// Returns the size the view would like with the given restrictions.
+    ///
+    /// This can be called at any time, for example to pick the size of a
+    /// popup before adding it. It is not used by the layout phase itself.
+    ///
+    /// Views that can compute their size without updating their layout
+    /// cache should override this to do so.
+    ///
+    /// Default implementation calls `required_size`.
+    fn measure(&mut self, constraint: Vec2) -> Vec2 {
+        self.required_size(constraint)
+    }
+
     /// Called when an event is received (key press, mouse event, ...).
     ///
     /// You can return an `EventResult`:
@@ -87,6 +100,21 @@ pub trait View: Any + AnyView {
         // TODO: FnMut -> FnOnce once it works
     }
 
+    /// Detaches the child holding the view identified by the given selector.
+    ///
+    /// The child is removed from the closest view group holding the
+    /// matching view. It is returned as it was added to that group, so it
+    /// may still be wrapped in other views, like a `ResizedView`.
+    ///
+    /// View groups should implement this to first forward the call to each
+    /// child, and then remove the child where
+    /// [`view::contains`](crate::view::contains) finds a match.
+    ///
+    /// Default implementation simply returns `None`.
+    fn remove_view(&mut self, _: &Selector<'_>) -> Option<Box<dyn View>> {
+        None
+    }
+
     /// Moves the focus to the view identified by the given selector.
     ///
     /// Returns `Ok(())` if the view was found and selected.
@@ -107,6 +135,15 @@ pub trait View: Any + AnyView {
         false
     }
 
+    /// Should this view take the focus when the mouse moves over it?
+    ///
+    /// Only used when focus-follows-mouse is enabled.
+    ///
+    /// Default implementation returns `true`.
+    fn focus_on_hover(&self) -> bool {
+        true
+    }
+
     /// What part of the view is important and should be visible?
     ///
     /// When only part of this view can be visible, this helps
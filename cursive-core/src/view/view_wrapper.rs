@@ -57,6 +57,12 @@ pub trait ViewWrapper: 'static {
             .unwrap_or_else(Vec2::zero)
     }
 
+    /// Wraps the `measure` method.
+    fn wrap_measure(&mut self, req: Vec2) -> Vec2 {
+        self.with_view_mut(|v| v.measure(req))
+            .unwrap_or_else(Vec2::zero)
+    }
+
     /// Wraps the `on_event` method.
     fn wrap_on_event(&mut self, ch: Event) -> EventResult {
         self.with_view_mut(|v| v.on_event(ch))
@@ -89,11 +95,24 @@ pub trait ViewWrapper: 'static {
             .unwrap_or(Err(()))
     }
 
+    /// Wraps the `remove_view` method.
+    fn wrap_remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        self.with_view_mut(|v| v.remove_view(selector)).flatten()
+    }
+
     /// Wraps the `needs_relayout` method.
     fn wrap_needs_relayout(&self) -> bool {
         self.with_view(View::needs_relayout).unwrap_or(true)
     }
 
+    /// Wraps the `focus_on_hover` method.
+    fn wrap_focus_on_hover(&self) -> bool {
+        self.with_view(View::focus_on_hover).unwrap_or(true)
+    }
+
     /// Wraps the `important_area` method.
     fn wrap_important_area(&self, size: Vec2) -> Rect {
         self.with_view(|v| v.important_area(size))
@@ -111,6 +130,10 @@ impl<T: ViewWrapper> View for T {
         self.wrap_required_size(req)
     }
 
+    fn measure(&mut self, req: Vec2) -> Vec2 {
+        self.wrap_measure(req)
+    }
+
     fn on_event(&mut self, ch: Event) -> EventResult {
         self.wrap_on_event(ch)
     }
@@ -139,9 +162,20 @@ impl<T: ViewWrapper> View for T {
         self.wrap_focus_view(selector)
     }
 
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        self.wrap_remove_view(selector)
+    }
+
     fn important_area(&self, size: Vec2) -> Rect {
         self.wrap_important_area(size)
     }
+
+    fn focus_on_hover(&self) -> bool {
+        self.wrap_focus_on_hover()
+    }
 }
 
 /// Convenient macro to implement the [`ViewWrapper`] trait.
@@ -0,0 +1,196 @@
+use crate::animation::{Animation, Easing, Transition};
+use crate::direction::Absolute;
+use crate::theme::{PaletteColor, Theme};
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+use std::time::{Duration, Instant};
+
+/// Default duration of a transition.
+const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+/// Wrapper bringing a view in with a transition.
+///
+/// The transition starts when the view is first laid out, usually right
+/// after it is added to the screen. Frames are requested until it ends, so
+/// no FPS needs to be set.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::direction::Absolute;
+/// use cursive_core::views::{Animated, Dialog};
+///
+/// let mut siv = cursive_core::Cursive::dummy();
+/// siv.add_layer(Animated::slide_in(
+///     Dialog::info("Saved."),
+///     Absolute::Down,
+/// ));
+/// ```
+pub struct Animated<V> {
+    view: V,
+    transition: Transition,
+    duration: Duration,
+
+    // Goes from 0 to 1.
+    progress: Animation<f32>,
+    started: bool,
+}
+
+impl<V> Animated<V> {
+    /// Wraps `view`, bringing it in with the given transition.
+    pub fn new(view: V, transition: Transition) -> Self {
+        Animated {
+            view,
+            transition,
+            duration: DEFAULT_DURATION,
+            progress: Animation::new(0.0),
+            started: false,
+        }
+    }
+
+    /// Wraps `view`, sliding it in from the given side.
+    pub fn slide_in(view: V, from: Absolute) -> Self {
+        Self::new(view, Transition::Slide(from))
+    }
+
+    /// Wraps `view`, fading its colors in from the background.
+    pub fn fade_in(view: V) -> Self {
+        Self::new(view, Transition::Fade)
+    }
+
+    /// Sets the duration of the transition.
+    ///
+    /// Defaults to 200ms.
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+
+    /// Sets the duration of the transition.
+    ///
+    /// Chainable variant.
+    pub fn duration(self, duration: Duration) -> Self {
+        self.with(|s| s.set_duration(duration))
+    }
+
+    /// Returns the duration of the transition.
+    pub fn get_duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Sets the curve followed by the transition.
+    pub fn set_easing(&mut self, easing: Easing) {
+        self.progress.set_easing(easing);
+    }
+
+    /// Sets the curve followed by the transition.
+    ///
+    /// Chainable variant.
+    pub fn easing(self, easing: Easing) -> Self {
+        self.with(|s| s.set_easing(easing))
+    }
+
+    /// Returns the transition used.
+    pub fn get_transition(&self) -> Transition {
+        self.transition
+    }
+
+    /// Plays the transition again, from the start.
+    pub fn restart(&mut self) {
+        self.progress.set(0.0);
+        self.started = false;
+    }
+
+    /// Returns `true` until the transition is over.
+    pub fn is_running(&self) -> bool {
+        !self.started || self.progress.is_running()
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for Animated<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        if !self.started {
+            self.started = true;
+            self.progress.animate_to(1.0, self.duration);
+        }
+        self.view.layout(size);
+    }
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        let now = Instant::now();
+        let progress = self.progress.value_at(now);
+        if self.progress.is_running_at(now) {
+            printer.request_frame();
+        }
+
+        match self.transition {
+            Transition::Slide(from) => {
+                // Remaining distance on the axis of the movement.
+                let remaining = |size: usize| {
+                    ((1.0 - progress) * size as f32).round() as usize
+                };
+                let size = printer.size;
+                let printer = match from {
+                    Absolute::Left => {
+                        printer.content_offset((remaining(size.x), 0))
+                    }
+                    Absolute::Up => {
+                        printer.content_offset((0, remaining(size.y)))
+                    }
+                    Absolute::Right => {
+                        printer.offset((remaining(size.x), 0)).inner_size(size)
+                    }
+                    Absolute::Down => {
+                        printer.offset((0, remaining(size.y))).inner_size(size)
+                    }
+                    Absolute::None => printer.clone(),
+                };
+                self.view.draw(&printer);
+            }
+            Transition::Fade if progress < 1.0 => {
+                let palette = &printer.theme.palette;
+                let background = palette[PaletteColor::Background];
+                let mut faded = palette.clone();
+                faded.extend(
+                    palette.basic_colors().map(|(key, _)| (key, background)),
+                );
+                let theme = Theme {
+                    palette: faded.interpolate(palette, progress),
+                    ..printer.theme.clone()
+                };
+                self.view.draw(&printer.theme(&theme));
+            }
+            Transition::Fade => self.view.draw(printer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::views::TextView;
+
+    #[test]
+    fn slide_in() {
+        let mut view =
+            Animated::slide_in(TextView::new("abcd"), Absolute::Left)
+                .easing(Easing::Linear)
+                .duration(Duration::from_secs(3600));
+        assert_eq!(render_to_buffer(&mut view, (4, 1)).lines(), ["    "]);
+        assert!(view.is_running());
+
+        // Halfway, the left part is still out of the area.
+        view.progress.set(0.5);
+        assert_eq!(render_to_buffer(&mut view, (4, 1)).lines(), ["cd  "]);
+
+        view.progress.set(1.0);
+        assert_eq!(render_to_buffer(&mut view, (4, 1)).lines(), ["abcd"]);
+        assert!(!view.is_running());
+    }
+}
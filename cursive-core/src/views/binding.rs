@@ -0,0 +1,84 @@
+use crate::event::Callback;
+use crate::printer::AnyBinding;
+use crate::Cursive;
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Getter<V> = dyn Fn(&dyn Any) -> Option<V>;
+type Setter<V> = dyn Fn(&mut Cursive, V);
+
+/// Two-way binding between the value of a view and the user data.
+///
+/// Cursive reads the value every frame, and the view applies it on its
+/// next layout. Changes made with the view are written back with a
+/// callback.
+pub(crate) struct Binding<V> {
+    get: Box<Getter<V>>,
+    set: Rc<Setter<V>>,
+
+    // Last value read from the user data.
+    last: RefCell<Option<V>>,
+    // Value read from the user data, not yet applied to the view.
+    pending: RefCell<Option<V>>,
+}
+
+impl<V: 'static + Clone + PartialEq> Binding<V> {
+    /// Creates a binding from functions reading and writing the value.
+    ///
+    /// `get` returns `None` if the user data doesn't have the value.
+    pub fn new<G, S>(get: G, set: S) -> Rc<Self>
+    where
+        G: 'static + Fn(&dyn Any) -> Option<V>,
+        S: 'static + Fn(&mut Cursive, V),
+    {
+        Rc::new(Binding {
+            get: Box::new(get),
+            set: Rc::new(set),
+            last: RefCell::new(None),
+            pending: RefCell::new(None),
+        })
+    }
+
+    /// Creates a binding to a part of the user data, of type `T`.
+    pub fn lens<T, G, S>(get: G, set: S) -> Rc<Self>
+    where
+        T: Any,
+        G: 'static + Fn(&T) -> V,
+        S: 'static + Fn(&mut T, V),
+    {
+        Self::new(
+            move |data| data.downcast_ref::<T>().map(&get),
+            move |s, value| {
+                s.with_user_data(|data: &mut T| set(data, value));
+            },
+        )
+    }
+
+    /// Returns the value to apply to the view, if any.
+    pub fn take_pending(&self) -> Option<V> {
+        self.pending.borrow_mut().take()
+    }
+
+    /// Returns a callback writing `value` to the user data.
+    pub fn write(&self, value: V) -> Callback {
+        let set = Rc::clone(&self.set);
+        Callback::from_fn(move |s| set(s, value.clone()))
+    }
+}
+
+impl<V: 'static + Clone + PartialEq> AnyBinding for Binding<V> {
+    fn read(&self, user_data: &dyn Any) -> bool {
+        let value = match (self.get)(user_data) {
+            Some(value) => value,
+            None => return false,
+        };
+
+        let mut last = self.last.borrow_mut();
+        let changed = last.as_ref() != Some(&value);
+        *last = Some(value.clone());
+        // Views skip values they already have.
+        *self.pending.borrow_mut() = Some(value);
+        changed
+    }
+}
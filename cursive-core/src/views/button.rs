@@ -154,6 +154,10 @@ impl View for Button {
         self.invalidated = false;
     }
 
+    fn measure(&mut self, _: Vec2) -> Vec2 {
+        self.req_size()
+    }
+
     fn required_size(&mut self, _: Vec2) -> Vec2 {
         // Meh. Fixed size we are.
         self.req_size()
@@ -1,11 +1,14 @@
 use crate::direction::Direction;
 use crate::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use crate::printer::AnyBinding;
 use crate::theme::ColorStyle;
 use crate::view::View;
+use crate::views::Binding;
 use crate::Cursive;
 use crate::Printer;
 use crate::Vec2;
 use crate::With;
+use std::any::Any;
 use std::rc::Rc;
 
 /// Checkable box.
@@ -23,6 +26,7 @@ pub struct Checkbox {
     enabled: bool,
 
     on_change: Option<Rc<dyn Fn(&mut Cursive, bool)>>,
+    binding: Option<Rc<Binding<bool>>>,
 }
 
 new_default!(Checkbox);
@@ -36,6 +40,41 @@ impl Checkbox {
             checked: false,
             enabled: true,
             on_change: None,
+            binding: None,
+        }
+    }
+
+    /// Creates a new checkbox bound to a value in the user data.
+    ///
+    /// `get` reads the value from the user data, of type `T`. The checkbox
+    /// follows it every frame, and changes made with the checkbox are
+    /// written back with `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Checkbox;
+    ///
+    /// struct Settings {
+    ///     dark_mode: bool,
+    /// }
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_user_data(Settings { dark_mode: false });
+    /// siv.add_layer(Checkbox::bound(
+    ///     |settings: &Settings| settings.dark_mode,
+    ///     |settings, checked| settings.dark_mode = checked,
+    /// ));
+    /// ```
+    pub fn bound<T, G, S>(get: G, set: S) -> Self
+    where
+        T: Any,
+        G: 'static + Fn(&T) -> bool,
+        S: 'static + Fn(&mut T, bool),
+    {
+        Checkbox {
+            binding: Some(Binding::lens(get, set)),
+            ..Checkbox::new()
         }
     }
 
@@ -111,11 +150,17 @@ impl Checkbox {
     /// Sets the checkbox state.
     pub fn set_checked(&mut self, checked: bool) -> EventResult {
         self.checked = checked;
-        if let Some(ref on_change) = self.on_change {
+        let result = if let Some(ref on_change) = self.on_change {
             let on_change = Rc::clone(on_change);
             EventResult::with_cb(move |s| on_change(s, checked))
         } else {
             EventResult::Consumed(None)
+        };
+        match self.binding {
+            Some(ref binding) => {
+                EventResult::Consumed(Some(binding.write(checked))).and(result)
+            }
+            None => result,
         }
     }
 
@@ -141,11 +186,27 @@ impl View for Checkbox {
         Vec2::new(3, 1)
     }
 
+    fn measure(&mut self, _: Vec2) -> Vec2 {
+        Vec2::new(3, 1)
+    }
+
     fn take_focus(&mut self, _: Direction) -> bool {
         self.enabled
     }
 
+    fn layout(&mut self, _: Vec2) {
+        if let Some(checked) =
+            self.binding.as_ref().and_then(|b| b.take_pending())
+        {
+            self.checked = checked;
+        }
+    }
+
     fn draw(&self, printer: &Printer<'_, '_>) {
+        if let Some(ref binding) = self.binding {
+            printer
+                .record_binding(|| Rc::clone(binding) as Rc<dyn AnyBinding>);
+        }
         if self.enabled && printer.enabled {
             printer.with_selection(printer.focused, |printer| {
                 self.draw_internal(printer)
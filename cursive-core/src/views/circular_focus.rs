@@ -1,6 +1,7 @@
 use crate::direction::Direction;
 use crate::event::{Event, EventResult, Key};
 use crate::view::{View, ViewWrapper};
+use crate::Vec2;
 
 /// Adds circular focus to a wrapped view.
 ///
@@ -12,6 +13,8 @@ pub struct CircularFocus<T: View> {
     view: T,
     wrap_tab: bool,
     wrap_arrows: bool,
+    // Size from the last layout, to find the focused view.
+    size: Vec2,
 }
 
 impl<T: View> CircularFocus<T> {
@@ -24,6 +27,7 @@ impl<T: View> CircularFocus<T> {
             view,
             wrap_tab,
             wrap_arrows,
+            size: Vec2::zero(),
         }
     }
 
@@ -57,70 +61,124 @@ impl<T: View> CircularFocus<T> {
     inner_getters!(self.view: T);
 }
 
+impl<T: View> CircularFocus<T> {
+    // Focus comes back!
+    //
+    // `back` is the opposite of `source`: it leads to the view we're leaving.
+    fn wrap_focus(
+        &mut self,
+        source: Direction,
+        back: Direction,
+    ) -> EventResult {
+        let before = self.view.important_area(self.size);
+        if !self.view.take_focus(source) {
+            return EventResult::Ignored;
+        }
+
+        // With a single focusable view, the focus doesn't actually move.
+        if self.view.important_area(self.size) == before {
+            return EventResult::Consumed(None);
+        }
+
+        // The old view must be focused to get `FocusLost`.
+        self.view.take_focus(back);
+        let lost = self.view.on_event(Event::FocusLost);
+        self.view.take_focus(source);
+        let gained = self.view.on_event(Event::FocusGained);
+        lost.and(gained).and(EventResult::Consumed(None))
+    }
+}
+
 impl<T: View> ViewWrapper for CircularFocus<T> {
     wrap_impl!(self.view: T);
 
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
         match (self.view.on_event(event.clone()), event) {
             (EventResult::Ignored, Event::Key(Key::Tab)) if self.wrap_tab => {
-                // Focus comes back!
-                if self.view.take_focus(Direction::front()) {
-                    EventResult::Consumed(None)
-                } else {
-                    EventResult::Ignored
-                }
+                self.wrap_focus(Direction::front(), Direction::back())
             }
             (EventResult::Ignored, Event::Shift(Key::Tab))
                 if self.wrap_tab =>
             {
-                // Focus comes back!
-                if self.view.take_focus(Direction::back()) {
-                    EventResult::Consumed(None)
-                } else {
-                    EventResult::Ignored
-                }
+                self.wrap_focus(Direction::back(), Direction::front())
             }
             (EventResult::Ignored, Event::Key(Key::Right))
                 if self.wrap_arrows =>
             {
-                // Focus comes back!
-                if self.view.take_focus(Direction::left()) {
-                    EventResult::Consumed(None)
-                } else {
-                    EventResult::Ignored
-                }
+                self.wrap_focus(Direction::left(), Direction::right())
             }
             (EventResult::Ignored, Event::Key(Key::Left))
                 if self.wrap_arrows =>
             {
-                // Focus comes back!
-                if self.view.take_focus(Direction::right()) {
-                    EventResult::Consumed(None)
-                } else {
-                    EventResult::Ignored
-                }
+                self.wrap_focus(Direction::right(), Direction::left())
             }
             (EventResult::Ignored, Event::Key(Key::Up))
                 if self.wrap_arrows =>
             {
-                // Focus comes back!
-                if self.view.take_focus(Direction::down()) {
-                    EventResult::Consumed(None)
-                } else {
-                    EventResult::Ignored
-                }
+                self.wrap_focus(Direction::down(), Direction::up())
             }
             (EventResult::Ignored, Event::Key(Key::Down))
                 if self.wrap_arrows =>
             {
-                // Focus comes back!
-                if self.view.take_focus(Direction::up()) {
-                    EventResult::Consumed(None)
-                } else {
-                    EventResult::Ignored
-                }
+                self.wrap_focus(Direction::up(), Direction::down())
             }
             (other, _) => other,
         }
     }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.size = size;
+        self.view.layout(size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Button, FocusTracker, LinearLayout};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    fn tracked(log: &Log, label: &'static str) -> FocusTracker<Button> {
+        let (gained, lost) = (Rc::clone(log), Rc::clone(log));
+        FocusTracker::new(Button::new(label, |_| ()))
+            .on_focus(move |_| {
+                gained.borrow_mut().push(format!("gained {}", label));
+                EventResult::Ignored
+            })
+            .on_focus_lost(move |_| {
+                lost.borrow_mut().push(format!("lost {}", label));
+                EventResult::Ignored
+            })
+    }
+
+    #[test]
+    fn wrap_events() {
+        let log = Log::default();
+        let mut view = CircularFocus::wrap_tab(
+            LinearLayout::vertical()
+                .child(tracked(&log, "a"))
+                .child(tracked(&log, "b")),
+        );
+        view.layout(Vec2::new(10, 2));
+        view.take_focus(Direction::none());
+
+        assert!(view.on_event(Event::Key(Key::Tab)).is_consumed());
+        log.borrow_mut().clear();
+        assert!(view.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert_eq!(*log.borrow(), ["lost b", "gained a"]);
+    }
+
+    #[test]
+    fn wrap_to_same_view() {
+        let log = Log::default();
+        let mut view = CircularFocus::wrap_tab(tracked(&log, "a"));
+        view.layout(Vec2::new(10, 1));
+        view.take_focus(Direction::none());
+
+        assert!(view.on_event(Event::Key(Key::Tab)).is_consumed());
+        assert!(log.borrow().is_empty());
+    }
 }
@@ -0,0 +1,390 @@
+use crate::direction::Direction;
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::rect::Rect;
+use crate::view::View;
+use crate::views::edit_view::OnSubmit;
+use crate::views::{EditView, ScrollView, SelectView};
+use crate::{Cursive, Printer, Vec2, With};
+use std::cmp::{max, min};
+use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
+
+/// Text input with a list of suggestions.
+///
+/// The list opens below the input with `Down`, or with a click on the
+/// arrow. Typing filters the list, `Up` and `Down` move in it, and `Enter`
+/// accepts either the selected item or, without any, the typed text.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{ComboBox, Dialog};
+///
+/// let combo = ComboBox::new()
+///     .item("Red")
+///     .item("Green")
+///     .item("Blue")
+///     .on_submit(|s, color| {
+///         s.add_layer(Dialog::info(format!("You chose {}", color)));
+///     });
+/// ```
+pub struct ComboBox {
+    edit: EditView,
+    list: ScrollView<SelectView<String>>,
+
+    // `true` if the list is shown below the input.
+    open: bool,
+
+    // Maximum number of rows shown in the list.
+    max_rows: usize,
+
+    on_submit: Option<Rc<OnSubmit>>,
+
+    last_size: Vec2,
+}
+
+new_default!(ComboBox);
+
+impl ComboBox {
+    /// Creates a new, empty combo box.
+    pub fn new() -> Self {
+        ComboBox {
+            edit: EditView::new(),
+            list: ScrollView::new(SelectView::new()),
+            open: false,
+            max_rows: 5,
+            on_submit: None,
+            last_size: Vec2::zero(),
+        }
+    }
+
+    /// Adds an item to the list.
+    pub fn add_item<S: Into<String>>(&mut self, item: S) {
+        self.select_mut().add_item_str(item);
+    }
+
+    /// Adds an item to the list.
+    ///
+    /// Chainable variant.
+    pub fn item<S: Into<String>>(self, item: S) -> Self {
+        self.with(|s| s.add_item(item))
+    }
+
+    /// Adds all items from an iterator.
+    pub fn add_all<S, I>(&mut self, iter: I)
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.select_mut().add_all_str(iter);
+    }
+
+    /// Adds all items from an iterator.
+    ///
+    /// Chainable variant.
+    pub fn with_all<S, I>(self, iter: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.with(|s| s.add_all(iter))
+    }
+
+    /// Inserts an item at position `index`.
+    pub fn insert_item<S: Into<String>>(&mut self, index: usize, item: S) {
+        self.select_mut().insert_item_str(index, item);
+    }
+
+    /// Removes the item at position `index`.
+    pub fn remove_item(&mut self, index: usize) {
+        self.select_mut().remove_item(index);
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.select_mut().clear();
+        self.open = false;
+    }
+
+    /// Returns the items in the list.
+    pub fn items(&self) -> impl Iterator<Item = &str> {
+        self.select().iter().map(|(label, _)| label)
+    }
+
+    /// Returns the number of items in the list.
+    pub fn len(&self) -> usize {
+        self.select().len()
+    }
+
+    /// Returns `true` if the list has no item.
+    pub fn is_empty(&self) -> bool {
+        self.select().is_empty()
+    }
+
+    /// Replaces the typed text.
+    ///
+    /// The list is filtered accordingly, but is not opened.
+    pub fn set_content<S: Into<String>>(&mut self, content: S) -> Callback {
+        let cb = self.edit.set_content(content);
+        self.update_filter();
+        cb
+    }
+
+    /// Replaces the typed text.
+    ///
+    /// Chainable variant.
+    pub fn content<S: Into<String>>(self, content: S) -> Self {
+        self.with(|s| {
+            s.set_content(content);
+        })
+    }
+
+    /// Returns the typed text.
+    pub fn get_content(&self) -> Rc<String> {
+        self.edit.get_content()
+    }
+
+    /// Sets the maximum number of rows shown in the list.
+    ///
+    /// The list scrolls if more items match. Defaults to 5.
+    pub fn set_max_rows(&mut self, max_rows: usize) {
+        self.max_rows = max_rows;
+    }
+
+    /// Sets the maximum number of rows shown in the list.
+    ///
+    /// Chainable variant.
+    pub fn max_rows(self, max_rows: usize) -> Self {
+        self.with(|s| s.set_max_rows(max_rows))
+    }
+
+    /// Sets a callback to run when a value is accepted with `Enter`.
+    ///
+    /// It is given the selected item, or the typed text.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_submit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when a value is accepted with `Enter`.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    /// Returns `true` if the list is shown.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Shows or hides the list.
+    ///
+    /// The list stays hidden if no item matches the typed text.
+    pub fn set_open(&mut self, open: bool) {
+        self.open = open && !self.select().visible_ids().is_empty();
+    }
+
+    fn select(&self) -> &SelectView<String> {
+        self.list.get_inner()
+    }
+
+    fn select_mut(&mut self) -> &mut SelectView<String> {
+        self.list.get_inner_mut()
+    }
+
+    // Filters the list with the typed text.
+    fn update_filter(&mut self) {
+        let content = self.edit.get_content();
+        self.select_mut().set_filter(&content[..]);
+        if self.select().visible_ids().is_empty() {
+            self.open = false;
+        }
+    }
+
+    // Accepts the selected item if the list is open, or the typed text.
+    fn submit(&mut self) -> EventResult {
+        let item = self
+            .select()
+            .selection()
+            .filter(|_| self.open)
+            .map(|item| String::clone(&item));
+        if let Some(item) = item {
+            self.set_content(item);
+        }
+        self.open = false;
+
+        let content = self.edit.get_content();
+        EventResult::Consumed(
+            self.on_submit
+                .clone()
+                .map(|cb| Callback::from_fn(move |s| cb(s, &content))),
+        )
+    }
+
+    // Width of the text input, without the arrow.
+    fn edit_width(&self) -> usize {
+        self.last_size.x.saturating_sub(1)
+    }
+
+    fn list_rows(&self) -> usize {
+        min(self.select().visible_ids().len(), self.max_rows)
+    }
+
+    fn on_mouse_event(&mut self, event: Event) -> EventResult {
+        let (position, offset) = match event {
+            Event::Mouse {
+                position, offset, ..
+            } => (position, offset),
+            _ => return EventResult::Ignored,
+        };
+        let position = match position.checked_sub(offset) {
+            Some(position) if position < self.last_size => position,
+            _ => return EventResult::Ignored,
+        };
+
+        if position.y > 0 {
+            if !self.open {
+                return EventResult::Ignored;
+            }
+            let result = self.list.on_event(event.relativized((0, 1)));
+            if let Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } = event
+            {
+                return self.submit();
+            }
+            return result;
+        }
+
+        match event {
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                ..
+            } if position.x >= self.edit_width() => {
+                let open = !self.open;
+                self.set_open(open);
+                EventResult::Consumed(None)
+            }
+            event => self.edit.on_event(event),
+        }
+    }
+}
+
+impl View for ComboBox {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        let width = self.edit_width();
+        self.edit.draw(&printer.cropped((width, 1)));
+        printer.print((width, 0), if self.open { "▲" } else { "▼" });
+
+        if self.open {
+            self.list.draw(&printer.offset((0, 1)));
+        }
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        let list = self
+            .list
+            .required_size(Vec2::new(req.x, self.max_rows.max(1)));
+        let content = self.edit.get_content().width() + 1;
+
+        let height = if self.open { 1 + self.list_rows() } else { 1 };
+        Vec2::new(max(list.x, content) + 1, height)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        self.edit.layout(Vec2::new(self.edit_width(), 1));
+        if self.open {
+            self.list
+                .layout(Vec2::new(size.x, size.y.saturating_sub(1)));
+        }
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.edit.needs_relayout() || self.list.needs_relayout()
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        self.edit.take_focus(source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Down) if !self.open => {
+                self.set_open(true);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Up)
+            | Event::Key(Key::Down)
+            | Event::Key(Key::PageUp)
+            | Event::Key(Key::PageDown)
+                if self.open =>
+            {
+                self.list.on_event(event);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Esc) if self.open => {
+                self.open = false;
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Enter) => self.submit(),
+            Event::Mouse { .. } => self.on_mouse_event(event),
+            event => {
+                let before = self.edit.get_content();
+                let result = self.edit.on_event(event);
+                if self.edit.get_content() != before {
+                    self.update_filter();
+                    self.set_open(true);
+                }
+                result
+            }
+        }
+    }
+
+    fn important_area(&self, _: Vec2) -> Rect {
+        self.edit.important_area(Vec2::new(self.edit_width(), 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_and_submit() {
+        let mut combo = ComboBox::new()
+            .with_all(vec!["apple", "apricot", "banana"])
+            .on_submit(|s, value| s.set_user_data(value.to_string()));
+
+        // Down opens the list.
+        combo.on_event(Event::Key(Key::Down));
+        assert!(combo.is_open());
+        assert_eq!(combo.required_size(Vec2::new(20, 10)).y, 4);
+
+        // Typing filters it.
+        combo.on_event(Event::Char('a'));
+        combo.on_event(Event::Char('p'));
+        assert_eq!(combo.required_size(Vec2::new(20, 10)).y, 3);
+
+        let mut siv = Cursive::dummy();
+        combo.on_event(Event::Key(Key::Down));
+        combo.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(&*combo.get_content(), "apricot");
+        assert_eq!(siv.take_user_data(), Some("apricot".to_string()));
+        assert!(!combo.is_open());
+
+        // Text matching no item is accepted as typed.
+        combo.set_content("cherry");
+        combo.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(siv.take_user_data(), Some("cherry".to_string()));
+    }
+}
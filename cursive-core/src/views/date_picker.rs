@@ -0,0 +1,436 @@
+use crate::direction::Direction;
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::rect::Rect;
+use crate::theme::ColorStyle;
+use crate::view::View;
+use crate::{Cursive, Printer, Vec2, With};
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use std::rc::Rc;
+
+type DateCallback = Rc<dyn Fn(&mut Cursive, NaiveDate)>;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Width of the calendar: seven columns of two digits, with spaces between.
+const WIDTH: usize = 20;
+
+/// Rows before the first week: the month and the days of the week.
+const HEADER: usize = 2;
+
+/// Calendar to pick a date.
+///
+/// The calendar shows a month at a time, and the selected date is moved
+/// with the arrow keys: `Left` and `Right` by one day, `Up` and `Down` by
+/// one week, and `PageUp` and `PageDown` by one month. The arrows next to
+/// the month can also be clicked. `Enter`, or clicking the selected date,
+/// submits it.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use cursive_core::views::{DatePicker, Dialog};
+///
+/// let picker = DatePicker::new()
+///     .date(NaiveDate::from_ymd_opt(2024, 3, 14).unwrap())
+///     .on_submit(|s, date| {
+///         s.pop_layer();
+///         s.add_layer(Dialog::info(format!("Meeting on {}", date)));
+///     });
+/// ```
+pub struct DatePicker {
+    date: NaiveDate,
+    min: Option<NaiveDate>,
+    max: Option<NaiveDate>,
+
+    // First day of each week.
+    week_start: Weekday,
+
+    on_select: Option<DateCallback>,
+    on_submit: Option<DateCallback>,
+}
+
+new_default!(DatePicker);
+
+impl DatePicker {
+    /// Creates a new date picker, on today's date.
+    pub fn new() -> Self {
+        DatePicker {
+            date: Local::now().naive_local().date(),
+            min: None,
+            max: None,
+            week_start: Weekday::Mon,
+            on_select: None,
+            on_submit: None,
+        }
+    }
+
+    /// Selects the given date, clamped between the minimum and the maximum.
+    ///
+    /// Returns the `on_select` callback, if any.
+    pub fn set_date(&mut self, date: NaiveDate) -> Callback {
+        self.date = self.clamp(date);
+
+        let date = self.date;
+        match self.on_select.clone() {
+            Some(cb) => Callback::from_fn(move |s| cb(s, date)),
+            None => Callback::dummy(),
+        }
+    }
+
+    /// Selects the given date.
+    ///
+    /// Chainable variant. Does not run the `on_select` callback.
+    pub fn date(self, date: NaiveDate) -> Self {
+        self.with(|s| {
+            s.set_date(date);
+        })
+    }
+
+    /// Returns the selected date.
+    pub fn get_date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Sets the earliest date that can be selected.
+    pub fn set_min(&mut self, min: NaiveDate) {
+        self.min = Some(min);
+        self.date = self.clamp(self.date);
+    }
+
+    /// Sets the earliest date that can be selected.
+    ///
+    /// Chainable variant.
+    pub fn min(self, min: NaiveDate) -> Self {
+        self.with(|s| s.set_min(min))
+    }
+
+    /// Sets the latest date that can be selected.
+    pub fn set_max(&mut self, max: NaiveDate) {
+        self.max = Some(max);
+        self.date = self.clamp(self.date);
+    }
+
+    /// Sets the latest date that can be selected.
+    ///
+    /// Chainable variant.
+    pub fn max(self, max: NaiveDate) -> Self {
+        self.with(|s| s.set_max(max))
+    }
+
+    /// Sets the first day of the week, shown in the first column.
+    ///
+    /// Defaults to Monday.
+    pub fn set_week_start(&mut self, day: Weekday) {
+        self.week_start = day;
+    }
+
+    /// Sets the first day of the week, shown in the first column.
+    ///
+    /// Chainable variant.
+    pub fn week_start(self, day: Weekday) -> Self {
+        self.with(|s| s.set_week_start(day))
+    }
+
+    /// Sets a callback to run when the selected date changes.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, NaiveDate) + 'static,
+    {
+        self.on_select = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when the selected date changes.
+    ///
+    /// Chainable variant.
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, NaiveDate) + 'static,
+    {
+        self.with(|s| s.set_on_select(cb))
+    }
+
+    /// Sets a callback to run when a date is submitted.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, NaiveDate) + 'static,
+    {
+        self.on_submit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when a date is submitted.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, NaiveDate) + 'static,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    fn clamp(&self, date: NaiveDate) -> NaiveDate {
+        match (self.min, self.max) {
+            (Some(min), _) if date < min => min,
+            (_, Some(max)) if date > max => max,
+            _ => date,
+        }
+    }
+
+    fn is_enabled(&self, date: NaiveDate) -> bool {
+        self.clamp(date) == date
+    }
+
+    // First day of the displayed month.
+    fn first_day(&self) -> NaiveDate {
+        self.date.with_day(1).unwrap()
+    }
+
+    // Column of the first day of the month.
+    fn first_column(&self) -> usize {
+        let first = self.first_day().weekday().num_days_from_monday();
+        let start = self.week_start.num_days_from_monday();
+        ((first + 7 - start) % 7) as usize
+    }
+
+    // Moves the selection, clamped in the allowed range.
+    fn move_to(&mut self, date: Option<NaiveDate>) -> EventResult {
+        match date.map(|date| self.clamp(date)) {
+            Some(date) if date != self.date => {
+                EventResult::Consumed(Some(self.set_date(date)))
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn add_days(&mut self, days: i64) -> EventResult {
+        let date = self.date.checked_add_signed(Duration::days(days));
+        self.move_to(date)
+    }
+
+    fn add_months(&mut self, months: i32) -> EventResult {
+        let date = add_months(self.date, months);
+        self.move_to(date)
+    }
+
+    fn submit(&self) -> EventResult {
+        let date = self.date;
+        EventResult::Consumed(
+            self.on_submit
+                .clone()
+                .map(|cb| Callback::from_fn(move |s| cb(s, date))),
+        )
+    }
+
+    // Returns the date drawn at the given position, if any.
+    fn date_at(&self, position: Vec2) -> Option<NaiveDate> {
+        if position.y < HEADER || position.x % 3 == 2 {
+            return None;
+        }
+        let cell = (position.y - HEADER) * 7 + position.x / 3;
+        let day = cell.checked_sub(self.first_column())? + 1;
+        self.date.with_day(day as u32)
+    }
+
+    fn on_mouse_event(
+        &mut self,
+        event: MouseEvent,
+        position: Vec2,
+    ) -> EventResult {
+        match event {
+            MouseEvent::WheelUp => self.add_months(-1),
+            MouseEvent::WheelDown => self.add_months(1),
+            MouseEvent::Press(MouseButton::Left) if position.y == 0 => {
+                match position.x {
+                    0..=1 => self.add_months(-1),
+                    x if x >= WIDTH - 2 => self.add_months(1),
+                    _ => EventResult::Ignored,
+                }
+            }
+            MouseEvent::Release(MouseButton::Left) => {
+                match self.date_at(position) {
+                    Some(date) if date == self.date => self.submit(),
+                    Some(date) if self.is_enabled(date) => {
+                        EventResult::Consumed(Some(self.set_date(date)))
+                    }
+                    _ => EventResult::Ignored,
+                }
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+impl View for DatePicker {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        // The month, with arrows to change it.
+        let title = format!(
+            "{} {}",
+            MONTHS[self.date.month0() as usize],
+            self.date.year()
+        );
+        printer.print((0, 0), "<");
+        printer.with_color(ColorStyle::title_primary(), |printer| {
+            printer.print(((WIDTH - title.len()) / 2, 0), &title);
+        });
+        printer.print((WIDTH - 1, 0), ">");
+
+        // The days of the week.
+        let start = self.week_start.num_days_from_monday() as usize;
+        printer.with_color(ColorStyle::secondary(), |printer| {
+            for col in 0..7 {
+                printer.print((col * 3, 1), DAYS[(start + col) % 7]);
+            }
+        });
+
+        let offset = self.first_column();
+        let mut date = self.first_day();
+        while date.month() == self.date.month() {
+            let cell = offset + date.day0() as usize;
+            let pos = ((cell % 7) * 3, HEADER + cell / 7);
+            let text = format!("{:>2}", date.day());
+
+            if date == self.date {
+                printer.with_selection(true, |printer| {
+                    printer.print(pos, &text);
+                });
+            } else if !self.is_enabled(date) {
+                printer.with_color(ColorStyle::secondary(), |printer| {
+                    printer.print(pos, &text);
+                });
+            } else {
+                printer.print(pos, &text);
+            }
+
+            date = match date.succ_opt() {
+                Some(date) => date,
+                None => break,
+            };
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        // Always leave room for six weeks, so the size doesn't change.
+        Vec2::new(WIDTH, HEADER + 6)
+    }
+
+    fn take_focus(&mut self, _: Direction) -> bool {
+        true
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Left) => self.add_days(-1),
+            Event::Key(Key::Right) => self.add_days(1),
+            Event::Key(Key::Up) => self.add_days(-7),
+            Event::Key(Key::Down) => self.add_days(7),
+            Event::Key(Key::PageUp) => self.add_months(-1),
+            Event::Key(Key::PageDown) => self.add_months(1),
+            Event::Key(Key::Home) => self.move_to(Some(self.first_day())),
+            Event::Key(Key::End) => {
+                let last = add_months(self.first_day(), 1)
+                    .and_then(|date| date.pred_opt());
+                self.move_to(last)
+            }
+            Event::Key(Key::Enter) => self.submit(),
+            Event::Mouse {
+                event,
+                position,
+                offset,
+            } => match position.checked_sub(offset) {
+                Some(position)
+                    if position
+                        .fits_in_rect(Vec2::zero(), (WIDTH, HEADER + 6)) =>
+                {
+                    self.on_mouse_event(event, position)
+                }
+                _ => EventResult::Ignored,
+            },
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn important_area(&self, _: Vec2) -> Rect {
+        let cell = self.first_column() + self.date.day0() as usize;
+        Rect::from_size(((cell % 7) * 3, HEADER + cell / 7), (2, 1))
+    }
+}
+
+// Adds some months to a date, keeping the day in the new month.
+fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let months = date.year() * 12 + date.month0() as i32 + months;
+    let (year, month) = (months.div_euclid(12), months.rem_euclid(12) as u32);
+
+    // Last day of the new month.
+    let next = if month == 11 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 2, 1)
+    };
+    let last = next?.pred_opt()?.day();
+
+    NaiveDate::from_ymd_opt(year, month + 1, date.day().min(last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn navigation() {
+        let mut picker = DatePicker::new()
+            .date(ymd(2024, 1, 31))
+            .max(ymd(2024, 3, 10));
+
+        let lines = render_to_buffer(&mut picker, (20, 8)).lines();
+        assert_eq!(lines[0], "<   January 2024   >");
+        assert_eq!(lines[1], "Mo Tu We Th Fr Sa Su");
+        assert_eq!(lines[2], " 1  2  3  4  5  6  7");
+        assert_eq!(lines[6], "29 30 31            ");
+
+        // Days are kept in shorter months.
+        picker.on_event(Event::Key(Key::PageDown));
+        assert_eq!(picker.get_date(), ymd(2024, 2, 29));
+        picker.on_event(Event::Key(Key::Down));
+        assert_eq!(picker.get_date(), ymd(2024, 3, 7));
+
+        // But not past the maximum.
+        picker.on_event(Event::Key(Key::Down));
+        assert_eq!(picker.get_date(), ymd(2024, 3, 10));
+        assert!(!picker.on_event(Event::Key(Key::Right)).is_consumed());
+
+        // Clicking a day selects it.
+        picker.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(12, 2),
+            event: MouseEvent::Release(MouseButton::Left),
+        });
+        assert_eq!(picker.get_date(), ymd(2024, 3, 1));
+
+        let mut siv = Cursive::dummy();
+        picker = picker.on_submit(|s, date| s.set_user_data(date));
+        picker.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(siv.take_user_data(), Some(ymd(2024, 3, 1)));
+    }
+}
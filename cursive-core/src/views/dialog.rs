@@ -1,16 +1,25 @@
 use crate::align::*;
 use crate::direction::{Absolute, Direction, Relative};
-use crate::event::{AnyCb, Event, EventResult, Key};
+use crate::event::{AnyCb, Event, EventResult, Key, MouseEvent};
 use crate::rect::Rect;
-use crate::theme::ColorStyle;
+use crate::theme::{BorderStyle, Color, ColorStyle, Palette, PaletteColor};
 use crate::view::{Margins, Selector, View};
-use crate::views::{BoxedView, Button, DummyView, LastSizeView, TextView};
+use crate::views::{
+    BoxedView, Button, DummyView, EditView, LastSizeView, LinearLayout,
+    TextView,
+};
 use crate::Cursive;
 use crate::Printer;
 use crate::Vec2;
 use crate::{utils::markup::StyledString, With};
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::cmp::max;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
 use unicode_width::UnicodeWidthStr;
 
 /// Identifies currently focused element in [`Dialog`].
@@ -22,6 +31,273 @@ pub enum DialogFocus {
     Button(usize),
 }
 
+/// Outcome of a dialog with a standard [`ButtonSet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DialogResult {
+    /// The `Ok` button was pressed.
+    Ok,
+    /// The `Cancel` button was pressed, or `Esc`.
+    Cancel,
+    /// The `Yes` button was pressed.
+    Yes,
+    /// The `No` button was pressed.
+    No,
+    /// The `Retry` button was pressed.
+    Retry,
+    /// The `Abort` button was pressed.
+    Abort,
+}
+
+impl fmt::Display for DialogResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DialogResult::Ok => "Ok",
+            DialogResult::Cancel => "Cancel",
+            DialogResult::Yes => "Yes",
+            DialogResult::No => "No",
+            DialogResult::Retry => "Retry",
+            DialogResult::Abort => "Abort",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Standard set of buttons for a [`Dialog`].
+///
+/// See [`Dialog::with_buttons`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ButtonSet {
+    /// A single `Ok` button.
+    Ok,
+    /// `Ok` and `Cancel` buttons.
+    OkCancel,
+    /// `Yes` and `No` buttons.
+    YesNo,
+    /// `Yes`, `No` and `Cancel` buttons.
+    YesNoCancel,
+    /// `Retry` and `Abort` buttons.
+    RetryAbort,
+}
+
+impl ButtonSet {
+    /// Returns the results of the buttons in this set, in order.
+    pub fn results(self) -> &'static [DialogResult] {
+        use DialogResult::*;
+        match self {
+            ButtonSet::Ok => &[Ok],
+            ButtonSet::OkCancel => &[Ok, Cancel],
+            ButtonSet::YesNo => &[Yes, No],
+            ButtonSet::YesNoCancel => &[Yes, No, Cancel],
+            ButtonSet::RetryAbort => &[Retry, Abort],
+        }
+    }
+
+    /// Returns the result given by the `Esc` key.
+    pub fn cancel(self) -> DialogResult {
+        match self {
+            ButtonSet::Ok => DialogResult::Ok,
+            ButtonSet::OkCancel | ButtonSet::YesNoCancel => {
+                DialogResult::Cancel
+            }
+            ButtonSet::YesNo => DialogResult::No,
+            ButtonSet::RetryAbort => DialogResult::Abort,
+        }
+    }
+}
+
+/// Level of a message dialog, setting its colors and glyph.
+///
+/// See [`Dialog::alert`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlertLevel {
+    /// Neutral information.
+    Info,
+    /// An operation went well.
+    Success,
+    /// Something may need attention.
+    Warning,
+    /// Something went wrong.
+    Error,
+}
+
+impl AlertLevel {
+    /// Returns the glyph shown before the message.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            AlertLevel::Info => "ℹ",
+            AlertLevel::Success => "✔",
+            AlertLevel::Warning => "⚠",
+            AlertLevel::Error => "✖",
+        }
+    }
+
+    /// Returns the default title of dialogs with this level.
+    pub fn title(self) -> &'static str {
+        match self {
+            AlertLevel::Info => "Info",
+            AlertLevel::Success => "Success",
+            AlertLevel::Warning => "Warning",
+            AlertLevel::Error => "Error",
+        }
+    }
+
+    /// Returns the name of the palette color used for this level.
+    ///
+    /// Themes can define `info`, `success`, `warning` and `error` colors to
+    /// replace the defaults.
+    pub fn palette_key(self) -> &'static str {
+        match self {
+            AlertLevel::Info => "info",
+            AlertLevel::Success => "success",
+            AlertLevel::Warning => "warning",
+            AlertLevel::Error => "error",
+        }
+    }
+
+    /// Returns the color for this level in the given palette.
+    ///
+    /// Uses the palette color named by [`palette_key`], or blue, green,
+    /// yellow or red if the palette doesn't define it.
+    ///
+    /// [`palette_key`]: #method.palette_key
+    pub fn color(self, palette: &Palette) -> Color {
+        use crate::theme::BaseColor::*;
+
+        let default = Color::Dark(match self {
+            AlertLevel::Info => Blue,
+            AlertLevel::Success => Green,
+            AlertLevel::Warning => Yellow,
+            AlertLevel::Error => Red,
+        });
+        palette
+            .custom(self.palette_key())
+            .copied()
+            .unwrap_or(default)
+    }
+}
+
+type ResultCallback = Rc<dyn Fn(&mut Cursive, DialogResult)>;
+type Translator = dyn Fn(DialogResult) -> Option<String>;
+
+/// Standard buttons added with `Dialog::with_buttons`.
+struct StandardButtons {
+    set: ButtonSet,
+    // Shared with the buttons, so `on_result` can be set afterwards.
+    on_result: Rc<RefCell<Option<ResultCallback>>>,
+    // Translates the labels, if set.
+    translator: Option<Rc<Translator>>,
+}
+
+impl StandardButtons {
+    fn new(set: ButtonSet) -> Self {
+        StandardButtons {
+            set,
+            on_result: Rc::default(),
+            translator: None,
+        }
+    }
+
+    /// Returns the label of the button giving `result`.
+    ///
+    /// This is the English name, unless the translator gives another one.
+    fn label(&self, result: DialogResult) -> String {
+        self.translator
+            .as_ref()
+            .and_then(|translator| translator(result))
+            .unwrap_or_else(|| result.to_string())
+    }
+
+    /// Returns a callback closing the dialog with the given result.
+    fn callback(&self, result: DialogResult) -> impl Fn(&mut Cursive) {
+        let on_result = Rc::clone(&self.on_result);
+        move |s| {
+            s.pop_layer();
+            let cb = on_result.borrow().clone();
+            if let Some(cb) = cb {
+                cb(s, result);
+            }
+        }
+    }
+
+    /// Returns the result for a key press, if any.
+    ///
+    /// `Esc` cancels, and a letter picks the button starting with it.
+    fn result_for(&self, event: &Event) -> Option<DialogResult> {
+        match *event {
+            Event::Key(Key::Esc) => Some(self.set.cancel()),
+            Event::Char(c) => {
+                let c = c.to_lowercase().next();
+                self.set.results().iter().copied().find(|&result| {
+                    self.label(result)
+                        .chars()
+                        .flat_map(char::to_lowercase)
+                        .next()
+                        == c
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Answer of a dialog opened with [`Dialog::confirm_async`] or
+/// [`Dialog::prompt_async`].
+///
+/// This future completes when the dialog is closed. It does not depend on a
+/// specific runtime, and can be awaited from another thread.
+pub struct DialogAnswer<T> {
+    state: Arc<Mutex<AnswerState<T>>>,
+}
+
+struct AnswerState<T> {
+    value: Option<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> DialogAnswer<T> {
+    fn new() -> Self {
+        DialogAnswer {
+            state: Arc::new(Mutex::new(AnswerState {
+                value: None,
+                waker: None,
+            })),
+        }
+    }
+
+    // Returns a callback giving the answer, to be called when the dialog
+    // is closed.
+    fn sender(&self) -> impl Fn(&mut Cursive, T) {
+        let state = Arc::clone(&self.state);
+        move |_, value| {
+            let mut state = state.lock().unwrap();
+            state.value = Some(value);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Returns the answer if the dialog was closed, without waiting.
+    pub fn try_take(&self) -> Option<T> {
+        self.state.lock().unwrap().value.take()
+    }
+}
+
+impl<T> Future for DialogAnswer<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+        match state.value.take() {
+            Some(value) => Poll::Ready(value),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
 struct ChildButton {
     button: LastSizeView<Button>,
     offset: Cell<Vec2>,
@@ -50,11 +326,14 @@ impl ChildButton {
 /// ```
 pub struct Dialog {
     // Possibly empty title.
-    title: String,
+    title: StyledString,
 
     // Where to put the title position
     title_position: HAlign,
 
+    // Possibly empty status, right-aligned in the title bar.
+    status: StyledString,
+
     // The actual inner view.
     content: LastSizeView<BoxedView>,
 
@@ -76,6 +355,18 @@ pub struct Dialog {
 
     // `true` when we needs to relayout
     invalidated: bool,
+
+    // Set by `with_buttons`.
+    standard_buttons: Option<StandardButtons>,
+
+    // Level setting the colors, if any.
+    alert: Option<AlertLevel>,
+    // Show the glyph of the alert level before the content.
+    alert_glyph: bool,
+    // Called when the alert is first drawn.
+    on_alert: Option<Box<dyn Fn(AlertLevel)>>,
+    // `true` once the alert hook ran.
+    alerted: Cell<bool>,
 }
 
 new_default!(Dialog);
@@ -93,13 +384,19 @@ impl Dialog {
         Dialog {
             content: LastSizeView::new(BoxedView::boxed(view)),
             buttons: Vec::new(),
-            title: String::new(),
+            title: StyledString::new(),
             title_position: HAlign::Center,
+            status: StyledString::new(),
             focus: DialogFocus::Content,
             padding: Margins::lr(1, 1),
             borders: Margins::lrtb(1, 1, 1, 1),
             align: Align::top_right(),
             invalidated: true,
+            standard_buttons: None,
+            alert: None,
+            alert_glyph: true,
+            on_alert: None,
+            alerted: Cell::new(false),
         }
     }
 
@@ -178,6 +475,226 @@ impl Dialog {
         Dialog::text(text).dismiss_button("Ok")
     }
 
+    /// Creates a message dialog with the given level.
+    ///
+    /// Like an infobox, it has a `Ok` dismiss button. It also has a title,
+    /// and the colors and glyph of the level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{AlertLevel, Dialog};
+    ///
+    /// let dialog = Dialog::alert(AlertLevel::Info, "Update available.")
+    ///     .title("Updates");
+    /// ```
+    pub fn alert<S: Into<StyledString>>(level: AlertLevel, text: S) -> Self {
+        Dialog::info(text).title(level.title()).alert_level(level)
+    }
+
+    /// Creates a warning message dialog.
+    ///
+    /// See [`Dialog::alert`].
+    pub fn warn<S: Into<StyledString>>(text: S) -> Self {
+        Dialog::alert(AlertLevel::Warning, text)
+    }
+
+    /// Creates an error message dialog.
+    ///
+    /// See [`Dialog::alert`].
+    pub fn error<S: Into<StyledString>>(text: S) -> Self {
+        Dialog::alert(AlertLevel::Error, text)
+    }
+
+    /// Creates a success message dialog.
+    ///
+    /// See [`Dialog::alert`].
+    pub fn success<S: Into<StyledString>>(text: S) -> Self {
+        Dialog::alert(AlertLevel::Success, text)
+    }
+
+    /// Asks a yes/no question in a new layer.
+    ///
+    /// The `Yes` button is focused; `Esc` answers no. The dialog is closed
+    /// before `cb` is called with the answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// Dialog::confirm(&mut siv, "Quit without saving?", |s, yes| {
+    ///     if yes {
+    ///         s.quit();
+    ///     }
+    /// });
+    /// ```
+    pub fn confirm<S, F>(siv: &mut Cursive, text: S, cb: F)
+    where
+        S: Into<StyledString>,
+        F: Fn(&mut Cursive, bool) + 'static,
+    {
+        siv.add_layer(
+            Dialog::text(text).with_buttons(ButtonSet::YesNo).on_result(
+                move |s, result| cb(s, result == DialogResult::Yes),
+            ),
+        );
+    }
+
+    /// Asks for a line of text in a new layer.
+    ///
+    /// The text field is focused; `Enter` validates it, and `Esc` cancels
+    /// the dialog. The dialog is closed before `cb` is called with the text,
+    /// or `None` if it was cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// Dialog::prompt(&mut siv, "Name of the branch:", |s, name| {
+    ///     if let Some(name) = name {
+    ///         s.add_layer(Dialog::info(format!("Created {}.", name)));
+    ///     }
+    /// });
+    /// ```
+    pub fn prompt<S, F>(siv: &mut Cursive, text: S, cb: F)
+    where
+        S: Into<StyledString>,
+        F: Fn(&mut Cursive, Option<String>) + 'static,
+    {
+        let cb = Rc::new(cb);
+        let content = Rc::new(RefCell::new(String::new()));
+
+        let edit = EditView::new()
+            .on_edit({
+                let content = Rc::clone(&content);
+                move |_, text, _| *content.borrow_mut() = text.to_string()
+            })
+            .on_submit({
+                let cb = Rc::clone(&cb);
+                move |s, text| {
+                    s.pop_layer();
+                    cb(s, Some(text.to_string()));
+                }
+            });
+
+        siv.add_layer(
+            Dialog::around(
+                LinearLayout::vertical()
+                    .child(TextView::new(text))
+                    .child(edit),
+            )
+            .with_buttons(ButtonSet::OkCancel)
+            .on_result(move |s, result| {
+                let text = match result {
+                    DialogResult::Ok => Some(content.borrow().clone()),
+                    _ => None,
+                };
+                cb(s, text)
+            }),
+        );
+    }
+
+    /// Asks a yes/no question in a new layer, and returns the answer.
+    ///
+    /// See [`confirm`](#method.confirm).
+    pub fn confirm_async<S>(siv: &mut Cursive, text: S) -> DialogAnswer<bool>
+    where
+        S: Into<StyledString>,
+    {
+        let answer = DialogAnswer::new();
+        Dialog::confirm(siv, text, answer.sender());
+        answer
+    }
+
+    /// Asks for a line of text in a new layer, and returns it.
+    ///
+    /// See [`prompt`](#method.prompt).
+    pub fn prompt_async<S>(
+        siv: &mut Cursive,
+        text: S,
+    ) -> DialogAnswer<Option<String>>
+    where
+        S: Into<StyledString>,
+    {
+        let answer = DialogAnswer::new();
+        Dialog::prompt(siv, text, answer.sender());
+        answer
+    }
+
+    /// Sets the alert level, coloring the borders and the title.
+    ///
+    /// `None` restores the regular colors.
+    pub fn set_alert_level(&mut self, level: Option<AlertLevel>) {
+        self.alert = level;
+        self.alerted.set(false);
+        self.invalidate();
+    }
+
+    /// Sets the alert level, coloring the borders and the title.
+    ///
+    /// Chainable variant.
+    pub fn alert_level(self, level: AlertLevel) -> Self {
+        self.with(|s| s.set_alert_level(Some(level)))
+    }
+
+    /// Returns the alert level, if any.
+    pub fn get_alert_level(&self) -> Option<AlertLevel> {
+        self.alert
+    }
+
+    /// Shows the glyph of the alert level before the content.
+    ///
+    /// Defaults to `true`.
+    pub fn set_alert_glyph(&mut self, show: bool) {
+        self.alert_glyph = show;
+        self.invalidate();
+    }
+
+    /// Shows the glyph of the alert level before the content.
+    ///
+    /// Chainable variant.
+    pub fn alert_glyph(self, show: bool) -> Self {
+        self.with(|s| s.set_alert_glyph(show))
+    }
+
+    /// Sets a function to call when the alert is first drawn.
+    ///
+    /// This can play a sound or ring the bell, depending on the level. By
+    /// default, nothing happens.
+    pub fn set_on_alert<F>(&mut self, hook: F)
+    where
+        F: Fn(AlertLevel) + 'static,
+    {
+        self.on_alert = Some(Box::new(hook));
+    }
+
+    /// Sets a function to call when the alert is first drawn.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{AlertLevel, Dialog};
+    ///
+    /// let dialog = Dialog::error("Disk full").on_alert(|level| {
+    ///     if level == AlertLevel::Error {
+    ///         // Ring the terminal bell.
+    ///         eprint!("\x07");
+    ///     }
+    /// });
+    /// ```
+    pub fn on_alert<F>(self, hook: F) -> Self
+    where
+        F: Fn(AlertLevel) + 'static,
+    {
+        self.with(|s| s.set_on_alert(hook))
+    }
+
     /// Adds a button to the dialog with the given label and callback.
     ///
     /// Consumes and returns self for easy chaining.
@@ -240,6 +757,125 @@ impl Dialog {
     }
     */
 
+    /// Adds a standard set of buttons, each closing the dialog.
+    ///
+    /// The buttons are labeled with the English names of the results, unless
+    /// [`button_labels`](#method.button_labels) translates them. They report
+    /// to the callback set with [`on_result`](#method.on_result). Unless the
+    /// focused view uses them, `Esc` cancels the dialog (see
+    /// [`ButtonSet::cancel`]), and typing the first letter of a label
+    /// presses its button, like `y` or `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{ButtonSet, Dialog, DialogResult};
+    ///
+    /// let dialog = Dialog::text("Save changes?")
+    ///     .with_buttons(ButtonSet::YesNoCancel)
+    ///     .on_result(|s, result| {
+    ///         if result == DialogResult::Yes {
+    ///             s.quit();
+    ///         }
+    ///     });
+    /// ```
+    pub fn with_buttons(self, set: ButtonSet) -> Self {
+        self.with(|s| s.add_buttons(set))
+    }
+
+    /// Adds a standard set of buttons, each closing the dialog.
+    ///
+    /// See [`with_buttons`](#method.with_buttons).
+    pub fn add_buttons(&mut self, set: ButtonSet) {
+        let mut buttons = self
+            .standard_buttons
+            .take()
+            .unwrap_or_else(|| StandardButtons::new(set));
+        buttons.set = set;
+        for &result in set.results() {
+            self.add_button(buttons.label(result), buttons.callback(result));
+        }
+        self.standard_buttons = Some(buttons);
+    }
+
+    /// Translates the labels of the buttons added with `with_buttons`.
+    ///
+    /// `translator` returns the label for each result, or `None` to keep
+    /// the English name. Buttons already added are relabeled.
+    pub fn set_button_labels<F>(&mut self, translator: F)
+    where
+        F: Fn(DialogResult) -> Option<String> + 'static,
+    {
+        let buttons = self
+            .standard_buttons
+            .get_or_insert_with(|| StandardButtons::new(ButtonSet::Ok));
+        let old: Vec<_> = buttons
+            .set
+            .results()
+            .iter()
+            .map(|&result| format!("<{}>", buttons.label(result)))
+            .collect();
+        buttons.translator = Some(Rc::new(translator));
+
+        let buttons = self.standard_buttons.as_ref().unwrap();
+        for child in &mut self.buttons {
+            let button = &mut child.button.view;
+            let i = old.iter().position(|label| label == button.label());
+            if let Some(i) = i {
+                button.set_label(buttons.label(buttons.set.results()[i]));
+            }
+        }
+        self.invalidate();
+    }
+
+    /// Translates the labels of the buttons added with `with_buttons`.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{ButtonSet, Dialog, DialogResult};
+    ///
+    /// let dialog = Dialog::text("Enregistrer ?")
+    ///     .with_buttons(ButtonSet::YesNoCancel)
+    ///     .button_labels(|result| match result {
+    ///         DialogResult::Yes => Some("Oui".into()),
+    ///         DialogResult::No => Some("Non".into()),
+    ///         DialogResult::Cancel => Some("Annuler".into()),
+    ///         _ => None,
+    ///     });
+    /// ```
+    pub fn button_labels<F>(self, translator: F) -> Self
+    where
+        F: Fn(DialogResult) -> Option<String> + 'static,
+    {
+        self.with(|s| s.set_button_labels(translator))
+    }
+
+    /// Sets the callback for the buttons added with `with_buttons`.
+    ///
+    /// It is called with the result after the dialog is closed.
+    pub fn set_on_result<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, DialogResult) + 'static,
+    {
+        let buttons = self
+            .standard_buttons
+            .get_or_insert_with(|| StandardButtons::new(ButtonSet::Ok));
+        *buttons.on_result.borrow_mut() = Some(Rc::new(cb));
+    }
+
+    /// Sets the callback for the buttons added with `with_buttons`.
+    ///
+    /// Chainable variant.
+    pub fn on_result<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, DialogResult) + 'static,
+    {
+        self.with(|s| s.set_on_result(cb))
+    }
+
     /// Shortcut method to add a button that will dismiss the dialog.
     ///
     /// # Examples
@@ -260,6 +896,9 @@ impl Dialog {
     ///
     /// If not empty, it will be visible at the top.
     ///
+    /// The title can be styled; unstyled parts use the `TitlePrimary`
+    /// palette color.
+    ///
     /// # Examples
     ///
     /// ```
@@ -268,16 +907,55 @@ impl Dialog {
     /// let dialog = Dialog::info("Some info")
     ///         .title("Read me!");
     /// ```
-    pub fn title<S: Into<String>>(self, label: S) -> Self {
+    pub fn title<S: Into<StyledString>>(self, label: S) -> Self {
         self.with(|s| s.set_title(label))
     }
 
     /// Sets the title of the dialog.
-    pub fn set_title<S: Into<String>>(&mut self, label: S) {
+    pub fn set_title<S: Into<StyledString>>(&mut self, label: S) {
         self.title = label.into();
         self.invalidate();
     }
 
+    /// Returns the title of the dialog.
+    pub fn get_title(&self) -> &StyledString {
+        &self.title
+    }
+
+    /// Sets the status of the dialog.
+    ///
+    /// If not empty, it will be visible at the right end of the title bar.
+    /// This can be used for short indicators like `"modified ●"`.
+    ///
+    /// The status can be styled; unstyled parts use the `TitleSecondary`
+    /// palette color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::Dialog;
+    ///
+    /// let dialog = Dialog::text("Lorem ipsum")
+    ///     .title("notes.txt")
+    ///     .status("modified ●");
+    /// ```
+    pub fn status<S: Into<StyledString>>(self, status: S) -> Self {
+        self.with(|s| s.set_status(status))
+    }
+
+    /// Sets the status of the dialog.
+    ///
+    /// Use an empty string to remove the status.
+    pub fn set_status<S: Into<StyledString>>(&mut self, status: S) {
+        self.status = status.into();
+        self.invalidate();
+    }
+
+    /// Returns the status of the dialog.
+    pub fn get_status(&self) -> &StyledString {
+        &self.status
+    }
+
     /// Sets the horizontal position of the title in the dialog.
     /// The default position is `HAlign::Center`
     pub fn title_position(self, align: HAlign) -> Self {
@@ -377,10 +1055,49 @@ impl Dialog {
 
     // Private methods
 
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        // First: some mouse events can instantly change the focus.
+        self.check_focus_grab(&event);
+
+        let result = match self.focus {
+            // If we are on the content, we can only go down.
+            // TODO: Careful if/when we add buttons elsewhere on the dialog!
+            DialogFocus::Content => self.on_event_content(event.clone()),
+            // If we are on a button, we have more choice
+            DialogFocus::Button(i) => self.on_event_button(event.clone(), i),
+        };
+
+        match (result, &self.standard_buttons) {
+            (EventResult::Ignored, Some(buttons)) => {
+                match buttons.result_for(&event) {
+                    Some(result) => {
+                        EventResult::with_cb(buttons.callback(result))
+                    }
+                    None => EventResult::Ignored,
+                }
+            }
+            (result, _) => result,
+        }
+    }
+
+    // Tells the content about a focus change, after an event.
+    fn notify_focus_change(&mut self, old: DialogFocus) -> EventResult {
+        match (old, self.focus) {
+            (DialogFocus::Content, DialogFocus::Button(_)) => {
+                self.content.on_event(Event::FocusLost)
+            }
+            (DialogFocus::Button(_), DialogFocus::Content) => {
+                self.content.on_event(Event::FocusGained)
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
     // An event is received while the content is in focus
     fn on_event_content(&mut self, event: Event) -> EventResult {
         match self.content.on_event(
-            event.relativized((self.padding + self.borders).top_left()),
+            event
+                .relativized((self.inner_padding() + self.borders).top_left()),
         ) {
             EventResult::Ignored => {
                 if self.buttons.is_empty() {
@@ -489,7 +1206,7 @@ impl Dialog {
             .map(|button| button.button.size.x)
             .sum::<usize>()
             + self.buttons.len().saturating_sub(1);
-        let overhead = self.padding + self.borders;
+        let overhead = self.inner_padding() + self.borders;
         if printer.size.x < overhead.horizontal() {
             return None;
         }
@@ -530,7 +1247,7 @@ impl Dialog {
         // What do we have left?
         let taken = Vec2::new(0, buttons_height)
             + self.borders.combined()
-            + self.padding.combined();
+            + self.inner_padding().combined();
 
         let inner_size = match printer.size.checked_sub(taken) {
             Some(s) => s,
@@ -539,33 +1256,80 @@ impl Dialog {
 
         self.content.draw(
             &printer
-                .offset(self.borders.top_left() + self.padding.top_left())
+                .offset(
+                    self.borders.top_left() + self.inner_padding().top_left(),
+                )
                 .cropped(inner_size)
                 .focused(self.focus == DialogFocus::Content),
         );
     }
 
     fn draw_title(&self, printer: &Printer<'_, '_>) {
+        let spacing = 3; //minimum distance to borders
+        let spacing_both_ends = 2 * spacing;
+        let mut available = printer.size.x.saturating_sub(spacing_both_ends);
+
+        if !self.status.is_empty() {
+            let len = self.status.width();
+            if len > available {
+                return;
+            }
+            let x = printer.size.x - spacing - len;
+            self.draw_title_segment(
+                printer,
+                x,
+                &self.status,
+                ColorStyle::title_secondary(),
+            );
+            // Keep some room between the title and the status.
+            available = available.saturating_sub(len + 4);
+        }
+
         if !self.title.is_empty() {
             let len = self.title.width();
-            let spacing = 3; //minimum distance to borders
-            let spacing_both_ends = 2 * spacing;
-            if len + spacing_both_ends > printer.size.x {
+            if len > available {
                 return;
             }
-            let x = spacing
-                + self
-                    .title_position
-                    .get_offset(len, printer.size.x - spacing_both_ends);
-            printer.with_high_border(false, |printer| {
-                printer.print((x - 2, 0), "┤ ");
-                printer.print((x + len, 0), " ├");
-            });
+            let x = spacing + self.title_position.get_offset(len, available);
+            self.draw_title_segment(
+                printer,
+                x,
+                &self.title,
+                self.alert_style(printer)
+                    .unwrap_or_else(ColorStyle::title_primary),
+            );
+        }
+    }
 
-            printer.with_color(ColorStyle::title_primary(), |p| {
-                p.print((x, 0), &self.title)
-            });
+    fn draw_title_segment(
+        &self,
+        printer: &Printer<'_, '_>,
+        x: usize,
+        text: &StyledString,
+        color: ColorStyle,
+    ) {
+        self.with_border_color(printer, |printer| {
+            printer.print((x - 2, 0), "┤ ");
+            printer.print((x + text.width(), 0), " ├");
+        });
+
+        printer.with_color(color, |p| p.print_styled((x, 0), text.into()));
+    }
+
+    // Minimum width needed to show the title and status.
+    fn title_width(&self) -> usize {
+        let mut width = 0;
+        if !self.title.is_empty() {
+            width += self.title.width() + 6;
+        }
+        if !self.status.is_empty() {
+            width += self.status.width() + 6;
+            if !self.title.is_empty() {
+                // Both share the border spacing.
+                width -= 2;
+            }
         }
+        width
     }
 
     fn check_focus_grab(&mut self, event: &Event) {
@@ -593,9 +1357,11 @@ impl Dialog {
             }) {
                 self.focus = DialogFocus::Button(i);
             } else if position.fits_in_rect(
-                (self.padding + self.borders).top_left(),
+                (self.inner_padding() + self.borders).top_left(),
                 self.content.size,
-            ) && self.content.take_focus(Direction::none())
+            ) && (event != MouseEvent::Hover
+                || self.content.focus_on_hover())
+                && self.content.take_focus(Direction::none())
             {
                 // Or did we click the content?
                 self.focus = DialogFocus::Content;
@@ -606,6 +1372,67 @@ impl Dialog {
     fn invalidate(&mut self) {
         self.invalidated = true;
     }
+
+    // Glyph shown before the content, if any.
+    fn glyph(&self) -> Option<&'static str> {
+        self.alert
+            .filter(|_| self.alert_glyph)
+            .map(AlertLevel::glyph)
+    }
+
+    // Padding, with room for the glyph.
+    fn inner_padding(&self) -> Margins {
+        let mut padding = self.padding;
+        if let Some(glyph) = self.glyph() {
+            padding.left += glyph.width() + 1;
+        }
+        padding
+    }
+
+    // Color of the alert level, if any.
+    fn alert_style(&self, printer: &Printer<'_, '_>) -> Option<ColorStyle> {
+        self.alert.map(|level| {
+            ColorStyle::new(
+                level.color(&printer.theme.palette),
+                PaletteColor::View,
+            )
+        })
+    }
+
+    // Runs `f` with the color of the borders.
+    fn with_border_color<F>(&self, printer: &Printer<'_, '_>, f: F)
+    where
+        F: FnOnce(&Printer<'_, '_>),
+    {
+        match self.alert_style(printer) {
+            Some(_) if printer.theme.borders == BorderStyle::None => (),
+            Some(style) => printer.with_color(style, f),
+            None => printer.with_high_border(false, f),
+        }
+    }
+
+    fn draw_borders(&self, printer: &Printer<'_, '_>) {
+        if self.alert.is_none() {
+            printer.print_box(Vec2::new(0, 0), printer.size, false);
+            return;
+        }
+
+        // Alerts use a single color for the whole box.
+        let size = printer.size.saturating_sub((1, 1));
+        if size.x == 0 || size.y == 0 {
+            return;
+        }
+        self.with_border_color(printer, |printer| {
+            printer.print((0, 0), "┌");
+            printer.print(size.keep_x(), "┐");
+            printer.print(size.keep_y(), "└");
+            printer.print(size, "┘");
+            printer.print_hline((1, 0), size.x - 1, "─");
+            printer.print_hline((1, size.y), size.x - 1, "─");
+            printer.print_vline((0, 1), size.y - 1, "│");
+            printer.print_vline((size.x, 1), size.y - 1, "│");
+        });
+    }
 }
 
 impl View for Dialog {
@@ -618,15 +1445,31 @@ impl View for Dialog {
 
         self.draw_content(printer, buttons_height);
 
+        if let (Some(glyph), Some(style)) =
+            (self.glyph(), self.alert_style(printer))
+        {
+            let position = self.borders.top_left() + self.padding.top_left();
+            printer.with_color(style, |printer| {
+                printer.print(position, glyph);
+            });
+        }
+
         // Print the borders
-        printer.print_box(Vec2::new(0, 0), printer.size, false);
+        self.draw_borders(printer);
 
         self.draw_title(printer);
+
+        if let (Some(level), Some(hook)) = (self.alert, &self.on_alert) {
+            if !self.alerted.replace(true) {
+                hook(level);
+            }
+        }
     }
 
     fn required_size(&mut self, req: Vec2) -> Vec2 {
         // Padding and borders are not available for kids.
-        let nomans_land = self.padding.combined() + self.borders.combined();
+        let nomans_land =
+            self.inner_padding().combined() + self.borders.combined();
 
         // Buttons are not flexible, so their size doesn't depend on ours.
         let mut buttons_size = Vec2::new(0, 0);
@@ -656,13 +1499,11 @@ impl View for Dialog {
         let mut inner_size = Vec2::new(
             max(content_size.x, buttons_size.x),
             content_size.y + buttons_size.y,
-        ) + self.padding.combined()
+        ) + self.inner_padding().combined()
             + self.borders.combined();
 
-        if !self.title.is_empty() {
-            // If we have a title, we have to fit it too!
-            inner_size.x = max(inner_size.x, self.title.width() + 6);
-        }
+        // If we have a title, we have to fit it too!
+        inner_size.x = max(inner_size.x, self.title_width());
 
         inner_size
     }
@@ -670,7 +1511,7 @@ impl View for Dialog {
     fn layout(&mut self, mut size: Vec2) {
         // Padding and borders are taken, sorry.
         // TODO: handle border-less themes?
-        let taken = self.borders.combined() + self.padding.combined();
+        let taken = self.borders.combined() + self.inner_padding().combined();
         size = size.saturating_sub(taken);
 
         // Buttons are kings, we give them everything they want.
@@ -693,16 +1534,9 @@ impl View for Dialog {
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
-        // First: some mouse events can instantly change the focus.
-        self.check_focus_grab(&event);
-
-        match self.focus {
-            // If we are on the content, we can only go down.
-            // TODO: Careful if/when we add buttons elsewhere on the dialog!
-            DialogFocus::Content => self.on_event_content(event),
-            // If we are on a button, we have more choice
-            DialogFocus::Button(i) => self.on_event_button(event, i),
-        }
+        let focus = self.focus;
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(focus))
     }
 
     fn take_focus(&mut self, source: Direction) -> bool {
@@ -753,13 +1587,152 @@ impl View for Dialog {
         self.content.focus_view(selector)
     }
 
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        self.content.remove_view(selector)
+    }
+
     fn important_area(&self, _: Vec2) -> Rect {
         self.content.important_area(self.content.size)
             + self.borders.top_left()
-            + self.padding.top_left()
+            + self.inner_padding().top_left()
     }
 
     fn needs_relayout(&self) -> bool {
         self.invalidated || self.content.needs_relayout()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open(siv: &mut Cursive, set: ButtonSet) {
+        siv.set_user_data(None::<DialogResult>);
+        siv.add_layer(
+            Dialog::text("Sure?")
+                .with_buttons(set)
+                .on_result(|s, result| s.set_user_data(Some(result))),
+        );
+        // Lays out the dialog, so it focuses its first button.
+        siv.refresh();
+    }
+
+    fn result(siv: &mut Cursive) -> Option<DialogResult> {
+        *siv.user_data::<Option<DialogResult>>().unwrap()
+    }
+
+    #[test]
+    fn alert() {
+        use crate::buffer::render_to_buffer;
+
+        let alerts = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&alerts);
+        let mut dialog = Dialog::warn("Low battery").on_alert(move |level| {
+            if level == AlertLevel::Warning {
+                counter.set(counter.get() + 1);
+            }
+        });
+        let buffer = render_to_buffer(&mut dialog, (20, 6));
+        assert_eq!(buffer.lines()[1], "│ ⚠ Low battery    │");
+        render_to_buffer(&mut dialog, (20, 6));
+        assert_eq!(alerts.get(), 1);
+
+        // Borders use the level color, which themes can replace.
+        let corner = buffer.get(Vec2::zero()).unwrap().colors.front;
+        assert_eq!(corner, Color::Dark(crate::theme::BaseColor::Yellow));
+    }
+
+    #[test]
+    fn title_status() {
+        use crate::buffer::render_to_buffer;
+        use crate::theme::{Effect, Theme};
+
+        let title = StyledString::styled("notes", Effect::Bold);
+        let mut dialog = Dialog::text("Lorem").title(title).status("mod");
+        let buffer = render_to_buffer(&mut dialog, (30, 5));
+        assert_eq!(buffer.lines()[0], "┌──────┤ notes ├──────┤ mod ├┐");
+
+        let palette = &Theme::default().palette;
+        let cell = |x| buffer.get(Vec2::new(x, 0)).unwrap();
+        assert!(cell(9).effects.contains(Effect::Bold));
+        assert_eq!(
+            cell(24).colors.front,
+            palette[PaletteColor::TitleSecondary]
+        );
+    }
+
+    #[test]
+    fn button_set() {
+        let mut siv = crate::testing::dummy((30, 10));
+
+        open(&mut siv, ButtonSet::YesNoCancel);
+        assert_eq!(siv.screen().len(), 1);
+        siv.on_event(Event::Char('n'));
+        assert_eq!(result(&mut siv), Some(DialogResult::No));
+        assert_eq!(siv.screen().len(), 0);
+
+        open(&mut siv, ButtonSet::RetryAbort);
+        siv.on_event(Event::Key(Key::Esc));
+        assert_eq!(result(&mut siv), Some(DialogResult::Abort));
+
+        // Enter presses the focused button.
+        open(&mut siv, ButtonSet::OkCancel);
+        siv.on_event(Event::Key(Key::Enter));
+        assert_eq!(result(&mut siv), Some(DialogResult::Ok));
+    }
+
+    #[test]
+    fn button_labels() {
+        let mut dialog = Dialog::text("Sûr ?")
+            .with_buttons(ButtonSet::YesNo)
+            .button_labels(|result| match result {
+                DialogResult::No => Some("Non".into()),
+                _ => None,
+            });
+        let labels: Vec<_> = dialog
+            .buttons_mut()
+            .map(|b| b.label().to_string())
+            .collect();
+        assert_eq!(labels, vec!["<Yes>", "<Non>"]);
+
+        // Shortcuts follow the translated labels.
+        let buttons = dialog.standard_buttons.as_ref().unwrap();
+        assert_eq!(
+            buttons.result_for(&Event::Char('n')),
+            Some(DialogResult::No)
+        );
+        assert_eq!(
+            buttons.result_for(&Event::Char('y')),
+            Some(DialogResult::Yes)
+        );
+    }
+
+    #[test]
+    fn prompt() {
+        let mut siv = crate::testing::dummy((30, 10));
+
+        let answer = Dialog::confirm_async(&mut siv, "Sure?");
+        siv.refresh();
+        siv.on_event(Event::Key(Key::Esc));
+        assert_eq!(answer.try_take(), Some(false));
+
+        let answer = Dialog::prompt_async(&mut siv, "Name:");
+        siv.refresh();
+        for c in "abc".chars() {
+            siv.on_event(Event::Char(c));
+        }
+        assert_eq!(answer.try_take(), None);
+        siv.on_event(Event::Key(Key::Enter));
+        assert_eq!(answer.try_take(), Some(Some("abc".into())));
+        assert_eq!(siv.screen().len(), 0);
+
+        let answer = Dialog::prompt_async(&mut siv, "Name:");
+        siv.refresh();
+        siv.on_event(Event::Char('x'));
+        siv.on_event(Event::Key(Key::Esc));
+        assert_eq!(answer.try_take(), Some(None));
+    }
+}
@@ -0,0 +1,269 @@
+use crate::event::{Callback, Event, EventResult, MouseButton, MouseEvent};
+use crate::printer::DropZone;
+use crate::rect::Rect;
+use crate::view::{View, ViewWrapper};
+use crate::{Cursive, Printer, Vec2, With};
+use std::any::Any;
+use std::rc::Rc;
+
+type Payload<V, T> = dyn Fn(&V) -> Option<T>;
+type Ghost<T> = dyn Fn(&T) -> String;
+type Accept<T> = dyn Fn(&T) -> bool;
+type OnDrop<T> = dyn Fn(&mut Cursive, T, Vec2);
+/// Drag in progress, started by a [`DragSource`].
+pub(crate) struct Drag {
+    pub payload: Box<dyn Any>,
+    /// Text drawn under the mouse.
+    pub ghost: String,
+    /// Last known position of the mouse, in screen coordinates.
+    pub position: Vec2,
+}
+
+/// Wrapper letting the mouse drag a value out of a view.
+///
+/// When the mouse is pressed on the view, then moved while held, the
+/// payload function is called with the view. If it returns a value, a drag
+/// starts: a ghost follows the mouse until the button is released over a
+/// [`DropTarget`] accepting this type of value. `Esc` cancels the drag.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{DragSource, SelectView};
+///
+/// let list = SelectView::<String>::new().with_all_str(vec!["a", "b"]);
+/// let source = DragSource::new(list, |list: &SelectView<String>| {
+///     list.selection().map(|item| String::clone(&item))
+/// })
+/// .ghost(|item: &String| item.clone());
+/// ```
+pub struct DragSource<V, T> {
+    view: V,
+    payload: Rc<Payload<V, T>>,
+    ghost: Option<Rc<Ghost<T>>>,
+
+    // Where the mouse was pressed, until the drag starts.
+    pressed: Option<Vec2>,
+}
+
+impl<V, T: 'static> DragSource<V, T> {
+    /// Wraps `view`, with a function producing the dragged value.
+    pub fn new<F>(view: V, payload: F) -> Self
+    where
+        F: Fn(&V) -> Option<T> + 'static,
+    {
+        DragSource {
+            view,
+            payload: Rc::new(payload),
+            ghost: None,
+            pressed: None,
+        }
+    }
+
+    /// Sets a function giving the text drawn under the mouse while
+    /// dragging.
+    ///
+    /// Defaults to a small square.
+    pub fn set_ghost<F>(&mut self, ghost: F)
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.ghost = Some(Rc::new(ghost));
+    }
+
+    /// Sets a function giving the text drawn under the mouse while
+    /// dragging.
+    ///
+    /// Chainable variant.
+    pub fn ghost<F>(self, ghost: F) -> Self
+    where
+        F: Fn(&T) -> String + 'static,
+    {
+        self.with(|s| s.set_ghost(ghost))
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View, T: 'static> ViewWrapper for DragSource<V, T> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        if let Event::Mouse {
+            event: mouse_event,
+            position,
+            ..
+        } = event
+        {
+            match mouse_event {
+                MouseEvent::Press(MouseButton::Left) => {
+                    self.pressed = Some(position);
+                }
+                MouseEvent::Hold(MouseButton::Left)
+                    if self.pressed.is_some_and(|p| p != position) =>
+                {
+                    self.pressed = None;
+                    if let Some(payload) = (self.payload)(&self.view) {
+                        let ghost = match self.ghost {
+                            Some(ref ghost) => ghost(&payload),
+                            None => String::from("▪"),
+                        };
+                        let mut drag = Some((payload, ghost));
+                        return EventResult::Consumed(Some(
+                            Callback::from_fn_mut(move |s| {
+                                if let Some((payload, ghost)) = drag.take() {
+                                    s.start_drag_at(payload, ghost, position);
+                                }
+                            }),
+                        ));
+                    }
+                }
+                MouseEvent::Release(_) => self.pressed = None,
+                _ => (),
+            }
+        }
+        self.view.on_event(event)
+    }
+}
+
+/// Wrapper accepting values dropped on a view.
+///
+/// Values dragged from a [`DragSource`] are dropped here if they have the
+/// type `T`, and if the accept function, if any, returns `true`. The drop
+/// callback gets the value and the position of the mouse in the view.
+///
+/// The callback can't access the view directly: give it a name to find it,
+/// for example with [`Cursive::call_on_name`].
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{DropTarget, SelectView};
+/// use cursive_core::view::Nameable;
+///
+/// let list = SelectView::<String>::new().with_name("done");
+/// let target = DropTarget::new(list, |s, item: String, _position| {
+///     s.call_on_name("done", |list: &mut SelectView<String>| {
+///         list.add_item_str(item);
+///     });
+/// })
+/// .accept(|item: &String| !item.is_empty());
+/// ```
+pub struct DropTarget<V, T> {
+    view: V,
+    accept: Option<Rc<Accept<T>>>,
+    on_drop: Rc<OnDrop<T>>,
+}
+
+impl<V, T: 'static> DropTarget<V, T> {
+    /// Wraps `view`, calling `on_drop` when a value is dropped on it.
+    pub fn new<F>(view: V, on_drop: F) -> Self
+    where
+        F: Fn(&mut Cursive, T, Vec2) + 'static,
+    {
+        DropTarget {
+            view,
+            accept: None,
+            on_drop: Rc::new(on_drop),
+        }
+    }
+
+    /// Sets a function telling if a value can be dropped here.
+    ///
+    /// By default, any value of type `T` is accepted.
+    pub fn set_accept<F>(&mut self, accept: F)
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.accept = Some(Rc::new(accept));
+    }
+
+    /// Sets a function telling if a value can be dropped here.
+    ///
+    /// Chainable variant.
+    pub fn accept<F>(self, accept: F) -> Self
+    where
+        F: Fn(&T) -> bool + 'static,
+    {
+        self.with(|s| s.set_accept(accept))
+    }
+
+    inner_getters!(self.view: V);
+
+    fn zone(&self, printer: &Printer<'_, '_>) -> DropZone {
+        let accept = self.accept.clone();
+        let on_drop = Rc::clone(&self.on_drop);
+        DropZone {
+            area: Rect::from_size(printer.offset, printer.output_size),
+            origin: printer.offset.saturating_sub(printer.content_offset),
+            accept: Rc::new(move |payload| {
+                payload.downcast_ref::<T>().is_some_and(|payload| {
+                    accept.as_ref().is_none_or(|accept| accept(payload))
+                })
+            }),
+            on_drop: Rc::new(move |s, payload, position| {
+                if let Ok(payload) = payload.downcast::<T>() {
+                    on_drop(s, *payload, position);
+                }
+            }),
+        }
+    }
+}
+
+impl<V: View, T: 'static> ViewWrapper for DropTarget<V, T> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        printer.record_drop_zone(|| self.zone(printer));
+        self.view.draw(printer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{LinearLayout, TextView};
+
+    #[test]
+    fn drag_and_drop() {
+        let mut siv = crate::testing::dummy((10, 3));
+        siv.add_fullscreen_layer(
+            LinearLayout::vertical()
+                .child(DragSource::new(TextView::new("item"), |_| {
+                    Some(String::from("item"))
+                }))
+                .child(DropTarget::new(
+                    TextView::new("numbers"),
+                    |s, n: u32, _| s.set_user_data(n),
+                ))
+                .child(DropTarget::new(
+                    TextView::new("strings"),
+                    |s, text: String, position| {
+                        s.set_user_data((text, position))
+                    },
+                )),
+        );
+        siv.refresh();
+
+        let mouse = |event, y| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(3, y),
+            event,
+        };
+        siv.on_event(mouse(MouseEvent::Press(MouseButton::Left), 0));
+        siv.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 1));
+        assert!(siv.is_dragging());
+
+        // The ghost follows the mouse.
+        siv.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 2));
+        assert_eq!(crate::testing::screen(&mut siv)[2], "str▪ngs   ");
+
+        // Only the target accepting strings gets it.
+        siv.on_event(mouse(MouseEvent::Release(MouseButton::Left), 2));
+        assert!(!siv.is_dragging());
+        assert_eq!(
+            siv.take_user_data(),
+            Some((String::from("item"), Vec2::new(3, 0)))
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use crate::direction::Direction;
 use crate::event::{Callback, Event, EventResult, Key, MouseEvent};
+use crate::printer::AnyBinding;
 use crate::rect::Rect;
-use crate::theme::{ColorStyle, Effect};
+use crate::theme::{BaseColor, ColorStyle, Effect, PaletteColor};
 use crate::utils::lines::simple::{simple_prefix, simple_suffix};
 use crate::view::View;
+use crate::views::Binding;
 use crate::Vec2;
 use crate::{Cursive, Printer, With};
+use std::any::Any;
 use std::cell::RefCell;
+use std::ops::Range;
 use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
@@ -22,6 +26,56 @@ pub type OnEdit = dyn Fn(&mut Cursive, &str, usize);
 /// Arguments are the `Cursive` and the content of the input.
 pub type OnSubmit = dyn Fn(&mut Cursive, &str);
 
+/// Closure type for validators.
+///
+/// Returns `true` if the given content is acceptable.
+pub type Validator = dyn Fn(&str) -> bool;
+
+/// Preset validators for common inputs.
+///
+/// Masks accept partial input, like `-` for a number, so the content can be
+/// typed one character at a time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputMask {
+    /// Integer, with an optional leading `-`.
+    Integer,
+    /// Decimal number, with an optional leading `-` and a single `.`.
+    Float,
+    /// Hexadecimal number, with an optional `0x` prefix.
+    Hex,
+}
+
+impl InputMask {
+    /// Returns `true` if `content` matches this mask.
+    ///
+    /// The empty string is always accepted.
+    pub fn accepts(self, content: &str) -> bool {
+        match self {
+            InputMask::Integer => content
+                .strip_prefix('-')
+                .unwrap_or(content)
+                .chars()
+                .all(|c| c.is_ascii_digit()),
+            InputMask::Float => {
+                let number = content.strip_prefix('-').unwrap_or(content);
+                number.chars().all(|c| c.is_ascii_digit() || c == '.')
+                    && number.matches('.').count() <= 1
+            }
+            InputMask::Hex => {
+                let digits = if content == "0" {
+                    ""
+                } else {
+                    content
+                        .strip_prefix("0x")
+                        .or_else(|| content.strip_prefix("0X"))
+                        .unwrap_or(content)
+                };
+                digits.chars().all(|c| c.is_ascii_hexdigit())
+            }
+        }
+    }
+}
+
 /// Input box where the user can enter and edit text.
 ///
 /// # Examples
@@ -106,6 +160,32 @@ pub struct EditView {
     enabled: bool,
 
     style: ColorStyle,
+
+    /// Content rejected by this validator cannot be typed.
+    validator: Option<Rc<Validator>>,
+
+    /// Callback when a keystroke is rejected by the validator.
+    ///
+    /// Will be called with the rejected content.
+    on_invalid: Option<Rc<OnSubmit>>,
+
+    /// Style used when the content is not valid.
+    invalid_style: ColorStyle,
+
+    /// Previously submitted contents, oldest first.
+    history: Vec<String>,
+
+    /// Maximum length of the history. `0` disables it.
+    max_history: usize,
+
+    /// Position in the history while browsing it with Up/Down.
+    history_index: Option<usize>,
+
+    /// Content typed before browsing the history.
+    draft: String,
+
+    /// Binding of the content to the user data.
+    binding: Option<Rc<Binding<String>>>,
 }
 
 new_default!(EditView);
@@ -127,6 +207,48 @@ impl EditView {
             filler: "_".to_string(),
             enabled: true,
             style: ColorStyle::secondary(),
+            validator: None,
+            on_invalid: None,
+            invalid_style: ColorStyle::new(
+                BaseColor::Red.dark(),
+                PaletteColor::View,
+            ),
+            history: Vec::new(),
+            max_history: 0,
+            history_index: None,
+            draft: String::new(),
+            binding: None,
+        }
+    }
+
+    /// Creates a new edit view bound to a string in the user data.
+    ///
+    /// `get` reads the content from the user data, of type `T`. The view
+    /// follows it every frame, and edits are written back with `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::EditView;
+    ///
+    /// struct Profile {
+    ///     name: String,
+    /// }
+    ///
+    /// let edit_view = EditView::bound(
+    ///     |profile: &Profile| profile.name.clone(),
+    ///     |profile, name| profile.name = name,
+    /// );
+    /// ```
+    pub fn bound<T, G, S>(get: G, set: S) -> Self
+    where
+        T: Any,
+        G: 'static + Fn(&T) -> String,
+        S: 'static + Fn(&mut T, String),
+    {
+        EditView {
+            binding: Some(Binding::lens(get, set)),
+            ..EditView::new()
         }
     }
 
@@ -340,6 +462,220 @@ impl EditView {
         self.with(|v| v.set_on_submit(callback))
     }
 
+    /// Sets a validator for the content.
+    ///
+    /// Keystrokes producing content rejected by `validator` are ignored.
+    /// Since the content is typed one character at a time, the validator
+    /// should accept incomplete input.
+    ///
+    /// Content set with [`set_content`](#method.set_content) is not
+    /// rejected, but is shown with the invalid style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::EditView;
+    ///
+    /// let mut edit = EditView::new().validator(|content| content.len() <= 3);
+    /// edit.insert('a');
+    /// edit.insert('b');
+    /// edit.insert('c');
+    /// edit.insert('d');
+    /// assert_eq!(&*edit.get_content(), "abc");
+    /// ```
+    pub fn set_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&str) -> bool + 'static,
+    {
+        self.validator = Some(Rc::new(validator));
+    }
+
+    /// Sets a validator for the content.
+    ///
+    /// Chainable variant.
+    pub fn validator<F>(self, validator: F) -> Self
+    where
+        F: Fn(&str) -> bool + 'static,
+    {
+        self.with(|v| v.set_validator(validator))
+    }
+
+    /// Removes the validator, if any.
+    pub fn clear_validator(&mut self) {
+        self.validator = None;
+    }
+
+    /// Sets a preset validator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{EditView, InputMask};
+    ///
+    /// let mut edit = EditView::new().mask(InputMask::Integer);
+    /// edit.insert('4');
+    /// edit.insert('x');
+    /// edit.insert('2');
+    /// assert_eq!(&*edit.get_content(), "42");
+    /// ```
+    pub fn set_mask(&mut self, mask: InputMask) {
+        self.set_validator(move |content| mask.accepts(content));
+    }
+
+    /// Sets a preset validator.
+    ///
+    /// Chainable variant.
+    pub fn mask(self, mask: InputMask) -> Self {
+        self.with(|v| v.set_mask(mask))
+    }
+
+    /// Sets a callback to run when a keystroke is rejected by the validator.
+    ///
+    /// It is given the content that was rejected. It can be used to show an
+    /// error, or to change the style of surrounding views.
+    pub fn set_on_invalid<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_invalid = Some(Rc::new(callback));
+    }
+
+    /// Sets a callback to run when a keystroke is rejected by the validator.
+    ///
+    /// Chainable variant.
+    pub fn on_invalid<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|v| v.set_on_invalid(callback))
+    }
+
+    /// Sets the style used when the content is not valid.
+    ///
+    /// Defaults to red.
+    pub fn set_invalid_style(&mut self, style: ColorStyle) {
+        self.invalid_style = style;
+    }
+
+    /// Sets the style used when the content is not valid.
+    ///
+    /// Chainable variant.
+    pub fn invalid_style(self, style: ColorStyle) -> Self {
+        self.with(|v| v.set_invalid_style(style))
+    }
+
+    /// Returns `true` if the validator accepts the current content.
+    ///
+    /// Always `true` without a validator.
+    pub fn is_valid(&self) -> bool {
+        self.accepts(&self.content)
+    }
+
+    fn accepts(&self, content: &str) -> bool {
+        self.validator
+            .as_ref()
+            .is_none_or(|validator| validator(content))
+    }
+
+    fn make_invalid_cb(&self, content: String) -> Callback {
+        match self.on_invalid.clone() {
+            Some(cb) => Callback::from_fn(move |s| cb(s, &content)),
+            None => Callback::dummy(),
+        }
+    }
+
+    /// Sets the maximum number of entries kept in the history.
+    ///
+    /// Submitted contents are added to the history, and can be recalled
+    /// with `Up` and `Down`. Defaults to `0`, which disables the history.
+    pub fn set_max_history(&mut self, max_history: usize) {
+        self.max_history = max_history;
+        let excess = self.history.len().saturating_sub(max_history);
+        self.history.drain(..excess);
+    }
+
+    /// Sets the maximum number of entries kept in the history.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::EditView;
+    ///
+    /// let prompt = EditView::new().max_history(100).on_submit(|s, command| {
+    ///     // Run the command...
+    /// });
+    /// ```
+    pub fn max_history(self, max_history: usize) -> Self {
+        self.with(|v| v.set_max_history(max_history))
+    }
+
+    /// Returns the history, oldest entry first.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    /// Adds an entry to the history.
+    ///
+    /// Empty entries and repetitions of the last entry are ignored, as are
+    /// all entries when the history is disabled.
+    pub fn add_history<S: Into<String>>(&mut self, entry: S) {
+        let entry = entry.into();
+        self.history_index = None;
+        if self.max_history == 0
+            || entry.is_empty()
+            || self.history.last() == Some(&entry)
+        {
+            return;
+        }
+        self.history.push(entry);
+        if self.history.len() > self.max_history {
+            self.history.remove(0);
+        }
+    }
+
+    /// Removes all entries from the history.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.history_index = None;
+    }
+
+    /// Recalls the previous entry in the history.
+    ///
+    /// Ignored at the oldest entry, so focus can move on.
+    fn history_up(&mut self) -> EventResult {
+        let index = match self.history_index {
+            None if self.history.is_empty() => return EventResult::Ignored,
+            None => {
+                self.draft = String::clone(&self.content);
+                self.history.len() - 1
+            }
+            Some(0) => return EventResult::Ignored,
+            Some(index) => index - 1,
+        };
+        self.history_index = Some(index);
+        let cb = self.set_content(self.history[index].clone());
+        EventResult::Consumed(Some(cb))
+    }
+
+    /// Recalls the next entry in the history, or the draft after the last.
+    fn history_down(&mut self) -> EventResult {
+        let index = match self.history_index {
+            Some(index) => index + 1,
+            None => return EventResult::Ignored,
+        };
+        let content = if index < self.history.len() {
+            self.history_index = Some(index);
+            self.history[index].clone()
+        } else {
+            self.history_index = None;
+            std::mem::take(&mut self.draft)
+        };
+        let cb = self.set_content(content);
+        EventResult::Consumed(Some(cb))
+    }
+
     /// Replace the entire content of the view with the given one.
     ///
     /// Returns a callback in response to content change.
@@ -396,6 +732,14 @@ impl EditView {
             }
         }
 
+        if self.validator.is_some() {
+            let mut content = String::clone(&self.content);
+            content.insert(self.cursor, ch);
+            if !self.accepts(&content) {
+                return self.make_invalid_cb(content);
+            }
+        }
+
         // `make_mut` applies copy-on-write
         // It means it'll just return a ref if no one else has a ref,
         // and it will clone it into `self.content` otherwise.
@@ -416,6 +760,15 @@ impl EditView {
     pub fn remove(&mut self, len: usize) -> Callback {
         let start = self.cursor;
         let end = self.cursor + len;
+
+        if self.validator.is_some() {
+            let mut content = String::clone(&self.content);
+            content.replace_range(start..end, "");
+            if !self.accepts(&content) {
+                return self.make_invalid_cb(content);
+            }
+        }
+
         for _ in Rc::make_mut(&mut self.content).drain(start..end) {}
 
         self.keep_cursor_in_view();
@@ -423,16 +776,62 @@ impl EditView {
         self.make_edit_cb().unwrap_or_else(Callback::dummy)
     }
 
+    /// Removes the given byte range, and moves the cursor to its start.
+    ///
+    /// The cursor doesn't move if the validator rejects the change.
+    fn remove_range(&mut self, range: Range<usize>) -> Callback {
+        let before = self.content.len();
+        let cursor = std::mem::replace(&mut self.cursor, range.start);
+        let cb = self.remove(range.end - range.start);
+        if self.content.len() == before {
+            self.cursor = cursor;
+        }
+        cb
+    }
+
+    /// Returns the start of the word before the cursor.
+    fn word_start(&self) -> usize {
+        let cursor = self.cursor;
+        self.content
+            .unicode_word_indices()
+            .map(|(i, _)| i)
+            .take_while(|&i| i < cursor)
+            .last()
+            .unwrap_or(0)
+    }
+
+    /// Returns the end of the word after the cursor.
+    fn word_end(&self) -> usize {
+        let cursor = self.cursor;
+        self.content
+            .unicode_word_indices()
+            .map(|(i, word)| i + word.len())
+            .find(|&end| end > cursor)
+            .unwrap_or(self.content.len())
+    }
+
     fn make_edit_cb(&self) -> Option<Callback> {
-        self.on_edit.clone().map(|cb| {
-            // Get a new Rc on the content
-            let content = Rc::clone(&self.content);
-            let cursor = self.cursor;
+        if self.on_edit.is_none() && self.binding.is_none() {
+            return None;
+        }
 
-            Callback::from_fn(move |s| {
+        let write = self
+            .binding
+            .as_ref()
+            .map(|binding| binding.write(String::clone(&self.content)));
+        let on_edit = self.on_edit.clone();
+        // Get a new Rc on the content
+        let content = Rc::clone(&self.content);
+        let cursor = self.cursor;
+
+        Some(Callback::from_fn(move |s| {
+            if let Some(ref write) = write {
+                write(s);
+            }
+            if let Some(ref cb) = on_edit {
                 cb(s, &content, cursor);
-            })
-        })
+            }
+        }))
     }
 
     fn keep_cursor_in_view(&mut self) {
@@ -497,6 +896,10 @@ fn make_small_stars(length: usize) -> &'static str {
 
 impl View for EditView {
     fn draw(&self, printer: &Printer<'_, '_>) {
+        if let Some(ref binding) = self.binding {
+            printer
+                .record_binding(|| Rc::clone(binding) as Rc<dyn AnyBinding>);
+        }
         assert_eq!(
             printer.size.x, self.last_length,
             "Was promised {}, received {}",
@@ -504,7 +907,12 @@ impl View for EditView {
         );
 
         let width = self.content.width();
-        printer.with_color(self.style, |printer| {
+        let style = if self.is_valid() {
+            self.style
+        } else {
+            self.invalid_style
+        };
+        printer.with_color(style, |printer| {
             let effect = if self.enabled && printer.enabled {
                 Effect::Reverse
             } else {
@@ -591,6 +999,16 @@ impl View for EditView {
 
     fn layout(&mut self, size: Vec2) {
         self.last_length = size.x;
+
+        let pending = self.binding.as_ref().and_then(|b| b.take_pending());
+        if let Some(content) = pending {
+            if content != *self.content {
+                let len = content.len();
+                self.content = Rc::new(content);
+                self.offset = 0;
+                self.set_cursor(len);
+            }
+        }
     }
 
     fn take_focus(&mut self, _: Direction) -> bool {
@@ -602,9 +1020,8 @@ impl View for EditView {
             Event::Char(ch) => {
                 return EventResult::Consumed(Some(self.insert(ch)));
             }
-            // TODO: handle ctrl-key?
-            Event::Key(Key::Home) => self.set_cursor(0),
-            Event::Key(Key::End) => {
+            Event::Key(Key::Home) | Event::CtrlChar('a') => self.set_cursor(0),
+            Event::Key(Key::End) | Event::CtrlChar('e') => {
                 // When possible, NLL to the rescue!
                 let len = self.content.len();
                 self.set_cursor(len);
@@ -633,8 +1050,8 @@ impl View for EditView {
                     .last()
                     .unwrap()
                     .len();
-                self.cursor -= len;
-                return EventResult::Consumed(Some(self.remove(len)));
+                let cb = self.remove_range(self.cursor - len..self.cursor);
+                return EventResult::Consumed(Some(cb));
             }
             Event::Key(Key::Del) if self.cursor < self.content.len() => {
                 let len = self.content[self.cursor..]
@@ -644,9 +1061,30 @@ impl View for EditView {
                     .len();
                 return EventResult::Consumed(Some(self.remove(len)));
             }
+            Event::AltChar('b') => self.set_cursor(self.word_start()),
+            Event::AltChar('f') => self.set_cursor(self.word_end()),
+            Event::CtrlChar('k') if self.cursor < self.content.len() => {
+                let cb = self.remove_range(self.cursor..self.content.len());
+                return EventResult::Consumed(Some(cb));
+            }
+            Event::CtrlChar('u') if self.cursor > 0 => {
+                let cb = self.remove_range(0..self.cursor);
+                return EventResult::Consumed(Some(cb));
+            }
+            Event::CtrlChar('w') if self.cursor > 0 => {
+                let cb = self.remove_range(self.word_start()..self.cursor);
+                return EventResult::Consumed(Some(cb));
+            }
+            Event::Key(Key::Up) if self.max_history > 0 => {
+                return self.history_up();
+            }
+            Event::Key(Key::Down) if self.max_history > 0 => {
+                return self.history_down();
+            }
             Event::Key(Key::Enter) if self.on_submit.is_some() => {
                 let cb = self.on_submit.clone().unwrap();
                 let content = Rc::clone(&self.content);
+                self.add_history(String::clone(&content));
                 return EventResult::with_cb(move |s| {
                     cb(s, &content);
                 });
@@ -691,3 +1129,60 @@ impl View for EditView {
         Rect::from_size((x, 0), (char_width, 1))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks() {
+        assert!(InputMask::Integer.accepts("-12"));
+        assert!(!InputMask::Integer.accepts("1-2"));
+        assert!(InputMask::Float.accepts("-."));
+        assert!(!InputMask::Float.accepts("1.2.3"));
+        assert!(InputMask::Hex.accepts("0xFf"));
+        assert!(!InputMask::Hex.accepts("0xg"));
+
+        // Backspace can't make the content invalid either.
+        let mut edit = EditView::new()
+            .validator(|content| content.starts_with('a'))
+            .content("ab");
+        edit.set_cursor(1);
+        edit.on_event(Event::Key(Key::Backspace));
+        assert_eq!(&*edit.get_content(), "ab");
+        assert_eq!(edit.cursor, 1);
+
+        edit.set_content("b");
+        assert!(!edit.is_valid());
+    }
+
+    #[test]
+    fn history() {
+        let mut edit = EditView::new().max_history(2).on_submit(|_, _| ());
+        for command in &["ls", "cd", "pwd"] {
+            edit.set_content(*command);
+            edit.on_event(Event::Key(Key::Enter));
+        }
+        assert_eq!(edit.history(), ["cd", "pwd"]);
+
+        edit.set_content("draft");
+        edit.on_event(Event::Key(Key::Up));
+        edit.on_event(Event::Key(Key::Up));
+        assert!(!edit.on_event(Event::Key(Key::Up)).is_consumed());
+        assert_eq!(&*edit.get_content(), "cd");
+        edit.on_event(Event::Key(Key::Down));
+        edit.on_event(Event::Key(Key::Down));
+        assert_eq!(&*edit.get_content(), "draft");
+        assert!(!edit.on_event(Event::Key(Key::Down)).is_consumed());
+
+        // Readline-style bindings.
+        edit.set_content("git commit --amend");
+        edit.on_event(Event::CtrlChar('w'));
+        assert_eq!(&*edit.get_content(), "git commit --");
+        edit.on_event(Event::AltChar('b'));
+        edit.on_event(Event::CtrlChar('k'));
+        assert_eq!(&*edit.get_content(), "git ");
+        edit.on_event(Event::CtrlChar('u'));
+        assert_eq!(&*edit.get_content(), "");
+    }
+}
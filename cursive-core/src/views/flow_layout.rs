@@ -0,0 +1,432 @@
+use crate::direction::{Absolute, Direction, Relative};
+use crate::event::{AnyCb, Event, EventResult, Key, MouseEvent};
+use crate::rect::Rect;
+use crate::view::{self, IntoBoxedView, Selector, View};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+use std::cmp::{max, min};
+
+struct FlowChild {
+    view: Box<dyn View>,
+    // Computed in `required_size`.
+    offset: Vec2,
+    size: Vec2,
+    row: usize,
+}
+
+/// Arranges its children from left to right, wrapping to a new row when
+/// the width is exhausted.
+///
+/// Rows are as high as their highest child, and are recomputed when the
+/// available width changes.
+///
+/// `Tab`, `Shift+Tab`, `Left` and `Right` move the focus in order, while
+/// `Up` and `Down` move it to the closest child in the previous or next row.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Button, FlowLayout};
+///
+/// let toolbar = FlowLayout::new()
+///     .child(Button::new("Open", |_| ()))
+///     .child(Button::new("Save", |_| ()))
+///     .child(Button::new("Quit", |s| s.quit()));
+/// ```
+pub struct FlowLayout {
+    children: Vec<FlowChild>,
+    spacing: Vec2,
+    focus: Option<usize>,
+}
+
+new_default!(FlowLayout);
+
+impl FlowLayout {
+    /// Creates a new, empty flow layout.
+    pub fn new() -> Self {
+        FlowLayout {
+            children: Vec::new(),
+            spacing: Vec2::new(1, 0),
+            focus: None,
+        }
+    }
+
+    /// Sets the space between children in a row, and between rows.
+    ///
+    /// Defaults to `(1, 0)`.
+    pub fn set_spacing<S: Into<Vec2>>(&mut self, spacing: S) {
+        self.spacing = spacing.into();
+    }
+
+    /// Sets the space between children in a row, and between rows.
+    ///
+    /// Chainable variant.
+    pub fn spacing<S: Into<Vec2>>(self, spacing: S) -> Self {
+        self.with(|s| s.set_spacing(spacing))
+    }
+
+    /// Returns the space between children in a row, and between rows.
+    pub fn get_spacing(&self) -> Vec2 {
+        self.spacing
+    }
+
+    /// Adds a child at the end.
+    pub fn add_child<V: IntoBoxedView + 'static>(&mut self, view: V) {
+        self.children.push(FlowChild {
+            view: view.as_boxed_view(),
+            offset: Vec2::zero(),
+            size: Vec2::zero(),
+            row: 0,
+        });
+    }
+
+    /// Adds a child at the end.
+    ///
+    /// Chainable variant.
+    pub fn child<V: IntoBoxedView + 'static>(self, view: V) -> Self {
+        self.with(|s| s.add_child(view))
+    }
+
+    /// Removes a child.
+    ///
+    /// Returns `None` if `i` is out of bounds.
+    pub fn remove_child(&mut self, i: usize) -> Option<Box<dyn View>> {
+        if i >= self.children.len() {
+            return None;
+        }
+        self.focus = match self.focus {
+            Some(focus) if focus == i => None,
+            Some(focus) if focus > i => Some(focus - 1),
+            focus => focus,
+        };
+        Some(self.children.remove(i).view)
+    }
+
+    /// Returns a reference to a child.
+    pub fn get_child(&self, i: usize) -> Option<&dyn View> {
+        self.children.get(i).map(|child| &*child.view)
+    }
+
+    /// Returns a mutable reference to a child.
+    pub fn get_child_mut(&mut self, i: usize) -> Option<&mut dyn View> {
+        self.children.get_mut(i).map(|child| &mut *child.view)
+    }
+
+    /// Returns the number of children.
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns `true` if this layout has no child.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns the index of the focused child, if any.
+    pub fn get_focus_index(&self) -> Option<usize> {
+        self.focus
+    }
+
+    /// Returns the number of rows from the last layout.
+    pub fn rows(&self) -> usize {
+        self.children.last().map_or(0, |child| child.row + 1)
+    }
+
+    // Gives the focus to the first child of `candidates` taking it.
+    fn focus_first<I>(&mut self, candidates: I, source: Direction) -> bool
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        for i in candidates {
+            if self.children[i].view.take_focus(source) {
+                self.focus = Some(i);
+                return true;
+            }
+        }
+        false
+    }
+
+    fn move_focus(&mut self, direction: Absolute) -> EventResult {
+        let focus = match self.focus {
+            Some(focus) => focus,
+            None => return EventResult::Ignored,
+        };
+
+        let candidates: Vec<usize> = match direction {
+            Absolute::Right => (focus + 1..self.children.len()).collect(),
+            Absolute::Left => (0..focus).rev().collect(),
+            _ => {
+                // Closest child in the previous or next row.
+                let current = &self.children[focus];
+                let row = if direction == Absolute::Up {
+                    match current.row.checked_sub(1) {
+                        Some(row) => row,
+                        None => return EventResult::Ignored,
+                    }
+                } else {
+                    current.row + 1
+                };
+                let center = 2 * current.offset.x + current.size.x;
+                let mut candidates: Vec<usize> = (0..self.children.len())
+                    .filter(|&i| self.children[i].row == row)
+                    .collect();
+                candidates.sort_by_key(|&i| {
+                    let child = &self.children[i];
+                    let x = 2 * child.offset.x + child.size.x;
+                    max(x, center) - min(x, center)
+                });
+                candidates
+            }
+        };
+
+        // The focus comes from the opposite side.
+        let source = match direction {
+            Absolute::Left => Direction::back(),
+            Absolute::Right => Direction::front(),
+            Absolute::Up => Direction::down(),
+            _ => Direction::up(),
+        };
+        if self.focus_first(candidates, source) {
+            EventResult::Consumed(None)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        self.check_focus_grab(&event);
+
+        let focus = match self.focus {
+            Some(focus) => focus,
+            None => return EventResult::Ignored,
+        };
+        let offset = self.children[focus].offset;
+        let result = self.children[focus]
+            .view
+            .on_event(event.relativized(offset));
+        if result.is_consumed() {
+            return result;
+        }
+
+        match event {
+            Event::Key(Key::Tab) | Event::Key(Key::Right) => {
+                self.move_focus(Absolute::Right)
+            }
+            Event::Shift(Key::Tab) | Event::Key(Key::Left) => {
+                self.move_focus(Absolute::Left)
+            }
+            Event::Key(Key::Up) => self.move_focus(Absolute::Up),
+            Event::Key(Key::Down) => self.move_focus(Absolute::Down),
+            _ => EventResult::Ignored,
+        }
+    }
+
+    // Tells the children about a focus change, after an event.
+    fn notify_focus_change(&mut self, old: Option<usize>) -> EventResult {
+        if self.focus == old {
+            return EventResult::Ignored;
+        }
+        let focus = self.focus;
+        let mut notify = |i: Option<usize>, event: Event| match i {
+            Some(i) => self.children[i].view.on_event(event),
+            None => EventResult::Ignored,
+        };
+        let lost = notify(old, Event::FocusLost);
+        lost.and(notify(focus, Event::FocusGained))
+    }
+
+    // Focuses the child under the mouse, on click.
+    fn check_focus_grab(&mut self, event: &Event) {
+        if let Event::Mouse {
+            offset,
+            position,
+            event,
+        } = *event
+        {
+            if !event.grabs_focus() {
+                return;
+            }
+            let position = match position.checked_sub(offset) {
+                Some(position) => position,
+                None => return,
+            };
+
+            let found = self.children.iter().position(|child| {
+                Rect::from_size(child.offset, child.size).contains(position)
+            });
+            if let Some(i) = found {
+                if event == MouseEvent::Hover
+                    && !self.children[i].view.focus_on_hover()
+                {
+                    return;
+                }
+                if self.children[i].view.take_focus(Direction::none()) {
+                    self.focus = Some(i);
+                }
+            }
+        }
+    }
+}
+
+impl View for FlowLayout {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        for (i, child) in self.children.iter().enumerate() {
+            if !printer.is_visible(child.offset, child.size) {
+                continue;
+            }
+            let printer = printer
+                .offset(child.offset)
+                .cropped(child.size)
+                .focused(self.focus == Some(i));
+            child.view.draw(&printer);
+        }
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        let spacing = self.spacing;
+        let mut size = Vec2::zero();
+        let mut cursor = Vec2::zero();
+        let mut row = 0;
+        let mut row_height = 0;
+        let mut row_start = 0;
+
+        for i in 0..self.children.len() {
+            let child_size = self.children[i].view.required_size(req);
+            let child_size = Vec2::new(min(child_size.x, req.x), child_size.y);
+
+            // Wrap, unless this is the first child in the row.
+            if cursor.x > 0 && cursor.x + child_size.x > req.x {
+                for child in &mut self.children[row_start..i] {
+                    child.size.y = row_height;
+                }
+                cursor = Vec2::new(0, cursor.y + row_height + spacing.y);
+                row += 1;
+                row_height = 0;
+                row_start = i;
+            }
+
+            let child = &mut self.children[i];
+            child.offset = cursor;
+            child.size = child_size;
+            child.row = row;
+
+            row_height = max(row_height, child_size.y);
+            size.x = max(size.x, cursor.x + child_size.x);
+            size.y = cursor.y + row_height;
+            cursor.x += child_size.x + spacing.x;
+        }
+        for child in &mut self.children[row_start..] {
+            child.size.y = row_height;
+        }
+
+        size
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.required_size(size);
+        for child in &mut self.children {
+            child.view.layout(child.size);
+        }
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        let mut order: Vec<usize> = (0..self.children.len()).collect();
+        if matches!(
+            source,
+            Direction::Abs(Absolute::Down | Absolute::Right)
+                | Direction::Rel(Relative::Back)
+        ) {
+            order.reverse();
+        }
+        self.focus_first(order, source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let focus = self.focus;
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(focus))
+    }
+
+    fn call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        for child in &mut self.children {
+            child.view.call_on_any(selector, callback);
+        }
+    }
+
+    fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
+        for (i, child) in self.children.iter_mut().enumerate() {
+            if child.view.focus_view(selector).is_ok() {
+                self.focus = Some(i);
+                return Ok(());
+            }
+        }
+        Err(())
+    }
+
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        for child in &mut self.children {
+            if let Some(view) = child.view.remove_view(selector) {
+                return Some(view);
+            }
+        }
+
+        let i = self
+            .children
+            .iter_mut()
+            .position(|child| view::contains(&mut *child.view, selector))?;
+        self.remove_child(i)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        match self.focus {
+            Some(focus) => {
+                let child = &self.children[focus];
+                child.view.important_area(child.size) + child.offset
+            }
+            None => Rect::from_size((0, 0), size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::views::Button;
+
+    #[test]
+    fn wrapping() {
+        let button = |label: &str| Button::new(label, |_| ());
+        let mut flow = FlowLayout::new()
+            .child(button("one"))
+            .child(button("two"))
+            .child(button("three"))
+            .child(button("four"));
+        assert_eq!(
+            render_to_buffer(&mut flow, (16, 2)).lines(),
+            ["<one> <two>     ", "<three> <four>  "]
+        );
+        assert_eq!(flow.rows(), 2);
+
+        // Resizing wraps again.
+        assert_eq!(flow.required_size(Vec2::new(40, 2)), Vec2::new(26, 1));
+        flow.layout(Vec2::new(12, 3));
+        assert_eq!(flow.rows(), 3);
+
+        assert!(flow.take_focus(Direction::none()));
+        flow.on_event(Event::Key(Key::Down));
+        assert_eq!(flow.get_focus_index(), Some(2));
+        flow.on_event(Event::Key(Key::Right));
+        assert_eq!(flow.get_focus_index(), Some(3));
+        flow.on_event(Event::Key(Key::Up));
+        assert_eq!(flow.get_focus_index(), Some(2));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::event::{Event, EventResult};
+use crate::view::{View, ViewWrapper};
+use crate::With;
+
+type FocusCallback<V> = dyn Fn(&mut V) -> EventResult;
+
+/// Wrapper running callbacks when a view gets or loses the focus.
+///
+/// Callbacks run when a view group moves the focus to or from this view,
+/// for example with `Tab`, the arrow keys or the mouse. They don't run when
+/// a whole layer is added or removed.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{EditView, FocusTracker, TextView};
+/// use cursive_core::event::EventResult;
+/// use cursive_core::view::Nameable;
+///
+/// let edit = FocusTracker::new(EditView::new())
+///     .on_focus(|_| {
+///         EventResult::with_cb(|s| {
+///             s.call_on_name("help", |view: &mut TextView| {
+///                 view.set_content("Enter your name.");
+///             });
+///         })
+///     })
+///     .on_focus_lost(|edit: &mut EditView| {
+///         let valid = !edit.get_content().is_empty();
+///         EventResult::with_cb(move |s| {
+///             let help = if valid { "" } else { "The name is required." };
+///             s.call_on_name("help", |view: &mut TextView| {
+///                 view.set_content(help);
+///             });
+///         })
+///     });
+/// ```
+pub struct FocusTracker<V> {
+    view: V,
+    on_focus: Option<Box<FocusCallback<V>>>,
+    on_focus_lost: Option<Box<FocusCallback<V>>>,
+}
+
+impl<V> FocusTracker<V> {
+    /// Wraps the given view.
+    pub fn new(view: V) -> Self {
+        FocusTracker {
+            view,
+            on_focus: None,
+            on_focus_lost: None,
+        }
+    }
+
+    /// Sets a callback to run when the view gets the focus.
+    pub fn set_on_focus<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut V) -> EventResult + 'static,
+    {
+        self.on_focus = Some(Box::new(cb));
+    }
+
+    /// Sets a callback to run when the view gets the focus.
+    ///
+    /// Chainable variant.
+    pub fn on_focus<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut V) -> EventResult + 'static,
+    {
+        self.with(|s| s.set_on_focus(cb))
+    }
+
+    /// Sets a callback to run when the view loses the focus.
+    pub fn set_on_focus_lost<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut V) -> EventResult + 'static,
+    {
+        self.on_focus_lost = Some(Box::new(cb));
+    }
+
+    /// Sets a callback to run when the view loses the focus.
+    ///
+    /// Chainable variant.
+    pub fn on_focus_lost<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut V) -> EventResult + 'static,
+    {
+        self.with(|s| s.set_on_focus_lost(cb))
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for FocusTracker<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let cb = match event {
+            Event::FocusGained => self.on_focus.as_ref(),
+            Event::FocusLost => self.on_focus_lost.as_ref(),
+            _ => None,
+        };
+        // Let nested trackers know first.
+        let result = self.view.on_event(event);
+        match cb {
+            Some(cb) => result.and(cb(&mut self.view)),
+            None => result,
+        }
+    }
+}
@@ -0,0 +1,834 @@
+use crate::direction::{Absolute, Direction, Relative};
+use crate::event::{AnyCb, Event, EventResult, Key, MouseEvent};
+use crate::rect::Rect;
+use crate::view::{self, IntoBoxedView, Selector, View};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+use crate::XY;
+use std::cell::Cell;
+use std::cmp::{max, min};
+
+struct GridChild {
+    view: Box<dyn View>,
+    // Top-left cell.
+    row: usize,
+    col: usize,
+    // Number of rows and columns covered, at least 1.
+    rows: usize,
+    cols: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Column {
+    min: usize,
+    max: usize,
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Column {
+            min: 0,
+            max: usize::MAX,
+        }
+    }
+}
+
+/// Arranges its children in rows and columns.
+///
+/// Each child is placed in a cell, and can span several rows or columns.
+/// Columns are as wide as their widest child, within optional bounds, and
+/// rows as high as their highest child.
+///
+/// `Tab` and `Shift+Tab` move the focus in reading order, and the arrow keys
+/// move it to the closest cell in their direction.
+///
+/// Inside a [`ScrollView`](crate::views::ScrollView), leading rows and
+/// columns can be frozen: they stay at the top and on the left of the view
+/// while the other cells scroll, like headers in a spreadsheet.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Button, Grid};
+///
+/// let mut keypad = Grid::new();
+/// for i in 1..10 {
+///     let label = i.to_string();
+///     keypad.add_child((9 - i) / 3, (i - 1) % 3, Button::new(label, |_| ()));
+/// }
+/// keypad.add_child_spanning(3, 0, 1, 3, Button::new("0", |_| ()));
+/// ```
+pub struct Grid {
+    children: Vec<GridChild>,
+    columns: Vec<Column>,
+    // Minimum height of each row.
+    row_heights: Vec<usize>,
+    focus: Option<usize>,
+
+    // Number of leading rows and columns kept in view.
+    frozen: XY<usize>,
+
+    // Scroll offset of the last draw, to find frozen cells.
+    scroll: Cell<Vec2>,
+
+    // Computed in `required_size`.
+    widths: Vec<usize>,
+    heights: Vec<usize>,
+}
+
+new_default!(Grid);
+
+impl Grid {
+    /// Creates a new, empty grid.
+    pub fn new() -> Self {
+        Grid {
+            children: Vec::new(),
+            columns: Vec::new(),
+            row_heights: Vec::new(),
+            focus: None,
+            frozen: XY::new(0, 0),
+            scroll: Cell::new(Vec2::zero()),
+            widths: Vec::new(),
+            heights: Vec::new(),
+        }
+    }
+
+    /// Places a child in the given cell.
+    ///
+    /// Replaces the child placed in this cell before, if any.
+    pub fn add_child<V>(&mut self, row: usize, col: usize, view: V)
+    where
+        V: IntoBoxedView + 'static,
+    {
+        self.add_child_spanning(row, col, 1, 1, view);
+    }
+
+    /// Places a child in the given cell.
+    ///
+    /// Chainable variant.
+    pub fn child<V>(self, row: usize, col: usize, view: V) -> Self
+    where
+        V: IntoBoxedView + 'static,
+    {
+        self.with(|s| s.add_child(row, col, view))
+    }
+
+    /// Places a child covering `rows` rows and `cols` columns.
+    ///
+    /// `row` and `col` give the top-left cell. Replaces the child placed in
+    /// this cell before, if any.
+    pub fn add_child_spanning<V>(
+        &mut self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+        view: V,
+    ) where
+        V: IntoBoxedView + 'static,
+    {
+        self.remove_child(row, col);
+        self.children.push(GridChild {
+            view: view.as_boxed_view(),
+            row,
+            col,
+            rows: max(rows, 1),
+            cols: max(cols, 1),
+        });
+    }
+
+    /// Places a child covering `rows` rows and `cols` columns.
+    ///
+    /// Chainable variant.
+    pub fn child_spanning<V>(
+        self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+        view: V,
+    ) -> Self
+    where
+        V: IntoBoxedView + 'static,
+    {
+        self.with(|s| s.add_child_spanning(row, col, rows, cols, view))
+    }
+
+    /// Removes the child placed in the given cell.
+    ///
+    /// Returns `None` if no child starts in this cell.
+    pub fn remove_child(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> Option<Box<dyn View>> {
+        let i = self.position(row, col)?;
+        self.focus = match self.focus {
+            Some(focus) if focus == i => None,
+            Some(focus) if focus > i => Some(focus - 1),
+            focus => focus,
+        };
+        Some(self.children.remove(i).view)
+    }
+
+    /// Returns a reference to the child placed in the given cell.
+    pub fn get_child(&self, row: usize, col: usize) -> Option<&dyn View> {
+        let i = self.position(row, col)?;
+        Some(&*self.children[i].view)
+    }
+
+    /// Returns a mutable reference to the child placed in the given cell.
+    pub fn get_child_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+    ) -> Option<&mut dyn View> {
+        let i = self.position(row, col)?;
+        Some(&mut *self.children[i].view)
+    }
+
+    /// Returns the number of children.
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// Returns `true` if this grid has no child.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns the number of rows and columns.
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.children.iter().fold((0, 0), |(rows, cols), child| {
+            (
+                max(rows, child.row + child.rows),
+                max(cols, child.col + child.cols),
+            )
+        })
+    }
+
+    /// Returns the top-left cell of the focused child, if any.
+    pub fn get_focus_cell(&self) -> Option<(usize, usize)> {
+        self.focus
+            .map(|i| (self.children[i].row, self.children[i].col))
+    }
+
+    /// Sets the minimum width of a column.
+    pub fn set_column_min_width(&mut self, col: usize, width: usize) {
+        self.column_mut(col).min = width;
+    }
+
+    /// Sets the minimum width of a column.
+    ///
+    /// Chainable variant.
+    pub fn column_min_width(self, col: usize, width: usize) -> Self {
+        self.with(|s| s.set_column_min_width(col, width))
+    }
+
+    /// Sets the maximum width of a column.
+    ///
+    /// Wider children are cropped.
+    pub fn set_column_max_width(&mut self, col: usize, width: usize) {
+        self.column_mut(col).max = width;
+    }
+
+    /// Sets the maximum width of a column.
+    ///
+    /// Chainable variant.
+    pub fn column_max_width(self, col: usize, width: usize) -> Self {
+        self.with(|s| s.set_column_max_width(col, width))
+    }
+
+    /// Sets the minimum height of a row.
+    ///
+    /// Without it, rows shrink to fit the available height, so the grid
+    /// never scrolls vertically.
+    pub fn set_row_min_height(&mut self, row: usize, height: usize) {
+        if self.row_heights.len() <= row {
+            self.row_heights.resize(row + 1, 0);
+        }
+        self.row_heights[row] = height;
+    }
+
+    /// Sets the minimum height of a row.
+    ///
+    /// Chainable variant.
+    pub fn row_min_height(self, row: usize, height: usize) -> Self {
+        self.with(|s| s.set_row_min_height(row, height))
+    }
+
+    /// Keeps the first `rows` rows at the top of the view when scrolling.
+    ///
+    /// Rows only scroll with a minimum height, see
+    /// [`set_row_min_height`](#method.set_row_min_height). Defaults to 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{Grid, ScrollView, TextView};
+    ///
+    /// let mut table = Grid::new().frozen_rows(1).frozen_columns(1);
+    /// for (col, name) in ["Name", "Size", "Owner"].iter().enumerate() {
+    ///     table.add_child(0, col, TextView::new(*name));
+    /// }
+    /// let view = ScrollView::new(table).scroll_x(true);
+    /// ```
+    pub fn set_frozen_rows(&mut self, rows: usize) {
+        self.frozen.y = rows;
+    }
+
+    /// Keeps the first `rows` rows at the top of the view when scrolling.
+    ///
+    /// Chainable variant.
+    pub fn frozen_rows(self, rows: usize) -> Self {
+        self.with(|s| s.set_frozen_rows(rows))
+    }
+
+    /// Keeps the first `cols` columns on the left of the view when
+    /// scrolling.
+    ///
+    /// Defaults to 0.
+    pub fn set_frozen_columns(&mut self, cols: usize) {
+        self.frozen.x = cols;
+    }
+
+    /// Keeps the first `cols` columns on the left of the view when
+    /// scrolling.
+    ///
+    /// Chainable variant.
+    pub fn frozen_columns(self, cols: usize) -> Self {
+        self.with(|s| s.set_frozen_columns(cols))
+    }
+
+    /// Returns the number of frozen rows and columns.
+    pub fn get_frozen(&self) -> (usize, usize) {
+        (self.frozen.y, self.frozen.x)
+    }
+
+    fn column_mut(&mut self, col: usize) -> &mut Column {
+        if self.columns.len() <= col {
+            self.columns.resize(col + 1, Column::default());
+        }
+        &mut self.columns[col]
+    }
+
+    fn column(&self, col: usize) -> Column {
+        self.columns.get(col).copied().unwrap_or_default()
+    }
+
+    // Index of the child starting in the given cell.
+    fn position(&self, row: usize, col: usize) -> Option<usize> {
+        self.children
+            .iter()
+            .position(|child| child.row == row && child.col == col)
+    }
+
+    // Offset of the given child, from the computed sizes.
+    fn offset(&self, child: &GridChild) -> Vec2 {
+        Vec2::new(
+            self.widths.iter().take(child.col).sum(),
+            self.heights.iter().take(child.row).sum(),
+        )
+    }
+
+    // Size of the given child, from the computed sizes.
+    fn cell_size(&self, child: &GridChild) -> Vec2 {
+        Vec2::new(
+            self.widths.iter().skip(child.col).take(child.cols).sum(),
+            self.heights.iter().skip(child.row).take(child.rows).sum(),
+        )
+    }
+
+    // Size of the frozen rows and columns, from the computed sizes.
+    fn frozen_size(&self) -> Vec2 {
+        Vec2::new(
+            self.widths.iter().take(self.frozen.x).sum(),
+            self.heights.iter().take(self.frozen.y).sum(),
+        )
+    }
+
+    // For each axis, `true` if the given child is frozen.
+    fn is_frozen(&self, child: &GridChild) -> XY<bool> {
+        XY::new(child.col < self.frozen.x, child.row < self.frozen.y)
+    }
+
+    // Returns the printer for cells frozen on the given axes.
+    //
+    // Frozen axes ignore the scroll offset, and stay in the frozen area.
+    // Other axes start after it, so scrolled cells don't go below.
+    fn pane<'a, 'b>(
+        &self,
+        printer: &Printer<'a, 'b>,
+        frozen: XY<bool>,
+    ) -> Printer<'a, 'b> {
+        let extent = self.frozen_size();
+        let scroll = frozen.select_or(Vec2::zero(), printer.content_offset);
+        let skip = frozen.select_or(Vec2::zero(), extent);
+        printer
+            .clone()
+            .with(|s| s.content_offset = scroll)
+            .cropped(frozen.select_or(extent, printer.size))
+            .offset(scroll + skip)
+            .content_offset(scroll + skip)
+    }
+
+    // Maps mouse positions on frozen cells to their place in the grid.
+    fn locate_frozen(&self, mut event: Event) -> Event {
+        if let Event::Mouse {
+            offset,
+            ref mut position,
+            ..
+        } = event
+        {
+            if let Some(inside) = position.checked_sub(offset) {
+                let shown = inside.saturating_sub(self.scroll.get());
+                let frozen = shown.zip_map(self.frozen_size(), |s, e| s < e);
+                *position = offset + frozen.select_or(shown, inside);
+            }
+        }
+        event
+    }
+
+    // Children indexes, in reading order.
+    fn reading_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.children.len()).collect();
+        order.sort_by_key(|&i| (self.children[i].row, self.children[i].col));
+        order
+    }
+
+    // Gives the focus to the first child of `candidates` taking it.
+    fn focus_first<I>(&mut self, candidates: I, source: Direction) -> bool
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        for i in candidates {
+            if self.children[i].view.take_focus(source) {
+                self.focus = Some(i);
+                return true;
+            }
+        }
+        false
+    }
+
+    // Moves the focus in reading order.
+    fn move_focus_linear(&mut self, forward: bool) -> EventResult {
+        let order = self.reading_order();
+        let current = self
+            .focus
+            .and_then(|focus| order.iter().position(|&i| i == focus));
+        let candidates: Vec<usize> = match (current, forward) {
+            (Some(c), true) => order[c + 1..].to_vec(),
+            (Some(c), false) => order[..c].iter().rev().copied().collect(),
+            (None, _) => return EventResult::Ignored,
+        };
+        let source = if forward {
+            Direction::front()
+        } else {
+            Direction::back()
+        };
+        if self.focus_first(candidates, source) {
+            EventResult::Consumed(None)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    // Moves the focus to the closest child in the given direction.
+    fn move_focus_spatial(&mut self, direction: Absolute) -> EventResult {
+        let focus = match self.focus {
+            Some(focus) => focus,
+            None => return EventResult::Ignored,
+        };
+
+        // Work with doubled coordinates, to compare centers.
+        let bounds = |child: &GridChild| {
+            (
+                2 * child.col,
+                2 * (child.col + child.cols),
+                2 * child.row,
+                2 * (child.row + child.rows),
+            )
+        };
+        let (left, right, top, bottom) = bounds(&self.children[focus]);
+        let center = ((left + right) / 2, (top + bottom) / 2);
+
+        let mut candidates: Vec<((usize, usize), usize)> = self
+            .children
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != focus)
+            .filter_map(|(i, child)| {
+                let (l, r, t, b) = bounds(child);
+                let (x, y) = ((l + r) / 2, (t + b) / 2);
+                // Distance along the direction, then across it.
+                let distance = match direction {
+                    Absolute::Left if r <= left => {
+                        (left - r, diff(y, center.1))
+                    }
+                    Absolute::Right if l >= right => {
+                        (l - right, diff(y, center.1))
+                    }
+                    Absolute::Up if b <= top => (top - b, diff(x, center.0)),
+                    Absolute::Down if t >= bottom => {
+                        (t - bottom, diff(x, center.0))
+                    }
+                    _ => return None,
+                };
+                Some((distance, i))
+            })
+            .collect();
+        candidates.sort();
+
+        // The focus comes from the opposite side.
+        let source = match direction {
+            Absolute::Left => Direction::right(),
+            Absolute::Right => Direction::left(),
+            Absolute::Up => Direction::down(),
+            _ => Direction::up(),
+        };
+        let candidates = candidates.into_iter().map(|(_, i)| i);
+        if self.focus_first(candidates, source) {
+            EventResult::Consumed(None)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        self.check_focus_grab(&event);
+
+        let focus = match self.focus {
+            Some(focus) => focus,
+            None => return EventResult::Ignored,
+        };
+        let offset = self.offset(&self.children[focus]);
+        let result = self.children[focus]
+            .view
+            .on_event(event.relativized(offset));
+        if result.is_consumed() {
+            return result;
+        }
+
+        match event {
+            Event::Key(Key::Tab) => self.move_focus_linear(true),
+            Event::Shift(Key::Tab) => self.move_focus_linear(false),
+            Event::Key(Key::Left) => self.move_focus_spatial(Absolute::Left),
+            Event::Key(Key::Right) => self.move_focus_spatial(Absolute::Right),
+            Event::Key(Key::Up) => self.move_focus_spatial(Absolute::Up),
+            Event::Key(Key::Down) => self.move_focus_spatial(Absolute::Down),
+            _ => EventResult::Ignored,
+        }
+    }
+
+    // Tells the children about a focus change, after an event.
+    fn notify_focus_change(&mut self, old: Option<usize>) -> EventResult {
+        if self.focus == old {
+            return EventResult::Ignored;
+        }
+        let focus = self.focus;
+        let mut notify = |i: Option<usize>, event: Event| match i {
+            Some(i) => self.children[i].view.on_event(event),
+            None => EventResult::Ignored,
+        };
+        let lost = notify(old, Event::FocusLost);
+        lost.and(notify(focus, Event::FocusGained))
+    }
+
+    // Focuses the child under the mouse, on click.
+    fn check_focus_grab(&mut self, event: &Event) {
+        if let Event::Mouse {
+            offset,
+            position,
+            event,
+        } = *event
+        {
+            if !event.grabs_focus() {
+                return;
+            }
+            let position = match position.checked_sub(offset) {
+                Some(position) => position,
+                None => return,
+            };
+
+            let found = self.children.iter().position(|child| {
+                Rect::from_size(self.offset(child), self.cell_size(child))
+                    .contains(position)
+            });
+            if let Some(i) = found {
+                if event == MouseEvent::Hover
+                    && !self.children[i].view.focus_on_hover()
+                {
+                    return;
+                }
+                if self.children[i].view.take_focus(Direction::none()) {
+                    self.focus = Some(i);
+                }
+            }
+        }
+    }
+}
+
+fn diff(a: usize, b: usize) -> usize {
+    max(a, b) - min(a, b)
+}
+
+// Reduces the largest sizes, down to their minimum, until they fit.
+fn shrink(sizes: &mut [usize], mins: &[usize], available: usize) {
+    while sizes.iter().sum::<usize>() > available {
+        let largest = sizes
+            .iter()
+            .zip(mins)
+            .enumerate()
+            .filter(|(_, (size, min))| size > min)
+            .max_by_key(|(_, (size, _))| **size)
+            .map(|(i, _)| i);
+        match largest {
+            Some(i) => sizes[i] -= 1,
+            None => return,
+        }
+    }
+}
+
+impl View for Grid {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        self.scroll.set(printer.content_offset);
+        let frozen = self.frozen_size() != Vec2::zero();
+
+        for (i, child) in self.children.iter().enumerate() {
+            let pane;
+            let printer = if frozen {
+                pane = self.pane(printer, self.is_frozen(child));
+                &pane
+            } else {
+                printer
+            };
+
+            let offset = self.offset(child);
+            let size = self.cell_size(child);
+            if !printer.is_visible(offset, size) {
+                continue;
+            }
+            let printer = printer
+                .offset(offset)
+                .cropped(size)
+                .focused(self.focus == Some(i));
+            child.view.draw(&printer);
+        }
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        let (rows, cols) = self.dimensions();
+        let mut widths = vec![0; cols];
+        let mut heights = vec![0; rows];
+
+        let sizes: Vec<Vec2> = self
+            .children
+            .iter_mut()
+            .map(|child| child.view.required_size(req))
+            .collect();
+
+        // Single cells first, then make room for the spanning children.
+        let mut order: Vec<usize> = (0..self.children.len()).collect();
+        order.sort_by_key(|&i| self.children[i].rows * self.children[i].cols);
+        for i in order {
+            let child = &self.children[i];
+            let cols = child.col..child.col + child.cols;
+            let rows = child.row..child.row + child.rows;
+
+            let width: usize = widths[cols.clone()].iter().sum();
+            widths[cols.end - 1] += sizes[i].x.saturating_sub(width);
+            let height: usize = heights[rows.clone()].iter().sum();
+            heights[rows.end - 1] += sizes[i].y.saturating_sub(height);
+        }
+
+        let columns: Vec<Column> =
+            (0..cols).map(|col| self.column(col)).collect();
+        for (width, column) in widths.iter_mut().zip(&columns) {
+            *width = max(min(*width, column.max), column.min);
+        }
+
+        let mins: Vec<usize> =
+            columns.iter().map(|column| column.min).collect();
+        shrink(&mut widths, &mins, req.x);
+
+        let mins: Vec<usize> = (0..rows)
+            .map(|row| self.row_heights.get(row).copied().unwrap_or(0))
+            .collect();
+        for (height, &min) in heights.iter_mut().zip(&mins) {
+            *height = max(*height, min);
+        }
+        shrink(&mut heights, &mins, req.y);
+
+        let size = Vec2::new(widths.iter().sum(), heights.iter().sum());
+        self.widths = widths;
+        self.heights = heights;
+        size
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.required_size(size);
+        for i in 0..self.children.len() {
+            let size = self.cell_size(&self.children[i]);
+            self.children[i].view.layout(size);
+        }
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        let mut order = self.reading_order();
+        let children = &self.children;
+        match source {
+            Direction::Abs(Absolute::Left) => {
+                order.sort_by_key(|&i| (children[i].col, children[i].row))
+            }
+            Direction::Abs(Absolute::Right) => order.sort_by_key(|&i| {
+                (usize::MAX - children[i].col, children[i].row)
+            }),
+            Direction::Abs(Absolute::Down)
+            | Direction::Rel(Relative::Back) => order.reverse(),
+            _ => (),
+        }
+        self.focus_first(order, source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let focus = self.focus;
+        let event = self.locate_frozen(event);
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(focus))
+    }
+
+    fn call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        for child in &mut self.children {
+            child.view.call_on_any(selector, callback);
+        }
+    }
+
+    fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
+        for (i, child) in self.children.iter_mut().enumerate() {
+            if child.view.focus_view(selector).is_ok() {
+                self.focus = Some(i);
+                return Ok(());
+            }
+        }
+        Err(())
+    }
+
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        for child in &mut self.children {
+            if let Some(view) = child.view.remove_view(selector) {
+                return Some(view);
+            }
+        }
+
+        let i = self
+            .children
+            .iter_mut()
+            .position(|child| view::contains(&mut *child.view, selector))?;
+        let (row, col) = (self.children[i].row, self.children[i].col);
+        self.remove_child(row, col)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        match self.focus {
+            Some(focus) => {
+                let child = &self.children[focus];
+                let area = child.view.important_area(self.cell_size(child))
+                    + self.offset(child);
+
+                // Frozen cells are shown where we are, and other cells
+                // must not hide below them.
+                let frozen = self.is_frozen(child);
+                let top_left = frozen.select_or(
+                    area.top_left() + self.scroll.get(),
+                    area.top_left().saturating_sub(self.frozen_size()),
+                );
+                let bottom_right = frozen.select_or(
+                    area.bottom_right() + self.scroll.get(),
+                    area.bottom_right(),
+                );
+                Rect::from_corners(top_left, bottom_right)
+            }
+            None => Rect::from_size((0, 0), size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::views::{Button, ScrollView, TextView};
+
+    #[test]
+    fn layout_and_focus() {
+        let button = |label: &str| Button::new(label, |_| ());
+        let mut grid = Grid::new()
+            .child(0, 0, button("1"))
+            .child(0, 1, button("2"))
+            .child(0, 2, TextView::new("long text"))
+            .child_spanning(1, 0, 1, 2, button("0"))
+            .child(1, 2, button("="))
+            .column_max_width(2, 5);
+        assert_eq!(grid.dimensions(), (2, 3));
+        assert_eq!(
+            render_to_buffer(&mut grid, (20, 2)).lines(),
+            ["<1><2>long          ", " <0>   <=>          "]
+        );
+
+        assert!(grid.take_focus(Direction::none()));
+        assert_eq!(grid.get_focus_cell(), Some((0, 0)));
+
+        // Arrows follow the layout, and skip the text.
+        grid.on_event(Event::Key(Key::Right));
+        assert_eq!(grid.get_focus_cell(), Some((0, 1)));
+        grid.on_event(Event::Key(Key::Down));
+        assert_eq!(grid.get_focus_cell(), Some((1, 0)));
+        grid.on_event(Event::Key(Key::Right));
+        assert_eq!(grid.get_focus_cell(), Some((1, 2)));
+        assert!(!grid.on_event(Event::Key(Key::Tab)).is_consumed());
+        grid.on_event(Event::Shift(Key::Tab));
+        assert_eq!(grid.get_focus_cell(), Some((1, 0)));
+    }
+
+    #[test]
+    fn frozen() {
+        let mut grid = Grid::new().frozen_rows(1).frozen_columns(1);
+        for row in 0..5 {
+            for col in 0..5 {
+                let label = format!("{}{}", row, col);
+                grid.add_child(row, col, TextView::new(label));
+            }
+            grid.set_column_min_width(row, 2);
+            grid.set_row_min_height(row, 1);
+        }
+        let mut view =
+            ScrollView::new(grid).scroll_x(true).show_scrollbars(false);
+        render_to_buffer(&mut view, (6, 3));
+
+        // The header row and column stay, and hide what scrolled below.
+        view.set_offset((4, 2));
+        assert_eq!(
+            render_to_buffer(&mut view, (6, 3)).lines(),
+            ["000304", "303334", "404344"]
+        );
+
+        // Clicking a header finds it where it is shown.
+        let grid = view.get_inner_mut();
+        grid.add_child(0, 4, Button::new_raw("04", |_| ()));
+        grid.layout(Vec2::new(10, 5));
+        grid.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(8, 2),
+            event: MouseEvent::Press(crate::event::MouseButton::Left),
+        });
+        assert_eq!(grid.get_focus_cell(), Some((0, 4)));
+    }
+}
@@ -1,6 +1,114 @@
-use crate::theme::ColorStyle;
+use crate::theme::{ColorStyle, ColorType};
 use crate::view::{View, ViewWrapper};
 use crate::Printer;
+use crate::With;
+use std::fmt;
+use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
+
+/// Fill drawn behind a layer, or behind all the layers of a screen.
+///
+/// See [`Layer::set_background`] and [`StackView::set_background`].
+///
+/// [`StackView::set_background`]: crate::views::StackView::set_background
+#[derive(Clone)]
+pub enum Background {
+    /// Fills the area with a color.
+    Color(ColorType),
+
+    /// Repeats some text over the area, with the given style.
+    ///
+    /// Each line of the text is repeated horizontally, and the lines are
+    /// repeated vertically.
+    Pattern(String, ColorStyle),
+
+    /// Draws the background with a custom function.
+    Custom(Rc<dyn Fn(&Printer<'_, '_>)>),
+}
+
+impl Background {
+    /// Fills the area with a color.
+    pub fn color<C: Into<ColorType>>(color: C) -> Self {
+        Background::Color(color.into())
+    }
+
+    /// Repeats some text over the area, with the given style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::ColorStyle;
+    /// use cursive_core::views::Background;
+    ///
+    /// let dots = Background::pattern("· ", ColorStyle::background());
+    /// ```
+    pub fn pattern<S: Into<String>>(pattern: S, style: ColorStyle) -> Self {
+        Background::Pattern(pattern.into(), style)
+    }
+
+    /// Draws the background with a custom function.
+    pub fn custom<F>(f: F) -> Self
+    where
+        F: 'static + Fn(&Printer<'_, '_>),
+    {
+        Background::Custom(Rc::new(f))
+    }
+
+    /// Returns the color used for the empty parts of this background.
+    pub fn color_type(&self) -> ColorType {
+        match *self {
+            Background::Color(color) => color,
+            Background::Pattern(_, style) => style.back,
+            Background::Custom(_) => ColorStyle::background().back,
+        }
+    }
+
+    /// Draws this background on the whole printer area.
+    pub fn draw(&self, printer: &Printer<'_, '_>) {
+        match *self {
+            Background::Color(color) => {
+                let style = ColorStyle::new(color, color);
+                fill(printer, style, &[" "]);
+            }
+            Background::Pattern(ref pattern, style) => {
+                let lines: Vec<&str> = pattern.lines().collect();
+                fill(printer, style, &lines);
+            }
+            Background::Custom(ref f) => f(printer),
+        }
+    }
+}
+
+impl fmt::Debug for Background {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Background::Color(ref color) => {
+                f.debug_tuple("Color").field(color).finish()
+            }
+            Background::Pattern(ref pattern, ref style) => f
+                .debug_tuple("Pattern")
+                .field(pattern)
+                .field(style)
+                .finish(),
+            Background::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+// Fills the printer area, repeating the given lines.
+fn fill(printer: &Printer<'_, '_>, style: ColorStyle, lines: &[&str]) {
+    printer.with_color(style, |printer| {
+        for y in 0..printer.size.y {
+            let line = match lines.get(y % lines.len().max(1)) {
+                Some(line) if line.width() > 0 => line,
+                _ => " ",
+            };
+            let repeats = printer.size.x / line.width() + 1;
+            printer.print_hline((0, y), printer.size.x, " ");
+            printer.print((0, y), &line.repeat(repeats));
+        }
+    });
+}
 
 /// Wrapper view that fills the background.
 ///
@@ -11,6 +119,7 @@ use crate::Printer;
 pub struct Layer<T: View> {
     view: T,
     color: ColorStyle,
+    background: Option<Background>,
 }
 
 impl<T: View> Layer<T> {
@@ -21,7 +130,11 @@ impl<T: View> Layer<T> {
 
     /// Wraps the given view with a custom background color.
     pub fn with_color(view: T, color: ColorStyle) -> Self {
-        Layer { view, color }
+        Layer {
+            view,
+            color,
+            background: None,
+        }
     }
 
     /// Gets the current color.
@@ -34,6 +147,25 @@ impl<T: View> Layer<T> {
         self.color = color;
     }
 
+    /// Sets a background drawn instead of the plain color.
+    ///
+    /// `None` restores the plain color.
+    pub fn set_background(&mut self, background: Option<Background>) {
+        self.background = background;
+    }
+
+    /// Sets a background drawn instead of the plain color.
+    ///
+    /// Chainable variant.
+    pub fn background(self, background: Background) -> Self {
+        self.with(|s| s.set_background(Some(background)))
+    }
+
+    /// Returns the background drawn instead of the plain color, if any.
+    pub fn get_background(&self) -> Option<&Background> {
+        self.background.as_ref()
+    }
+
     inner_getters!(self.view: T);
 }
 
@@ -41,11 +173,14 @@ impl<T: View> ViewWrapper for Layer<T> {
     wrap_impl!(self.view: T);
 
     fn wrap_draw(&self, printer: &Printer<'_, '_>) {
-        printer.with_color(self.color, |printer| {
-            for y in 0..printer.size.y {
-                printer.print_hline((0, y), printer.size.x, " ");
-            }
-        });
+        match self.background {
+            Some(ref background) => background.draw(printer),
+            None => printer.with_color(self.color, |printer| {
+                for y in 0..printer.size.y {
+                    printer.print_hline((0, y), printer.size.x, " ");
+                }
+            }),
+        }
         self.view.draw(printer);
     }
 }
@@ -1,7 +1,8 @@
-use crate::direction;
-use crate::event::{AnyCb, Event, EventResult, Key};
+use crate::direction::{self, Orientation};
+use crate::event::{AnyCb, Event, EventResult, Key, MouseEvent};
+use crate::printer::SeparatorEnds;
 use crate::rect::Rect;
-use crate::view::{IntoBoxedView, Selector, SizeCache, View};
+use crate::view::{self, IntoBoxedView, Selector, SizeCache, View};
 use crate::Printer;
 use crate::Vec2;
 use crate::With;
@@ -12,6 +13,11 @@ use std::ops::Deref;
 
 /// Arranges its children linearly according to its orientation.
 ///
+/// Once enabled with [`share_extra_space`](#method.share_extra_space),
+/// children with a weight share the space left once every child got its
+/// required size, in proportion to their weight. Across the orientation,
+/// each child is stretched by default, or aligned with [`CrossAlign`].
+///
 /// # Examples
 ///
 /// ```
@@ -23,26 +29,91 @@ use std::ops::Deref;
 ///     .child(TextArea::new().fixed_size((20, 5)))
 ///     .child(Button::new("Ok", |s| s.quit()));
 /// ```
+///
+/// ```
+/// use cursive_core::views::{CrossAlign, LinearLayout, TextView};
+///
+/// // The body takes two thirds of the extra space, the footer one third.
+/// let linear_layout = LinearLayout::vertical()
+///     .child(TextView::new("Title"))
+///     .cross_align(CrossAlign::Center)
+///     .child_weighted(TextView::new("Body"), 2)
+///     .child_weighted(TextView::new("Footer"), 1);
+/// ```
 pub struct LinearLayout {
     children: Vec<Child>,
     orientation: direction::Orientation,
     focus: usize,
+    // If true, `take_focus` first tries the last focused child.
+    remember_focus: bool,
+    // If true, a line is drawn between children.
+    separators: bool,
+    // If true, weighted children share the extra space.
+    share_extra: bool,
 
     cache: Option<XY<SizeCache>>,
 }
 
+/// Alignment of a child across the orientation of a [`LinearLayout`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum CrossAlign {
+    /// Aligned on the left, or at the top.
+    Start,
+    /// Centered.
+    Center,
+    /// Aligned on the right, or at the bottom.
+    End,
+    /// Takes the entire space.
+    #[default]
+    Stretch,
+}
+
+impl CrossAlign {
+    // Returns the size and offset of a child in the given space.
+    fn place(self, content: usize, container: usize) -> (usize, usize) {
+        let content = min(content, container);
+        match self {
+            CrossAlign::Start => (content, 0),
+            CrossAlign::Center => (content, (container - content) / 2),
+            CrossAlign::End => (content, container - content),
+            CrossAlign::Stretch => (container, 0),
+        }
+    }
+}
+
 struct Child {
     view: Box<dyn View>,
     // The last result from the child's required_size
     // Doesn't have to be what the child actually gets.
     size: Vec2,
+    // The last required size, untouched by the layout.
+    required: Vec2,
     weight: usize,
+    // Extra length given to the child, from its weight.
+    extra: usize,
+    align: CrossAlign,
+    // Offset across the orientation, from the alignment.
+    cross_offset: usize,
 }
 
 impl Child {
+    fn new(view: Box<dyn View>, weight: usize) -> Self {
+        Child {
+            view,
+            size: Vec2::zero(),
+            required: Vec2::zero(),
+            weight,
+            extra: 0,
+            align: CrossAlign::default(),
+            cross_offset: 0,
+        }
+    }
+
     // Compute and caches the required size.
     fn required_size(&mut self, req: Vec2) -> Vec2 {
         self.size = self.view.required_size(req);
+        self.required = self.size;
+        self.extra = 0;
         self.size
     }
 
@@ -60,6 +131,8 @@ struct ChildIterator<I> {
     available: usize,
     // Orientation for this layout
     orientation: direction::Orientation,
+    // Space between two children
+    spacing: usize,
 }
 
 struct ChildItem<T> {
@@ -72,12 +145,14 @@ impl<T> ChildIterator<T> {
     fn new(
         inner: T,
         orientation: direction::Orientation,
+        spacing: usize,
         available: usize,
     ) -> Self {
         ChildIterator {
             inner,
             available,
             orientation,
+            spacing,
             offset: 0,
         }
     }
@@ -103,6 +178,10 @@ impl<'a, T: Deref<Target = Child>, I: Iterator<Item = T>> Iterator
 
             self.offset += length;
 
+            // Leave room for the separator.
+            self.available = self.available.saturating_sub(self.spacing);
+            self.offset += self.spacing;
+
             ChildItem {
                 offset,
                 length,
@@ -112,6 +191,30 @@ impl<'a, T: Deref<Target = Child>, I: Iterator<Item = T>> Iterator
     }
 }
 
+// Splits `extra` between the children, in proportion to their weight.
+//
+// The rounding leftover goes to the first weighted children.
+fn distribute(weights: &[usize], extra: usize) -> Vec<usize> {
+    let total: usize = weights.iter().sum();
+    if total == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let mut shares: Vec<usize> =
+        weights.iter().map(|w| extra * w / total).collect();
+    let mut leftover = extra - shares.iter().sum::<usize>();
+    for (share, &weight) in shares.iter_mut().zip(weights) {
+        if leftover == 0 {
+            break;
+        }
+        if weight > 0 {
+            *share += 1;
+            leftover -= 1;
+        }
+    }
+    shares
+}
+
 fn cap<'a, I: Iterator<Item = &'a mut usize>>(iter: I, max: usize) {
     let mut available = max;
     for item in iter {
@@ -130,17 +233,75 @@ impl LinearLayout {
             children: Vec::new(),
             orientation,
             focus: 0,
+            remember_focus: false,
+            separators: false,
+            share_extra: false,
             cache: None,
         }
     }
 
     /// Sets the weight of the given child.
     ///
+    /// Children with a weight share the extra space in the layout, if
+    /// enabled with [`set_share_extra_space`](#method.set_share_extra_space).
+    /// A weight of 0, the default, gets no extra space.
+    ///
     /// # Panics
     ///
     /// Panics if `i >= self.len()`.
     pub fn set_weight(&mut self, i: usize, weight: usize) {
         self.children[i].weight = weight;
+        self.invalidate();
+    }
+
+    /// Sets whether weighted children share the extra space.
+    ///
+    /// Disabled by default, so weights set with `set_weight` do not change
+    /// the layout until this is enabled. Adding a child with
+    /// [`add_child_weighted`](#method.add_child_weighted) enables it.
+    pub fn set_share_extra_space(&mut self, share: bool) {
+        self.share_extra = share;
+        self.invalidate();
+    }
+
+    /// Sets whether weighted children share the extra space.
+    ///
+    /// Chainable variant.
+    pub fn share_extra_space(self, share: bool) -> Self {
+        self.with(|s| s.set_share_extra_space(share))
+    }
+
+    /// Returns `true` if weighted children share the extra space.
+    pub fn get_share_extra_space(&self) -> bool {
+        self.share_extra
+    }
+
+    /// Returns the weight of the given child.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.len()`.
+    pub fn get_weight(&self, i: usize) -> usize {
+        self.children[i].weight
+    }
+
+    /// Sets the alignment of the given child across the orientation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= self.len()`.
+    pub fn set_cross_align(&mut self, i: usize, align: CrossAlign) {
+        self.children[i].align = align;
+        self.invalidate();
+    }
+
+    /// Modifies the alignment of the last child added.
+    ///
+    /// It is an error to call this before adding a child (and it will panic).
+    pub fn cross_align(mut self, align: CrossAlign) -> Self {
+        let i = self.children.len() - 1;
+        self.set_cross_align(i, align);
+        self
     }
 
     /// Modifies the weight of the last child added.
@@ -161,11 +322,33 @@ impl LinearLayout {
 
     /// Adds a child to the layout.
     pub fn add_child<V: IntoBoxedView + 'static>(&mut self, view: V) {
-        self.children.push(Child {
-            view: view.as_boxed_view(),
-            size: Vec2::zero(),
-            weight: 0,
-        });
+        self.children.push(Child::new(view.as_boxed_view(), 0));
+        self.invalidate();
+    }
+
+    /// Adds a child with the given weight to the layout.
+    ///
+    /// Chainable variant.
+    pub fn child_weighted<V: IntoBoxedView + 'static>(
+        self,
+        view: V,
+        weight: usize,
+    ) -> Self {
+        self.with(|s| s.add_child_weighted(view, weight))
+    }
+
+    /// Adds a child with the given weight to the layout.
+    ///
+    /// This also enables
+    /// [`set_share_extra_space`](#method.set_share_extra_space). See
+    /// [`set_weight`](#method.set_weight).
+    pub fn add_child_weighted<V: IntoBoxedView + 'static>(
+        &mut self,
+        view: V,
+        weight: usize,
+    ) {
+        self.share_extra = true;
+        self.children.push(Child::new(view.as_boxed_view(), weight));
         self.invalidate();
     }
 
@@ -179,14 +362,7 @@ impl LinearLayout {
         i: usize,
         view: V,
     ) {
-        self.children.insert(
-            i,
-            Child {
-                view: view.as_boxed_view(),
-                size: Vec2::zero(),
-                weight: 0,
-            },
-        );
+        self.children.insert(i, Child::new(view.as_boxed_view(), 0));
         self.invalidate();
     }
 
@@ -211,6 +387,55 @@ impl LinearLayout {
         self.focus
     }
 
+    /// Makes this layout restore the focus on its last focused child.
+    ///
+    /// When the focus comes back to this layout, it goes to the child that
+    /// had it last, instead of the first child in the direction of travel.
+    /// That child is [`get_focus_index`](#method.get_focus_index), and can
+    /// be changed with [`set_focus_index`](#method.set_focus_index).
+    ///
+    /// Defaults to `false`.
+    pub fn set_remember_focus(&mut self, remember_focus: bool) {
+        self.remember_focus = remember_focus;
+    }
+
+    /// Makes this layout restore the focus on its last focused child.
+    ///
+    /// Chainable variant.
+    pub fn remember_focus(self, remember_focus: bool) -> Self {
+        self.with(|s| s.set_remember_focus(remember_focus))
+    }
+
+    /// Returns `true` if this layout restores the focus on its last focused
+    /// child.
+    pub fn get_remember_focus(&self) -> bool {
+        self.remember_focus
+    }
+
+    /// Draws a line between children.
+    ///
+    /// Lines use the border style and colors of the theme, and take one
+    /// row or column each. Where they meet the separators of nested
+    /// layouts, junctions like `┼` are drawn, for table-like appearances.
+    ///
+    /// Defaults to `false`.
+    pub fn set_separators(&mut self, separators: bool) {
+        self.separators = separators;
+        self.invalidate();
+    }
+
+    /// Draws a line between children.
+    ///
+    /// Chainable variant.
+    pub fn separators(self, separators: bool) -> Self {
+        self.with(|s| s.set_separators(separators))
+    }
+
+    /// Returns `true` if lines are drawn between children.
+    pub fn get_separators(&self) -> bool {
+        self.separators
+    }
+
     /// Attemps to set the focus on the given child.
     ///
     /// Returns `Err(())` if `index >= self.len()`, or if the view at the
@@ -329,6 +554,84 @@ impl LinearLayout {
         }
     }
 
+    // Gives the focus back to the last focused child, if it still wants it.
+    fn restore_focus(&mut self) -> bool {
+        self.children
+            .get_mut(self.focus)
+            .map(|child| child.view.take_focus(direction::Direction::none()))
+            .unwrap_or(false)
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        self.check_focus_grab(&event);
+
+        let result = {
+            let mut iterator = ChildIterator::new(
+                self.children.iter_mut(),
+                self.orientation,
+                usize::from(self.separators),
+                usize::max_value(),
+            );
+            let item = iterator.nth(self.focus).unwrap();
+            let offset = self
+                .orientation
+                .make_vec(item.offset, item.child.cross_offset);
+            item.child.view.on_event(event.relativized(offset))
+        };
+        match result {
+            EventResult::Ignored => match event {
+                Event::Shift(Key::Tab) if self.focus > 0 => {
+                    self.move_focus(direction::Direction::back())
+                }
+                Event::Key(Key::Tab)
+                    if self.focus + 1 < self.children.len() =>
+                {
+                    self.move_focus(direction::Direction::front())
+                }
+                Event::Key(Key::Left)
+                    if self.orientation
+                        == direction::Orientation::Horizontal
+                        && self.focus > 0 =>
+                {
+                    self.move_focus(direction::Direction::right())
+                }
+                Event::Key(Key::Up)
+                    if self.orientation
+                        == direction::Orientation::Vertical
+                        && self.focus > 0 =>
+                {
+                    self.move_focus(direction::Direction::down())
+                }
+                Event::Key(Key::Right)
+                    if self.orientation
+                        == direction::Orientation::Horizontal
+                        && self.focus + 1 < self.children.len() =>
+                {
+                    self.move_focus(direction::Direction::left())
+                }
+                Event::Key(Key::Down)
+                    if self.orientation
+                        == direction::Orientation::Vertical
+                        && self.focus + 1 < self.children.len() =>
+                {
+                    self.move_focus(direction::Direction::up())
+                }
+                _ => EventResult::Ignored,
+            },
+            res => res,
+        }
+    }
+
+    // Tells the children about a focus change, after an event.
+    fn notify_focus_change(&mut self, old: usize) -> EventResult {
+        if self.focus == old || old >= self.children.len() {
+            return EventResult::Ignored;
+        }
+        let lost = self.children[old].view.on_event(Event::FocusLost);
+        let focus = self.focus;
+        lost.and(self.children[focus].view.on_event(Event::FocusGained))
+    }
+
     // Attempt to move the focus, coming from the given direction.
     //
     // Consumes the event if the focus was moved, otherwise ignores it.
@@ -377,6 +680,7 @@ impl LinearLayout {
             for (i, item) in ChildIterator::new(
                 self.children.iter_mut(),
                 self.orientation,
+                usize::from(self.separators),
                 // TODO: get actual width (not super important)
                 usize::max_value(),
             )
@@ -387,6 +691,11 @@ impl LinearLayout {
                 let child_size = item.child.size.get(self.orientation);
 
                 if item.offset + child_size > position {
+                    if event == MouseEvent::Hover
+                        && !item.child.view.focus_on_hover()
+                    {
+                        return;
+                    }
                     if item.child.view.take_focus(direction::Direction::none())
                     {
                         self.focus = i;
@@ -396,26 +705,24 @@ impl LinearLayout {
             }
         }
     }
-}
 
-fn try_focus(
-    (i, child): (usize, &mut Child),
-    source: direction::Direction,
-) -> Option<usize> {
-    if child.view.take_focus(source) {
-        Some(i)
-    } else {
-        None
+    // Returns the space taken by the separators.
+    fn separators_size(&self) -> Vec2 {
+        let count = if self.separators {
+            self.children.len().saturating_sub(1)
+        } else {
+            0
+        };
+        self.orientation.make_vec(count, 0)
     }
-}
 
-impl View for LinearLayout {
-    fn draw(&self, printer: &Printer<'_, '_>) {
-        // Use pre-computed sizes
+    // Draws the children, with pre-computed sizes.
+    fn draw_children(&self, printer: &Printer<'_, '_>) {
         // debug!("Pre loop!");
         for (i, item) in ChildIterator::new(
             self.children.iter(),
             self.orientation,
+            usize::from(self.separators),
             *printer.size.get(self.orientation),
         )
         .enumerate()
@@ -423,43 +730,83 @@ impl View for LinearLayout {
             // debug!("Printer size: {:?}", printer.size);
             // debug!("Child size: {:?}", item.child.size);
             // debug!("Offset: {:?}", item.offset);
+            let offset = self
+                .orientation
+                .make_vec(item.offset, item.child.cross_offset);
+
+            // Skip children outside of the visible area (when scrolling).
+            if !printer.is_visible(offset, item.child.size) {
+                continue;
+            }
+
             let printer = &printer
-                .offset(self.orientation.make_vec(item.offset, 0))
+                .offset(offset)
                 .cropped(item.child.size)
                 .focused(i == self.focus);
             item.child.view.draw(printer);
         }
     }
 
-    fn needs_relayout(&self) -> bool {
-        if self.cache.is_none() {
-            return true;
-        }
-
-        !self.children_are_sleeping()
-    }
-
-    fn layout(&mut self, size: Vec2) {
-        // If we can get away without breaking a sweat, you can bet we will.
-        // debug!("Laying out with {:?}", size);
-        if self.get_cache(size).is_none() {
-            self.required_size(size);
-        }
-
-        // We'll use this guy a few times, but it's a mouthful...
+    // Draws the lines between children.
+    //
+    // Junctions are used where the separators of nested layouts end next to
+    // these lines.
+    fn draw_separators(
+        &self,
+        printer: &Printer<'_, '_>,
+        ends: &SeparatorEnds,
+    ) {
         let o = self.orientation;
+        let ends = ends.borrow();
+        // Nested separators meeting ours go along the orientation.
+        let has_end = |pos: Option<Vec2>| {
+            matches!(pos, Some(pos)
+                if ends.contains(&(printer.screen_position(pos), o)))
+        };
 
-        for item in
-            ChildIterator::new(self.children.iter_mut(), o, *size.get(o))
-        {
-            // Every item has the same size orthogonal to the layout
-            item.child.size.set_axis_from(o.swap(), &size);
-
-            item.child.view.layout(size.with_axis(o, item.length));
+        let length = *printer.size.get(o.swap());
+        let lines: Vec<usize> = ChildIterator::new(
+            self.children.iter(),
+            o,
+            1,
+            *printer.size.get(o),
+        )
+        .map(|item| item.offset + item.length)
+        .take(self.children.len().saturating_sub(1))
+        .filter(|&main| main < *printer.size.get(o))
+        .collect();
+        if length == 0 {
+            return;
         }
+
+        printer.with_low_border(false, |printer| {
+            for main in lines {
+                for cross in 0..length {
+                    let before =
+                        main.checked_sub(1).map(|m| o.make_vec(m, cross));
+                    let after = Some(o.make_vec(main + 1, cross));
+                    let c = match (o, has_end(before), has_end(after)) {
+                        (_, true, true) => "┼",
+                        (Orientation::Vertical, false, false) => "─",
+                        (Orientation::Vertical, true, false) => "┴",
+                        (Orientation::Vertical, false, true) => "┬",
+                        (Orientation::Horizontal, false, false) => "│",
+                        (Orientation::Horizontal, true, false) => "┤",
+                        (Orientation::Horizontal, false, true) => "├",
+                    };
+                    printer.print(o.make_vec(main, cross), c);
+                }
+                printer.record_separator_end(o.make_vec(main, 0), o.swap());
+                printer.record_separator_end(
+                    o.make_vec(main, length - 1),
+                    o.swap(),
+                );
+            }
+        });
     }
 
-    fn required_size(&mut self, req: Vec2) -> Vec2 {
+    // Computes the size of the children, without the separators.
+    fn children_required_size(&mut self, req: Vec2) -> Vec2 {
         // Did anything change since last time?
         if let Some(size) = self.get_cache(req) {
             return size;
@@ -590,8 +937,102 @@ impl View for LinearLayout {
 
         compromise
     }
+}
+
+fn try_focus(
+    (i, child): (usize, &mut Child),
+    source: direction::Direction,
+) -> Option<usize> {
+    if child.view.take_focus(source) {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+impl View for LinearLayout {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        if !self.separators {
+            self.draw_children(printer);
+            return;
+        }
+
+        // Nested layouts tell where their own separators end.
+        let ends = SeparatorEnds::default();
+        self.draw_children(&printer.track_separator_ends(&ends));
+        self.draw_separators(printer, &ends);
+        printer.record_separator_ends(&ends);
+    }
+
+    fn needs_relayout(&self) -> bool {
+        if self.cache.is_none() {
+            return true;
+        }
+
+        !self.children_are_sleeping()
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        // Children share what the separators leave.
+        let inner = size.saturating_sub(self.separators_size());
+
+        // If we can get away without breaking a sweat, you can bet we will.
+        // debug!("Laying out with {:?}", size);
+        if self.get_cache(inner).is_none() {
+            self.children_required_size(inner);
+        }
+
+        // We'll use this guy a few times, but it's a mouthful...
+        let o = self.orientation;
+        let spacing = usize::from(self.separators);
+
+        // Share the space left between the weighted children.
+        let used: usize =
+            self.children.iter().map(|c| c.size.get(o) - c.extra).sum();
+        let share_extra = self.share_extra;
+        let weights: Vec<usize> = self
+            .children
+            .iter()
+            .map(|c| if share_extra { c.weight } else { 0 })
+            .collect();
+        let shares = distribute(&weights, inner.get(o).saturating_sub(used));
+        for (child, share) in self.children.iter_mut().zip(shares) {
+            *child.size.get_mut(o) += share;
+            *child.size.get_mut(o) -= child.extra;
+            child.extra = share;
+        }
+
+        for item in ChildIterator::new(
+            self.children.iter_mut(),
+            o,
+            spacing,
+            *size.get(o),
+        ) {
+            // Place the item across the layout
+            let (cross, offset) = item.child.align.place(
+                *item.child.required.get(o.swap()),
+                *size.get(o.swap()),
+            );
+            *item.child.size.get_mut(o.swap()) = cross;
+            item.child.cross_offset = offset;
+
+            item.child
+                .view
+                .layout(item.child.size.with_axis(o, item.length));
+        }
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        let separators = self.separators_size();
+        self.children_required_size(req.saturating_sub(separators))
+            + separators
+    }
 
     fn take_focus(&mut self, source: direction::Direction) -> bool {
+        if self.remember_focus && self.restore_focus() {
+            return true;
+        }
+
         // In what order will we iterate on the children?
         let rel = source.relative(self.orientation);
         // We activate from_focus only if coming from the "sides".
@@ -617,60 +1058,9 @@ impl View for LinearLayout {
             return EventResult::Ignored;
         }
 
-        self.check_focus_grab(&event);
-
-        let result = {
-            let mut iterator = ChildIterator::new(
-                self.children.iter_mut(),
-                self.orientation,
-                usize::max_value(),
-            );
-            let item = iterator.nth(self.focus).unwrap();
-            let offset = self.orientation.make_vec(item.offset, 0);
-            item.child.view.on_event(event.relativized(offset))
-        };
-        match result {
-            EventResult::Ignored => match event {
-                Event::Shift(Key::Tab) if self.focus > 0 => {
-                    self.move_focus(direction::Direction::back())
-                }
-                Event::Key(Key::Tab)
-                    if self.focus + 1 < self.children.len() =>
-                {
-                    self.move_focus(direction::Direction::front())
-                }
-                Event::Key(Key::Left)
-                    if self.orientation
-                        == direction::Orientation::Horizontal
-                        && self.focus > 0 =>
-                {
-                    self.move_focus(direction::Direction::right())
-                }
-                Event::Key(Key::Up)
-                    if self.orientation
-                        == direction::Orientation::Vertical
-                        && self.focus > 0 =>
-                {
-                    self.move_focus(direction::Direction::down())
-                }
-                Event::Key(Key::Right)
-                    if self.orientation
-                        == direction::Orientation::Horizontal
-                        && self.focus + 1 < self.children.len() =>
-                {
-                    self.move_focus(direction::Direction::left())
-                }
-                Event::Key(Key::Down)
-                    if self.orientation
-                        == direction::Orientation::Vertical
-                        && self.focus + 1 < self.children.len() =>
-                {
-                    self.move_focus(direction::Direction::up())
-                }
-                _ => EventResult::Ignored,
-            },
-            res => res,
-        }
+        let focus = self.focus;
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(focus))
     }
 
     fn call_on_any<'a>(
@@ -683,6 +1073,24 @@ impl View for LinearLayout {
         }
     }
 
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        for child in &mut self.children {
+            if let Some(view) = child.view.remove_view(selector) {
+                self.invalidate();
+                return Some(view);
+            }
+        }
+
+        let i = self
+            .children
+            .iter_mut()
+            .position(|child| view::contains(&mut *child.view, selector))?;
+        self.remove_child(i)
+    }
+
     fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
         for (i, child) in self.children.iter_mut().enumerate() {
             if child.view.focus_view(selector).is_ok() {
@@ -705,13 +1113,16 @@ impl View for LinearLayout {
             let mut iterator = ChildIterator::new(
                 self.children.iter(),
                 self.orientation,
+                usize::from(self.separators),
                 usize::max_value(),
             );
             iterator.nth(self.focus).unwrap()
         };
 
         // Make a vector offset from the scalar value
-        let offset = self.orientation.make_vec(item.offset, 0);
+        let offset = self
+            .orientation
+            .make_vec(item.offset, item.child.cross_offset);
 
         // And ask the child its own area.
         let rect = item.child.view.important_area(item.child.size);
@@ -720,3 +1131,99 @@ impl View for LinearLayout {
         rect + offset
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer;
+    use crate::direction::Direction;
+    use crate::views::{Button, Canvas, ScrollView};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn culled_children() {
+        let draws = Rc::new(Cell::new(0));
+        let mut layout = LinearLayout::vertical();
+        for _ in 0..100 {
+            layout.add_child(
+                Canvas::new(Rc::clone(&draws))
+                    .with_draw(|draws, _| draws.set(draws.get() + 1))
+                    .with_required_size(|_, _| Vec2::new(5, 1)),
+            );
+        }
+
+        let mut view = ScrollView::new(layout);
+        buffer::render_to_buffer(&mut view, (10, 5));
+
+        // Only the visible children are drawn.
+        assert_eq!(draws.get(), 5);
+    }
+
+    #[test]
+    fn weights_and_alignment() {
+        use crate::views::TextView;
+
+        let mut layout = LinearLayout::horizontal()
+            .child(TextView::new("a"))
+            .child_weighted(TextView::new("b"), 1)
+            .cross_align(CrossAlign::End)
+            .child_weighted(TextView::new("c"), 2)
+            .cross_align(CrossAlign::Center);
+
+        // 7 extra columns: 2 + 1 for b, 4 for c.
+        let buffer = buffer::render_to_buffer(&mut layout, (10, 3));
+        assert_eq!(buffer.lines(), ["a         ", "     c    ", " b        "]);
+        assert_eq!(distribute(&[0, 1, 2], 7), vec![0, 3, 4]);
+
+        // Weights alone keep the previous layout.
+        let mut layout = LinearLayout::horizontal()
+            .child(TextView::new("a"))
+            .child(TextView::new("b"))
+            .weight(1);
+        layout.layout(Vec2::new(10, 1));
+        assert_eq!(layout.children[1].size, Vec2::new(1, 1));
+
+        layout.set_share_extra_space(true);
+        layout.layout(Vec2::new(10, 1));
+        assert_eq!(layout.children[1].size, Vec2::new(9, 1));
+    }
+
+    #[test]
+    fn separators() {
+        use crate::views::TextView;
+
+        let row = |a, b| {
+            LinearLayout::horizontal()
+                .child(TextView::new(a))
+                .child(TextView::new(b))
+                .separators(true)
+        };
+        let mut layout = LinearLayout::vertical()
+            .child(row("a", "b"))
+            .child(row("c", "d"))
+            .child(TextView::new("e"))
+            .separators(true);
+
+        let buffer = buffer::render_to_buffer(&mut layout, (3, 5));
+        assert_eq!(buffer.lines(), ["a│b", "─┼─", "c│d", "─┴─", "e  "]);
+    }
+
+    #[test]
+    fn remember_focus() {
+        let mut layout = LinearLayout::vertical()
+            .child(Button::new("a", |_| ()))
+            .child(Button::new("b", |_| ()))
+            .child(Button::new("c", |_| ()));
+        layout.set_focus_index(1).unwrap();
+
+        // By default, the focus follows the direction of travel.
+        assert!(layout.take_focus(Direction::up()));
+        assert_eq!(layout.get_focus_index(), 0);
+
+        layout.set_remember_focus(true);
+        layout.set_focus_index(1).unwrap();
+        assert!(layout.take_focus(Direction::up()));
+        assert_eq!(layout.get_focus_index(), 1);
+    }
+}
@@ -1,12 +1,17 @@
+use crate::align::HAlign;
 use crate::direction;
-use crate::event::{AnyCb, Callback, Event, EventResult, Key};
+use crate::event::{AnyCb, Callback, Event, EventResult, Key, MouseEvent};
 use crate::rect::Rect;
-use crate::view::{IntoBoxedView, Selector, View};
+use crate::utils::markup::StyledString;
+use crate::view::{
+    self, IntoBoxedView, SearchStep, Searchable, Selector, View,
+};
 use crate::Cursive;
 use crate::Printer;
 use crate::Vec2;
 use crate::With;
 use log::debug;
+use std::borrow::Cow;
 use std::rc::Rc;
 use unicode_width::UnicodeWidthStr;
 
@@ -38,6 +43,14 @@ impl ListChild {
 pub struct ListView {
     children: Vec<ListChild>,
     focus: usize,
+    // If true, `take_focus` first tries the last focused row.
+    remember_focus: bool,
+    // Alignment of the labels in their column.
+    label_align: HAlign,
+    // Printed after every label, aligned on the widest one.
+    label_suffix: String,
+    // Styled version of each label, if any, in the same order as `children`.
+    styled_labels: Vec<Option<StyledString>>,
     // This callback is called when the selection is changed.
     on_select: Option<Rc<dyn Fn(&mut Cursive, &String)>>,
     last_size: Vec2,
@@ -51,6 +64,10 @@ impl ListView {
         ListView {
             children: Vec::new(),
             focus: 0,
+            remember_focus: false,
+            label_align: HAlign::Left,
+            label_suffix: String::new(),
+            styled_labels: Vec::new(),
             on_select: None,
             last_size: Vec2::zero(),
         }
@@ -88,36 +105,43 @@ impl ListView {
     }
 
     /// Adds a view to the end of the list.
-    pub fn add_child<V: IntoBoxedView + 'static>(
-        &mut self,
-        label: &str,
-        view: V,
-    ) {
+    ///
+    /// The label can be a plain string or a `StyledString`.
+    pub fn add_child<S, V>(&mut self, label: S, view: V)
+    where
+        S: Into<StyledString>,
+        V: IntoBoxedView + 'static,
+    {
         let mut view = view.as_boxed_view();
         view.take_focus(direction::Direction::none());
-        self.children.push(ListChild::Row(label.to_string(), view));
+        let label = label.into();
+        self.children
+            .push(ListChild::Row(label.source().to_string(), view));
+        self.styled_labels.push(Some(label));
     }
 
     /// Removes all children from this view.
     pub fn clear(&mut self) {
         self.children.clear();
+        self.styled_labels.clear();
         self.focus = 0;
     }
 
     /// Adds a view to the end of the list.
     ///
     /// Chainable variant.
-    pub fn child<V: IntoBoxedView + 'static>(
-        self,
-        label: &str,
-        view: V,
-    ) -> Self {
+    pub fn child<S, V>(self, label: S, view: V) -> Self
+    where
+        S: Into<StyledString>,
+        V: IntoBoxedView + 'static,
+    {
         self.with(|s| s.add_child(label, view))
     }
 
     /// Adds a delimiter to the end of the list.
     pub fn add_delimiter(&mut self) {
         self.children.push(ListChild::Delimiter);
+        self.styled_labels.push(None);
     }
 
     /// Adds a delimiter to the end of the list.
@@ -133,9 +157,54 @@ impl ListView {
     ///
     /// If `index >= self.len()`.
     pub fn remove_child(&mut self, index: usize) -> ListChild {
+        self.styled_labels.remove(index);
         self.children.remove(index)
     }
 
+    /// Sets the alignment of the labels in their column.
+    ///
+    /// The column is as wide as the widest label. Use `HAlign::Right` to
+    /// line up the labels against the views.
+    ///
+    /// Defaults to `HAlign::Left`.
+    pub fn set_label_align(&mut self, align: HAlign) {
+        self.label_align = align;
+    }
+
+    /// Sets the alignment of the labels in their column.
+    ///
+    /// Chainable variant.
+    pub fn label_align(self, align: HAlign) -> Self {
+        self.with(|s| s.set_label_align(align))
+    }
+
+    /// Returns the alignment of the labels.
+    pub fn get_label_align(&self) -> HAlign {
+        self.label_align
+    }
+
+    /// Sets a suffix printed after every label, like `":"`.
+    ///
+    /// Suffixes are aligned with each other, right after the label column,
+    /// whatever the alignment of the labels.
+    ///
+    /// Defaults to an empty string.
+    pub fn set_label_suffix<S: Into<String>>(&mut self, suffix: S) {
+        self.label_suffix = suffix.into();
+    }
+
+    /// Sets a suffix printed after every label.
+    ///
+    /// Chainable variant.
+    pub fn label_suffix<S: Into<String>>(self, suffix: S) -> Self {
+        self.with(|s| s.set_label_suffix(suffix))
+    }
+
+    /// Returns the suffix printed after every label.
+    pub fn get_label_suffix(&self) -> &str {
+        &self.label_suffix
+    }
+
     /// Sets a callback to be used when an item is selected.
     pub fn set_on_select<F>(&mut self, cb: F)
     where
@@ -161,6 +230,53 @@ impl ListView {
         self.focus
     }
 
+    /// Attempts to set the focus on the given row.
+    ///
+    /// Returns `Err(())` if `index >= self.len()`, or if the row at the
+    /// given index does not accept focus.
+    ///
+    /// This does not run the `on_select` callback.
+    #[allow(clippy::result_unit_err)]
+    pub fn set_focus(&mut self, index: usize) -> Result<(), ()> {
+        let focused = match self.children.get_mut(index) {
+            Some(ListChild::Row(_, ref mut view)) => {
+                view.take_focus(direction::Direction::none())
+            }
+            _ => false,
+        };
+        if focused {
+            self.focus = index;
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+
+    /// Makes this list restore the focus on its last focused row.
+    ///
+    /// When the focus comes back to this list, it goes to the row that had
+    /// it last, instead of the first row in the direction of travel. That
+    /// row is [`focus`](#method.focus), and can be changed with
+    /// [`set_focus`](#method.set_focus).
+    ///
+    /// Defaults to `false`.
+    pub fn set_remember_focus(&mut self, remember_focus: bool) {
+        self.remember_focus = remember_focus;
+    }
+
+    /// Makes this list restore the focus on its last focused row.
+    ///
+    /// Chainable variant.
+    pub fn remember_focus(self, remember_focus: bool) -> Self {
+        self.with(|s| s.set_remember_focus(remember_focus))
+    }
+
+    /// Returns `true` if this list restores the focus on its last focused
+    /// row.
+    pub fn get_remember_focus(&self) -> bool {
+        self.remember_focus
+    }
+
     fn iter_mut<'a>(
         &'a mut self,
         from_focus: bool,
@@ -205,6 +321,69 @@ impl ListView {
         };
         self.focus = i;
 
+        self.focus_changed()
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        self.check_focus_grab(&event);
+
+        // Send the event to the focused child.
+        let labels_width = self.labels_width();
+        if let ListChild::Row(_, ref mut view) = self.children[self.focus] {
+            // If self.focus < self.scrollbase.start_line, it means the focus is not
+            // in view. Something's fishy, so don't send the event.
+            let offset = (labels_width + 1, self.focus);
+            let result = view.on_event(event.relativized(offset));
+            if result.is_consumed() {
+                return result;
+            }
+        }
+
+        // If the child ignored this event, change the focus.
+        match event {
+            Event::Key(Key::Up) if self.focus > 0 => {
+                self.move_focus(1, direction::Direction::down())
+            }
+            Event::Key(Key::Down) if self.focus + 1 < self.children.len() => {
+                self.move_focus(1, direction::Direction::up())
+            }
+            Event::Key(Key::PageUp) => {
+                self.move_focus(10, direction::Direction::down())
+            }
+            Event::Key(Key::PageDown) => {
+                self.move_focus(10, direction::Direction::up())
+            }
+            Event::Key(Key::Home) | Event::Ctrl(Key::Home) => self
+                .move_focus(usize::max_value(), direction::Direction::back()),
+            Event::Key(Key::End) | Event::Ctrl(Key::End) => self
+                .move_focus(usize::max_value(), direction::Direction::front()),
+            Event::Key(Key::Tab) => {
+                self.move_focus(1, direction::Direction::front())
+            }
+            Event::Shift(Key::Tab) => {
+                self.move_focus(1, direction::Direction::back())
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    // Tells the children about a focus change, after an event.
+    fn notify_focus_change(&mut self, old: usize) -> EventResult {
+        if self.focus == old {
+            return EventResult::Ignored;
+        }
+        let focus = self.focus;
+        let mut notify =
+            |i: usize, event: Event| match self.children.get_mut(i) {
+                Some(ListChild::Row(_, view)) => view.on_event(event),
+                _ => EventResult::Ignored,
+            };
+        let lost = notify(old, Event::FocusLost);
+        lost.and(notify(focus, Event::FocusGained))
+    }
+
+    // Runs the `on_select` callback for the current focus.
+    fn focus_changed(&self) -> EventResult {
         EventResult::Consumed(self.on_select.clone().map(|cb| {
             let i = self.focus();
             let focused_string = String::from(self.children[i].label());
@@ -212,15 +391,33 @@ impl ListView {
         }))
     }
 
-    fn labels_width(&self) -> usize {
-        self.children
-            .iter()
-            .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
+    // Returns the styled label of the given row.
+    //
+    // Falls back to the plain label if the row was replaced through
+    // `row_mut`.
+    fn styled_label(&self, i: usize) -> Cow<'_, StyledString> {
+        let label = self.children[i].label();
+        match self.styled_labels.get(i) {
+            Some(Some(styled)) if styled.source() == label => {
+                Cow::Borrowed(styled)
+            }
+            _ => Cow::Owned(StyledString::plain(label)),
+        }
+    }
+
+    // Width of the widest label, without the suffix.
+    fn max_label_width(&self) -> usize {
+        (0..self.children.len())
+            .map(|i| self.styled_label(i).width())
             .max()
             .unwrap_or(0)
     }
 
+    // Width of the label column, including the suffix.
+    fn labels_width(&self) -> usize {
+        self.max_label_width() + self.label_suffix.width()
+    }
+
     fn check_focus_grab(&mut self, event: &Event) {
         if let Event::Mouse {
             offset,
@@ -246,6 +443,9 @@ impl ListView {
             }
 
             if let ListChild::Row(_, ref mut view) = self.children[focus] {
+                if event == MouseEvent::Hover && !view.focus_on_hover() {
+                    return;
+                }
                 if view.take_focus(direction::Direction::none()) {
                     self.focus = focus;
                 }
@@ -270,19 +470,50 @@ fn try_focus(
     }
 }
 
+impl Searchable for ListView {
+    fn search(&mut self, query: &str, step: SearchStep) -> EventResult {
+        let order = view::search_order(self.children.len(), self.focus, step);
+        for i in order {
+            let found = match self.children[i] {
+                ListChild::Row(ref label, ref mut view) => {
+                    view::is_match(label, query)
+                        && view.take_focus(direction::Direction::none())
+                }
+                ListChild::Delimiter => false,
+            };
+            if found {
+                self.focus = i;
+                return self.focus_changed();
+            }
+        }
+
+        EventResult::Ignored
+    }
+}
+
 impl View for ListView {
     fn draw(&self, printer: &Printer<'_, '_>) {
         if self.children.is_empty() {
             return;
         }
 
+        let label_width = self.max_label_width();
         let offset = self.labels_width() + 1;
 
         debug!("Offset: {}", offset);
         for (i, child) in self.children.iter().enumerate() {
+            // Skip rows outside of the visible area (when scrolling).
+            if !printer.is_visible((0, i), (printer.size.x, 1)) {
+                continue;
+            }
             match child {
-                ListChild::Row(ref label, ref view) => {
-                    printer.print((0, i), label);
+                ListChild::Row(_, ref view) => {
+                    let label = self.styled_label(i);
+                    let x = self
+                        .label_align
+                        .get_offset(label.width(), label_width);
+                    printer.print_styled((x, i), label.as_ref().into());
+                    printer.print((label_width, i), &self.label_suffix);
                     view.draw(
                         &printer.offset((offset, i)).focused(i == self.focus),
                     );
@@ -294,13 +525,7 @@ impl View for ListView {
 
     fn required_size(&mut self, req: Vec2) -> Vec2 {
         // We'll show 2 columns: the labels, and the views.
-        let label_width = self
-            .children
-            .iter()
-            .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
-            .max()
-            .unwrap_or(0);
+        let label_width = self.labels_width();
 
         let view_size = self
             .children
@@ -313,17 +538,25 @@ impl View for ListView {
         Vec2::new(label_width + 1 + view_size, self.children.len())
     }
 
+    fn measure(&mut self, req: Vec2) -> Vec2 {
+        let view_size = self
+            .children
+            .iter_mut()
+            .filter_map(|child| match *child {
+                ListChild::Row(_, ref mut view) => Some(view.measure(req).x),
+                ListChild::Delimiter => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        Vec2::new(self.labels_width() + 1 + view_size, self.children.len())
+    }
+
     fn layout(&mut self, size: Vec2) {
         self.last_size = size;
 
         // We'll show 2 columns: the labels, and the views.
-        let label_width = self
-            .children
-            .iter()
-            .map(ListChild::label)
-            .map(UnicodeWidthStr::width)
-            .max()
-            .unwrap_or(0);
+        let label_width = self.labels_width();
 
         let spacing = 1;
 
@@ -341,49 +574,16 @@ impl View for ListView {
             return EventResult::Ignored;
         }
 
-        self.check_focus_grab(&event);
-
-        // Send the event to the focused child.
-        let labels_width = self.labels_width();
-        if let ListChild::Row(_, ref mut view) = self.children[self.focus] {
-            // If self.focus < self.scrollbase.start_line, it means the focus is not
-            // in view. Something's fishy, so don't send the event.
-            let offset = (labels_width + 1, self.focus);
-            let result = view.on_event(event.relativized(offset));
-            if result.is_consumed() {
-                return result;
-            }
-        }
-
-        // If the child ignored this event, change the focus.
-        match event {
-            Event::Key(Key::Up) if self.focus > 0 => {
-                self.move_focus(1, direction::Direction::down())
-            }
-            Event::Key(Key::Down) if self.focus + 1 < self.children.len() => {
-                self.move_focus(1, direction::Direction::up())
-            }
-            Event::Key(Key::PageUp) => {
-                self.move_focus(10, direction::Direction::down())
-            }
-            Event::Key(Key::PageDown) => {
-                self.move_focus(10, direction::Direction::up())
-            }
-            Event::Key(Key::Home) | Event::Ctrl(Key::Home) => self
-                .move_focus(usize::max_value(), direction::Direction::back()),
-            Event::Key(Key::End) | Event::Ctrl(Key::End) => self
-                .move_focus(usize::max_value(), direction::Direction::front()),
-            Event::Key(Key::Tab) => {
-                self.move_focus(1, direction::Direction::front())
-            }
-            Event::Shift(Key::Tab) => {
-                self.move_focus(1, direction::Direction::back())
-            }
-            _ => EventResult::Ignored,
-        }
+        let focus = self.focus;
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(focus))
     }
 
     fn take_focus(&mut self, source: direction::Direction) -> bool {
+        if self.remember_focus && self.set_focus(self.focus).is_ok() {
+            return true;
+        }
+
         let rel = source.relative(direction::Orientation::Vertical);
         let i = if let Some(i) = self
             .iter_mut(rel.is_none(), rel.unwrap_or(direction::Relative::Front))
@@ -409,6 +609,34 @@ impl View for ListView {
         }
     }
 
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        for view in self.children.iter_mut().filter_map(ListChild::view) {
+            if let Some(view) = view.remove_view(selector) {
+                return Some(view);
+            }
+        }
+
+        let i = self.children.iter_mut().position(|child| {
+            child
+                .view()
+                .map(|view| view::contains(view, selector))
+                .unwrap_or(false)
+        })?;
+
+        // Keep the same row focused, if possible.
+        if self.focus > i || self.focus + 1 == self.children.len() {
+            self.focus = self.focus.saturating_sub(1);
+        }
+        self.styled_labels.remove(i);
+        match self.children.remove(i) {
+            ListChild::Row(_, view) => Some(view),
+            ListChild::Delimiter => None,
+        }
+    }
+
     fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
         if let Some(i) = self
             .children
@@ -444,3 +672,52 @@ impl View for ListView {
         area + (0, self.focus)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::views::TextView;
+
+    #[test]
+    fn label_align() {
+        let mut list = ListView::new()
+            .label_align(HAlign::Right)
+            .label_suffix(":")
+            .child("Name", TextView::new("a"))
+            .child("Id", TextView::new("b"));
+
+        assert_eq!(
+            render_to_buffer(&mut list, (8, 2)).lines(),
+            ["Name: a ", "  Id: b "]
+        );
+    }
+
+    #[test]
+    fn styled_labels() {
+        use crate::theme::Effect;
+
+        let mut list = ListView::new()
+            .delimiter()
+            .child(
+                StyledString::styled("Name", Effect::Bold),
+                TextView::new("a"),
+            )
+            .child("Id", TextView::new("b"));
+
+        match list.get_row(1) {
+            ListChild::Row(label, _) => assert_eq!(label, "Name"),
+            ListChild::Delimiter => panic!("expected a row"),
+        }
+
+        list.remove_child(0);
+        let buffer = render_to_buffer(&mut list, (7, 2));
+        assert_eq!(buffer.lines(), ["Name a ", "Id   b "]);
+        let bold = |y| {
+            let cell = buffer.get(Vec2::new(0, y)).unwrap();
+            cell.effects.contains(Effect::Bold)
+        };
+        assert!(bold(0));
+        assert!(!bold(1));
+    }
+}
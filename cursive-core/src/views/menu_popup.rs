@@ -2,7 +2,7 @@ use crate::align::Align;
 use crate::event::{
     Callback, Event, EventResult, Key, MouseButton, MouseEvent,
 };
-use crate::menu::{MenuItem, MenuTree};
+use crate::menu::{self, MenuItem, MenuTree};
 use crate::rect::Rect;
 use crate::view::scroll;
 use crate::view::{Position, View};
@@ -13,6 +13,7 @@ use crate::Vec2;
 use crate::With;
 use std::cmp::min;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 use unicode_width::UnicodeWidthStr;
 
 /// Popup that shows a list of items.
@@ -29,8 +30,26 @@ pub struct MenuPopup {
     align: Align,
     on_dismiss: Option<Callback>,
     on_action: Option<Callback>,
+
+    // Delay before opening a submenu under the mouse.
+    submenu_delay: Duration,
+    // Subtree item under the mouse, and since when.
+    hover: Option<(usize, Instant)>,
+    // Printed after the label of subtrees.
+    subtree_arrow: String,
+
+    // Requested number of columns, or 0 to fit the available space.
+    columns: usize,
+    // Number of columns used for the last layout.
+    grid_columns: usize,
 }
 
+/// Default delay before opening a submenu under the mouse.
+const SUBMENU_DELAY: Duration = Duration::from_millis(300);
+
+/// Default mark after the label of subtrees.
+const SUBTREE_ARROW: &str = ">>";
+
 // The `scroll::Scroller` trait is used to weave the borrow phases.
 //
 // TODO: use some macro to auto-generate this.
@@ -46,9 +65,91 @@ impl MenuPopup {
             align: Align::top_left(),
             on_dismiss: None,
             on_action: None,
+            submenu_delay: SUBMENU_DELAY,
+            hover: None,
+            subtree_arrow: String::from(SUBTREE_ARROW),
+            columns: 1,
+            grid_columns: 1,
         }
     }
 
+    /// Sets the number of columns used to show the items.
+    ///
+    /// Items fill the first column from top to bottom, then the next ones.
+    /// `Left` and `Right` move between columns.
+    ///
+    /// Use `0` to pick as many columns as needed to avoid scrolling, within
+    /// the available width. Defaults to `1`.
+    pub fn set_columns(&mut self, columns: usize) {
+        self.columns = columns;
+        self.grid_columns = columns.max(1);
+    }
+
+    /// Sets the number of columns used to show the items.
+    ///
+    /// Chainable variant.
+    pub fn columns(self, columns: usize) -> Self {
+        self.with(|s| s.set_columns(columns))
+    }
+
+    /// Returns the width of a column, including padding.
+    fn column_width(&self) -> usize {
+        2 + (0..self.menu.len())
+            .map(|i| self.item_width(i))
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// Returns the number of rows in the grid.
+    fn rows(&self) -> usize {
+        let len = self.menu.len();
+        len.div_ceil(self.grid_columns)
+    }
+
+    /// Returns the column and row of the given item.
+    fn cell_of(&self, i: usize) -> Vec2 {
+        let rows = self.rows().max(1);
+        Vec2::new(i / rows, i % rows)
+    }
+
+    /// Returns the item at the given position in the content, if any.
+    fn item_at(&self, position: Vec2) -> Option<usize> {
+        let column = if self.grid_columns == 1 {
+            0
+        } else {
+            position.x / self.column_width()
+        };
+        if column >= self.grid_columns || position.y >= self.rows() {
+            return None;
+        }
+        Some(column * self.rows() + position.y)
+            .filter(|&i| i < self.menu.len())
+            .filter(|&i| !self.menu.children[i].is_delimiter())
+    }
+
+    /// Moves the focus to the same row in another column.
+    ///
+    /// Returns `false` if there is no such item.
+    fn move_column(&mut self, right: bool) -> bool {
+        let rows = self.rows();
+        let target = if right {
+            self.focus + rows
+        } else {
+            match self.focus.checked_sub(rows) {
+                Some(target) => target,
+                None => return false,
+            }
+        };
+        if self.grid_columns == 1
+            || target >= self.menu.len()
+            || self.menu.children[target].is_delimiter()
+        {
+            return false;
+        }
+        self.focus = target;
+        true
+    }
+
     /// Sets the currently focused element.
     pub fn set_focus(&mut self, focus: usize) {
         self.focus = min(focus, self.menu.len());
@@ -66,11 +167,22 @@ impl MenuPopup {
         self.focus
     }
 
-    fn item_width(item: &MenuItem) -> usize {
+    fn item_width(&self, i: usize) -> usize {
+        let item = &self.menu.children[i];
         match *item {
             MenuItem::Delimiter => 1,
-            MenuItem::Leaf(ref title, _) => title.width(),
-            MenuItem::Subtree(ref title, _) => title.width() + 3,
+            MenuItem::Leaf(_, _) => {
+                // Accelerators are shown after a gap.
+                let hint = self
+                    .menu
+                    .accelerator(i)
+                    .and_then(menu::describe_accelerator)
+                    .map_or(0, |hint| hint.width() + 2);
+                self.menu.text(item).width() + hint
+            }
+            MenuItem::Subtree(_, _) => {
+                self.menu.text(item).width() + self.subtree_arrow.width() + 1
+            }
         }
     }
 
@@ -86,6 +198,41 @@ impl MenuPopup {
         self.align = align;
     }
 
+    /// Sets the delay before opening a submenu under the mouse.
+    ///
+    /// This needs mouse moves, which backends only report with
+    /// [`Cursive::set_focus_follows_mouse`]. Defaults to 300ms.
+    pub fn set_submenu_delay(&mut self, delay: Duration) {
+        self.submenu_delay = delay;
+    }
+
+    /// Sets the delay before opening a submenu under the mouse.
+    ///
+    /// Chainable variant.
+    pub fn submenu_delay(self, delay: Duration) -> Self {
+        self.with(|s| s.set_submenu_delay(delay))
+    }
+
+    /// Sets the mark shown after the label of subtrees.
+    ///
+    /// Submenus use the same mark. Defaults to `>>`, for terminals without
+    /// unicode support; `▶` is a common alternative.
+    pub fn set_subtree_arrow<S: Into<String>>(&mut self, arrow: S) {
+        self.subtree_arrow = arrow.into();
+    }
+
+    /// Sets the mark shown after the label of subtrees.
+    ///
+    /// Chainable variant.
+    pub fn subtree_arrow<S: Into<String>>(self, arrow: S) -> Self {
+        self.with(|s| s.set_subtree_arrow(arrow))
+    }
+
+    /// Returns the mark shown after the label of subtrees.
+    pub fn get_subtree_arrow(&self) -> &str {
+        &self.subtree_arrow
+    }
+
     /// Sets a callback to be used when this view is actively dismissed.
     ///
     /// (When the user hits <ESC>)
@@ -188,31 +335,31 @@ impl MenuPopup {
 
     fn make_subtree_cb(&self, tree: &Rc<MenuTree>) -> EventResult {
         let tree = Rc::clone(tree);
-        let max_width = 4 + self
-            .menu
-            .children
-            .iter()
-            .map(MenuPopup::item_width)
-            .max()
-            .unwrap_or(1);
-        let offset = Vec2::new(max_width, self.focus);
+        let cell = self.cell_of(self.focus);
+        let max_width = 2 + self.column_width() * (cell.x + 1);
+        let offset = Vec2::new(max_width, cell.y);
         let action_cb = self.on_action.clone();
+        let delay = self.submenu_delay;
+        let arrow = self.subtree_arrow.clone();
 
         EventResult::with_cb(move |s| {
             let action_cb = action_cb.clone();
             s.screen_mut().add_layer_at(
                 Position::parent(offset),
-                OnEventView::new(MenuPopup::new(Rc::clone(&tree)).on_action(
-                    move |s| {
-                        // This will happen when the subtree popup
-                        // activates something;
-                        // First, remove ourselve.
-                        s.pop_layer();
-                        if let Some(ref action_cb) = action_cb {
-                            action_cb.clone()(s);
-                        }
-                    },
-                ))
+                OnEventView::new(
+                    MenuPopup::new(Rc::clone(&tree))
+                        .submenu_delay(delay)
+                        .subtree_arrow(arrow.clone())
+                        .on_action(move |s| {
+                            // This will happen when the subtree popup
+                            // activates something;
+                            // First, remove ourselve.
+                            s.pop_layer();
+                            if let Some(ref action_cb) = action_cb {
+                                action_cb.clone()(s);
+                            }
+                        }),
+                )
                 .on_event(Key::Left, |s| {
                     s.pop_layer();
                 }),
@@ -220,6 +367,30 @@ impl MenuPopup {
         })
     }
 
+    /// Opens the focused item if it is a subtree.
+    fn open_subtree(&self) -> EventResult {
+        match self.menu.children[self.focus] {
+            MenuItem::Subtree(_, ref tree) => self.make_subtree_cb(tree),
+            _ => EventResult::Ignored,
+        }
+    }
+
+    /// Focuses the item under the mouse.
+    ///
+    /// If it is a subtree, it will be opened after a delay: `draw` asks
+    /// for new frames until then, and `Event::Refresh` opens it.
+    fn hover(&mut self, focus: usize) -> EventResult {
+        if focus != self.focus {
+            self.focus = focus;
+            self.hover = None;
+        }
+
+        if self.hover.is_none() && self.menu.children[focus].is_subtree() {
+            self.hover = Some((focus, Instant::now()));
+        }
+        EventResult::Consumed(None)
+    }
+
     /// Handle an event for the content.
     ///
     /// Here the event has already been relativized. This means `y=0` points to the first item.
@@ -235,14 +406,36 @@ impl MenuPopup {
                 self.focus = self.menu.children.len().saturating_sub(1)
             }
 
+            Event::Key(Key::Left) if self.move_column(false) => (),
+            Event::Key(Key::Right) if self.move_column(true) => (),
             Event::Key(Key::Right)
                 if self.menu.children[self.focus].is_subtree() =>
             {
-                return match self.menu.children[self.focus] {
-                    MenuItem::Subtree(_, ref tree) => {
-                        self.make_subtree_cb(tree)
+                return self.open_subtree();
+            }
+            Event::Refresh => {
+                return match self.hover {
+                    Some((focus, since))
+                        if focus == self.focus
+                            && since.elapsed() >= self.submenu_delay =>
+                    {
+                        self.hover = None;
+                        self.open_subtree()
                     }
-                    _ => unreachable!("Child is a subtree"),
+                    _ => EventResult::Ignored,
+                };
+            }
+            Event::Mouse {
+                event: MouseEvent::Move | MouseEvent::Hover,
+                position,
+                offset,
+            } => {
+                // Moving over the border or a delimiter is just ignored.
+                let focus =
+                    position.checked_sub(offset).and_then(|p| self.item_at(p));
+                return match focus {
+                    Some(focus) => self.hover(focus),
+                    None => EventResult::Ignored,
                 };
             }
             Event::Key(Key::Enter)
@@ -256,14 +449,11 @@ impl MenuPopup {
                 offset,
             } => {
                 // eprintln!("Position: {:?} / {:?}", position, offset);
-                if let Some(position) = position.checked_sub(offset) {
-                    // Now `position` is relative to the top-left of the content.
-                    let focus = position.y;
-                    if focus < self.menu.len()
-                        && !self.menu.children[focus].is_delimiter()
-                    {
-                        self.focus = focus;
-                    }
+                // `position` is made relative to the top-left of the content.
+                if let Some(focus) =
+                    position.checked_sub(offset).and_then(|p| self.item_at(p))
+                {
+                    self.focus = focus;
                 }
             }
             Event::Mouse {
@@ -273,14 +463,23 @@ impl MenuPopup {
             } if !self.menu.children[self.focus].is_delimiter()
                 && position
                     .checked_sub(offset)
-                    .map(|position| position.y == self.focus)
-                    .unwrap_or(false) =>
+                    .and_then(|position| self.item_at(position))
+                    == Some(self.focus) =>
             {
                 return self.submit();
             }
             Event::Key(Key::Esc) => {
                 return self.dismiss();
             }
+            Event::AltChar(c) => {
+                return match self.menu.find_mnemonic(c) {
+                    Some(focus) => {
+                        self.focus = focus;
+                        self.submit()
+                    }
+                    None => EventResult::Ignored,
+                };
+            }
 
             _ => return EventResult::Ignored,
         }
@@ -289,18 +488,20 @@ impl MenuPopup {
     }
 
     /// Compute the required size for the content.
-    fn inner_required_size(&mut self, _req: Vec2) -> Vec2 {
-        let w = 2 + self
-            .menu
-            .children
-            .iter()
-            .map(Self::item_width)
-            .max()
-            .unwrap_or(1);
-
-        let h = self.menu.children.len();
+    fn inner_required_size(&mut self, req: Vec2) -> Vec2 {
+        let w = self.column_width();
+        let len = self.menu.len();
+
+        self.grid_columns = if self.columns == 0 {
+            // Enough columns to avoid scrolling, if they fit.
+            let needed = len.div_ceil(req.y.max(1));
+            needed.min(req.x / w).max(1)
+        } else {
+            self.columns
+        }
+        .min(len.max(1));
 
-        Vec2::new(w, h)
+        Vec2::new(w * self.grid_columns, self.rows())
     }
 
     fn inner_important_area(&self, size: Vec2) -> Rect {
@@ -308,7 +509,55 @@ impl MenuPopup {
             return Rect::from((0, 0));
         }
 
-        Rect::from_size((0, self.focus), (size.x, 1))
+        if self.grid_columns == 1 {
+            return Rect::from_size((0, self.focus), (size.x, 1));
+        }
+
+        let w = self.column_width();
+        let cell = self.cell_of(self.focus);
+        Rect::from_size((cell.x * w, cell.y), (w, 1))
+    }
+
+    /// Draws a single item on the given line.
+    fn draw_item(&self, printer: &Printer<'_, '_>, i: usize) {
+        let item = &self.menu.children[i];
+        printer.with_selection(i == self.focus, |printer| {
+            match *item {
+                MenuItem::Delimiter => {
+                    printer.print_hline((0, 0), printer.size.x, "─");
+                }
+                MenuItem::Subtree(_, _) => {
+                    if printer.size.x < 4 {
+                        return;
+                    }
+                    printer.print_hline((0, 0), printer.size.x, " ");
+                    self.menu.print_label(printer, (1, 0).into(), item);
+                    let x = printer
+                        .size
+                        .x
+                        .saturating_sub(self.subtree_arrow.width() + 1);
+                    printer.print((x, 0), &self.subtree_arrow);
+                }
+                MenuItem::Leaf(_, _) => {
+                    if printer.size.x < 2 {
+                        return;
+                    }
+                    printer.print_hline((0, 0), printer.size.x, " ");
+                    self.menu.print_label(printer, (1, 0).into(), item);
+
+                    // Right-aligned, like the subtree arrows.
+                    if let Some(hint) = self
+                        .menu
+                        .accelerator(i)
+                        .and_then(menu::describe_accelerator)
+                    {
+                        let x =
+                            printer.size.x.saturating_sub(hint.width() + 1);
+                        printer.print((x, 0), &hint);
+                    }
+                }
+            }
+        });
     }
 }
 
@@ -318,48 +567,46 @@ impl View for MenuPopup {
             return;
         }
 
-        let h = self.menu.len();
+        // Keep refreshing until the hovered submenu opens.
+        if self.hover.is_some() {
+            printer.request_frame();
+        }
+
+        let h = self.rows();
         // If we're too high, add a vertical offset
         let offset = self.align.v.get_offset(h, printer.size.y);
         let printer = &printer.offset((0, offset));
 
         // Start with a box
+        // Delimiters only reach the border with a single column.
         scroll::draw_box_frame(
             self,
             &printer,
-            |s, y| s.menu.children[y].is_delimiter(),
+            |s, y| s.grid_columns == 1 && s.menu.children[y].is_delimiter(),
             |_s, _x| false,
         );
 
         // We're giving it a reduced size because of borders.
         let printer = printer.shrinked_centered((2, 2));
 
-        scroll::draw_lines(self, &printer, |s, printer, i| {
-            printer.with_selection(i == s.focus, |printer| {
-                let item = &s.menu.children[i];
-                match *item {
-                    MenuItem::Delimiter => {
-                        // printer.print_hdelim((0, 0), printer.size.x)
-                        printer.print_hline((0, 0), printer.size.x, "─");
-                    }
-                    MenuItem::Subtree(ref label, _) => {
-                        if printer.size.x < 4 {
-                            return;
-                        }
-                        printer.print_hline((0, 0), printer.size.x, " ");
-                        printer.print((1, 0), label);
-                        let x = printer.size.x.saturating_sub(3);
-                        printer.print((x, 0), ">>");
-                    }
-                    MenuItem::Leaf(ref label, _) => {
-                        if printer.size.x < 2 {
-                            return;
-                        }
-                        printer.print_hline((0, 0), printer.size.x, " ");
-                        printer.print((1, 0), label);
-                    }
-                }
+        if self.grid_columns == 1 {
+            scroll::draw_lines(self, &printer, |s, printer, i| {
+                s.draw_item(printer, i);
             });
+            return;
+        }
+
+        let w = self.column_width();
+        let rows = self.rows();
+        scroll::draw_lines(self, &printer, |s, printer, row| {
+            for column in 0..s.grid_columns {
+                let i = column * rows + row;
+                if i >= s.menu.len() {
+                    break;
+                }
+                let printer = printer.offset((column * w, 0)).cropped((w, 1));
+                s.draw_item(&printer, i);
+            }
         });
     }
 
@@ -384,6 +631,15 @@ impl View for MenuPopup {
             Self::inner_important_area,
         ) {
             EventResult::Ignored => {
+                // The mouse left the items: cancel any pending submenu.
+                if let Event::Mouse {
+                    event: MouseEvent::Move | MouseEvent::Hover,
+                    ..
+                } = event
+                {
+                    self.hover = None;
+                }
+
                 // Check back the non-relativized event now
                 if let Event::Mouse {
                     event: MouseEvent::Press(_),
@@ -434,3 +690,99 @@ impl View for MenuPopup {
         .with(|area| area.offset((1, 1)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hover_opens_submenu() {
+        let menu = MenuTree::new()
+            .leaf("Leaf", |_| ())
+            .subtree("Sub", MenuTree::new().leaf("Inner", |_| ()));
+        let mut popup = MenuPopup::new(Rc::new(menu))
+            .submenu_delay(Duration::from_millis(0));
+        popup.layout(Vec2::new(20, 10));
+
+        // Hovering a leaf only moves the focus.
+        let hover_leaf = Event::Mouse {
+            event: MouseEvent::Move,
+            position: Vec2::new(2, 1),
+            offset: Vec2::zero(),
+        };
+        assert!(!popup.on_event(hover_leaf).has_callback());
+        assert!(!popup.on_event(Event::Refresh).has_callback());
+
+        let hover_subtree = Event::Mouse {
+            event: MouseEvent::Move,
+            position: Vec2::new(2, 2),
+            offset: Vec2::zero(),
+        };
+        assert!(!popup.on_event(hover_subtree.clone()).has_callback());
+        assert_eq!(popup.get_focus(), 1);
+        assert!(popup.on_event(Event::Refresh).has_callback());
+
+        // Leaving the menu cancels the submenu.
+        popup.on_event(hover_subtree);
+        popup.on_event(Event::Mouse {
+            event: MouseEvent::Move,
+            position: Vec2::new(30, 2),
+            offset: Vec2::zero(),
+        });
+        assert!(!popup.on_event(Event::Refresh).has_callback());
+    }
+
+    #[test]
+    fn subtree_arrow() {
+        use crate::buffer::render_to_buffer;
+
+        let menu = Rc::new(
+            MenuTree::new().subtree("Sub", MenuTree::new().leaf("a", |_| ())),
+        );
+
+        let mut popup = MenuPopup::new(Rc::clone(&menu));
+        assert_eq!(popup.required_size(Vec2::new(20, 5)), Vec2::new(10, 3));
+        assert_eq!(
+            render_to_buffer(&mut popup, (10, 3)).lines()[1],
+            "│ Sub >> │"
+        );
+
+        let mut popup = MenuPopup::new(menu).subtree_arrow("▶");
+        assert_eq!(
+            render_to_buffer(&mut popup, (9, 3)).lines()[1],
+            "│ Sub ▶ │"
+        );
+    }
+
+    #[test]
+    fn columns() {
+        let mut menu = MenuTree::new();
+        for label in &["a", "b", "c", "d", "e"] {
+            menu.add_leaf(*label, |_| ());
+        }
+        let menu = Rc::new(menu);
+
+        // Columns of width 3, with borders.
+        let mut popup = MenuPopup::new(Rc::clone(&menu)).columns(2);
+        assert_eq!(popup.required_size(Vec2::new(80, 24)), Vec2::new(8, 5));
+        popup.layout(Vec2::new(8, 5));
+
+        popup.on_event(Event::Key(Key::Right));
+        assert_eq!(popup.get_focus(), 3);
+        // There is no item on the right of `c`.
+        popup.set_focus(2);
+        popup.on_event(Event::Key(Key::Right));
+        assert_eq!(popup.get_focus(), 2);
+
+        popup.on_event(Event::Mouse {
+            event: MouseEvent::Press(MouseButton::Left),
+            position: Vec2::new(4, 2),
+            offset: Vec2::zero(),
+        });
+        assert_eq!(popup.get_focus(), 4);
+
+        // Automatic columns avoid scrolling.
+        let mut popup = MenuPopup::new(menu).columns(0);
+        assert_eq!(popup.required_size(Vec2::new(80, 4)), Vec2::new(11, 4));
+    }
+}
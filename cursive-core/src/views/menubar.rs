@@ -42,6 +42,9 @@ pub struct Menubar {
 
     // TODO: make Menubar impl View and take out the State management
     state: State,
+
+    // Given to the menus opened from this menubar.
+    subtree_arrow: Option<String>,
 }
 
 new_default!(Menubar);
@@ -54,6 +57,7 @@ impl Menubar {
             autohide: true,
             state: State::Inactive,
             focus: 0,
+            subtree_arrow: None,
         }
     }
 
@@ -77,6 +81,37 @@ impl Menubar {
         !self.autohide || self.state != State::Inactive
     }
 
+    /// Replaces all the items in the menubar.
+    ///
+    /// Subtrees of `tree` open popup menus, as with `add_subtree`. This is
+    /// useful with menus loaded from a file, using `MenuTree::from_toml`.
+    pub fn set_tree(&mut self, tree: MenuTree) -> &mut Self {
+        self.root = tree;
+        self.focus = 0;
+        self
+    }
+
+    /// Sets whether `&` marks mnemonics in the labels of the menubar items.
+    ///
+    /// With mnemonics, `Alt` and the marked letter select an item. Menus
+    /// opened from the menubar have their own setting, see
+    /// [`MenuTree::with_mnemonics`].
+    pub fn set_mnemonics(&mut self, mnemonics: bool) -> &mut Self {
+        self.root.set_mnemonics(mnemonics);
+        self
+    }
+
+    /// Sets the mark shown after the label of submenus.
+    ///
+    /// See [`MenuPopup::set_subtree_arrow`].
+    pub fn set_subtree_arrow<S: Into<String>>(
+        &mut self,
+        arrow: S,
+    ) -> &mut Self {
+        self.subtree_arrow = Some(arrow.into());
+        self
+    }
+
     /// Adds a new item to the menubar.
     ///
     /// The item will use the given title, and on selection, will open a
@@ -179,6 +214,42 @@ impl Menubar {
         self.root.remove(i);
     }
 
+    /// Handles accelerators, and mnemonics with `Alt`.
+    ///
+    /// Cursive calls this before sending events to the views, unless a
+    /// submenu is open.
+    pub(crate) fn on_shortcut(
+        &mut self,
+        event: &Event,
+    ) -> Option<EventResult> {
+        if self.has_submenu() {
+            return None;
+        }
+
+        if let Some(cb) = self.root.find_accelerator(event) {
+            let selected = self.receive_events();
+            self.hide();
+            return Some(if selected {
+                // The menubar may have been drawn over the views.
+                EventResult::with_cb(move |s| {
+                    s.clear();
+                    cb(s);
+                })
+            } else {
+                EventResult::Consumed(Some(cb))
+            });
+        }
+
+        match *event {
+            Event::AltChar(c) => {
+                self.focus = self.root.find_mnemonic(c)?;
+                self.state = State::Selected;
+                Some(self.select_child(false))
+            }
+            _ => None,
+        }
+    }
+
     fn child_at(&self, x: usize) -> Option<usize> {
         if x == 0 {
             return None;
@@ -186,7 +257,7 @@ impl Menubar {
         let mut offset = 1;
 
         for (i, child) in self.root.children.iter().enumerate() {
-            offset += child.label().width() + 2;
+            offset += self.root.text(child).width() + 2;
             if x < offset {
                 return Some(i);
             }
@@ -206,19 +277,20 @@ impl Menubar {
                 // First, we need a new Rc to send the callback,
                 // since we don't know when it will be called.
                 let menu = Rc::clone(tree);
+                let arrow = self.subtree_arrow.clone();
 
                 self.state = State::Submenu;
                 let offset = Vec2::new(
                     self.root.children[..self.focus]
                         .iter()
-                        .map(|child| child.label().width() + 2)
+                        .map(|child| self.root.text(child).width() + 2)
                         .sum(),
                     if self.autohide { 1 } else { 0 },
                 );
                 // Since the closure will be called multiple times,
                 // we also need a new Rc on every call.
                 EventResult::with_cb(move |s| {
-                    show_child(s, offset, Rc::clone(&menu))
+                    show_child(s, offset, Rc::clone(&menu), arrow.clone())
                 })
             }
             _ => EventResult::Ignored,
@@ -226,7 +298,19 @@ impl Menubar {
     }
 }
 
-fn show_child(s: &mut Cursive, offset: Vec2, menu: Rc<MenuTree>) {
+fn show_child(
+    s: &mut Cursive,
+    offset: Vec2,
+    menu: Rc<MenuTree>,
+    arrow: Option<String>,
+) {
+    let mut popup = MenuPopup::new(menu)
+        .on_dismiss(Cursive::select_menubar)
+        .on_action(|s| s.menubar().state = State::Inactive);
+    if let Some(arrow) = arrow {
+        popup.set_subtree_arrow(arrow);
+    }
+
     // Adds a new layer located near the item title with the menu popup.
     // Also adds two key callbacks on this new view, to handle `left` and
     // `right` key presses.
@@ -235,33 +319,29 @@ fn show_child(s: &mut Cursive, offset: Vec2, menu: Rc<MenuTree>) {
     // be entered.)
     s.screen_mut().add_layer_at(
         Position::absolute(offset),
-        OnEventView::new(
-            MenuPopup::new(menu)
-                .on_dismiss(Cursive::select_menubar)
-                .on_action(|s| s.menubar().state = State::Inactive),
-        )
-        .on_event(Key::Right, |s| {
-            s.pop_layer();
-            s.select_menubar();
-            // Act as if we sent "Right" then "Down"
-            s.menubar().on_event(Event::Key(Key::Right)).process(s);
-            if let EventResult::Consumed(Some(cb)) =
-                s.menubar().on_event(Event::Key(Key::Down))
-            {
-                cb(s);
-            }
-        })
-        .on_event(Key::Left, |s| {
-            s.pop_layer();
-            s.select_menubar();
-            // Act as if we sent "Left" then "Down"
-            s.menubar().on_event(Event::Key(Key::Left)).process(s);
-            if let EventResult::Consumed(Some(cb)) =
-                s.menubar().on_event(Event::Key(Key::Down))
-            {
-                cb(s);
-            }
-        }),
+        OnEventView::new(popup)
+            .on_event(Key::Right, |s| {
+                s.pop_layer();
+                s.select_menubar();
+                // Act as if we sent "Right" then "Down"
+                s.menubar().on_event(Event::Key(Key::Right)).process(s);
+                if let EventResult::Consumed(Some(cb)) =
+                    s.menubar().on_event(Event::Key(Key::Down))
+                {
+                    cb(s);
+                }
+            })
+            .on_event(Key::Left, |s| {
+                s.pop_layer();
+                s.select_menubar();
+                // Act as if we sent "Left" then "Down"
+                s.menubar().on_event(Event::Key(Key::Left)).process(s);
+                if let EventResult::Consumed(Some(cb)) =
+                    s.menubar().on_event(Event::Key(Key::Down))
+                {
+                    cb(s);
+                }
+            }),
     );
 }
 
@@ -275,16 +355,17 @@ impl View for Menubar {
         // TODO: draw the rest
         let mut offset = 1;
         for (i, item) in self.root.children.iter().enumerate() {
-            let title = item.label();
+            let width = self.root.text(item).width() + 2;
 
             // We don't want to show HighlightInactive when we're not selected,
             // because it's ugly on the menubar.
             let selected =
                 (self.state != State::Inactive) && (i == self.focus);
             printer.with_selection(selected, |printer| {
-                printer.print((offset, 0), &format!(" {} ", title));
+                printer.print_hline((offset, 0), width, " ");
+                self.root.print_label(printer, (offset + 1, 0).into(), item);
             });
-            offset += title.width() + 2;
+            offset += width;
         }
     }
 
@@ -320,6 +401,13 @@ impl View for Menubar {
             Event::Key(Key::Enter) => {
                 return self.select_child(false);
             }
+            Event::AltChar(c) => match self.root.find_mnemonic(c) {
+                Some(i) => {
+                    self.focus = i;
+                    return self.select_child(false);
+                }
+                None => return EventResult::Ignored,
+            },
             Event::Mouse {
                 event: MouseEvent::Press(btn),
                 position,
@@ -381,7 +469,7 @@ impl View for Menubar {
             .root
             .children
             .iter()
-            .map(|item| item.label().len() + 2)
+            .map(|item| self.root.text(item).width() + 2)
             .sum();
 
         Vec2::new(width, 1)
@@ -396,10 +484,10 @@ impl View for Menubar {
         // And each item has a 2 cells padding.
         let x = 1 + self.root.children[..self.focus]
             .iter()
-            .map(|child| child.label().width() + 2)
+            .map(|child| self.root.text(child).width() + 2)
             .sum::<usize>();
 
-        let width = self.root.children[self.focus].label().width();
+        let width = self.root.text(&self.root.children[self.focus]).width();
 
         Rect::from_size((x, 0), (width, 1))
     }
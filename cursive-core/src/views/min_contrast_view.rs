@@ -0,0 +1,62 @@
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+
+/// Wrapper view that keeps its content readable.
+///
+/// When drawing the wrapped view, front colors with a contrast ratio below
+/// the given one are made lighter or darker, depending on the background.
+///
+/// See [`Color::contrast_ratio`] for how the ratio is computed. WCAG
+/// recommends at least `4.5` for normal text, and `7.0` for enhanced
+/// contrast.
+///
+/// [`Color::contrast_ratio`]: crate::theme::Color::contrast_ratio
+///
+/// # Examples
+///
+/// ```
+/// use cursive_core::views::{MinContrastView, TextView};
+///
+/// let view = MinContrastView::new(4.5, TextView::new("Readable text"));
+/// ```
+pub struct MinContrastView<V> {
+    view: V,
+    ratio: Option<f32>,
+}
+
+impl<V> MinContrastView<V> {
+    /// Wraps `view`, enforcing the given minimum contrast ratio.
+    pub fn new(ratio: f32, view: V) -> Self {
+        MinContrastView {
+            view,
+            ratio: Some(ratio),
+        }
+    }
+
+    /// Sets the minimum contrast ratio.
+    ///
+    /// `None` disables the check, but keeps any ratio set by a parent view.
+    pub fn set_ratio(&mut self, ratio: Option<f32>) {
+        self.ratio = ratio;
+    }
+
+    /// Returns the minimum contrast ratio, if any.
+    pub fn get_ratio(&self) -> Option<f32> {
+        self.ratio
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for MinContrastView<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        match self.ratio {
+            Some(ratio) => {
+                self.view.draw(&printer.min_contrast(Some(ratio)));
+            }
+            None => self.view.draw(printer),
+        }
+    }
+}
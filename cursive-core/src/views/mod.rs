@@ -59,16 +59,24 @@ macro_rules! impl_enabled {
     };
 }
 
+mod animated;
+mod binding;
 mod boxed_view;
 mod button;
 mod canvas;
 mod checkbox;
 mod circular_focus;
+mod combo_box;
+mod date_picker;
 mod debug_view;
 mod dialog;
+mod drag_drop;
 mod dummy;
 mod edit_view;
 mod enableable_view;
+mod flow_layout;
+mod focus_tracker;
+mod grid;
 mod hideable_view;
 mod last_size_view;
 mod layer;
@@ -76,58 +84,104 @@ mod linear_layout;
 mod list_view;
 mod menu_popup;
 mod menubar;
+mod min_contrast_view;
 mod named_view;
+mod no_hover_focus;
 mod on_event_view;
 mod padded_view;
 mod panel;
 mod progress_bar;
 mod radio;
 mod resized_view;
+mod responsive_view;
 mod screens_view;
 mod scroll_view;
+mod search_wrapper;
 mod select_view;
 mod shadow_view;
+mod sidebar_layout;
 mod slider_view;
+mod spatial_focus;
+mod spinner;
+mod split;
 mod stack_view;
+mod tab_order;
 mod text_area;
 mod text_view;
+mod themed;
+mod time_picker;
+mod timeline_view;
 mod tracked_view;
+mod transformed;
+mod virtual_list;
+mod window;
 
+pub use self::animated::Animated;
+pub(crate) use self::binding::Binding;
 pub use self::boxed_view::BoxedView;
 pub use self::button::Button;
 pub use self::canvas::Canvas;
 pub use self::checkbox::Checkbox;
 pub use self::circular_focus::CircularFocus;
+pub use self::combo_box::ComboBox;
+pub use self::date_picker::DatePicker;
 pub use self::debug_view::DebugView;
-pub use self::dialog::{Dialog, DialogFocus};
+pub use self::dialog::{
+    AlertLevel, ButtonSet, Dialog, DialogAnswer, DialogFocus, DialogResult,
+};
+pub(crate) use self::drag_drop::Drag;
+pub use self::drag_drop::{DragSource, DropTarget};
 pub use self::dummy::DummyView;
-pub use self::edit_view::EditView;
+pub use self::edit_view::{EditView, InputMask};
 pub use self::enableable_view::EnableableView;
+pub use self::flow_layout::FlowLayout;
+pub use self::focus_tracker::FocusTracker;
+pub use self::grid::Grid;
 pub use self::hideable_view::HideableView;
 pub use self::last_size_view::LastSizeView;
-pub use self::layer::Layer;
-pub use self::linear_layout::LinearLayout;
+pub use self::layer::{Background, Layer};
+pub use self::linear_layout::{CrossAlign, LinearLayout};
 pub use self::list_view::{ListChild, ListView};
 pub use self::menu_popup::MenuPopup;
 pub use self::menubar::Menubar;
+pub use self::min_contrast_view::MinContrastView;
 pub use self::named_view::{NamedView, ViewRef};
+pub use self::no_hover_focus::NoHoverFocus;
 pub use self::on_event_view::OnEventView;
 pub use self::padded_view::PaddedView;
 pub use self::panel::Panel;
 pub use self::progress_bar::ProgressBar;
+#[allow(deprecated)]
+pub use self::radio::DEFAULT_RADIO_BUTTON_CONFIG;
 pub use self::radio::{
-    RadioButton, RadioButtonConfig, RadioGroup, DEFAULT_RADIO_BUTTON_CONFIG,
+    OwnedRadioButtonConfig, RadioButton, RadioButtonConfig, RadioGroup,
+    RadioLayout,
 };
 pub use self::resized_view::ResizedView;
+pub use self::responsive_view::ResponsiveView;
 pub use self::screens_view::ScreensView;
 pub use self::scroll_view::ScrollView;
+pub use self::search_wrapper::SearchWrapper;
 pub use self::select_view::SelectView;
 pub use self::shadow_view::ShadowView;
+pub use self::sidebar_layout::SidebarLayout;
 pub use self::slider_view::SliderView;
-pub use self::stack_view::{LayerPosition, StackView};
+pub use self::spatial_focus::SpatialFocus;
+pub use self::spinner::Spinner;
+pub use self::split::Split;
+pub use self::stack_view::{
+    LayerEvents, LayerPlacement, LayerPolicy, LayerPosition, StackView,
+};
+pub use self::tab_order::TabOrder;
 pub use self::text_area::TextArea;
 pub use self::text_view::{TextContent, TextContentRef, TextView};
+pub use self::themed::Themed;
+pub use self::time_picker::TimePicker;
+pub use self::timeline_view::TimelineView;
 pub use self::tracked_view::TrackedView;
+pub use self::transformed::{Transformation, Transformed};
+pub use self::virtual_list::VirtualList;
+pub use self::window::Window;
 
 /// Same as [`LastSizeView`](self::LastSizeView).
 #[deprecated(note = "`SizedView` is being renamed to `LastSizeView`")]
@@ -1,5 +1,6 @@
-use crate::event::AnyCb;
-use crate::view::{Selector, View, ViewWrapper};
+use crate::event::{AnyCb, EventResult};
+use crate::view::{SearchStep, Searchable, Selector, View, ViewWrapper};
+use crate::Printer;
 use owning_ref::{OwningHandle, RcRef};
 use std::cell::{RefCell, RefMut};
 use std::ops::DerefMut;
@@ -45,6 +46,16 @@ impl<V: View> NamedView<V> {
     }
 }
 
+impl<T: Searchable + 'static> Searchable for NamedView<T> {
+    fn search(&mut self, query: &str, step: SearchStep) -> EventResult {
+        self.get_mut().search(query, step)
+    }
+
+    fn clear_search(&mut self) {
+        self.get_mut().clear_search();
+    }
+}
+
 impl<T: View + 'static> ViewWrapper for NamedView<T> {
     type V = T;
 
@@ -76,6 +87,12 @@ impl<T: View + 'static> ViewWrapper for NamedView<T> {
         }
     }
 
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        // Remember where we are, for `Cursive::redraw_view`.
+        printer.record_area(&self.id);
+        self.with_view(|v| v.draw(printer));
+    }
+
     fn wrap_call_on_any<'a>(
         &mut self,
         selector: &Selector<'_>,
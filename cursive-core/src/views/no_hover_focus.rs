@@ -0,0 +1,86 @@
+use crate::event::{Event, EventResult, MouseEvent};
+use crate::view::{View, ViewWrapper};
+
+/// Wrapper preventing a view from taking the focus on mouse hover.
+///
+/// With focus-follows-mouse enabled, moving the mouse over a view gives it
+/// the focus. Views wrapped in `NoHoverFocus`, and their children, only
+/// take it when clicked or reached with the keyboard.
+///
+/// See [`Cursive::set_focus_follows_mouse`].
+///
+/// [`Cursive::set_focus_follows_mouse`]: crate::Cursive::set_focus_follows_mouse
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{EditView, NoHoverFocus};
+///
+/// let edit = NoHoverFocus::new(EditView::new());
+/// ```
+pub struct NoHoverFocus<V> {
+    view: V,
+}
+
+impl<V> NoHoverFocus<V> {
+    /// Wraps the given view.
+    pub fn new(view: V) -> Self {
+        NoHoverFocus { view }
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for NoHoverFocus<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        // Children just see the mouse moving, and keep their focus.
+        let event = match event {
+            Event::Mouse {
+                offset,
+                position,
+                event: MouseEvent::Hover,
+            } => Event::Mouse {
+                offset,
+                position,
+                event: MouseEvent::Move,
+            },
+            event => event,
+        };
+        self.view.on_event(event)
+    }
+
+    fn wrap_focus_on_hover(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Button, LinearLayout};
+    use crate::Vec2;
+
+    #[test]
+    fn hover_focus() {
+        let mut layout = LinearLayout::vertical()
+            .child(Button::new("A", |_| ()))
+            .child(NoHoverFocus::new(Button::new("B", |_| ())))
+            .child(Button::new("C", |_| ()));
+        layout.layout(Vec2::new(5, 3));
+
+        let hover = |y| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(1, y),
+            event: MouseEvent::Hover,
+        };
+
+        layout.on_event(hover(2));
+        assert_eq!(layout.get_focus_index(), 2);
+        layout.on_event(hover(1));
+        assert_eq!(layout.get_focus_index(), 2);
+        layout.on_event(hover(0));
+        assert_eq!(layout.get_focus_index(), 0);
+    }
+}
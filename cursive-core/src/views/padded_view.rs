@@ -58,6 +58,11 @@ impl<V: View> ViewWrapper for PaddedView<V> {
         self.view.required_size(req.saturating_sub(margins)) + margins
     }
 
+    fn wrap_measure(&mut self, req: Vec2) -> Vec2 {
+        let margins = self.margins.combined();
+        self.view.measure(req.saturating_sub(margins)) + margins
+    }
+
     fn wrap_layout(&mut self, size: Vec2) {
         let margins = self.margins.combined();
         self.view.layout(size.saturating_sub(margins));
@@ -104,6 +104,12 @@ impl<V: View> ViewWrapper for Panel<V> {
         self.view.required_size(req) + (2, 2)
     }
 
+    fn wrap_measure(&mut self, req: Vec2) -> Vec2 {
+        let req = req.saturating_sub((2, 2));
+
+        self.view.measure(req) + (2, 2)
+    }
+
     fn wrap_draw(&self, printer: &Printer<'_, '_>) {
         printer.print_box((0, 0), printer.size, true);
         self.draw_title(&printer);
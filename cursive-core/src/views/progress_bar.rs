@@ -5,6 +5,7 @@ use crate::view::View;
 use crate::{Printer, With};
 use std::cmp;
 use std::thread;
+use unicode_segmentation::UnicodeSegmentation;
 
 // pub type CbPromise = Option<Box<Fn(&mut Cursive) + Send>>;
 
@@ -39,6 +40,7 @@ pub struct ProgressBar {
     max: usize,
     value: Counter,
     color: ColorType,
+    gradient: Option<(ColorType, ColorType)>,
     // TODO: use a Promise instead?
     label_maker: Box<dyn Fn(usize, (usize, usize)) -> String>,
 }
@@ -90,6 +92,7 @@ impl ProgressBar {
             max: 100,
             value: Counter::new(0),
             color: ColorStyle::highlight().back,
+            gradient: None,
             label_maker: Box::new(make_percentage),
         }
     }
@@ -103,6 +106,17 @@ impl ProgressBar {
         self
     }
 
+    /// Returns the counter followed by this bar.
+    ///
+    /// Give it to a [`ProgressReader`] or a [`ProgressWriter`] to follow
+    /// the progress of an IO task.
+    ///
+    /// [`ProgressReader`]: crate::utils::ProgressReader
+    /// [`ProgressWriter`]: crate::utils::ProgressWriter
+    pub fn counter(&self) -> Counter {
+        self.value.clone()
+    }
+
     /// Starts a function in a separate thread, and monitor the progress.
     ///
     /// `f` will be given a `Counter` to increment the bar's progress.
@@ -210,6 +224,68 @@ impl ProgressBar {
     {
         self.with(|s| s.set_color(color))
     }
+
+    /// Fills the bar with a gradient from `start` to `end`.
+    ///
+    /// The gradient spans the whole width of the bar, so each cell keeps its
+    /// color as the bar fills up. It is only drawn when the backend supports
+    /// 24-bit colors; otherwise, the color from `set_color` is used.
+    pub fn set_gradient<S, E>(&mut self, start: S, end: E)
+    where
+        S: Into<ColorType>,
+        E: Into<ColorType>,
+    {
+        self.gradient = Some((start.into(), end.into()));
+    }
+
+    /// Fills the bar with a gradient from `start` to `end`.
+    ///
+    /// Chainable variant of `set_gradient`.
+    pub fn with_gradient<S, E>(self, start: S, end: E) -> Self
+    where
+        S: Into<ColorType>,
+        E: Into<ColorType>,
+    {
+        self.with(|s| s.set_gradient(start, end))
+    }
+
+    /// Removes the gradient set with `set_gradient`.
+    pub fn clear_gradient(&mut self) {
+        self.gradient = None;
+    }
+
+    /// Draws the bar one cell at a time, each with its own color.
+    fn draw_gradient(
+        &self,
+        printer: &Printer<'_, '_>,
+        (start, end): (ColorType, ColorType),
+        (length, extra): (usize, usize),
+        (offset, label): (usize, &str),
+    ) {
+        let palette = &printer.theme.palette;
+        let start = start.resolve(palette);
+        let end = end.resolve(palette);
+        let front = ColorStyle::highlight().front;
+        let label: Vec<&str> = label.graphemes(true).collect();
+
+        for (x, back) in start.gradient(end, printer.size.x).enumerate() {
+            let text = match x.checked_sub(offset).and_then(|i| label.get(i)) {
+                Some(text) => text,
+                None if x == length => sub_block(extra),
+                None if x < length => " ",
+                None => continue,
+            };
+            printer.with_color(ColorStyle::new(front, back), |printer| {
+                if x < length {
+                    printer.print((x, 0), text);
+                } else {
+                    printer.with_effect(Effect::Reverse, |printer| {
+                        printer.print((x, 0), text);
+                    });
+                }
+            });
+        }
+    }
 }
 
 fn sub_block(extra: usize) -> &'static str {
@@ -245,6 +321,18 @@ impl View for ProgressBar {
         let label = (self.label_maker)(value, (self.min, self.max));
         let offset = HAlign::Center.get_offset(label.len(), printer.size.x);
 
+        if let Some(gradient) = self.gradient {
+            if printer.has_true_colors() {
+                self.draw_gradient(
+                    printer,
+                    gradient,
+                    (length, extra),
+                    (offset, &label),
+                );
+                return;
+            }
+        }
+
         let color_style =
             ColorStyle::new(ColorStyle::highlight().front, self.color);
 
@@ -262,3 +350,28 @@ impl View for ProgressBar {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::theme::Color;
+    use crate::Vec2;
+
+    #[test]
+    fn gradient() {
+        let mut bar = ProgressBar::new()
+            .with_label(|_, _| String::new())
+            .with_gradient(Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 200));
+        bar.set_value(60);
+
+        let buffer = render_to_buffer(&mut bar, (5, 1));
+        let back = |x| buffer.get(Vec2::new(x, 0)).unwrap().colors.back;
+        assert_eq!(back(0), Color::Rgb(0, 0, 0));
+        assert_eq!(back(2), Color::Rgb(0, 0, 100));
+        // The next cell is reversed, to show the partial block.
+        assert_eq!(back(3), Color::Rgb(0, 0, 150));
+        let cell = buffer.get(Vec2::new(3, 0)).unwrap();
+        assert!(cell.effects.contains(Effect::Reverse));
+    }
+}
@@ -1,18 +1,25 @@
-use crate::direction::Direction;
+use crate::direction::{Direction, Orientation};
 use crate::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use crate::printer::AnyBinding;
 use crate::theme::ColorStyle;
-use crate::view::View;
+use crate::view::{View, ViewWrapper};
+use crate::views::{Binding, LinearLayout};
 use crate::Cursive;
 use crate::Vec2;
 use crate::{Printer, With};
+use std::any::Any;
 use std::cell::RefCell;
 use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
 
 struct SharedState<T> {
     selection: usize,
     values: Vec<Rc<T>>,
 
     on_change: Option<Rc<dyn Fn(&mut Cursive, &T)>>,
+
+    // Binds the selected id to the user data.
+    binding: Option<Rc<Binding<usize>>>,
 }
 
 impl<T> SharedState<T> {
@@ -30,7 +37,7 @@ impl<T> SharedState<T> {
 pub struct RadioGroup<T> {
     // Given to every child button
     state: Rc<RefCell<SharedState<T>>>,
-    config: RadioButtonConfig,
+    config: OwnedRadioButtonConfig,
 }
 
 impl<T: 'static> Default for RadioGroup<T> {
@@ -41,30 +48,88 @@ impl<T: 'static> Default for RadioGroup<T> {
 
 impl<T: 'static> RadioGroup<T> {
     /// Creates an empty group for radio buttons. It will use the default
-    /// [`RadioButtonConfig`] [`DEFAULT_RADIO_BUTTON_CONFIG`].
+    /// [`RadioButtonConfig`].
     pub fn new() -> Self {
-        RadioGroup {
-            state: Rc::new(RefCell::new(SharedState {
-                selection: 0,
-                values: Vec::new(),
-                on_change: None,
-            })),
-            config: DEFAULT_RADIO_BUTTON_CONFIG,
-        }
+        Self::with_config(RadioButtonConfig::default())
     }
 
-    /// Creates an empty group for radio buttons with a [`RadioButtonConfig`].
-    pub fn with_config(config: RadioButtonConfig) -> Self {
+    /// Creates an empty group for radio buttons with a custom configuration.
+    ///
+    /// Accepts either a [`RadioButtonConfig`] or an
+    /// [`OwnedRadioButtonConfig`].
+    pub fn with_config<C: Into<OwnedRadioButtonConfig>>(config: C) -> Self {
+        let config = config.into();
         RadioGroup {
             state: Rc::new(RefCell::new(SharedState {
                 selection: 0,
                 values: Vec::new(),
                 on_change: None,
+                binding: None,
             })),
             config,
         }
     }
 
+    /// Creates an empty group for radio buttons, bound to a value in the
+    /// user data.
+    ///
+    /// `get` reads the value from the user data, of type `D`. The button
+    /// holding this value is selected every frame, and selecting a button
+    /// writes its value back with `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::direction::Orientation;
+    /// use cursive_core::views::RadioGroup;
+    ///
+    /// #[derive(Clone, Copy, PartialEq)]
+    /// enum Size {
+    ///     Small,
+    ///     Large,
+    /// }
+    ///
+    /// struct Order {
+    ///     size: Size,
+    /// }
+    ///
+    /// let group = RadioGroup::bound(
+    ///     |order: &Order| order.size,
+    ///     |order, size| order.size = *size,
+    /// );
+    /// let layout = group
+    ///     .layout(Orientation::Vertical)
+    ///     .button(Size::Small, "Small")
+    ///     .button(Size::Large, "Large");
+    /// ```
+    pub fn bound<D, G, S>(get: G, set: S) -> Self
+    where
+        T: PartialEq,
+        D: Any,
+        G: 'static + Fn(&D) -> T,
+        S: 'static + Fn(&mut D, &T),
+    {
+        let group = Self::new();
+        let state = Rc::downgrade(&group.state);
+        let get = move |data: &dyn Any| {
+            let data = data.downcast_ref::<D>()?;
+            let state = state.upgrade()?;
+            let value = get(data);
+            let state = state.try_borrow().ok()?;
+            state.values.iter().position(|v| **v == value)
+        };
+        let state = Rc::downgrade(&group.state);
+        let set = move |s: &mut Cursive, id: usize| {
+            let value = match state.upgrade() {
+                Some(state) => Rc::clone(&state.borrow().values[id]),
+                None => return,
+            };
+            s.with_user_data(|data: &mut D| set(data, &value));
+        };
+        group.state.borrow_mut().binding = Some(Binding::new(get, set));
+        group
+    }
+
     /// Adds a new button to the group.
     ///
     /// The button will display `label` next to it, and will embed `value`.
@@ -79,7 +144,7 @@ impl<T: 'static> RadioGroup<T> {
             Rc::clone(&self.state),
             count,
             label.into(),
-            self.config,
+            self.config.clone(),
         )
     }
 
@@ -112,6 +177,38 @@ impl<T: 'static> RadioGroup<T> {
     ) -> Self {
         self.with(|s| s.set_on_change(on_change))
     }
+
+    /// Creates a new container for buttons from this group.
+    ///
+    /// In this container, arrow keys move the focus to the next or previous
+    /// button _and_ select it, like radio buttons in most GUI toolkits. The
+    /// arrows follow the given orientation: up and down for a vertical
+    /// layout, left and right for a horizontal one. Moving past the first or
+    /// last button leaves the container.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::direction::Orientation;
+    /// use cursive_core::views::RadioGroup;
+    ///
+    /// let mut group = RadioGroup::new();
+    /// let layout = group
+    ///     .layout(Orientation::Vertical)
+    ///     .button(1, "One")
+    ///     .button(2, "Two")
+    ///     .button(3, "Three");
+    /// ```
+    pub fn layout(&self, orientation: Orientation) -> RadioLayout<T> {
+        RadioLayout {
+            group: RadioGroup {
+                state: Rc::clone(&self.state),
+                config: self.config.clone(),
+            },
+            layout: LinearLayout::new(orientation),
+            orientation,
+        }
+    }
 }
 
 impl RadioGroup<String> {
@@ -125,6 +222,96 @@ impl RadioGroup<String> {
     }
 }
 
+/// Container for the buttons of a [`RadioGroup`].
+///
+/// Arrow keys move the selection between buttons. It is created with
+/// [`RadioGroup::layout`].
+pub struct RadioLayout<T> {
+    group: RadioGroup<T>,
+    layout: LinearLayout,
+    orientation: Orientation,
+}
+
+impl<T: 'static> RadioLayout<T> {
+    /// Adds a new button from the group to this layout.
+    pub fn add_button<S: Into<String>>(&mut self, value: T, label: S) {
+        let button = self.group.button(value, label);
+        self.layout.add_child(button);
+    }
+
+    /// Adds a new button from the group to this layout.
+    ///
+    /// Chainable variant.
+    pub fn button<S: Into<String>>(self, value: T, label: S) -> Self {
+        self.with(|s| s.add_button(value, label))
+    }
+
+    /// Returns the group this layout is bound to.
+    pub fn group(&self) -> &RadioGroup<T> {
+        &self.group
+    }
+
+    /// Moves the focus to the next button in the given direction, and
+    /// selects it.
+    ///
+    /// Disabled buttons are skipped.
+    fn select_next(&mut self, forward: bool) -> EventResult {
+        let focus = self.layout.get_focus_index();
+        let candidates: Box<dyn Iterator<Item = usize>> = if forward {
+            Box::new(focus + 1..self.layout.len())
+        } else {
+            Box::new((0..focus).rev())
+        };
+
+        for i in candidates {
+            if self.layout.set_focus_index(i).is_ok() {
+                return self
+                    .layout
+                    .get_child_mut(i)
+                    .and_then(|child| child.downcast_mut::<RadioButton<T>>())
+                    .map(RadioButton::select)
+                    .unwrap_or(EventResult::Consumed(None));
+            }
+        }
+
+        EventResult::Ignored
+    }
+}
+
+impl RadioLayout<String> {
+    /// Adds a button, using the label itself as value.
+    pub fn add_button_str<S: Into<String>>(&mut self, text: S) {
+        let text = text.into();
+        self.add_button(text.clone(), text);
+    }
+
+    /// Adds a button, using the label itself as value.
+    ///
+    /// Chainable variant.
+    pub fn button_str<S: Into<String>>(self, text: S) -> Self {
+        self.with(|s| s.add_button_str(text))
+    }
+}
+
+impl<T: 'static> ViewWrapper for RadioLayout<T> {
+    wrap_impl!(self.layout: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let forward = match (self.orientation, &event) {
+            (Orientation::Vertical, Event::Key(Key::Down))
+            | (Orientation::Horizontal, Event::Key(Key::Right)) => Some(true),
+            (Orientation::Vertical, Event::Key(Key::Up))
+            | (Orientation::Horizontal, Event::Key(Key::Left)) => Some(false),
+            _ => None,
+        };
+
+        match forward {
+            Some(forward) => self.select_next(forward),
+            None => self.layout.on_event(event),
+        }
+    }
+}
+
 /// Variant of `Checkbox` arranged in group.
 ///
 /// `RadioButton`s are managed by a [`RadioGroup`]. A single group can contain
@@ -139,7 +326,7 @@ pub struct RadioButton<T> {
     id: usize,
     enabled: bool,
     label: String,
-    config: RadioButtonConfig,
+    config: OwnedRadioButtonConfig,
 }
 
 impl<T: 'static> RadioButton<T> {
@@ -149,7 +336,7 @@ impl<T: 'static> RadioButton<T> {
         state: Rc<RefCell<SharedState<T>>>,
         id: usize,
         label: String,
-        config: RadioButtonConfig,
+        config: OwnedRadioButtonConfig,
     ) -> Self {
         RadioButton {
             state,
@@ -169,12 +356,18 @@ impl<T: 'static> RadioButton<T> {
     pub fn select(&mut self) -> EventResult {
         let mut state = self.state.borrow_mut();
         state.selection = self.id;
-        if let Some(ref on_change) = state.on_change {
+        let result = if let Some(ref on_change) = state.on_change {
             let on_change = Rc::clone(on_change);
             let value = state.selection();
             EventResult::with_cb(move |s| on_change(s, &value))
         } else {
             EventResult::Consumed(None)
+        };
+        match state.binding {
+            Some(ref binding) => {
+                EventResult::Consumed(Some(binding.write(self.id))).and(result)
+            }
+            None => result,
         }
     }
 
@@ -200,54 +393,46 @@ impl<T: 'static> RadioButton<T> {
         })
     }
 
-    fn draw_internal(&self, printer: &Printer<'_, '_>) {
-        let mut printer_xpos = 0;
-        printer.print((printer_xpos, 0), self.config.left_bracket);
-        printer_xpos += self.config.left_bracket.len();
-
-        if self.is_selected() {
-            printer.print((printer_xpos, 0), self.config.check);
-            printer_xpos += self.config.check.len();
+    /// Returns the pieces to draw, from left to right.
+    fn parts(&self) -> Vec<&str> {
+        let mark = if self.is_selected() {
+            &self.config.check
         } else {
-            printer.print((printer_xpos, 0), self.config.uncheck);
-            printer_xpos += self.config.uncheck.len();
-        }
-
-        printer.print((printer_xpos, 0), self.config.right_bracket);
-        printer_xpos += self.config.right_bracket.len();
-
+            &self.config.uncheck
+        };
+
+        let mut parts = vec![
+            &self.config.left_bracket[..],
+            mark,
+            &self.config.right_bracket,
+        ];
         if !self.label.is_empty() {
             // We want the space to be highlighted if focused
-            printer.print((printer_xpos, 0), self.config.post_label_space);
-            printer_xpos += self.config.post_label_space.len();
+            parts.push(&self.config.post_label_space);
+            parts.push(&self.label);
+        }
+        parts
+    }
 
-            printer.print((printer_xpos, 0), &self.label);
+    fn draw_internal(&self, printer: &Printer<'_, '_>) {
+        let mut printer_xpos = 0;
+        for part in self.parts() {
+            printer.print((printer_xpos, 0), part);
+            printer_xpos += part.width();
         }
     }
 
     fn req_size(&self) -> Vec2 {
-        let base_length = self.config.left_bracket.len()
-            + if self.is_selected() {
-                self.config.check.len()
-            } else {
-                self.config.uncheck.len()
-            }
-            + self.config.right_bracket.len();
-
-        if self.label.is_empty() {
-            Vec2::new(base_length, 1)
-        } else {
-            Vec2::new(
-                base_length
-                    + self.config.post_label_space.len()
-                    + self.label.len(),
-                1,
-            )
-        }
+        let width = self.parts().iter().map(|part| part.width()).sum();
+        Vec2::new(width, 1)
     }
 }
 
 impl<T: 'static> View for RadioButton<T> {
+    fn measure(&mut self, _: Vec2) -> Vec2 {
+        self.req_size()
+    }
+
     fn required_size(&mut self, _: Vec2) -> Vec2 {
         self.req_size()
     }
@@ -256,7 +441,26 @@ impl<T: 'static> View for RadioButton<T> {
         self.enabled
     }
 
+    fn layout(&mut self, _: Vec2) {
+        let pending = self
+            .state
+            .borrow()
+            .binding
+            .as_ref()
+            .and_then(|b| b.take_pending());
+        if let Some(id) = pending {
+            let mut state = self.state.borrow_mut();
+            if id < state.values.len() {
+                state.selection = id;
+            }
+        }
+    }
+
     fn draw(&self, printer: &Printer<'_, '_>) {
+        if let Some(ref binding) = self.state.borrow().binding {
+            printer
+                .record_binding(|| Rc::clone(binding) as Rc<dyn AnyBinding>);
+        }
         if self.enabled && printer.enabled {
             printer.with_selection(printer.focused, |printer| {
                 self.draw_internal(printer)
@@ -285,6 +489,7 @@ impl<T: 'static> View for RadioButton<T> {
 
 /// This is the default radio button configuration,
 /// used with the [`RadioGroup::button`] method.
+#[deprecated(note = "Use `RadioButtonConfig::default()` instead.")]
 pub const DEFAULT_RADIO_BUTTON_CONFIG: RadioButtonConfig = RadioButtonConfig {
     check: "X",
     uncheck: " ",
@@ -297,7 +502,9 @@ pub const DEFAULT_RADIO_BUTTON_CONFIG: RadioButtonConfig = RadioButtonConfig {
 /// "uncheck", and surrounding bracket pair look like. Although all
 /// the default options are single characters, a user can create
 /// multi-character marks as well.
-#[derive(Debug, Clone, Copy)]
+///
+/// Use [`OwnedRadioButtonConfig`] for marks built at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RadioButtonConfig {
     /// A mark for when a RadioButton is selected.
     ///
@@ -322,7 +529,7 @@ pub struct RadioButtonConfig {
     /// A mark for the space between the right bracket and the
     /// label. For example, if a RadioButton looked like:
     ///
-    /// ```
+    /// ```text
     /// (X) Selected
     /// ```
     /// the `post_label_space` is the space character between `)` and
@@ -331,3 +538,158 @@ pub struct RadioButtonConfig {
     /// It defaults to ` `.
     pub post_label_space: &'static str,
 }
+
+impl Default for RadioButtonConfig {
+    fn default() -> Self {
+        RadioButtonConfig {
+            check: "X",
+            uncheck: " ",
+            left_bracket: "(",
+            right_bracket: ")",
+            post_label_space: " ",
+        }
+    }
+}
+
+/// A [`RadioButtonConfig`] with owned marks.
+///
+/// Marks are measured by their display width, so wide characters like
+/// `✓` or CJK text are supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{OwnedRadioButtonConfig, RadioGroup};
+///
+/// let config = OwnedRadioButtonConfig::default()
+///     .check("✓")
+///     .brackets("[", "]");
+/// let mut group: RadioGroup<u32> = RadioGroup::with_config(config);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedRadioButtonConfig {
+    /// A mark for when a RadioButton is selected.
+    pub check: String,
+
+    /// A mark for when a RadioButton is *not* selected.
+    pub uncheck: String,
+
+    /// A mark for the Left Bracket of a RadioButton.
+    pub left_bracket: String,
+
+    /// A mark for the Right Bracket of a RadioButton.
+    pub right_bracket: String,
+
+    /// A mark for the space between the right bracket and the label.
+    pub post_label_space: String,
+}
+
+impl Default for OwnedRadioButtonConfig {
+    fn default() -> Self {
+        RadioButtonConfig::default().into()
+    }
+}
+
+impl From<RadioButtonConfig> for OwnedRadioButtonConfig {
+    fn from(config: RadioButtonConfig) -> Self {
+        OwnedRadioButtonConfig {
+            check: config.check.into(),
+            uncheck: config.uncheck.into(),
+            left_bracket: config.left_bracket.into(),
+            right_bracket: config.right_bracket.into(),
+            post_label_space: config.post_label_space.into(),
+        }
+    }
+}
+
+impl OwnedRadioButtonConfig {
+    /// Sets the mark for selected buttons.
+    ///
+    /// Chainable variant.
+    pub fn check<S: Into<String>>(self, check: S) -> Self {
+        self.with(|s| s.check = check.into())
+    }
+
+    /// Sets the mark for buttons which are not selected.
+    ///
+    /// Chainable variant.
+    pub fn uncheck<S: Into<String>>(self, uncheck: S) -> Self {
+        self.with(|s| s.uncheck = uncheck.into())
+    }
+
+    /// Sets the brackets around the mark.
+    ///
+    /// Chainable variant.
+    pub fn brackets<L, R>(self, left: L, right: R) -> Self
+    where
+        L: Into<String>,
+        R: Into<String>,
+    {
+        self.with(|s| {
+            s.left_bracket = left.into();
+            s.right_bracket = right.into();
+        })
+    }
+
+    /// Sets the space between the right bracket and the label.
+    ///
+    /// Chainable variant.
+    pub fn post_label_space<S: Into<String>>(self, space: S) -> Self {
+        self.with(|s| s.post_label_space = space.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrows_select() {
+        let group = RadioGroup::new();
+        let mut layout = group
+            .layout(Orientation::Vertical)
+            .button(1, "One")
+            .button(2, "Two")
+            .button(3, "Three");
+        layout.take_focus(Direction::none());
+
+        layout.on_event(Event::Key(Key::Down));
+        assert_eq!(group.selected_id(), 1);
+        layout.on_event(Event::Key(Key::Down));
+        assert_eq!(*group.selection(), 3);
+
+        // The last button lets the focus leave the layout.
+        assert!(!layout.on_event(Event::Key(Key::Down)).is_consumed());
+        assert!(!layout.on_event(Event::Key(Key::Right)).is_consumed());
+
+        layout.on_event(Event::Key(Key::Up));
+        assert_eq!(group.selected_id(), 1);
+    }
+
+    #[test]
+    fn wide_marks() {
+        let config =
+            OwnedRadioButtonConfig::default().check("✓").uncheck("　");
+        let mut group = RadioGroup::with_config(config);
+        let mut button = group.button(1, "日本語").selected();
+
+        // `(✓) 日本語`, with 3 double-width characters.
+        assert_eq!(button.required_size(Vec2::zero()), Vec2::new(10, 1));
+        let mut other = group.button(2, "");
+        // The full-width space takes 2 columns.
+        assert_eq!(other.required_size(Vec2::zero()), Vec2::new(4, 1));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn static_config() {
+        let config = RadioButtonConfig {
+            check: "✓",
+            ..DEFAULT_RADIO_BUTTON_CONFIG
+        };
+        let mut group = RadioGroup::with_config(config);
+        let mut button = group.button(1, "One").selected();
+
+        assert_eq!(button.required_size(Vec2::zero()), Vec2::new(7, 1));
+    }
+}
@@ -216,6 +216,13 @@ impl<T: View> ViewWrapper for ResizedView<T> {
             .zip_map(child_size.zip(req), SizeConstraint::result)
     }
 
+    fn wrap_measure(&mut self, req: Vec2) -> Vec2 {
+        let req = self.size.zip_map(req, SizeConstraint::available);
+        let child_size = self.view.measure(req);
+        self.size
+            .zip_map(child_size.zip(req), SizeConstraint::result)
+    }
+
     fn wrap_layout(&mut self, size: Vec2) {
         self.invalidated = false;
         self.view
@@ -0,0 +1,174 @@
+use crate::direction::Direction;
+use crate::event::AnyCb;
+use crate::view::{IntoBoxedView, Selector, View, ViewWrapper};
+use crate::views::BoxedView;
+use crate::{Vec2, With};
+
+/// A layout shown when enough space is available.
+struct Breakpoint {
+    min_size: Vec2,
+    view: BoxedView,
+}
+
+/// View choosing between alternative layouts depending on its size.
+///
+/// Each layout is added with a minimum size, similar to CSS breakpoints.
+/// The view shows the last added layout fitting in the available space,
+/// so layouts should be added from the most compact to the richest.
+///
+/// The layout is chosen again every time the available size changes.
+///
+/// Only the current layout is drawn and receives events, but all of them
+/// can be reached with `call_on_name`.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{LinearLayout, ResponsiveView, TextView};
+///
+/// let view = ResponsiveView::new(TextView::new("Compact"))
+///     .breakpoint(
+///         (120, 40),
+///         LinearLayout::horizontal()
+///             .child(TextView::new("Rich"))
+///             .child(TextView::new("layout")),
+///     );
+/// ```
+pub struct ResponsiveView {
+    // Invariant: the first breakpoint has a zero minimum size.
+    breakpoints: Vec<Breakpoint>,
+    current: usize,
+}
+
+impl ResponsiveView {
+    /// Creates a new `ResponsiveView`.
+    ///
+    /// `view` is shown when no other layout fits.
+    pub fn new<V: IntoBoxedView>(view: V) -> Self {
+        ResponsiveView {
+            breakpoints: vec![Breakpoint {
+                min_size: Vec2::zero(),
+                view: BoxedView::boxed(view),
+            }],
+            current: 0,
+        }
+    }
+
+    /// Adds a layout shown when at least `min_size` is available.
+    pub fn add_breakpoint<S, V>(&mut self, min_size: S, view: V)
+    where
+        S: Into<Vec2>,
+        V: IntoBoxedView,
+    {
+        self.breakpoints.push(Breakpoint {
+            min_size: min_size.into(),
+            view: BoxedView::boxed(view),
+        });
+    }
+
+    /// Adds a layout shown when at least `min_size` is available.
+    ///
+    /// Chainable variant.
+    pub fn breakpoint<S, V>(self, min_size: S, view: V) -> Self
+    where
+        S: Into<Vec2>,
+        V: IntoBoxedView,
+    {
+        self.with(|s| s.add_breakpoint(min_size, view))
+    }
+
+    /// Returns the index of the current layout.
+    ///
+    /// `0` is the layout given to `new`, and others follow in the order
+    /// they were added.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the layout with the given index, if any.
+    pub fn get_layout(&self, i: usize) -> Option<&dyn View> {
+        self.breakpoints.get(i).map(|b| &*b.view)
+    }
+
+    /// Returns the layout with the given index, if any.
+    pub fn get_layout_mut(&mut self, i: usize) -> Option<&mut dyn View> {
+        self.breakpoints.get_mut(i).map(|b| &mut *b.view)
+    }
+
+    /// Chooses the layout for the given size.
+    fn select(&mut self, size: Vec2) {
+        let current = self
+            .breakpoints
+            .iter()
+            .rposition(|b| b.min_size.fits_in(size))
+            .unwrap_or(0);
+
+        if current != self.current {
+            self.current = current;
+            // Give the new layout a chance to focus something.
+            self.breakpoints[current].view.take_focus(Direction::none());
+        }
+    }
+}
+
+impl ViewWrapper for ResponsiveView {
+    type V = BoxedView;
+
+    fn with_view<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Self::V) -> R,
+    {
+        self.breakpoints.get(self.current).map(|b| f(&b.view))
+    }
+
+    fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Self::V) -> R,
+    {
+        self.breakpoints
+            .get_mut(self.current)
+            .map(|b| f(&mut b.view))
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        self.select(req);
+        self.breakpoints[self.current].view.required_size(req)
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.select(size);
+        self.breakpoints[self.current].view.layout(size);
+    }
+
+    fn wrap_call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        for breakpoint in &mut self.breakpoints {
+            breakpoint.view.call_on_any(selector, callback);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::DummyView;
+
+    #[test]
+    fn select_layout() {
+        let mut view = ResponsiveView::new(DummyView)
+            .breakpoint((80, 24), DummyView)
+            .breakpoint((120, 40), DummyView);
+
+        view.layout(Vec2::new(100, 30));
+        assert_eq!(view.current(), 1);
+        view.layout(Vec2::new(200, 30));
+        assert_eq!(view.current(), 1);
+        view.layout(Vec2::new(200, 50));
+        assert_eq!(view.current(), 2);
+        view.layout(Vec2::new(40, 50));
+        assert_eq!(view.current(), 0);
+    }
+}
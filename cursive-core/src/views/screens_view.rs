@@ -1,3 +1,5 @@
+use crate::direction::Absolute;
+use crate::event::{Event, EventResult};
 use crate::views::BoxedView;
 use crate::View;
 
@@ -8,6 +10,8 @@ pub type ScreenId = usize;
 pub struct ScreensView<V = BoxedView> {
     screens: Vec<V>,
     active_screen: ScreenId,
+    // If `true`, swipes switch to the next or previous screen.
+    swipe_navigation: bool,
 }
 
 new_default!(ScreensView<V>);
@@ -18,6 +22,7 @@ impl<V> ScreensView<V> {
         ScreensView {
             screens: Vec::new(),
             active_screen: 0,
+            swipe_navigation: false,
         }
     }
 
@@ -26,6 +31,7 @@ impl<V> ScreensView<V> {
         ScreensView {
             screens: vec![v],
             active_screen: 0,
+            swipe_navigation: false,
         }
     }
 
@@ -73,6 +79,42 @@ impl<V> ScreensView<V> {
         }
         self.active_screen = screen_id;
     }
+
+    /// Lets swipes switch between screens.
+    ///
+    /// When enabled, a swipe to the left ignored by the active screen shows
+    /// the next screen, and a swipe to the right shows the previous one.
+    /// Swipes are only sent when a `SwipeDetector` is set on the
+    /// `Cursive` root.
+    ///
+    /// Defaults to `false`.
+    pub fn set_swipe_navigation(&mut self, enabled: bool) {
+        self.swipe_navigation = enabled;
+    }
+
+    /// Lets swipes switch between screens.
+    ///
+    /// Chainable variant.
+    pub fn swipe_navigation(mut self, enabled: bool) -> Self {
+        self.set_swipe_navigation(enabled);
+        self
+    }
+
+    // Switches screen after a swipe, if possible.
+    fn on_swipe(&mut self, direction: Absolute) -> EventResult {
+        let target = match direction {
+            Absolute::Left => self.active_screen + 1,
+            Absolute::Right if self.active_screen > 0 => {
+                self.active_screen - 1
+            }
+            _ => return EventResult::Ignored,
+        };
+        if target >= self.screens.len() {
+            return EventResult::Ignored;
+        }
+        self.active_screen = target;
+        EventResult::Consumed(None)
+    }
 }
 
 impl ScreensView<crate::views::StackView> {
@@ -117,5 +159,44 @@ where
         self.screen_mut().map(f)
     }
 
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let result = self
+            .screen_mut()
+            .map(|screen| screen.on_event(event.clone()))
+            .unwrap_or(EventResult::Ignored);
+
+        match event {
+            Event::Swipe(direction)
+                if self.swipe_navigation && !result.is_consumed() =>
+            {
+                self.on_swipe(direction)
+            }
+            _ => result,
+        }
+    }
+
     // TODO: Should `focus_view` work cross-screens? Should `call_on_id`? Answer: yes.
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::DummyView;
+
+    #[test]
+    fn swipe_navigation() {
+        let mut view = ScreensView::new().swipe_navigation(true);
+        view.add_screen(DummyView);
+        view.add_screen(DummyView);
+
+        view.on_event(Event::Swipe(Absolute::Left));
+        assert_eq!(view.active_screen(), 1);
+
+        // There is no screen after the last one.
+        let result = view.on_event(Event::Swipe(Absolute::Left));
+        assert!(!result.is_consumed());
+
+        view.on_event(Event::Swipe(Absolute::Right));
+        assert_eq!(view.active_screen(), 0);
+    }
+}
@@ -1,7 +1,15 @@
+use crate::align::Align;
 use crate::direction::Direction;
 use crate::event::{AnyCb, Event, EventResult};
-use crate::view::{scroll, ScrollStrategy, Selector, View};
-use crate::{Printer, Rect, Vec2, With};
+use crate::view::{
+    scroll, ScrollAware, ScrollStrategy, ScrollbarGlyphs, ScrollbarStyle,
+    ScrollbarVisibility, SearchStep, Searchable, Selector, View,
+};
+use crate::{Cursive, Printer, Rect, Vec2, With};
+use std::rc::Rc;
+
+type MinimapSampler<V> = Box<dyn Fn(&V, Vec2) -> Vec<usize>>;
+type OnScroll = Rc<dyn Fn(&mut Cursive, Rect)>;
 
 /// Wraps a view in a scrollable area.
 pub struct ScrollView<V> {
@@ -9,6 +17,15 @@ pub struct ScrollView<V> {
     inner: V,
 
     core: scroll::Core,
+
+    /// Computes the weight of each row for the minimap.
+    minimap_sampler: Option<MinimapSampler<V>>,
+
+    /// Callback run when the user scrolls the content.
+    on_scroll: Option<OnScroll>,
+
+    /// Tells the wrapped view its viewport, if it is `ScrollAware`.
+    set_viewport: Option<fn(&mut V, Rect)>,
 }
 
 impl_scroller!(ScrollView<V>::core);
@@ -22,6 +39,23 @@ where
         ScrollView {
             inner,
             core: scroll::Core::new(),
+            minimap_sampler: None,
+            on_scroll: None,
+            set_viewport: None,
+        }
+    }
+
+    /// Creates a new ScrollView around a view that needs its viewport.
+    ///
+    /// After every layout, `inner` is given the part of it in view with
+    /// [`ScrollAware::set_viewport`].
+    pub fn aware(inner: V) -> Self
+    where
+        V: ScrollAware,
+    {
+        ScrollView {
+            set_viewport: Some(V::set_viewport),
+            ..Self::new(inner)
         }
     }
 
@@ -95,6 +129,149 @@ where
         self.with(|s| s.set_show_scrollbars(show_scrollbars))
     }
 
+    /// Defines when scrollbars are shown.
+    ///
+    /// Defaults to `ScrollbarVisibility::Auto`.
+    pub fn set_scrollbar_visibility(
+        &mut self,
+        visibility: ScrollbarVisibility,
+    ) {
+        self.core.set_scrollbar_visibility(visibility);
+    }
+
+    /// Defines when scrollbars are shown.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_visibility(
+        self,
+        visibility: ScrollbarVisibility,
+    ) -> Self {
+        self.with(|s| s.set_scrollbar_visibility(visibility))
+    }
+
+    /// Defines on which sides scrollbars are drawn.
+    ///
+    /// `align.h` places the vertical scrollbar on the left or on the right,
+    /// and `align.v` places the horizontal scrollbar on the top or on the
+    /// bottom.
+    ///
+    /// Defaults to `Align::bot_right()`.
+    pub fn set_scrollbar_align(&mut self, align: Align) {
+        self.core.set_scrollbar_align(align);
+    }
+
+    /// Defines on which sides scrollbars are drawn.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_align(self, align: Align) -> Self {
+        self.with(|s| s.set_scrollbar_align(align))
+    }
+
+    /// Sets the characters used to draw scrollbars.
+    pub fn set_scrollbar_glyphs(&mut self, glyphs: ScrollbarGlyphs) {
+        self.core.set_scrollbar_glyphs(glyphs);
+    }
+
+    /// Sets the characters used to draw scrollbars.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_glyphs(self, glyphs: ScrollbarGlyphs) -> Self {
+        self.with(|s| s.set_scrollbar_glyphs(glyphs))
+    }
+
+    /// Sets the colors used to draw scrollbars.
+    pub fn set_scrollbar_style(&mut self, style: ScrollbarStyle) {
+        self.core.set_scrollbar_style(style);
+    }
+
+    /// Sets the colors used to draw scrollbars.
+    ///
+    /// Chainable variant.
+    pub fn scrollbar_style(self, style: ScrollbarStyle) -> Self {
+        self.with(|s| s.set_scrollbar_style(style))
+    }
+
+    /// Replaces the vertical scrollbar with a minimap of the given width.
+    ///
+    /// The minimap is a compressed view of the whole content height, with
+    /// the viewport highlighted. Clicking it jumps to the matching content.
+    ///
+    /// By default, the content is sampled with [`scroll::sample_rows`] on
+    /// every layout. See [`set_minimap_sampler`](#method.set_minimap_sampler)
+    /// for a cheaper alternative on large content.
+    ///
+    /// A width of 0 disables the minimap, which is the default.
+    pub fn set_minimap(&mut self, width: usize) {
+        self.core.set_minimap_width(width);
+    }
+
+    /// Replaces the vertical scrollbar with a minimap of the given width.
+    ///
+    /// Chainable variant.
+    pub fn minimap(self, width: usize) -> Self {
+        self.with(|s| s.set_minimap(width))
+    }
+
+    /// Sets the function used to sample the content for the minimap.
+    ///
+    /// It is given the content view and its size, and returns the weight of
+    /// each row, like the length of each line of a log.
+    pub fn set_minimap_sampler<F>(&mut self, sampler: F)
+    where
+        F: Fn(&V, Vec2) -> Vec<usize> + 'static,
+    {
+        self.minimap_sampler = Some(Box::new(sampler));
+    }
+
+    /// Sets the function used to sample the content for the minimap.
+    ///
+    /// Chainable variant.
+    pub fn minimap_sampler<F>(self, sampler: F) -> Self
+    where
+        F: Fn(&V, Vec2) -> Vec<usize> + 'static,
+    {
+        self.with(|s| s.set_minimap_sampler(sampler))
+    }
+
+    /// Sets a callback to run when the user scrolls the content.
+    ///
+    /// It is given the new [content viewport](#method.content_viewport),
+    /// whether the scroll comes from the keyboard, the mouse wheel or a
+    /// scrollbar drag. Changing the offset programmatically does not run it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{ScrollView, TextView};
+    ///
+    /// let view = ScrollView::new(TextView::new("...")).on_scroll(|_, viewport| {
+    ///     if viewport.top() > 100 {
+    ///         // Load more content
+    ///     }
+    /// });
+    /// ```
+    pub fn set_on_scroll<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, Rect) + 'static,
+    {
+        self.on_scroll = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when the user scrolls the content.
+    ///
+    /// Chainable variant.
+    pub fn on_scroll<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, Rect) + 'static,
+    {
+        self.with(|s| s.set_on_scroll(cb))
+    }
+
+    /// Returns `true` if the user is dragging the scrollbar.
+    pub fn is_dragging(&self) -> bool {
+        self.core.is_dragging()
+    }
+
     /// Sets the scroll offset to the given value
     pub fn set_offset<S>(&mut self, offset: S)
     where
@@ -135,6 +312,32 @@ where
         self.with(|s| s.set_scroll_x(enabled))
     }
 
+    /// Animates page jumps and programmatic scrolling.
+    ///
+    /// When enabled, the content moves to the new offset over a few frames
+    /// with an easing, instead of jumping there at once. Scrolling again
+    /// cancels the current animation.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{ScrollView, TextView};
+    ///
+    /// let view = ScrollView::new(TextView::new("...")).smooth_scrolling(true);
+    /// ```
+    pub fn set_smooth_scrolling(&mut self, smooth_scrolling: bool) {
+        self.core.set_smooth_scrolling(smooth_scrolling);
+    }
+
+    /// Animates page jumps and programmatic scrolling.
+    ///
+    /// Chainable variant.
+    pub fn smooth_scrolling(self, smooth_scrolling: bool) -> Self {
+        self.with(|s| s.set_smooth_scrolling(smooth_scrolling))
+    }
+
     /// Programmatically scroll to the top of the view.
     pub fn scroll_to_top(&mut self) {
         self.core.scroll_to_top();
@@ -169,6 +372,23 @@ where
     inner_getters!(self.inner: V);
 }
 
+impl<V> Searchable for ScrollView<V>
+where
+    V: Searchable,
+{
+    fn search(&mut self, query: &str, step: SearchStep) -> EventResult {
+        let result = self.inner.search(query, step);
+        if result.is_consumed() {
+            self.scroll_to_important_area();
+        }
+        result
+    }
+
+    fn clear_search(&mut self) {
+        self.inner.clear_search();
+    }
+}
+
 impl<V> View for ScrollView<V>
 where
     V: View,
@@ -178,12 +398,22 @@ where
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
-        scroll::on_event(
+        let viewport = self.content_viewport();
+        let result = scroll::on_event(
             self,
             event,
             |s, e| s.inner.on_event(e),
             |s, si| s.inner.important_area(si),
-        )
+        );
+
+        let new_viewport = self.content_viewport();
+        match self.on_scroll {
+            Some(ref cb) if new_viewport != viewport => {
+                let cb = Rc::clone(cb);
+                result.and(EventResult::with_cb(move |s| cb(s, new_viewport)))
+            }
+            _ => result,
+        }
     }
 
     fn layout(&mut self, size: Vec2) {
@@ -194,6 +424,20 @@ where
             |s, si| s.inner.layout(si),
             |s, c| s.inner.required_size(c),
         );
+
+        if let Some(set_viewport) = self.set_viewport {
+            set_viewport(&mut self.inner, self.core.content_viewport());
+        }
+
+        if self.core.get_minimap_width() > 0 && self.core.shown_scrollbars().y
+        {
+            let inner_size = self.core.inner_size();
+            let samples = match self.minimap_sampler {
+                Some(ref sampler) => sampler(&self.inner, inner_size),
+                None => scroll::sample_rows(&self.inner, inner_size),
+            };
+            self.core.set_minimap_samples(samples);
+        }
     }
 
     fn needs_relayout(&self) -> bool {
@@ -209,6 +453,29 @@ where
         )
     }
 
+    fn measure(&mut self, constraint: Vec2) -> Vec2 {
+        // Approximates `required_size`, with a single pass.
+        let inner_size = self.inner.measure(constraint);
+        let enabled = self.core.is_enabled();
+        let scrolling = enabled
+            .zip_map(inner_size.zip(constraint), |e, (i, c)| e && i > c)
+            .or(self.core.forced_scrollbars());
+
+        let scrollbar_size = if self.core.get_show_scrollbars() {
+            scrolling.swap().select_or(
+                self.core.get_scrollbar_padding() + self.core.thickness(),
+                Vec2::zero(),
+            )
+        } else {
+            Vec2::zero()
+        };
+
+        enabled.select_or(
+            Vec2::min(inner_size + scrollbar_size, constraint),
+            inner_size + scrollbar_size,
+        )
+    }
+
     fn call_on_any<'a>(&mut self, selector: &Selector<'_>, cb: AnyCb<'a>) {
         // TODO: should we scroll_to_important_area here?
         // The callback may change the focus or some other thing.
@@ -221,6 +488,13 @@ where
         })
     }
 
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        self.inner.remove_view(selector)
+    }
+
     fn take_focus(&mut self, source: Direction) -> bool {
         // If the inner view takes focus, re-align the important area.
         if self.inner.take_focus(source) {
@@ -235,3 +509,145 @@ where
         scroll::important_area(self, size, |s, si| s.inner.important_area(si))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::event::{Key, MouseButton, MouseEvent};
+    use crate::views::TextView;
+    use std::time::Duration;
+
+    #[test]
+    fn on_scroll() {
+        let content = "x\n".repeat(20);
+        let mut view = ScrollView::new(TextView::new(content))
+            .on_scroll(|s, viewport| s.set_user_data(viewport.top()));
+        render_to_buffer(&mut view, (4, 5));
+
+        let mut siv = Cursive::dummy();
+        view.on_event(Event::Key(Key::PageDown)).process(&mut siv);
+        assert_eq!(siv.take_user_data::<usize>(), Some(5));
+
+        view.on_event(Event::Key(Key::Home)).process(&mut siv);
+        assert_eq!(siv.take_user_data::<usize>(), Some(0));
+
+        // Nothing happens when the viewport doesn't move.
+        view.on_event(Event::Key(Key::Home)).process(&mut siv);
+        assert_eq!(siv.take_user_data::<usize>(), None);
+    }
+
+    struct Timeline {
+        viewport: Rect,
+    }
+
+    impl View for Timeline {
+        fn draw(&self, _: &Printer<'_, '_>) {}
+
+        fn required_size(&mut self, _: Vec2) -> Vec2 {
+            Vec2::new(1000, 1)
+        }
+    }
+
+    impl ScrollAware for Timeline {
+        fn set_viewport(&mut self, viewport: Rect) {
+            self.viewport = viewport;
+        }
+    }
+
+    #[test]
+    fn aware() {
+        let timeline = Timeline {
+            viewport: Rect::from((0, 0)),
+        };
+        let mut view = ScrollView::aware(timeline).scroll_x(true);
+
+        view.layout(Vec2::new(20, 2));
+        view.set_offset((100, 0));
+        view.layout(Vec2::new(20, 2));
+        assert_eq!(
+            view.get_inner().viewport,
+            Rect::from_size((100, 0), (20, 1))
+        );
+    }
+
+    #[test]
+    fn minimap() {
+        // Text in the first half only.
+        let content = "xxx\n".repeat(10) + "\n".repeat(10).as_str();
+        let mut view = ScrollView::new(TextView::new(content)).minimap(2);
+
+        let lines = render_to_buffer(&mut view, (8, 5)).lines();
+        assert_eq!(lines[0], "xxx   ██");
+        assert_eq!(lines[2], "xxx   █ ");
+        assert_eq!(lines[4], "xxx     ");
+
+        // Clicking the bottom of the minimap jumps to the end.
+        view.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(7, 4),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert!(view.is_at_bottom());
+    }
+
+    #[test]
+    fn scrollbar_options() {
+        // Always shown, even when the content fits.
+        let mut view = ScrollView::new(TextView::new("a\nb"))
+            .scrollbar_visibility(ScrollbarVisibility::Always);
+        let lines = render_to_buffer(&mut view, (4, 3)).lines();
+        assert_eq!(lines, ["a  ▒", "b  ▒", "   ▒"]);
+
+        // On the left, with custom glyphs.
+        let mut view = ScrollView::new(TextView::new("x\n".repeat(6)))
+            .scrollbar_align(Align::top_left())
+            .scrollbar_glyphs(ScrollbarGlyphs {
+                thumb: "#",
+                vertical_track: ":",
+                ..ScrollbarGlyphs::default()
+            });
+        let lines = render_to_buffer(&mut view, (4, 3)).lines();
+        assert_eq!(lines, ["# x ", ": x ", ": x "]);
+
+        // Clicking the track scrolls.
+        view.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(0, 2),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert!(view.is_at_bottom());
+    }
+
+    #[test]
+    fn smooth_scrolling() {
+        let content: Vec<String> =
+            ('a'..='t').map(|c| c.to_string()).collect();
+        let mut view = ScrollView::new(TextView::new(content.join("\n")))
+            .smooth_scrolling(true);
+        let top = |view: &mut ScrollView<TextView>| {
+            render_to_buffer(view, (4, 5)).lines()[0].remove(0)
+        };
+        assert_eq!(top(&mut view), 'a');
+
+        // The page jump starts where we were.
+        view.on_event(Event::Key(Key::PageDown));
+        assert_eq!(view.content_viewport().top(), 5);
+        assert!(view.core.is_animating());
+        assert_eq!(top(&mut view), 'a');
+
+        // Scrolling again cancels the animation.
+        view.on_event(Event::Key(Key::Down));
+        assert!(!view.core.is_animating());
+        assert_eq!(top(&mut view), 'g');
+
+        view.scroll_to_top();
+        assert!(view.core.is_animating());
+        view.core.step_animation(Duration::from_millis(50));
+        assert!(view.core.is_animating());
+        assert_ne!(top(&mut view), 'a');
+        view.core.step_animation(Duration::from_millis(100));
+        assert!(!view.core.is_animating());
+        assert_eq!(top(&mut view), 'a');
+    }
+}
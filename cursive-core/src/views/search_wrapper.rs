@@ -0,0 +1,245 @@
+use crate::event::{Event, EventResult, Key};
+use crate::theme::ColorStyle;
+use crate::view::{SearchStep, Searchable, ViewWrapper};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+use unicode_width::UnicodeWidthStr;
+
+const NOT_FOUND: &str = "(not found)";
+
+/// Adds incremental search to a [`Searchable`] view.
+///
+/// Pressing `/` opens a search field at the bottom of the view. Each key
+/// typed updates the search; `Enter` closes the field and keeps the result,
+/// while `Esc` cancels the search.
+///
+/// Once a search is done, `n` and `N` go to the next and previous matches.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{SearchWrapper, SelectView};
+///
+/// let select = SelectView::<i32>::new()
+///     .item("Apple", 1)
+///     .item("Banana", 2)
+///     .item("Cherry", 3);
+/// let view = SearchWrapper::new(select);
+/// ```
+pub struct SearchWrapper<V> {
+    view: V,
+
+    // Current (or last) search query.
+    query: String,
+
+    // `true` while the search field is shown.
+    editing: bool,
+
+    // `false` if the last search found nothing.
+    found: bool,
+
+    // Set when the search field is shown or hidden.
+    invalidated: bool,
+}
+
+impl<V: Searchable> SearchWrapper<V> {
+    /// Wraps `view` in a new `SearchWrapper`.
+    pub fn new(view: V) -> Self {
+        SearchWrapper {
+            view,
+            query: String::new(),
+            editing: false,
+            found: true,
+            invalidated: true,
+        }
+    }
+
+    /// Returns the current search query.
+    ///
+    /// Returns an empty string if no search was done.
+    pub fn get_query(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns `true` if the search field is currently shown.
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    /// Searches for `query`, as if it was typed in the search field.
+    pub fn set_query<S: Into<String>>(&mut self, query: S) -> EventResult {
+        self.query = query.into();
+        self.search(SearchStep::Current)
+    }
+
+    /// Searches for `query` - chainable variant.
+    pub fn query<S: Into<String>>(self, query: S) -> Self {
+        self.with(|s| {
+            s.set_query(query);
+        })
+    }
+
+    /// Cancels the search, clearing any highlight.
+    pub fn clear_search(&mut self) {
+        self.query.clear();
+        self.found = true;
+        self.set_editing(false);
+        self.view.clear_search();
+    }
+
+    inner_getters!(self.view: V);
+
+    fn set_editing(&mut self, editing: bool) {
+        if self.editing != editing {
+            self.editing = editing;
+            self.invalidated = true;
+        }
+    }
+
+    fn search(&mut self, step: SearchStep) -> EventResult {
+        if self.query.is_empty() {
+            self.found = true;
+            self.view.clear_search();
+            return EventResult::Consumed(None);
+        }
+
+        let result = self.view.search(&self.query, step);
+        self.found = result.is_consumed();
+
+        // Even without a match, the key was used by the search.
+        match result {
+            EventResult::Ignored => EventResult::Consumed(None),
+            result => result,
+        }
+    }
+
+    fn on_event_editing(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Char(c) => {
+                self.query.push(c);
+                self.search(SearchStep::Current)
+            }
+            Event::Key(Key::Backspace) => {
+                self.query.pop();
+                self.search(SearchStep::Current)
+            }
+            Event::Key(Key::Enter) => {
+                self.set_editing(false);
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Esc) => {
+                self.clear_search();
+                EventResult::Consumed(None)
+            }
+            // Other events close the field, and go to the view.
+            event => {
+                self.set_editing(false);
+                self.view.on_event(event)
+            }
+        }
+    }
+}
+
+impl<V: Searchable> ViewWrapper for SearchWrapper<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        if !self.editing {
+            self.view.draw(printer);
+            return;
+        }
+
+        let height = printer.size.y.saturating_sub(1);
+        self.view.draw(&printer.cropped((printer.size.x, height)));
+
+        printer.print((0, height), "/");
+        printer.print((1, height), &self.query);
+        if !self.found {
+            let x = 2 + self.query.width();
+            printer.with_color(ColorStyle::secondary(), |printer| {
+                printer.print((x, height), NOT_FOUND);
+            });
+        }
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        if !self.editing {
+            return self.view.required_size(req);
+        }
+
+        let size = self.view.required_size(req.saturating_sub((0, 1)));
+        // Leave room for the "not found" message.
+        let field_width = 2 + self.query.width() + NOT_FOUND.len();
+        Vec2::new(size.x.max(field_width), size.y + 1)
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.invalidated = false;
+        if self.editing {
+            self.view.layout(size.saturating_sub((0, 1)));
+        } else {
+            self.view.layout(size);
+        }
+    }
+
+    fn wrap_needs_relayout(&self) -> bool {
+        self.invalidated || self.view.needs_relayout()
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        if self.editing {
+            return self.on_event_editing(event);
+        }
+
+        match event {
+            Event::Char('/') => {
+                self.query.clear();
+                self.found = true;
+                self.view.clear_search();
+                self.set_editing(true);
+                EventResult::Consumed(None)
+            }
+            Event::Char('n') if !self.query.is_empty() => {
+                self.search(SearchStep::Next)
+            }
+            Event::Char('N') if !self.query.is_empty() => {
+                self.search(SearchStep::Previous)
+            }
+            event => self.view.on_event(event),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::View;
+    use crate::views::SelectView;
+
+    #[test]
+    fn search_select() {
+        let select = SelectView::<i32>::new()
+            .item("Apple", 1)
+            .item("Banana", 2)
+            .item("Blueberry", 3);
+        let mut view = SearchWrapper::new(select);
+
+        view.on_event(Event::Char('/'));
+        assert!(view.is_editing());
+        view.on_event(Event::Char('b'));
+        assert_eq!(view.get_inner().selected_id(), Some(1));
+        view.on_event(Event::Char('l'));
+        assert_eq!(view.get_inner().selected_id(), Some(2));
+
+        view.on_event(Event::Key(Key::Backspace));
+        view.on_event(Event::Key(Key::Enter));
+        assert!(!view.is_editing());
+        assert_eq!(view.get_query(), "b");
+
+        view.on_event(Event::Char('n'));
+        assert_eq!(view.get_inner().selected_id(), Some(1));
+        view.on_event(Event::Char('N'));
+        assert_eq!(view.get_inner().selected_id(), Some(2));
+    }
+}
@@ -5,9 +5,9 @@ use crate::event::{
 };
 use crate::menu::MenuTree;
 use crate::rect::Rect;
-use crate::theme::ColorStyle;
+use crate::theme::{ColorStyle, Effect};
 use crate::utils::markup::StyledString;
-use crate::view::{Position, View};
+use crate::view::{self, Position, SearchStep, Searchable, View};
 use crate::views::MenuPopup;
 use crate::Cursive;
 use crate::Printer;
@@ -15,8 +15,10 @@ use crate::Vec2;
 use crate::With;
 use std::borrow::Borrow;
 use std::cell::Cell;
-use std::cmp::{min, Ordering};
+use std::cmp::{max, min, Ordering};
+use std::ops::Range;
 use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
 
 /// View to select an item among a list.
 ///
@@ -72,10 +74,46 @@ pub struct SelectView<T = String> {
     // `true` if we show a one-line view, with popup on selection.
     popup: bool,
 
+    // Number of columns in the popup, or 0 to fit the screen.
+    popup_columns: usize,
+
     // We need the last offset to place the popup window
     // We "cache" it during the draw, so we need interior mutability.
     last_offset: Cell<Vec2>,
     last_size: Vec2,
+
+    // If `true`, items can be marked, and a marker column is shown.
+    markable: bool,
+
+    // Event toggling the mark on the selected item.
+    mark_event: Event,
+
+    // Printed in the marker column for marked items.
+    marker: String,
+
+    // Item where the range selection started, if any.
+    //
+    // The range goes from here to the focus.
+    range_anchor: Option<usize>,
+
+    // If `true`, typing characters edits the filter.
+    filterable: bool,
+
+    // Only items with a label matching this are shown, if not empty.
+    filter: String,
+}
+
+/// Returns `true` if `key` moves the focus.
+fn is_movement(key: Key) -> bool {
+    matches!(
+        key,
+        Key::Up
+            | Key::Down
+            | Key::PageUp
+            | Key::PageDown
+            | Key::Home
+            | Key::End
+    )
 }
 
 impl<T: 'static> Default for SelectView<T> {
@@ -97,9 +135,16 @@ impl<T: 'static> SelectView<T> {
             on_submit: None,
             align: Align::top_left(),
             popup: false,
+            popup_columns: 1,
             autojump: false,
             last_offset: Cell::new(Vec2::zero()),
             last_size: Vec2::zero(),
+            markable: false,
+            mark_event: Event::Char(' '),
+            marker: String::from("*"),
+            range_anchor: None,
+            filterable: false,
+            filter: String::new(),
         }
     }
 
@@ -133,6 +178,306 @@ impl<T: 'static> SelectView<T> {
         self.popup = popup;
     }
 
+    /// Sets the number of columns in the popup.
+    ///
+    /// With many short items, several columns reduce scrolling. Items fill
+    /// the columns from top to bottom, and `Left`/`Right` move between
+    /// columns.
+    ///
+    /// Use `0` to pick as many columns as needed to avoid scrolling, within
+    /// the screen width. Defaults to `1`.
+    ///
+    /// Only used with [`popup`](#method.popup) views.
+    pub fn set_popup_columns(&mut self, columns: usize) {
+        self.popup_columns = columns;
+    }
+
+    /// Sets the number of columns in the popup.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let countries = SelectView::<String>::new()
+    ///     .popup()
+    ///     .popup_columns(0)
+    ///     .with_all_str(vec!["France", "Germany", "Italy", "Spain"]);
+    /// ```
+    pub fn popup_columns(self, columns: usize) -> Self {
+        self.with(|s| s.set_popup_columns(columns))
+    }
+
+    /// Enables or disables marking items.
+    ///
+    /// Marks are independent from the selection, and can be used for bulk
+    /// operations on several items at once. When enabled, a marker column is
+    /// shown on the left of the items, and the mark event (`Space` by default)
+    /// toggles the mark on the selected item.
+    ///
+    /// Marking is not available for popup select views.
+    pub fn set_markable(&mut self, markable: bool) {
+        self.markable = markable;
+    }
+
+    /// Enables marking items.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let mut select = SelectView::new()
+    ///     .markable()
+    ///     .item("foo.txt", 1)
+    ///     .item("bar.txt", 2)
+    ///     .item("baz.rs", 3);
+    ///
+    /// select.mark_where(|&id| id != 2);
+    /// let marked: Vec<_> = select.marked_items().map(|(_, &id)| id).collect();
+    /// assert_eq!(marked, vec![1, 3]);
+    /// ```
+    pub fn markable(self) -> Self {
+        self.with(|s| s.set_markable(true))
+    }
+
+    /// Returns `true` if items can be marked.
+    pub fn is_markable(&self) -> bool {
+        self.markable
+    }
+
+    /// Sets the event toggling the mark on the selected item.
+    ///
+    /// Defaults to `Event::Char(' ')`.
+    pub fn set_mark_event<E: Into<Event>>(&mut self, event: E) {
+        self.mark_event = event.into();
+    }
+
+    /// Sets the event toggling the mark on the selected item.
+    ///
+    /// Chainable variant.
+    pub fn mark_event<E: Into<Event>>(self, event: E) -> Self {
+        self.with(|s| s.set_mark_event(event))
+    }
+
+    /// Sets the text shown in the marker column for marked items.
+    ///
+    /// Defaults to `"*"`.
+    pub fn set_marker<S: Into<String>>(&mut self, marker: S) {
+        self.marker = marker.into();
+    }
+
+    /// Sets the text shown in the marker column for marked items.
+    ///
+    /// Chainable variant.
+    pub fn marker<S: Into<String>>(self, marker: S) -> Self {
+        self.with(|s| s.set_marker(marker))
+    }
+
+    /// Returns `true` if the item at the given position is marked.
+    pub fn is_marked(&self, i: usize) -> bool {
+        self.items.get(i).map(|item| item.marked).unwrap_or(false)
+    }
+
+    /// Marks or unmarks the item at the given position.
+    ///
+    /// Does nothing if `i` is out of bounds.
+    pub fn set_marked(&mut self, i: usize, marked: bool) {
+        if let Some(item) = self.items.get_mut(i) {
+            item.marked = marked;
+        }
+    }
+
+    /// Toggles the mark on the item at the given position.
+    pub fn toggle_mark(&mut self, i: usize) {
+        let marked = self.is_marked(i);
+        self.set_marked(i, !marked);
+    }
+
+    /// Marks every item whose value matches the given predicate.
+    ///
+    /// Other items are left untouched.
+    pub fn mark_where<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        for item in &mut self.items {
+            if predicate(&item.value) {
+                item.marked = true;
+            }
+        }
+    }
+
+    /// Removes the mark from every item.
+    pub fn clear_marks(&mut self) {
+        for item in &mut self.items {
+            item.marked = false;
+        }
+    }
+
+    /// Returns the positions of the marked items.
+    pub fn marked_ids(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.marked)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Iterate on the marked items.
+    ///
+    /// Returns an iterator with each marked item and their labels.
+    pub fn marked_items(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.items
+            .iter()
+            .filter(|item| item.marked)
+            .map(|item| (item.label.source(), &*item.value))
+    }
+
+    /// Returns the positions of the items in the selected range.
+    ///
+    /// When the view is markable, a range of items can be selected with
+    /// `Shift` and the arrow keys, or by dragging the mouse. The mark event
+    /// then applies to the whole range.
+    ///
+    /// Returns `None` if no more than one item is selected.
+    pub fn selection_range(&self) -> Option<Range<usize>> {
+        let last = self.items.len().checked_sub(1)?;
+        let anchor = min(self.range_anchor?, last);
+        let focus = min(self.focus(), last);
+        if anchor == focus {
+            None
+        } else {
+            Some(min(anchor, focus)..max(anchor, focus) + 1)
+        }
+    }
+
+    /// Clears the selected range, keeping only the focused item.
+    pub fn clear_selection_range(&mut self) {
+        self.range_anchor = None;
+    }
+
+    /// Lets the user filter the items by typing.
+    ///
+    /// When enabled, typed characters are added to the
+    /// [filter](#method.set_filter), `Backspace` removes the last one, and
+    /// `Esc` clears it. This replaces [autojump](#method.set_autojump).
+    ///
+    /// Defaults to `false`.
+    pub fn set_filterable(&mut self, filterable: bool) {
+        self.filterable = filterable;
+    }
+
+    /// Lets the user filter the items by typing.
+    ///
+    /// Chainable variant.
+    pub fn filterable(self) -> Self {
+        self.with(|s| s.set_filterable(true))
+    }
+
+    /// Only shows the items with a label containing `filter`.
+    ///
+    /// Case is ignored, unless `filter` contains an uppercase character.
+    /// Matching parts of the labels are underlined. An empty filter shows
+    /// every item.
+    ///
+    /// If the selected item is hidden, the first item shown is selected.
+    /// Items keep their position: `selected_id` and `get_item` still use
+    /// indices in the full list. Filters are ignored in popup mode.
+    ///
+    /// Returns a callback in response to the selection change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let mut select_view = SelectView::new()
+    ///     .item("apple", 1)
+    ///     .item("banana", 2)
+    ///     .item("cherry", 3);
+    ///
+    /// select_view.set_filter("an");
+    /// assert_eq!(select_view.visible_ids(), vec![1]);
+    /// assert_eq!(select_view.selected_id(), Some(1));
+    /// ```
+    pub fn set_filter<S: Into<String>>(&mut self, filter: S) -> Callback {
+        self.filter = filter.into();
+        self.filter_changed().unwrap_or_else(Callback::dummy)
+    }
+
+    /// Removes the filter, showing every item.
+    pub fn clear_filter(&mut self) -> Callback {
+        self.set_filter(String::new())
+    }
+
+    /// Returns the current filter.
+    ///
+    /// Returns an empty string if every item is shown.
+    pub fn get_filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Returns the positions of the items currently shown.
+    pub fn visible_ids(&self) -> Vec<usize> {
+        (0..self.items.len())
+            .filter(|&i| self.is_visible(i))
+            .collect()
+    }
+
+    // Returns `true` if the item at `i` matches the filter.
+    fn is_visible(&self, i: usize) -> bool {
+        self.popup
+            || self.filter.is_empty()
+            || view::is_match(self.items[i].label.source(), &self.filter)
+    }
+
+    // Selects the first item shown if the selected item was hidden.
+    //
+    // Returns the selection callback if the selection changed.
+    fn filter_changed(&mut self) -> Option<Callback> {
+        self.range_anchor = None;
+        if self.is_empty() || self.is_visible(self.focus()) {
+            return None;
+        }
+        let first = (0..self.items.len()).find(|&i| self.is_visible(i))?;
+        self.focus.set(first);
+        self.make_select_cb()
+    }
+
+    // Handles the events editing the filter.
+    fn on_filter_event(&mut self, event: &Event) -> Option<EventResult> {
+        match *event {
+            Event::Char(c)
+                if !(self.markable && *event == self.mark_event) =>
+            {
+                self.filter.push(c);
+            }
+            Event::Key(Key::Backspace) if !self.filter.is_empty() => {
+                self.filter.pop();
+            }
+            Event::Key(Key::Esc) if !self.filter.is_empty() => {
+                self.filter.clear();
+            }
+            _ => return None,
+        }
+        Some(EventResult::Consumed(self.filter_changed()))
+    }
+
+    // Width of the marker column, including a separating space.
+    fn marker_width(&self) -> usize {
+        if self.markable && !self.popup {
+            self.marker.width() + 1
+        } else {
+            0
+        }
+    }
+
     /// Sets a callback to be used when an item is selected.
     pub fn set_on_select<F>(&mut self, cb: F)
     where
@@ -273,10 +618,23 @@ impl<T: 'static> SelectView<T> {
         }
     }
 
+    // Returns the value of the selected item, if it can be chosen.
+    //
+    // Disabled or hidden items are focused when nothing else is.
+    fn chosen_value(&self) -> Option<Rc<T>> {
+        let focus = self.focus();
+        if !self.is_selectable(focus) || !self.is_visible(focus) {
+            None
+        } else {
+            Some(Rc::clone(&self.items[focus].value))
+        }
+    }
+
     /// Removes all items from this view.
     pub fn clear(&mut self) {
         self.items.clear();
         self.focus.set(0);
+        self.range_anchor = None;
     }
 
     /// Adds a item to the list, with given label and value.
@@ -359,6 +717,57 @@ impl<T: 'static> SelectView<T> {
         self.items.insert(index, Item::new(label.into(), value));
     }
 
+    /// Adds a disabled item to the list.
+    ///
+    /// Disabled items are shown with a secondary style, and are skipped when
+    /// moving the selection.
+    pub fn add_disabled_item<S: Into<StyledString>>(
+        &mut self,
+        label: S,
+        value: T,
+    ) {
+        self.add_item(label, value);
+        if let Some(item) = self.items.last_mut() {
+            item.enabled = false;
+        }
+    }
+
+    /// Adds a disabled item to the list.
+    ///
+    /// Chainable variant.
+    pub fn disabled_item<S: Into<StyledString>>(
+        self,
+        label: S,
+        value: T,
+    ) -> Self {
+        self.with(|s| s.add_disabled_item(label, value))
+    }
+
+    /// Enables or disables the item at the given position.
+    ///
+    /// Does nothing if `i` is out of bounds.
+    pub fn set_item_enabled(&mut self, i: usize, enabled: bool) {
+        if let Some(item) = self.items.get_mut(i) {
+            item.enabled = enabled;
+        }
+    }
+
+    /// Returns `true` if the item at the given position is enabled.
+    ///
+    /// Headers are always enabled, even though they cannot be selected.
+    pub fn is_item_enabled(&self, i: usize) -> bool {
+        self.items.get(i).is_some_and(|item| item.enabled)
+    }
+
+    /// Returns `true` if the item at the given position is a header.
+    pub fn is_header(&self, i: usize) -> bool {
+        self.items.get(i).is_some_and(|item| item.header)
+    }
+
+    fn is_selectable(&self, i: usize) -> bool {
+        self.items.get(i).is_some_and(Item::is_selectable)
+    }
+
     /// Chainable variant of add_item
     ///
     /// # Examples
@@ -409,7 +818,108 @@ impl<T: 'static> SelectView<T> {
         self.with(|s| s.add_all(iter))
     }
 
+    /// Replaces all items, keeping the same item selected.
+    ///
+    /// The selected item is found in the new list by comparing values, so
+    /// that periodically refreshed lists don't jump back to the first item.
+    /// Marks are kept the same way. If the selected item is gone, the
+    /// selection stays at the same position.
+    ///
+    /// Returns a callback in response to the selection change, if the
+    /// selected item is gone.
+    ///
+    /// You should run this callback with a `&mut Cursive`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let mut select_view = SelectView::new()
+    ///     .item("bash", 12)
+    ///     .item("vim", 42)
+    ///     .selected(1);
+    ///
+    /// select_view.set_items_preserving_selection(vec![
+    ///     ("cargo", 7),
+    ///     ("bash", 12),
+    ///     ("vim", 42),
+    /// ]);
+    /// assert_eq!(select_view.selected_id(), Some(2));
+    /// ```
+    pub fn set_items_preserving_selection<S, I>(&mut self, iter: I) -> Callback
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+        T: PartialEq,
+    {
+        self.replace_items(iter, |a, b| a == b)
+    }
+
+    /// Replaces all items, keeping the same item selected.
+    ///
+    /// Like `set_items_preserving_selection`, but items are matched using the
+    /// key returned by `key`, for example a process ID.
+    pub fn set_items_preserving_selection_by_key<S, I, K, F>(
+        &mut self,
+        iter: I,
+        mut key: F,
+    ) -> Callback
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        self.replace_items(iter, |a, b| key(a) == key(b))
+    }
+
+    fn replace_items<S, I, F>(&mut self, iter: I, mut same: F) -> Callback
+    where
+        S: Into<StyledString>,
+        I: IntoIterator<Item = (S, T)>,
+        F: FnMut(&T, &T) -> bool,
+    {
+        let selected = self.selection();
+        let focus = self.focus();
+        let marked: Vec<Rc<T>> = self
+            .items
+            .drain(..)
+            .filter(|item| item.marked)
+            .map(|item| item.value)
+            .collect();
+
+        self.add_all(iter);
+
+        for item in &mut self.items {
+            item.marked = marked.iter().any(|old| same(old, &item.value));
+        }
+
+        let found = selected.and_then(|selected| {
+            self.items
+                .iter()
+                .position(|item| same(&selected, &item.value))
+        });
+
+        match found {
+            Some(i) => {
+                self.focus.set(i);
+                Callback::dummy()
+            }
+            None => self.set_selection(focus),
+        }
+    }
+
     fn draw_item(&self, printer: &Printer<'_, '_>, i: usize) {
+        let marker_width = self.marker_width();
+        if marker_width > 0 {
+            printer.print_hline((0, 0), marker_width, " ");
+            if self.items[i].marked {
+                printer.print((0, 0), &self.marker);
+            }
+        }
+        let printer = &printer.offset((marker_width, 0));
+
         let l = self.items[i].label.width();
         let x = self.align.h.get_offset(l, printer.size.x);
         printer.print_hline((0, 0), x, " ");
@@ -418,6 +928,15 @@ impl<T: 'static> SelectView<T> {
             assert!((l + x) <= printer.size.x);
             printer.print_hline((x + l, 0), printer.size.x - (l + x), " ");
         }
+
+        // Underline the parts matching the filter.
+        let text = self.items[i].label.source();
+        for range in view::find_matches(text, &self.filter) {
+            let offset = x + text[..range.start].width();
+            printer.with_effect(Effect::Underline, |printer| {
+                printer.print((offset, 0), &text[range]);
+            });
+        }
     }
 
     /// Returns the id of the item currently selected.
@@ -576,21 +1095,112 @@ impl<T: 'static> SelectView<T> {
         self.make_select_cb().unwrap_or_else(Callback::dummy)
     }
 
+    // Moves the focus up by `n` rows, to the closest selectable item.
+    //
+    // If there is none above the target row, the closest one below it is
+    // used instead, without going past the current focus.
     fn focus_up(&mut self, n: usize) {
-        let focus = self.focus().saturating_sub(n);
-        self.focus.set(focus);
+        let focus = match self.shown_focus() {
+            Some(focus) => focus,
+            None => return,
+        };
+        let target = (0..focus)
+            .rev()
+            .filter(|&i| self.is_visible(i))
+            .take(n)
+            .last()
+            .unwrap_or(focus);
+
+        let above = (0..=target).rev();
+        let below = target + 1..=focus;
+        if let Some(i) = above
+            .chain(below)
+            .find(|&i| self.is_visible(i) && self.is_selectable(i))
+        {
+            self.focus.set(i);
+        }
     }
 
+    // Moves the focus down by `n` rows, to the closest selectable item.
     fn focus_down(&mut self, n: usize) {
-        let focus = min(self.focus() + n, self.items.len().saturating_sub(1));
-        self.focus.set(focus);
+        let focus = match self.shown_focus() {
+            Some(focus) => focus,
+            None => return,
+        };
+        let target = (focus + 1..self.items.len())
+            .filter(|&i| self.is_visible(i))
+            .take(n)
+            .last()
+            .unwrap_or(focus);
+
+        let below = target..self.items.len();
+        let above = (focus..target).rev();
+        if let Some(i) = below
+            .chain(above)
+            .find(|&i| self.is_visible(i) && self.is_selectable(i))
+        {
+            self.focus.set(i);
+        }
+    }
+
+    // Returns the focus, or the first item shown if it is hidden.
+    fn shown_focus(&self) -> Option<usize> {
+        let focus = self.focus();
+        if focus < self.items.len() && self.is_visible(focus) {
+            Some(focus)
+        } else {
+            (0..self.items.len()).find(|&i| self.is_visible(i))
+        }
+    }
+
+    // Returns `true` if a selectable item is shown above (or below) the
+    // focus.
+    fn can_move(&self, up: bool) -> bool {
+        let focus = self.focus();
+        let len = self.items.len();
+        let mut rows = if focus >= len || !self.is_visible(focus) {
+            0..len
+        } else if up {
+            0..focus
+        } else {
+            focus + 1..len
+        };
+        rows.any(|i| self.is_visible(i) && self.is_selectable(i))
+    }
+
+    // Number of rows shown, and row of the selected item.
+    fn rows(&self) -> (usize, usize) {
+        if self.filter.is_empty() {
+            return (self.items.len(), self.focus());
+        }
+        let focus = self.focus();
+        let mut rows = 0;
+        let mut focus_row = 0;
+        for i in (0..self.items.len()).filter(|&i| self.is_visible(i)) {
+            if i == focus {
+                focus_row = rows;
+            }
+            rows += 1;
+        }
+        (rows, focus_row)
+    }
+
+    // Item shown on the given row, if any.
+    fn row_item(&self, row: usize) -> Option<usize> {
+        if self.filter.is_empty() {
+            Some(row).filter(|&row| row < self.items.len())
+        } else {
+            (0..self.items.len())
+                .filter(|&i| self.is_visible(i))
+                .nth(row)
+        }
     }
 
     fn submit(&mut self) -> EventResult {
         let cb = self.on_submit.clone().unwrap();
         // We return a Callback Rc<|s| cb(s, &*v)>
         EventResult::Consumed(
-            self.selection()
+            self.chosen_value()
                 .map(|v| Callback::from_fn(move |s| cb(s, &v))),
         )
     }
@@ -608,7 +1218,10 @@ impl<T: 'static> SelectView<T> {
             let lower_c: &[char] = &lower_c;
 
             if let Some((i, _)) = iter.enumerate().skip(self.focus() + 1).find(
-                |&(_, (label, _))| label.to_lowercase().starts_with(lower_c),
+                |&(i, (label, _))| {
+                    self.is_selectable(i % self.len())
+                        && label.to_lowercase().starts_with(lower_c)
+                },
             ) {
                 i % self.len()
             } else {
@@ -623,39 +1236,81 @@ impl<T: 'static> SelectView<T> {
     }
 
     fn on_event_regular(&mut self, event: Event) -> EventResult {
+        if self.markable {
+            match event {
+                // `Shift` extends the range from the current item.
+                Event::Shift(key) if is_movement(key) => {
+                    let anchor = self.range_anchor.unwrap_or(self.focus());
+                    let result = self.on_event_regular(Event::Key(key));
+                    self.range_anchor = Some(anchor);
+                    return result;
+                }
+                Event::Key(key) if is_movement(key) => {
+                    self.range_anchor = None;
+                }
+                _ => (),
+            }
+        }
+
+        if self.filterable {
+            if let Some(result) = self.on_filter_event(&event) {
+                return result;
+            }
+        }
+
+        let (rows, focus_row) = self.rows();
+
         match event {
-            Event::Key(Key::Up) if self.focus() > 0 => self.focus_up(1),
-            Event::Key(Key::Down) if self.focus() + 1 < self.items.len() => {
+            Event::Key(Key::Up) if self.can_move(true) => self.focus_up(1),
+            Event::Key(Key::Down) if self.can_move(false) => {
                 self.focus_down(1)
             }
             Event::Key(Key::PageUp) => self.focus_up(10),
             Event::Key(Key::PageDown) => self.focus_down(10),
-            Event::Key(Key::Home) => self.focus.set(0),
-            Event::Key(Key::End) => {
-                self.focus.set(self.items.len().saturating_sub(1))
-            }
+            Event::Key(Key::Home) => self.focus_up(usize::MAX),
+            Event::Key(Key::End) => self.focus_down(usize::MAX),
             Event::Mouse {
                 event: MouseEvent::Press(_),
                 position,
                 offset,
             } if position
                 .checked_sub(offset)
-                .map(|position| {
-                    position < self.last_size && position.y < self.len()
-                })
+                .map(|position| position < self.last_size && position.y < rows)
                 .unwrap_or(false) =>
             {
-                self.focus.set(position.y - offset.y)
+                if let Some(i) = self
+                    .row_item(position.y - offset.y)
+                    .filter(|&i| self.is_selectable(i))
+                {
+                    self.focus.set(i);
+                }
+                // Dragging from here selects a range.
+                self.range_anchor =
+                    Some(self.focus()).filter(|_| self.markable);
+            }
+            Event::Mouse {
+                event: MouseEvent::Hold(MouseButton::Left),
+                position,
+                offset,
+            } if self.range_anchor.is_some() && rows > 0 => {
+                let y = position.saturating_sub(offset).y;
+                if let Some(i) = self
+                    .row_item(min(y, rows - 1))
+                    .filter(|&i| self.is_selectable(i))
+                {
+                    self.focus.set(i);
+                }
             }
             Event::Mouse {
                 event: MouseEvent::Release(MouseButton::Left),
                 position,
                 offset,
             } if self.on_submit.is_some()
+                && self.selection_range().is_none()
                 && position
                     .checked_sub(offset)
                     .map(|position| {
-                        position < self.last_size && position.y == self.focus()
+                        position < self.last_size && position.y == focus_row
                     })
                     .unwrap_or(false) =>
             {
@@ -664,6 +1319,24 @@ impl<T: 'static> SelectView<T> {
             Event::Key(Key::Enter) if self.on_submit.is_some() => {
                 return self.submit();
             }
+            ref event if self.markable && *event == self.mark_event => {
+                if let Some(range) = self.selection_range() {
+                    // Mark the whole range, unless it's already marked.
+                    let marked = range.clone().all(|i| self.is_marked(i));
+                    for i in range {
+                        self.set_marked(i, !marked);
+                    }
+                    self.range_anchor = None;
+                    return EventResult::Consumed(None);
+                }
+
+                let focus = self.focus();
+                if focus >= self.items.len() {
+                    return EventResult::Ignored;
+                }
+                self.toggle_mark(focus);
+                self.focus_down(1);
+            }
             Event::Char(c) if self.autojump => return self.on_char_event(c),
             _ => return EventResult::Ignored,
         }
@@ -674,7 +1347,7 @@ impl<T: 'static> SelectView<T> {
     /// Returns a callback from selection change.
     fn make_select_cb(&self) -> Option<Callback> {
         self.on_select.clone().and_then(|cb| {
-            self.selection()
+            self.chosen_value()
                 .map(|v| Callback::from_fn(move |s| cb(s, &v)))
         })
     }
@@ -687,6 +1360,10 @@ impl<T: 'static> SelectView<T> {
             let focus = Rc::clone(&self.focus);
             let on_submit = self.on_submit.as_ref().cloned();
             let value = Rc::clone(&item.value);
+            if !item.is_selectable() {
+                tree.add_leaf(item.label.source(), |_| ());
+                continue;
+            }
             tree.add_leaf(item.label.source(), move |s| {
                 // TODO: What if an item was removed in the meantime?
                 focus.set(i);
@@ -710,10 +1387,20 @@ impl<T: 'static> SelectView<T> {
         // * shifted to the right of the text offset
         // * shifted to the top of the focus (so the line matches)
         // * shifted top-left of the border+padding of the popup
+        // With several columns, the focused row is only known for a fixed
+        // number of columns.
+        let row = match self.popup_columns {
+            0 => 0,
+            columns => {
+                let rows = self.items.len().div_ceil(columns);
+                focus % rows
+            }
+        };
         let offset = self.last_offset.get();
         let offset = offset + (text_offset, 0);
-        let offset = offset.saturating_sub((0, focus));
+        let offset = offset.saturating_sub((0, row));
         let offset = offset.saturating_sub((2, 1));
+        let columns = self.popup_columns;
 
         // And now, we can return the callback that will create the popup.
         EventResult::with_cb(move |s| {
@@ -728,7 +1415,7 @@ impl<T: 'static> SelectView<T> {
             // And finally, put the view in view!
             s.screen_mut().add_layer_at(
                 Position::parent(offset),
-                MenuPopup::new(tree).focus(focus),
+                MenuPopup::new(tree).focus(focus).columns(columns),
             );
         })
     }
@@ -839,6 +1526,59 @@ where
     }
 }
 
+impl<T: 'static> SelectView<T>
+where
+    T: Default,
+{
+    /// Adds a header to the list.
+    ///
+    /// Headers group the items below them. They are shown with a title
+    /// style, and cannot be selected. Their value is `T::default()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::SelectView;
+    ///
+    /// let select_view = SelectView::new()
+    ///     .header("Fruits")
+    ///     .item_str("Apple")
+    ///     .item_str("Banana")
+    ///     .header("Vegetables")
+    ///     .item_str("Carrot");
+    ///
+    /// assert!(select_view.is_header(3));
+    /// ```
+    pub fn add_header<S: Into<StyledString>>(&mut self, label: S) {
+        self.add_item(label, T::default());
+        if let Some(item) = self.items.last_mut() {
+            item.header = true;
+        }
+    }
+
+    /// Adds a header to the list.
+    ///
+    /// Chainable variant.
+    pub fn header<S: Into<StyledString>>(self, label: S) -> Self {
+        self.with(|s| s.add_header(label))
+    }
+}
+
+impl<T: 'static> Searchable for SelectView<T> {
+    fn search(&mut self, query: &str, step: SearchStep) -> EventResult {
+        let found =
+            view::search_order(self.len(), self.focus(), step).find(|&i| {
+                self.is_selectable(i)
+                    && view::is_match(self.items[i].label.source(), query)
+            });
+
+        match found {
+            Some(i) => EventResult::Consumed(Some(self.set_selection(i))),
+            None => EventResult::Ignored,
+        }
+    }
+}
+
 impl<T: 'static> View for SelectView<T> {
     fn draw(&self, printer: &Printer<'_, '_>) {
         self.last_offset.set(printer.offset);
@@ -876,22 +1616,40 @@ impl<T: 'static> View for SelectView<T> {
                 }
             });
         } else {
-            // Non-popup mode: we always print the entire list.
-            let h = self.items.len();
+            // Non-popup mode: we print every item matching the filter.
+            let (h, _) = self.rows();
             let offset = self.align.v.get_offset(h, printer.size.y);
             let printer = &printer.offset((0, offset));
+            let range = self.selection_range().unwrap_or(0..0);
 
-            for i in 0..self.len() {
-                printer.offset((0, i)).with_selection(
-                    i == self.focus(),
+            let visible =
+                (0..self.items.len()).filter(|&i| self.is_visible(i));
+            for (row, i) in visible.enumerate() {
+                if !printer.is_visible((0, row), (printer.size.x, 1)) {
+                    continue;
+                }
+                let item = &self.items[i];
+                printer.offset((0, row)).with_selection(
+                    i == self.focus() && item.is_selectable(),
                     |printer| {
-                        if i != self.focus()
-                            && !(self.enabled && printer.enabled)
+                        if item.header {
+                            printer.with_color(
+                                ColorStyle::title_primary(),
+                                |printer| self.draw_item(printer, i),
+                            );
+                        } else if !item.enabled
+                            || (i != self.focus()
+                                && !(self.enabled && printer.enabled))
                         {
                             printer.with_color(
                                 ColorStyle::secondary(),
                                 |printer| self.draw_item(printer, i),
                             );
+                        } else if i != self.focus() && range.contains(&i) {
+                            printer.with_color(
+                                ColorStyle::highlight_inactive(),
+                                |printer| self.draw_item(printer, i),
+                            );
                         } else {
                             self.draw_item(printer, i);
                         }
@@ -901,7 +1659,11 @@ impl<T: 'static> View for SelectView<T> {
         }
     }
 
-    fn required_size(&mut self, _: Vec2) -> Vec2 {
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        self.measure(req)
+    }
+
+    fn measure(&mut self, _: Vec2) -> Vec2 {
         // Items here are not compressible.
         // So no matter what the horizontal requirements are,
         // we'll still return our longest item.
@@ -910,11 +1672,12 @@ impl<T: 'static> View for SelectView<T> {
             .iter()
             .map(|item| item.label.width())
             .max()
-            .unwrap_or(1);
+            .unwrap_or(1)
+            + self.marker_width();
         if self.popup {
             Vec2::new(w + 2, 1)
         } else {
-            let h = self.items.len();
+            let (h, _) = self.rows();
 
             Vec2::new(w, h)
         }
@@ -929,7 +1692,19 @@ impl<T: 'static> View for SelectView<T> {
     }
 
     fn take_focus(&mut self, _: Direction) -> bool {
-        self.enabled && !self.items.is_empty()
+        if !self.enabled {
+            return false;
+        }
+        if self.popup {
+            return !self.items.is_empty();
+        }
+        if !self.is_selectable(self.focus()) {
+            self.focus_down(0);
+        }
+        if !self.is_selectable(self.focus()) {
+            self.focus_up(0);
+        }
+        self.is_selectable(self.focus())
     }
 
     fn layout(&mut self, size: Vec2) {
@@ -937,8 +1712,9 @@ impl<T: 'static> View for SelectView<T> {
     }
 
     fn important_area(&self, size: Vec2) -> Rect {
+        let (_, row) = self.rows();
         self.selected_id()
-            .map(|i| Rect::from_size((0, i), (size.x, 1)))
+            .map(|_| Rect::from_size((0, row), (size.x, 1)))
             .unwrap_or_else(|| Rect::from((0, 0)))
     }
 }
@@ -947,12 +1723,27 @@ impl<T: 'static> View for SelectView<T> {
 struct Item<T> {
     label: StyledString,
     value: Rc<T>,
+    marked: bool,
+    // Disabled items are shown but cannot be selected.
+    enabled: bool,
+    // Headers are never selectable, and use a title style.
+    header: bool,
 }
 
 impl<T> Item<T> {
     fn new(label: StyledString, value: T) -> Self {
         let value = Rc::new(value);
-        Item { label, value }
+        Item {
+            label,
+            value,
+            marked: false,
+            enabled: true,
+            header: false,
+        }
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.enabled && !self.header
     }
 }
 
@@ -960,6 +1751,66 @@ impl<T> Item<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn preserve_selection() {
+        let mut view = SelectView::new()
+            .item("init", (1, 0))
+            .item("bash", (12, 0))
+            .item("vim", (42, 1))
+            .selected(2);
+        view.set_mark_event(' ');
+        view.set_markable(true);
+        view.on_event(Event::Char(' '));
+
+        // Refreshed list, with new labels and order.
+        view.set_items_preserving_selection_by_key(
+            vec![("vim 2%", (42, 2)), ("init", (1, 0)), ("bash", (12, 0))],
+            |&(pid, _)| pid,
+        );
+        assert_eq!(view.selected_id(), Some(0));
+        assert_eq!(view.marked_ids(), vec![0]);
+
+        // The selected item is gone: stay at the same position.
+        view.set_items_preserving_selection_by_key(
+            vec![("init", (1, 0)), ("bash", (12, 0))],
+            |&(pid, _)| pid,
+        );
+        assert_eq!(view.selected_id(), Some(0));
+        assert!(view.marked_ids().is_empty());
+    }
+
+    #[test]
+    fn range_selection() {
+        let mut view = SelectView::new()
+            .with_all_str(vec!["a", "b", "c", "d", "e"])
+            .markable();
+        view.set_selection(1);
+
+        view.on_event(Event::Shift(Key::Down));
+        view.on_event(Event::Shift(Key::Down));
+        assert_eq!(view.selection_range(), Some(1..4));
+
+        // The mark applies to the whole range.
+        view.on_event(Event::Char(' '));
+        assert_eq!(view.marked_ids(), vec![1, 2, 3]);
+        assert_eq!(view.selection_range(), None);
+
+        // Dragging the mouse also selects a range.
+        let mouse = |event, y| Event::Mouse {
+            event,
+            position: Vec2::new(0, y),
+            offset: Vec2::zero(),
+        };
+        view.layout(Vec2::new(10, 5));
+        view.on_event(mouse(MouseEvent::Press(MouseButton::Left), 4));
+        view.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 2));
+        assert_eq!(view.selection_range(), Some(2..5));
+
+        // Moving without `Shift` clears the range.
+        view.on_event(Event::Key(Key::Up));
+        assert_eq!(view.selection_range(), None);
+    }
+
     #[test]
     fn select_view_sorting() {
         // We add items in no particular order, from going by their label.
@@ -1052,4 +1903,109 @@ mod tests {
         view.on_event(Event::Key(Key::Down));
         assert_eq!(view.selection(), Some(Rc::new(3)));
     }
+
+    #[test]
+    fn filter() {
+        let mut view = SelectView::new()
+            .filterable()
+            .item_str("apple")
+            .item_str("banana")
+            .item_str("cherry")
+            .item_str("grape");
+
+        // Typing narrows the list.
+        view.on_event(Event::Char('a'));
+        view.on_event(Event::Char('p'));
+        assert_eq!(view.get_filter(), "ap");
+        assert_eq!(view.visible_ids(), vec![0, 3]);
+
+        view.on_event(Event::Key(Key::Down));
+        assert_eq!(view.selected_id(), Some(3));
+        assert_eq!(
+            crate::buffer::render_to_buffer(&mut view, (6, 4)).lines(),
+            ["apple ", "grape ", "      ", "      "]
+        );
+
+        // Esc shows every item again.
+        view.on_event(Event::Key(Key::Esc));
+        assert_eq!(view.visible_ids().len(), 4);
+        assert_eq!(view.selected_id(), Some(3));
+
+        // A hidden item stays focused, but can't be submitted.
+        view.set_filter("xyz");
+        assert_eq!(view.selected_id(), Some(3));
+        assert!(view.chosen_value().is_none());
+    }
+
+    #[test]
+    fn headers() {
+        let mut view = SelectView::new()
+            .header("Fruits")
+            .item_str("apple")
+            .disabled_item("banana", String::new())
+            .header("Vegetables")
+            .item_str("carrot");
+
+        // The focus skips the header.
+        assert!(view.take_focus(Direction::none()));
+        assert_eq!(view.selected_id(), Some(1));
+
+        // And the disabled item and the next header.
+        view.on_event(Event::Key(Key::Down));
+        assert_eq!(view.selected_id(), Some(4));
+        assert!(!view.on_event(Event::Key(Key::Down)).is_consumed());
+
+        view.on_event(Event::Key(Key::Home));
+        assert_eq!(view.selected_id(), Some(1));
+        assert!(!view.on_event(Event::Key(Key::Up)).is_consumed());
+
+        view.set_item_enabled(2, true);
+        view.on_event(Event::Key(Key::Down));
+        assert_eq!(view.selected_id(), Some(2));
+    }
+
+    #[test]
+    fn select_view_marks() {
+        let mut view = SelectView::new()
+            .markable()
+            .item("A", 1)
+            .item("B", 2)
+            .item("C", 3);
+
+        // Marking moves the selection down.
+        view.on_event(Event::Char(' '));
+        view.on_event(Event::Char(' '));
+        assert_eq!(view.marked_ids(), vec![0, 1]);
+        assert_eq!(view.selected_id(), Some(2));
+
+        // Marks stay on their item when sorting.
+        view.sort_by_key(|&v| std::cmp::Reverse(v));
+        assert_eq!(view.marked_ids(), vec![1, 2]);
+
+        view.toggle_mark(2);
+        let marked: Vec<_> = view.marked_items().map(|(l, _)| l).collect();
+        assert_eq!(marked, vec!["B"]);
+
+        view.clear_marks();
+        assert!(view.marked_ids().is_empty());
+    }
+
+    #[test]
+    fn clipped_draw() {
+        use crate::buffer::render_to_buffer;
+        use crate::rect::Rect;
+        use crate::views::Canvas;
+
+        let view =
+            SelectView::<String>::new().with_all_str(vec!["a", "b", "c"]);
+        let mut canvas = Canvas::wrap(view).with_draw(|view, printer| {
+            let printer = printer.clipped(Rect::from_size((0, 1), (2, 1)));
+            assert!(!printer.is_visible((0, 0), (2, 1)));
+            assert!(printer.is_visible((0, 1), (2, 1)));
+            view.draw(&printer);
+        });
+
+        let buffer = render_to_buffer(&mut canvas, (3, 3));
+        assert_eq!(buffer.lines(), vec!["   ", "b  ", "   "]);
+    }
 }
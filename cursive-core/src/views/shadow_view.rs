@@ -60,6 +60,11 @@ impl<T: View> ViewWrapper for ShadowView<T> {
         self.view.required_size(req.saturating_sub(offset)) + offset
     }
 
+    fn wrap_measure(&mut self, req: Vec2) -> Vec2 {
+        let offset = self.padding();
+        self.view.measure(req.saturating_sub(offset)) + offset
+    }
+
     fn wrap_layout(&mut self, size: Vec2) {
         let offset = self.padding();
         self.view.layout(size.saturating_sub(offset));
@@ -0,0 +1,498 @@
+use crate::direction::Direction;
+use crate::event::{
+    AnyCb, Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::rect::Rect;
+use crate::theme::ColorStyle;
+use crate::view::{IntoBoxedView, Selector, View};
+use crate::Cursive;
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
+
+type OnSectionChange = dyn Fn(&mut Cursive, &str);
+
+struct Section {
+    label: String,
+    view: Box<dyn View>,
+}
+
+/// Layout with a list of sections on the left, and the current section on
+/// the right.
+///
+/// This is the typical layout of a settings screen. Only the view of the
+/// current section is shown.
+///
+/// * In the sidebar, `Up` and `Down` change the current section, and
+///   `Right`, `Enter` or `Tab` move the focus to the section content.
+/// * `Left` or `Shift+Tab` in the content (if not used there) moves the
+///   focus back to the sidebar.
+/// * `Alt+1` to `Alt+9` jump directly to one of the first 9 sections.
+///
+/// The sidebar can be collapsed to leave the entire width to the content.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Checkbox, SidebarLayout, TextView};
+///
+/// let settings = SidebarLayout::new()
+///     .section("General", Checkbox::new())
+///     .section("About", TextView::new("Version 1.0"))
+///     .on_section_change(|s, label| {
+///         // Do something with the new section
+///     });
+/// ```
+pub struct SidebarLayout {
+    sections: Vec<Section>,
+    current: usize,
+
+    // `true` if the focus is on the sidebar, rather than on the content.
+    sidebar_focused: bool,
+    collapsed: bool,
+
+    on_section_change: Option<Rc<OnSectionChange>>,
+}
+
+new_default!(SidebarLayout);
+
+impl SidebarLayout {
+    /// Creates a new, empty `SidebarLayout`.
+    pub fn new() -> Self {
+        SidebarLayout {
+            sections: Vec::new(),
+            current: 0,
+            sidebar_focused: true,
+            collapsed: false,
+            on_section_change: None,
+        }
+    }
+
+    /// Adds a new section at the end of the sidebar.
+    pub fn add_section<S, V>(&mut self, label: S, view: V)
+    where
+        S: Into<String>,
+        V: IntoBoxedView + 'static,
+    {
+        self.sections.push(Section {
+            label: label.into(),
+            view: view.as_boxed_view(),
+        });
+    }
+
+    /// Adds a new section at the end of the sidebar.
+    ///
+    /// Chainable variant.
+    pub fn section<S, V>(self, label: S, view: V) -> Self
+    where
+        S: Into<String>,
+        V: IntoBoxedView + 'static,
+    {
+        self.with(|s| s.add_section(label, view))
+    }
+
+    /// Removes the section at the given position.
+    ///
+    /// Returns `None` if `i >= self.len()`.
+    pub fn remove_section(&mut self, i: usize) -> Option<Box<dyn View>> {
+        if i >= self.sections.len() {
+            return None;
+        }
+
+        let section = self.sections.remove(i);
+        if self.current > i || self.current >= self.sections.len() {
+            self.current = self.current.saturating_sub(1);
+        }
+        Some(section.view)
+    }
+
+    /// Returns the number of sections.
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    /// Returns `true` if this layout has no section.
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    /// Returns the index of the current section.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the label of the current section.
+    ///
+    /// Returns `None` if this layout is empty.
+    pub fn current_label(&self) -> Option<&str> {
+        self.sections
+            .get(self.current)
+            .map(|section| section.label.as_str())
+    }
+
+    /// Makes the given section the current one.
+    ///
+    /// Returns a callback in response to the section change.
+    ///
+    /// You should run this callback with a `&mut Cursive`.
+    pub fn set_current(&mut self, i: usize) -> Callback {
+        if i >= self.sections.len() || i == self.current {
+            return Callback::dummy();
+        }
+
+        self.current = i;
+        match self.on_section_change {
+            Some(ref cb) => {
+                let cb = Rc::clone(cb);
+                let label = self.sections[i].label.clone();
+                Callback::from_fn(move |s| cb(s, &label))
+            }
+            None => Callback::dummy(),
+        }
+    }
+
+    /// Returns a reference to the view of the given section.
+    pub fn get_section(&self, i: usize) -> Option<&dyn View> {
+        self.sections.get(i).map(|section| &*section.view)
+    }
+
+    /// Returns a mutable reference to the view of the given section.
+    pub fn get_section_mut(&mut self, i: usize) -> Option<&mut dyn View> {
+        self.sections.get_mut(i).map(|section| &mut *section.view)
+    }
+
+    /// Hides or shows the sidebar.
+    ///
+    /// When collapsed, the content takes the entire width, and the focus
+    /// stays in the content.
+    pub fn set_collapsed(&mut self, collapsed: bool) {
+        self.collapsed = collapsed;
+        if collapsed {
+            self.sidebar_focused = false;
+        }
+    }
+
+    /// Hides or shows the sidebar.
+    ///
+    /// Chainable variant.
+    pub fn collapsed(self, collapsed: bool) -> Self {
+        self.with(|s| s.set_collapsed(collapsed))
+    }
+
+    /// Returns `true` if the sidebar is hidden.
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Hides the sidebar if it is visible, or shows it if it is hidden.
+    pub fn toggle_collapsed(&mut self) {
+        let collapsed = !self.collapsed;
+        self.set_collapsed(collapsed);
+    }
+
+    /// Sets a callback to be used when the current section changes.
+    ///
+    /// The callback receives the label of the new section.
+    pub fn set_on_section_change<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_section_change = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to be used when the current section changes.
+    ///
+    /// Chainable variant.
+    pub fn on_section_change<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_section_change(cb))
+    }
+
+    // Width of the sidebar, including the separator.
+    fn sidebar_width(&self) -> usize {
+        if self.collapsed || self.sections.is_empty() {
+            return 0;
+        }
+
+        let labels = self
+            .sections
+            .iter()
+            .map(|section| section.label.width())
+            .max()
+            .unwrap_or(0);
+
+        // One space on each side of the labels, then the separator.
+        labels + 3
+    }
+
+    fn change_section(&mut self, i: usize) -> EventResult {
+        EventResult::Consumed(Some(self.set_current(i)))
+    }
+
+    fn focus_content(&mut self) -> EventResult {
+        let focused = self
+            .sections
+            .get_mut(self.current)
+            .map(|section| section.view.take_focus(Direction::left()))
+            .unwrap_or(false);
+
+        if focused {
+            self.sidebar_focused = false;
+            EventResult::Consumed(None)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        // Jump to a section from anywhere.
+        if let Event::AltChar(c @ '1'..='9') = event {
+            let i = c as usize - '1' as usize;
+            if i < self.len() {
+                return self.change_section(i);
+            }
+        }
+
+        if let Event::Mouse {
+            offset,
+            position,
+            event: MouseEvent::Press(MouseButton::Left),
+        } = event
+        {
+            if let Some(pos) = position.checked_sub(offset) {
+                if pos.x < self.sidebar_width() {
+                    self.sidebar_focused = true;
+                    if pos.y < self.len() {
+                        return self.change_section(pos.y);
+                    }
+                    return EventResult::Consumed(None);
+                }
+            }
+        }
+
+        if self.sidebar_focused && !self.collapsed {
+            self.on_event_sidebar(event)
+        } else {
+            self.on_event_content(event)
+        }
+    }
+
+    // Tells the contents about a focus change, after an event.
+    //
+    // `old` is the previous `(sidebar_focused, current)` pair.
+    fn notify_focus_change(&mut self, old: (bool, usize)) -> EventResult {
+        let (sidebar_focused, current) = old;
+        if (self.sidebar_focused, self.current) == old {
+            return EventResult::Ignored;
+        }
+
+        let mut result = EventResult::Ignored;
+        if !sidebar_focused {
+            if let Some(section) = self.sections.get_mut(current) {
+                result = section.view.on_event(Event::FocusLost);
+            }
+        }
+        if !self.sidebar_focused {
+            let view = &mut self.sections[self.current].view;
+            result = result.and(view.on_event(Event::FocusGained));
+        }
+        result
+    }
+
+    fn on_event_sidebar(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Up) if self.current > 0 => {
+                self.change_section(self.current - 1)
+            }
+            Event::Key(Key::Down) if self.current + 1 < self.len() => {
+                self.change_section(self.current + 1)
+            }
+            Event::Key(Key::Home) => self.change_section(0),
+            Event::Key(Key::End) => self.change_section(self.len() - 1),
+            Event::Key(Key::Right)
+            | Event::Key(Key::Enter)
+            | Event::Key(Key::Tab) => self.focus_content(),
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn on_event_content(&mut self, event: Event) -> EventResult {
+        let offset = self.sidebar_width();
+        let result = self.sections[self.current]
+            .view
+            .on_event(event.relativized((offset, 0)));
+        if result.is_consumed() {
+            return result;
+        }
+
+        match event {
+            Event::Key(Key::Left) | Event::Shift(Key::Tab)
+                if !self.collapsed =>
+            {
+                self.sidebar_focused = true;
+                EventResult::Consumed(None)
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+impl View for SidebarLayout {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        let width = self.sidebar_width();
+
+        if width > 0 {
+            for (i, section) in self.sections.iter().enumerate() {
+                let style = if i != self.current {
+                    ColorStyle::primary()
+                } else if self.sidebar_focused && printer.focused {
+                    ColorStyle::highlight()
+                } else {
+                    ColorStyle::highlight_inactive()
+                };
+
+                printer.with_color(style, |printer| {
+                    printer.print_hline((0, i), width - 1, " ");
+                    printer.print((1, i), &section.label);
+                });
+            }
+            printer.print_vline((width - 1, 0), printer.size.y, "│");
+        }
+
+        if let Some(section) = self.sections.get(self.current) {
+            let printer = printer
+                .offset((width, 0))
+                .focused(!self.sidebar_focused || self.collapsed);
+            section.view.draw(&printer);
+        }
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        let width = self.sidebar_width();
+        let content = match self.sections.get_mut(self.current) {
+            Some(section) => {
+                section.view.required_size(req.saturating_sub((width, 0)))
+            }
+            None => Vec2::zero(),
+        };
+
+        let height = if self.collapsed {
+            content.y
+        } else {
+            content.y.max(self.sections.len())
+        };
+        Vec2::new(width + content.x, height)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        let width = self.sidebar_width();
+        if let Some(section) = self.sections.get_mut(self.current) {
+            section.view.layout(size.saturating_sub((width, 0)));
+        }
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if self.sections.is_empty() {
+            return EventResult::Ignored;
+        }
+
+        let focus = (self.sidebar_focused, self.current);
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(focus))
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        if self.sections.is_empty() {
+            return false;
+        }
+
+        if self.collapsed {
+            return self.sections[self.current].view.take_focus(source);
+        }
+
+        self.sidebar_focused = true;
+        true
+    }
+
+    fn call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        for section in &mut self.sections {
+            section.view.call_on_any(selector, callback);
+        }
+    }
+
+    fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
+        for (i, section) in self.sections.iter_mut().enumerate() {
+            if section.view.focus_view(selector).is_ok() {
+                self.current = i;
+                self.sidebar_focused = false;
+                return Ok(());
+            }
+        }
+        Err(())
+    }
+
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        self.sections
+            .iter_mut()
+            .find_map(|section| section.view.remove_view(selector))
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        let width = self.sidebar_width();
+        if self.sidebar_focused && width > 0 {
+            return Rect::from_size((0, self.current), (width, 1));
+        }
+
+        match self.sections.get(self.current) {
+            Some(section) => {
+                section.view.important_area(size.saturating_sub((width, 0)))
+                    + (width, 0)
+            }
+            None => Rect::from_size((0, 0), size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{Button, TextView};
+
+    #[test]
+    fn navigation() {
+        let mut layout = SidebarLayout::new()
+            .section("One", Button::new("Ok", |_| ()))
+            .section("Two", TextView::new("Text"))
+            .section("Three", Button::new("Ok", |_| ()));
+        assert!(layout.take_focus(Direction::none()));
+
+        layout.on_event(Event::Key(Key::Down));
+        assert_eq!(layout.current_label(), Some("Two"));
+
+        // The text view can't take focus.
+        assert!(!layout.on_event(Event::Key(Key::Right)).is_consumed());
+
+        layout.on_event(Event::AltChar('3'));
+        assert_eq!(layout.current(), 2);
+        assert!(layout.on_event(Event::Key(Key::Right)).is_consumed());
+
+        // Up goes to the button, not the sidebar.
+        assert!(!layout.on_event(Event::Key(Key::Up)).is_consumed());
+        assert_eq!(layout.current(), 2);
+
+        layout.on_event(Event::Key(Key::Left));
+        layout.on_event(Event::Key(Key::Up));
+        assert_eq!(layout.current(), 1);
+    }
+}
@@ -2,11 +2,14 @@ use crate::direction::{Direction, Orientation};
 use crate::event::{
     Callback, Event, EventResult, Key, MouseButton, MouseEvent,
 };
+use crate::printer::AnyBinding;
 use crate::theme::ColorStyle;
 use crate::view::View;
+use crate::views::Binding;
 use crate::Vec2;
 use crate::With;
 use crate::{Cursive, Printer};
+use std::any::Any;
 use std::rc::Rc;
 
 /// A horizontal or vertical slider.
@@ -32,6 +35,7 @@ pub struct SliderView {
     value: usize,
     max_value: usize,
     dragging: bool,
+    binding: Option<Rc<Binding<usize>>>,
 }
 
 impl SliderView {
@@ -49,6 +53,47 @@ impl SliderView {
             on_change: None,
             on_enter: None,
             dragging: false,
+            binding: None,
+        }
+    }
+
+    /// Creates a new `SliderView` bound to a value in the user data.
+    ///
+    /// `get` reads the value from the user data, of type `T`. The slider
+    /// follows it every frame, and changes made with the slider are
+    /// written back with `set`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::direction::Orientation;
+    /// use cursive_core::views::SliderView;
+    ///
+    /// struct Settings {
+    ///     volume: usize,
+    /// }
+    ///
+    /// let slider_view = SliderView::bound(
+    ///     Orientation::Horizontal,
+    ///     10,
+    ///     |settings: &Settings| settings.volume,
+    ///     |settings, volume| settings.volume = volume,
+    /// );
+    /// ```
+    pub fn bound<T, G, S>(
+        orientation: Orientation,
+        max_value: usize,
+        get: G,
+        set: S,
+    ) -> Self
+    where
+        T: Any,
+        G: 'static + Fn(&T) -> usize,
+        S: 'static + Fn(&mut T, usize),
+    {
+        SliderView {
+            binding: Some(Binding::lens(get, set)),
+            ..Self::new(orientation, max_value)
         }
     }
 
@@ -109,12 +154,19 @@ impl SliderView {
     }
 
     fn get_change_result(&self) -> EventResult {
-        EventResult::Consumed(self.on_change.clone().map(|cb| {
+        let result = EventResult::Consumed(self.on_change.clone().map(|cb| {
             let value = self.value;
             Callback::from_fn(move |s| {
                 cb(s, value);
             })
-        }))
+        }));
+        match self.binding {
+            Some(ref binding) => {
+                EventResult::Consumed(Some(binding.write(self.value)))
+                    .and(result)
+            }
+            None => result,
+        }
     }
 
     fn slide_plus(&mut self) -> EventResult {
@@ -141,7 +193,19 @@ impl SliderView {
 }
 
 impl View for SliderView {
+    fn layout(&mut self, _: Vec2) {
+        if let Some(value) =
+            self.binding.as_ref().and_then(|b| b.take_pending())
+        {
+            self.value = value.min(self.max_value.saturating_sub(1));
+        }
+    }
+
     fn draw(&self, printer: &Printer<'_, '_>) {
+        if let Some(ref binding) = self.binding {
+            printer
+                .record_binding(|| Rc::clone(binding) as Rc<dyn AnyBinding>);
+        }
         match self.orientation {
             Orientation::Vertical => {
                 printer.print_vline((0, 0), self.max_value, "|")
@@ -161,6 +225,10 @@ impl View for SliderView {
         });
     }
 
+    fn measure(&mut self, _: Vec2) -> Vec2 {
+        self.req_size()
+    }
+
     fn required_size(&mut self, _: Vec2) -> Vec2 {
         self.req_size()
     }
@@ -0,0 +1,226 @@
+use crate::direction::{Absolute, Direction};
+use crate::event::{Event, EventResult, Key};
+use crate::printer::ViewAreas;
+use crate::rect::Rect;
+use crate::view::{Selector, View, ViewWrapper};
+use crate::Printer;
+
+/// Wrapper moving the focus with arrow keys to the nearest view on screen.
+///
+/// Usually wraps a whole layer. Arrow keys are first given to the focused
+/// view; if it ignores them, the focus moves to the geometrically nearest
+/// view in that direction, wherever it is in the view tree. This avoids
+/// being trapped in nested layouts, which only move the focus along their
+/// own axis.
+///
+/// Only named views are considered, using the areas where they were last
+/// drawn. When no named view is in that direction, the layout handles the
+/// key as usual.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Button, LinearLayout, SpatialFocus};
+/// use cursive_core::view::Nameable;
+///
+/// let mut siv = cursive_core::Cursive::dummy();
+/// let column = |top, bottom| {
+///     LinearLayout::vertical()
+///         .child(Button::new(top, |_| ()).with_name(top))
+///         .child(Button::new(bottom, |_| ()).with_name(bottom))
+/// };
+/// siv.add_layer(SpatialFocus::new(
+///     LinearLayout::horizontal()
+///         .child(column("a", "b"))
+///         .child(column("c", "d")),
+/// ));
+/// ```
+pub struct SpatialFocus<V> {
+    view: V,
+
+    // Areas of the named views, from the last draw.
+    areas: ViewAreas,
+}
+
+impl<V> SpatialFocus<V> {
+    /// Wraps the given view.
+    pub fn new(view: V) -> Self {
+        SpatialFocus {
+            view,
+            areas: ViewAreas::default(),
+        }
+    }
+
+    inner_getters!(self.view: V);
+
+    // Returns the name and area of the innermost focused named view.
+    fn focused(&self) -> Option<(String, Rect)> {
+        self.areas
+            .borrow()
+            .iter()
+            .filter(|(_, area)| area.is_focused())
+            .filter_map(|(name, area)| {
+                Some((name.clone(), area.visible_rect()?))
+            })
+            .min_by_key(|(_, rect)| rect.surface())
+    }
+}
+
+// Returns the distance between two ranges, or 0 if they overlap.
+fn gap(a: (usize, usize), b: (usize, usize)) -> usize {
+    b.0.saturating_sub(a.1).max(a.0.saturating_sub(b.1))
+}
+
+// Returns how far `to` is from `from` in the given direction.
+//
+// Returns `None` if `to` is not entirely in that direction.
+fn distance(from: Rect, to: Rect, direction: Absolute) -> Option<usize> {
+    let (main, cross) = match direction {
+        Absolute::Left if to.right() < from.left() => {
+            (from.left() - to.right(), (to.top(), to.bottom()))
+        }
+        Absolute::Right if to.left() > from.right() => {
+            (to.left() - from.right(), (to.top(), to.bottom()))
+        }
+        Absolute::Up if to.bottom() < from.top() => {
+            (from.top() - to.bottom(), (to.left(), to.right()))
+        }
+        Absolute::Down if to.top() > from.bottom() => {
+            (to.top() - from.bottom(), (to.left(), to.right()))
+        }
+        _ => return None,
+    };
+    let from_cross = match direction {
+        Absolute::Left | Absolute::Right => (from.top(), from.bottom()),
+        _ => (from.left(), from.right()),
+    };
+    // Views out of line are farther than views right there.
+    Some(main + 2 * gap(from_cross, cross))
+}
+
+impl<V: View> SpatialFocus<V> {
+    // Focuses the nearest named view in the given direction that takes it.
+    fn move_focus(&mut self, from: Rect, direction: Absolute) -> EventResult {
+        let mut candidates: Vec<(usize, String)> = self
+            .areas
+            .borrow()
+            .iter()
+            .filter_map(|(name, area)| {
+                let distance =
+                    distance(from, area.visible_rect()?, direction)?;
+                Some((distance, name.clone()))
+            })
+            .collect();
+        candidates.sort();
+
+        // The focus comes from the opposite side.
+        let source = match direction {
+            Absolute::Left => Direction::right(),
+            Absolute::Right => Direction::left(),
+            Absolute::Up => Direction::down(),
+            _ => Direction::up(),
+        };
+        for (_, name) in candidates {
+            let selector = Selector::Name(&name);
+            let mut focusable = false;
+            self.view.call_on_any(&selector, &mut |view| {
+                focusable = view.take_focus(source);
+            });
+            if !focusable {
+                continue;
+            }
+
+            let lost = self.view.on_event(Event::FocusLost);
+            if self.view.focus_view(&selector).is_err() {
+                continue;
+            }
+            let gained = self.view.on_event(Event::FocusGained);
+            return lost.and(gained).and(EventResult::Consumed(None));
+        }
+        EventResult::Ignored
+    }
+}
+
+impl<V: View> ViewWrapper for SpatialFocus<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        self.areas.borrow_mut().clear();
+        self.view.draw(&printer.track_view_areas(&self.areas));
+        printer.record_areas(&self.areas);
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let direction = match event {
+            Event::Key(Key::Left) => Absolute::Left,
+            Event::Key(Key::Right) => Absolute::Right,
+            Event::Key(Key::Up) => Absolute::Up,
+            Event::Key(Key::Down) => Absolute::Down,
+            _ => return self.view.on_event(event),
+        };
+        let (name, from) = match self.focused() {
+            Some(focused) => focused,
+            None => return self.view.on_event(event),
+        };
+
+        // The focused view may use arrow keys itself.
+        let mut result = EventResult::Ignored;
+        self.view.call_on_any(&Selector::Name(&name), &mut |view| {
+            result = view.on_event(event.clone());
+        });
+        if result.is_consumed() {
+            return result;
+        }
+
+        match self.move_focus(from, direction) {
+            EventResult::Ignored => self.view.on_event(event),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Nameable;
+    use crate::views::{Button, FocusTracker, LinearLayout};
+    use crate::Cursive;
+
+    #[test]
+    fn nearest_view() {
+        let mut siv = crate::testing::dummy((20, 4));
+        let button = |name: &'static str| {
+            FocusTracker::new(Button::new(name, |_| ()))
+                .on_focus(move |_| {
+                    EventResult::with_cb(move |s: &mut Cursive| {
+                        s.with_user_data(|log: &mut Vec<&str>| log.push(name));
+                    })
+                })
+                .with_name(name)
+        };
+        siv.add_layer(SpatialFocus::new(
+            LinearLayout::vertical()
+                .child(
+                    LinearLayout::horizontal()
+                        .child(button("a"))
+                        .child(button("b")),
+                )
+                .child(
+                    LinearLayout::horizontal()
+                        .child(button("c"))
+                        .child(button("d")),
+                ),
+        ));
+        siv.set_user_data(Vec::<&str>::new());
+
+        for key in [Key::Right, Key::Down, Key::Left, Key::Up, Key::Up] {
+            siv.refresh();
+            siv.on_event(Event::Key(key));
+        }
+        // Without spatial focus, `Down` would focus `c`.
+        assert_eq!(
+            siv.take_user_data::<Vec<&str>>().unwrap(),
+            ["b", "d", "c", "a"]
+        );
+    }
+}
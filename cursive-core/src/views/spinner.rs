@@ -0,0 +1,440 @@
+use crate::direction::Direction;
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::rect::Rect;
+use crate::view::View;
+use crate::views::EditView;
+use crate::{Cursive, Printer, Vec2, With};
+use num::{Bounded, Num};
+use std::cmp::max;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+use unicode_width::UnicodeWidthStr;
+
+type OnChange<T> = Rc<dyn Fn(&mut Cursive, T)>;
+
+/// Numeric input with buttons to increment or decrement the value.
+///
+/// The value can be typed, or changed by one step with `Up`, `Down`, the
+/// mouse wheel or the `-` and `+` buttons. `PageUp` and `PageDown` change
+/// it by ten steps. It always stays between the minimum and the maximum.
+///
+/// A typed value is applied as soon as it is valid. Otherwise, it is fixed
+/// with `Enter`: clamped if it's out of bounds, reset if it's not a number.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::Spinner;
+///
+/// let spinner = Spinner::new()
+///     .range(0, 100)
+///     .step(5)
+///     .value(50)
+///     .on_change(|s, volume: i32| {
+///         // Change the volume...
+///     });
+/// assert_eq!(spinner.get_value(), 50);
+/// ```
+pub struct Spinner<T> {
+    edit: EditView,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    on_change: Option<OnChange<T>>,
+    last_size: Vec2,
+}
+
+impl<T> Default for Spinner<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Display + FromStr + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Spinner<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Display + FromStr + 'static,
+{
+    /// Creates a new spinner, with a value of zero and a step of one.
+    pub fn new() -> Self {
+        let signed = T::min_value() < T::zero();
+        let edit = EditView::new().validator(move |content| {
+            // Accept the beginning of a number.
+            match content {
+                "" | "." => true,
+                "-" | "-." => signed,
+                _ => content.parse::<T>().is_ok(),
+            }
+        });
+        let mut spinner = Spinner {
+            edit,
+            value: T::zero(),
+            min: None,
+            max: None,
+            step: T::one(),
+            on_change: None,
+            last_size: Vec2::zero(),
+        };
+        spinner.update_text();
+        spinner
+    }
+
+    /// Sets the value, clamped between the minimum and the maximum.
+    ///
+    /// Returns an event result with a possible callback, if `on_change` was
+    /// set.
+    pub fn set_value(&mut self, value: T) -> EventResult {
+        self.value = self.clamp(value);
+        self.update_text();
+        self.get_change_result()
+    }
+
+    /// Sets the value.
+    ///
+    /// Chainable variant.
+    pub fn value(self, value: T) -> Self {
+        self.with(|s| {
+            s.set_value(value);
+        })
+    }
+
+    /// Gets the current value.
+    pub fn get_value(&self) -> T {
+        self.value
+    }
+
+    /// Sets the minimum value.
+    ///
+    /// Defaults to the minimum of `T`.
+    pub fn set_min(&mut self, min: T) {
+        self.min = Some(min);
+        self.value = self.clamp(self.value);
+        self.update_text();
+    }
+
+    /// Sets the minimum value.
+    ///
+    /// Chainable variant.
+    pub fn min(self, min: T) -> Self {
+        self.with(|s| s.set_min(min))
+    }
+
+    /// Sets the maximum value.
+    ///
+    /// Defaults to the maximum of `T`.
+    pub fn set_max(&mut self, max: T) {
+        self.max = Some(max);
+        self.value = self.clamp(self.value);
+        self.update_text();
+    }
+
+    /// Sets the maximum value.
+    ///
+    /// Chainable variant.
+    pub fn max(self, max: T) -> Self {
+        self.with(|s| s.set_max(max))
+    }
+
+    /// Sets the minimum and maximum values.
+    ///
+    /// Chainable variant.
+    pub fn range(self, min: T, max: T) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Sets the amount added or removed by each step.
+    ///
+    /// Defaults to one.
+    pub fn set_step(&mut self, step: T) {
+        self.step = step;
+    }
+
+    /// Sets the amount added or removed by each step.
+    ///
+    /// Chainable variant.
+    pub fn step(self, step: T) -> Self {
+        self.with(|s| s.set_step(step))
+    }
+
+    /// Sets a callback to run when the value changes.
+    pub fn set_on_change<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, T) + 'static,
+    {
+        self.on_change = Some(Rc::new(callback));
+    }
+
+    /// Sets a callback to run when the value changes.
+    ///
+    /// Chainable variant.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, T) + 'static,
+    {
+        self.with(|s| s.set_on_change(callback))
+    }
+
+    fn bounds(&self) -> (T, T) {
+        (
+            self.min.unwrap_or_else(T::min_value),
+            self.max.unwrap_or_else(T::max_value),
+        )
+    }
+
+    fn clamp(&self, value: T) -> T {
+        let (min, max) = self.bounds();
+        if value < min {
+            min
+        } else if value > max {
+            max
+        } else {
+            value
+        }
+    }
+
+    fn update_text(&mut self) {
+        self.edit.set_content(self.value.to_string());
+    }
+
+    fn get_change_result(&self) -> EventResult {
+        EventResult::Consumed(self.on_change.clone().map(|cb| {
+            let value = self.value;
+            Callback::from_fn(move |s| cb(s, value))
+        }))
+    }
+
+    // Moves the value by `steps` steps, up or down.
+    fn add_steps(&mut self, up: bool, steps: usize) -> EventResult {
+        let (min, max) = self.bounds();
+        let mut value = self.value;
+        for _ in 0..steps {
+            value = if up {
+                step_up(value, self.step, max)
+            } else {
+                step_down(value, self.step, min)
+            };
+        }
+
+        if value == self.value {
+            // Typed text may still need to be fixed.
+            self.update_text();
+            return EventResult::Ignored;
+        }
+        self.set_value(value)
+    }
+
+    // Applies the typed text, or restores the value if it's not a number.
+    fn commit(&mut self) -> EventResult {
+        match self.edit.get_content().parse() {
+            Ok(value) if value != self.value => self.set_value(value),
+            _ => {
+                self.update_text();
+                EventResult::Consumed(None)
+            }
+        }
+    }
+
+    fn edit_width(&self) -> usize {
+        self.last_size.x.saturating_sub(2)
+    }
+}
+
+// Returns `value + step`, or `max` if it's bigger.
+//
+// The sign of `value` decides which operation can't overflow, even with
+// the bounds of the type. Floats have no checked operations.
+fn step_up<T: Num + PartialOrd + Copy>(value: T, step: T, max: T) -> T {
+    if value < T::zero() {
+        // Between `value` and `step`.
+        let value = value + step;
+        if value > max {
+            max
+        } else {
+            value
+        }
+    } else if max - value < step {
+        max
+    } else {
+        value + step
+    }
+}
+
+// Returns `value - step`, or `min` if it's smaller.
+fn step_down<T: Num + PartialOrd + Copy>(value: T, step: T, min: T) -> T {
+    if value > T::zero() {
+        if min >= T::zero() {
+            if value - min < step {
+                min
+            } else {
+                value - step
+            }
+        } else {
+            // Between `-step` and `value`, so signed.
+            let value = value - step;
+            if value < min {
+                min
+            } else {
+                value
+            }
+        }
+    } else if value < min + step {
+        min
+    } else {
+        value - step
+    }
+}
+
+impl<T> View for Spinner<T>
+where
+    T: Num + Bounded + PartialOrd + Copy + Display + FromStr + 'static,
+{
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        let width = self.edit_width();
+        printer.print((0, 0), "-");
+        self.edit.draw(&printer.offset((1, 0)).cropped((width, 1)));
+        printer.print((width + 1, 0), "+");
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        let width = [Some(self.value), self.min, self.max]
+            .iter()
+            .flatten()
+            .map(|value| value.to_string().width())
+            .fold(self.edit.get_content().width(), max);
+
+        // Room for the cursor and the buttons.
+        Vec2::new(width + 3, 1)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        self.edit.layout(Vec2::new(self.edit_width(), 1));
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        self.edit.take_focus(source)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Up) => self.add_steps(true, 1),
+            Event::Key(Key::Down) => self.add_steps(false, 1),
+            Event::Key(Key::PageUp) => self.add_steps(true, 10),
+            Event::Key(Key::PageDown) => self.add_steps(false, 10),
+            Event::Key(Key::Enter) => self.commit(),
+            Event::Mouse {
+                event,
+                position,
+                offset,
+            } => {
+                let position = match position.checked_sub(offset) {
+                    Some(position) if position < self.last_size => position,
+                    _ => return EventResult::Ignored,
+                };
+                match event {
+                    MouseEvent::WheelUp => self.add_steps(true, 1),
+                    MouseEvent::WheelDown => self.add_steps(false, 1),
+                    MouseEvent::Press(MouseButton::Left)
+                        if position.x == 0 =>
+                    {
+                        self.add_steps(false, 1)
+                    }
+                    MouseEvent::Press(MouseButton::Left)
+                        if position.x > self.edit_width() =>
+                    {
+                        self.add_steps(true, 1)
+                    }
+                    _ => self.edit.on_event(Event::Mouse {
+                        event,
+                        position,
+                        offset: Vec2::new(1, 0),
+                    }),
+                }
+            }
+            event => {
+                let result = self.edit.on_event(event);
+                // Apply typed values right away when they are valid.
+                match self.edit.get_content().parse() {
+                    Ok(value)
+                        if value != self.value
+                            && self.clamp(value) == value =>
+                    {
+                        self.value = value;
+                        result.and(self.get_change_result())
+                    }
+                    _ => result,
+                }
+            }
+        }
+    }
+
+    fn important_area(&self, _: Vec2) -> Rect {
+        self.edit.important_area(Vec2::new(self.edit_width(), 1))
+            + Vec2::new(1, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_and_bounds() {
+        let mut spinner = Spinner::<u8>::new().range(0, 20).step(5);
+
+        // No underflow below the minimum.
+        assert!(!spinner.on_event(Event::Key(Key::Down)).is_consumed());
+        spinner.on_event(Event::Key(Key::PageUp));
+        assert_eq!(spinner.get_value(), 20);
+
+        // Typed values are applied when valid.
+        spinner.on_event(Event::Key(Key::Backspace));
+        spinner.on_event(Event::Key(Key::Backspace));
+        spinner.on_event(Event::Char('7'));
+        assert_eq!(spinner.get_value(), 7);
+        spinner.on_event(Event::Char('7'));
+        assert_eq!(spinner.get_value(), 7);
+
+        // And clamped on Enter.
+        spinner.on_event(Event::Key(Key::Enter));
+        assert_eq!(spinner.get_value(), 20);
+        assert_eq!(&*spinner.edit.get_content(), "20");
+
+        let mut spinner = Spinner::new().value(0.5).step(0.25);
+        spinner.on_event(Event::Key(Key::Up));
+        assert_eq!(spinner.get_value(), 0.75);
+    }
+
+    #[test]
+    fn type_bounds() {
+        let mut spinner = Spinner::<i32>::new();
+        spinner.on_event(Event::Key(Key::Down));
+        assert_eq!(spinner.get_value(), -1);
+
+        spinner.set_value(i32::MIN + 5);
+        spinner.on_event(Event::Key(Key::PageDown));
+        assert_eq!(spinner.get_value(), i32::MIN);
+        assert!(!spinner.on_event(Event::Key(Key::Down)).is_consumed());
+
+        spinner.set_value(i32::MAX - 5);
+        spinner.on_event(Event::Key(Key::PageUp));
+        assert_eq!(spinner.get_value(), i32::MAX);
+
+        spinner.set_value(-1);
+        spinner.set_step(i32::MAX);
+        spinner.on_event(Event::Key(Key::Up));
+        assert_eq!(spinner.get_value(), i32::MAX - 1);
+
+        // Unsigned values can't be negative.
+        let mut spinner = Spinner::<u8>::new();
+        spinner.on_event(Event::Key(Key::Backspace));
+        spinner.on_event(Event::Char('-'));
+        assert_eq!(&*spinner.edit.get_content(), "");
+    }
+}
@@ -0,0 +1,467 @@
+use crate::direction::{Direction, Orientation, Relative};
+use crate::event::{AnyCb, Event, EventResult, Key, MouseButton, MouseEvent};
+use crate::rect::Rect;
+use crate::theme::ColorStyle;
+use crate::view::{IntoBoxedView, Selector, View};
+use crate::Printer;
+use crate::Vec2;
+use crate::With;
+
+/// Two views side by side, with a divider that can be moved.
+///
+/// The divider splits the space with a ratio, kept when the split is
+/// resized. It can be dragged with the mouse, or moved by one cell with
+/// `Ctrl+Left` and `Ctrl+Right` (`Ctrl+Up` and `Ctrl+Down` for a vertical
+/// split) when the focused child doesn't use these keys.
+///
+/// `Tab`, `Shift+Tab` and the arrow keys move the focus between the two
+/// children, like in a [`LinearLayout`].
+///
+/// [`LinearLayout`]: crate::views::LinearLayout
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Split, TextView};
+///
+/// let split = Split::horizontal(
+///     TextView::new("Files"),
+///     TextView::new("Preview"),
+/// )
+/// .ratio(0.3);
+/// ```
+pub struct Split {
+    first: Box<dyn View>,
+    second: Box<dyn View>,
+    orientation: Orientation,
+
+    // Share of the space given to the first child, between 0 and 1.
+    ratio: f32,
+
+    // `true` if the focus is on the second child.
+    second_focused: bool,
+
+    // `true` while the divider is dragged with the mouse.
+    dragging: bool,
+
+    last_size: Vec2,
+}
+
+impl Split {
+    /// Creates a new split with the given orientation.
+    ///
+    /// The space is split equally between the two children.
+    pub fn new<A, B>(orientation: Orientation, first: A, second: B) -> Self
+    where
+        A: IntoBoxedView,
+        B: IntoBoxedView,
+    {
+        Split {
+            first: first.as_boxed_view(),
+            second: second.as_boxed_view(),
+            orientation,
+            ratio: 0.5,
+            second_focused: false,
+            dragging: false,
+            last_size: Vec2::zero(),
+        }
+    }
+
+    /// Creates a new split with `first` on the left and `second` on the
+    /// right.
+    pub fn horizontal<A, B>(first: A, second: B) -> Self
+    where
+        A: IntoBoxedView,
+        B: IntoBoxedView,
+    {
+        Split::new(Orientation::Horizontal, first, second)
+    }
+
+    /// Creates a new split with `first` at the top and `second` at the
+    /// bottom.
+    pub fn vertical<A, B>(first: A, second: B) -> Self
+    where
+        A: IntoBoxedView,
+        B: IntoBoxedView,
+    {
+        Split::new(Orientation::Vertical, first, second)
+    }
+
+    /// Returns the orientation of this split.
+    pub fn get_orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Sets the share of the space given to the first child.
+    ///
+    /// The ratio is clamped between 0 and 1. Defaults to 0.5.
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio.clamp(0.0, 1.0);
+    }
+
+    /// Sets the share of the space given to the first child.
+    ///
+    /// Chainable variant.
+    pub fn ratio(self, ratio: f32) -> Self {
+        self.with(|s| s.set_ratio(ratio))
+    }
+
+    /// Returns the share of the space given to the first child.
+    pub fn get_ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Returns a reference to the first child.
+    pub fn get_first(&self) -> &dyn View {
+        &*self.first
+    }
+
+    /// Returns a mutable reference to the first child.
+    pub fn get_first_mut(&mut self) -> &mut dyn View {
+        &mut *self.first
+    }
+
+    /// Returns a reference to the second child.
+    pub fn get_second(&self) -> &dyn View {
+        &*self.second
+    }
+
+    /// Returns a mutable reference to the second child.
+    pub fn get_second_mut(&mut self) -> &mut dyn View {
+        &mut *self.second
+    }
+
+    /// Returns the index of the focused child: 0 or 1.
+    pub fn get_focus_index(&self) -> usize {
+        if self.second_focused {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn child(&self, second: bool) -> &dyn View {
+        if second {
+            &*self.second
+        } else {
+            &*self.first
+        }
+    }
+
+    fn child_mut(&mut self, second: bool) -> &mut dyn View {
+        if second {
+            &mut *self.second
+        } else {
+            &mut *self.first
+        }
+    }
+
+    // Space along the orientation, without the divider.
+    fn available(&self, size: Vec2) -> usize {
+        self.orientation.get(&size).saturating_sub(1)
+    }
+
+    // Returns the sizes of both children, along the orientation.
+    fn lengths(&self, size: Vec2) -> (usize, usize) {
+        let available = self.available(size);
+        let first = (available as f32 * self.ratio).round() as usize;
+        let first = first.min(available);
+        (first, available - first)
+    }
+
+    fn child_size(&self, second: bool, size: Vec2) -> Vec2 {
+        let (first, rest) = self.lengths(size);
+        let length = if second { rest } else { first };
+        self.orientation
+            .make_vec(length, self.orientation.swap().get(&size))
+    }
+
+    fn child_offset(&self, second: bool, size: Vec2) -> Vec2 {
+        let offset = if second { self.lengths(size).0 + 1 } else { 0 };
+        self.orientation.make_vec(offset, 0)
+    }
+
+    // Moves the divider to the given position along the orientation.
+    fn move_divider(&mut self, position: usize) -> EventResult {
+        let available = self.available(self.last_size);
+        if available == 0 {
+            return EventResult::Ignored;
+        }
+        self.ratio = position.min(available) as f32 / available as f32;
+        EventResult::Consumed(None)
+    }
+
+    // Moves the divider by one cell, towards the end if `forward`.
+    fn step_divider(&mut self, forward: bool) -> EventResult {
+        let (first, _) = self.lengths(self.last_size);
+        if forward {
+            self.move_divider(first + 1)
+        } else if first > 0 {
+            self.move_divider(first - 1)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn inner_on_event(&mut self, event: Event) -> EventResult {
+        if let Some(result) = self.on_mouse_event(event.clone()) {
+            return result;
+        }
+
+        let second = self.second_focused;
+        let offset = self.child_offset(second, self.last_size);
+        let result =
+            self.child_mut(second).on_event(event.relativized(offset));
+        if result.is_consumed() {
+            return result;
+        }
+
+        let horizontal = self.orientation == Orientation::Horizontal;
+        match event {
+            Event::Key(Key::Tab) if !second => {
+                self.switch_focus(Direction::front())
+            }
+            Event::Shift(Key::Tab) if second => {
+                self.switch_focus(Direction::back())
+            }
+            Event::Key(Key::Right) if horizontal && !second => {
+                self.switch_focus(Direction::left())
+            }
+            Event::Key(Key::Left) if horizontal && second => {
+                self.switch_focus(Direction::right())
+            }
+            Event::Key(Key::Down) if !horizontal && !second => {
+                self.switch_focus(Direction::up())
+            }
+            Event::Key(Key::Up) if !horizontal && second => {
+                self.switch_focus(Direction::down())
+            }
+            Event::Ctrl(Key::Right) if horizontal => self.step_divider(true),
+            Event::Ctrl(Key::Left) if horizontal => self.step_divider(false),
+            Event::Ctrl(Key::Down) if !horizontal => self.step_divider(true),
+            Event::Ctrl(Key::Up) if !horizontal => self.step_divider(false),
+            _ => EventResult::Ignored,
+        }
+    }
+
+    // Tells the children about a focus change, after an event.
+    fn notify_focus_change(&mut self, second: bool) -> EventResult {
+        if self.second_focused == second {
+            return EventResult::Ignored;
+        }
+        let lost = self.child_mut(second).on_event(Event::FocusLost);
+        lost.and(self.child_mut(!second).on_event(Event::FocusGained))
+    }
+
+    // Gives the focus to the other child, if it takes it.
+    fn switch_focus(&mut self, source: Direction) -> EventResult {
+        let second = !self.second_focused;
+        if self.child_mut(second).take_focus(source) {
+            self.second_focused = second;
+            EventResult::Consumed(None)
+        } else {
+            EventResult::Ignored
+        }
+    }
+
+    fn on_mouse_event(&mut self, event: Event) -> Option<EventResult> {
+        let (position, offset, mouse_event) = match event {
+            Event::Mouse {
+                position,
+                offset,
+                event,
+            } => (position, offset, event),
+            _ => return None,
+        };
+        let position = position.saturating_sub(offset);
+        let position = self.orientation.get(&position);
+
+        match mouse_event {
+            MouseEvent::Hold(MouseButton::Left) if self.dragging => {
+                Some(self.move_divider(position))
+            }
+            MouseEvent::Release(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                Some(EventResult::Consumed(None))
+            }
+            MouseEvent::Press(MouseButton::Left)
+                if position == self.lengths(self.last_size).0 =>
+            {
+                self.dragging = true;
+                Some(EventResult::Consumed(None))
+            }
+            event if event.grabs_focus() => {
+                // Focus the child under the mouse.
+                let second = position > self.lengths(self.last_size).0;
+                if second != self.second_focused
+                    && self.child_mut(second).take_focus(Direction::none())
+                {
+                    self.second_focused = second;
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl View for Split {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        for &second in &[false, true] {
+            let printer = printer
+                .offset(self.child_offset(second, printer.size))
+                .cropped(self.child_size(second, printer.size))
+                .focused(self.second_focused == second);
+            self.child(second).draw(&printer);
+        }
+
+        let (position, _) = self.lengths(printer.size);
+        let style = if self.dragging {
+            ColorStyle::highlight()
+        } else {
+            ColorStyle::primary()
+        };
+        printer.with_color(style, |printer| match self.orientation {
+            Orientation::Horizontal => {
+                printer.print_vline((position, 0), printer.size.y, "│")
+            }
+            Orientation::Vertical => {
+                printer.print_hline((0, position), printer.size.x, "─")
+            }
+        });
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        let first = self.child_size(false, req);
+        let second = self.child_size(true, req);
+        let first = self.first.required_size(first);
+        let second = self.second.required_size(second);
+
+        // Stack both children, plus the divider.
+        self.orientation.stack([first, second].iter())
+            + self.orientation.make_vec(1, 0)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        let first = self.child_size(false, size);
+        let second = self.child_size(true, size);
+        self.first.layout(first);
+        self.second.layout(second);
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.first.needs_relayout() || self.second.needs_relayout()
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        // Coming from the end, try the second child first.
+        let order = match source.relative(self.orientation) {
+            Some(Relative::Back) => [true, false],
+            Some(Relative::Front) => [false, true],
+            None => [self.second_focused, !self.second_focused],
+        };
+        for &second in &order {
+            if self.child_mut(second).take_focus(source) {
+                self.second_focused = second;
+                return true;
+            }
+        }
+        false
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let second = self.second_focused;
+        let result = self.inner_on_event(event);
+        result.and(self.notify_focus_change(second))
+    }
+
+    fn call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        self.first.call_on_any(selector, callback);
+        self.second.call_on_any(selector, callback);
+    }
+
+    fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
+        for &second in &[false, true] {
+            if self.child_mut(second).focus_view(selector).is_ok() {
+                self.second_focused = second;
+                return Ok(());
+            }
+        }
+        Err(())
+    }
+
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        self.first
+            .remove_view(selector)
+            .or_else(|| self.second.remove_view(selector))
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        let second = self.second_focused;
+        self.child(second)
+            .important_area(self.child_size(second, size))
+            + self.child_offset(second, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::views::{Button, TextView};
+
+    #[test]
+    fn divider() {
+        let mut split =
+            Split::horizontal(TextView::new("left"), TextView::new("right"));
+        assert_eq!(
+            render_to_buffer(&mut split, (11, 1)).lines(),
+            ["left │right"]
+        );
+
+        // The ratio is kept when resized.
+        assert_eq!(render_to_buffer(&mut split, (7, 1)).lines(), ["lef│rig"]);
+
+        // Drag the divider to the left.
+        let mouse = |event, x| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, 0),
+            event,
+        };
+        split.on_event(mouse(MouseEvent::Press(MouseButton::Left), 3));
+        split.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 1));
+        split.on_event(mouse(MouseEvent::Release(MouseButton::Left), 1));
+        assert_eq!(render_to_buffer(&mut split, (7, 1)).lines(), ["l│right"]);
+
+        // Keys move the divider when the child ignores them.
+        split.on_event(Event::Ctrl(Key::Right));
+        assert_eq!(split.get_ratio(), 2.0 / 6.0);
+    }
+
+    #[test]
+    fn focus() {
+        let mut split = Split::vertical(
+            Button::new("One", |_| ()),
+            Button::new("Two", |_| ()),
+        );
+        assert!(split.take_focus(Direction::up()));
+        assert_eq!(split.get_focus_index(), 0);
+
+        split.on_event(Event::Key(Key::Down));
+        assert_eq!(split.get_focus_index(), 1);
+        assert!(!split.on_event(Event::Key(Key::Tab)).is_consumed());
+
+        // Coming from below focuses the second child.
+        split.on_event(Event::Shift(Key::Tab));
+        assert!(split.take_focus(Direction::down()));
+        assert_eq!(split.get_focus_index(), 1);
+    }
+}
@@ -1,13 +1,18 @@
 use crate::direction::Direction;
-use crate::event::{AnyCb, Event, EventResult};
+use crate::event::{AnyCb, Event, EventResult, MouseEvent};
+use crate::rect::Rect;
 use crate::theme::ColorStyle;
 use crate::view::{
-    IntoBoxedView, Offset, Position, Selector, View, ViewWrapper,
+    self, IntoBoxedView, Offset, Position, Selector, View, ViewWrapper,
+};
+use crate::views::window::WindowRequest;
+use crate::views::{
+    Background, BoxedView, CircularFocus, Layer, ShadowView, Window,
 };
-use crate::views::{BoxedView, CircularFocus, Layer, ShadowView};
 use crate::Printer;
 use crate::Vec2;
 use crate::With;
+use crate::XY;
 use std::cell;
 use std::ops::Deref;
 
@@ -20,6 +25,122 @@ pub struct StackView {
     // Flag indicates if undrawn areas of the background are exposed
     // and therefore need redrawing.
     bg_dirty: cell::Cell<bool>,
+    // How to decorate and place new layers.
+    policy: LayerPolicy,
+    // If true, `take_focus` restores the focus inside the top layer.
+    remember_focus: bool,
+    // Drawn behind the layers, instead of the theme background.
+    background: Option<Background>,
+    // Layers added and removed since `take_layer_changes`.
+    pushed: usize,
+    popped: usize,
+    // If true, clicking a layer brings it to the front.
+    window_manager: bool,
+}
+
+/// How a layer of a [`StackView`] handles events.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{LayerEvents, LayerPosition, StackView, TextView};
+///
+/// // A status overlay, letting the keys go to the layer below.
+/// let mut stack = StackView::new()
+///     .layer(TextView::new("Main"))
+///     .transparent_layer(TextView::new("Connected"));
+/// stack.set_layer_events(LayerPosition::FromFront(0), LayerEvents::Transparent);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum LayerEvents {
+    /// The layer receives all events, even those it ignores.
+    ///
+    /// Layers below it never receive any event.
+    #[default]
+    Modal,
+
+    /// Events ignored by the layer go to the layer below.
+    PassThrough,
+
+    /// The layer never receives events, nor the focus.
+    ///
+    /// Events go directly to the layer below.
+    Transparent,
+}
+
+/// Controls how new layers are added to a `StackView`.
+///
+/// This decides whether floating layers get a shadow, and where
+/// [`StackView::add_layer`] places them.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{LayerPolicy, StackView, TextView};
+///
+/// // Each new window is shifted down and right from the previous one.
+/// let stack = StackView::new()
+///     .layer_policy(LayerPolicy::cascade((2, 1)))
+///     .layer(TextView::new("First"))
+///     .layer(TextView::new("Second"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LayerPolicy {
+    /// Draw a shadow around floating layers.
+    ///
+    /// Shadows are only visible if the theme enables them.
+    pub shadow: bool,
+
+    /// Where to place layers added with [`StackView::add_layer`].
+    pub placement: LayerPlacement,
+}
+
+/// Where [`StackView::add_layer`] places new layers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LayerPlacement {
+    /// Center each new layer on the screen.
+    Center,
+
+    /// Offset each new layer from the previous one.
+    ///
+    /// The new layer is centered if the top layer is not floating (or if
+    /// the stack is empty).
+    Cascade(XY<isize>),
+}
+
+impl Default for LayerPolicy {
+    fn default() -> Self {
+        LayerPolicy {
+            shadow: true,
+            placement: LayerPlacement::Center,
+        }
+    }
+}
+
+impl LayerPolicy {
+    /// Centered layers without shadow, for a flat, tiled look.
+    pub fn flat() -> Self {
+        LayerPolicy {
+            shadow: false,
+            placement: LayerPlacement::Center,
+        }
+    }
+
+    /// Cascading layers with shadows, each offset by `step` from the
+    /// previous one.
+    pub fn cascade<T: Into<XY<isize>>>(step: T) -> Self {
+        LayerPolicy {
+            shadow: true,
+            placement: LayerPlacement::Cascade(step.into()),
+        }
+    }
+
+    /// Sets whether floating layers have a shadow.
+    ///
+    /// Chainable variant.
+    pub fn with_shadow(self, shadow: bool) -> Self {
+        LayerPolicy { shadow, ..self }
+    }
 }
 
 /// Where should the view be on the screen (per dimension).
@@ -127,6 +248,17 @@ impl<T: View> ChildWrapper<T> {
             ChildWrapper::Plain(ref mut layer) => layer.get_inner_mut(),
         }
     }
+
+    /// Returns the view filling the background, unless it's transparent.
+    fn get_layer_mut(&mut self) -> Option<&mut Layer<CircularFocus<T>>> {
+        match *self {
+            ChildWrapper::Shadow(ref mut shadow) => {
+                Some(shadow.get_inner_mut())
+            }
+            ChildWrapper::Backfilled(ref mut background) => Some(background),
+            ChildWrapper::Plain(_) => None,
+        }
+    }
 }
 
 // TODO: use macros to make this less ugly?
@@ -196,6 +328,17 @@ impl<T: View> View for ChildWrapper<T> {
             ChildWrapper::Plain(ref mut v) => v.focus_view(selector),
         }
     }
+
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        match *self {
+            ChildWrapper::Shadow(ref mut v) => v.remove_view(selector),
+            ChildWrapper::Backfilled(ref mut v) => v.remove_view(selector),
+            ChildWrapper::Plain(ref mut v) => v.remove_view(selector),
+        }
+    }
 }
 
 struct Child {
@@ -208,6 +351,8 @@ struct Child {
     // So we want to call `take_focus` right after the first call to `layout`.
     // This flag remembers when we've done that.
     virgin: bool,
+
+    events: LayerEvents,
 }
 
 new_default!(StackView);
@@ -219,9 +364,147 @@ impl StackView {
             layers: Vec::new(),
             last_size: Vec2::zero(),
             bg_dirty: cell::Cell::new(true),
+            policy: LayerPolicy::default(),
+            remember_focus: false,
+            background: None,
+            pushed: 0,
+            popped: 0,
+            window_manager: false,
+        }
+    }
+
+    /// Sets the background drawn behind the layers.
+    ///
+    /// `None` uses the `Background` color of the theme.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::{BaseColor, Color};
+    /// use cursive_core::views::Background;
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// // Each screen can have its own background.
+    /// siv.add_active_screen();
+    /// siv.screen_mut().set_background(Some(Background::color(
+    ///     Color::Dark(BaseColor::Blue),
+    /// )));
+    /// ```
+    pub fn set_background(&mut self, background: Option<Background>) {
+        self.background = background;
+        self.bg_dirty.set(true);
+    }
+
+    /// Sets the background drawn behind the layers.
+    ///
+    /// Chainable variant.
+    pub fn background(self, background: Background) -> Self {
+        self.with(|s| s.set_background(Some(background)))
+    }
+
+    /// Returns the background drawn behind the layers, if any.
+    pub fn get_background(&self) -> Option<&Background> {
+        self.background.as_ref()
+    }
+
+    /// Sets the background of a layer, drawn instead of its plain color.
+    ///
+    /// Transparent layers have no background, and are left unchanged.
+    ///
+    /// Returns `false` if the layer does not exist or is transparent.
+    pub fn set_layer_background(
+        &mut self,
+        layer: LayerPosition,
+        background: Option<Background>,
+    ) -> bool {
+        let layer = self
+            .get_index(layer)
+            .and_then(|i| self.layers.get_mut(i))
+            .and_then(|child| child.view.get_layer_mut());
+        match layer {
+            Some(layer) => {
+                layer.set_background(background);
+                true
+            }
+            None => false,
         }
     }
 
+    /// Draws the background again on the next frame.
+    ///
+    /// The background is only drawn when it may have been covered, like
+    /// when a layer is removed.
+    pub(crate) fn invalidate_background(&self) {
+        self.bg_dirty.set(true);
+    }
+
+    /// Makes this stack restore the focus inside its top layer.
+    ///
+    /// When the focus comes back to this stack, the top layer is asked to
+    /// take it without a direction, so containers inside it resume from
+    /// their last focused child rather than their first.
+    ///
+    /// Defaults to `false`.
+    pub fn set_remember_focus(&mut self, remember_focus: bool) {
+        self.remember_focus = remember_focus;
+    }
+
+    /// Makes this stack restore the focus inside its top layer.
+    ///
+    /// Chainable variant.
+    pub fn remember_focus(self, remember_focus: bool) -> Self {
+        self.with(|s| s.set_remember_focus(remember_focus))
+    }
+
+    /// Returns `true` if this stack restores the focus inside its top layer.
+    pub fn get_remember_focus(&self) -> bool {
+        self.remember_focus
+    }
+
+    /// Makes this stack behave like a window manager.
+    ///
+    /// Pressing the mouse on a layer brings it to the front and gives it the
+    /// focus, instead of only sending events to the top layer. This is meant
+    /// for stacks of [`Window`]s, but works with any floating layer.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Window`]: crate::views::Window
+    pub fn set_window_manager(&mut self, window_manager: bool) {
+        self.window_manager = window_manager;
+    }
+
+    /// Makes this stack behave like a window manager.
+    ///
+    /// Chainable variant.
+    pub fn window_manager(self, window_manager: bool) -> Self {
+        self.with(|s| s.set_window_manager(window_manager))
+    }
+
+    /// Returns `true` if clicking a layer brings it to the front.
+    pub fn get_window_manager(&self) -> bool {
+        self.window_manager
+    }
+
+    /// Returns the policy used for new layers.
+    pub fn get_layer_policy(&self) -> LayerPolicy {
+        self.policy
+    }
+
+    /// Sets the policy used for new layers.
+    ///
+    /// Layers already in the stack are not affected.
+    pub fn set_layer_policy(&mut self, policy: LayerPolicy) {
+        self.policy = policy;
+    }
+
+    /// Sets the policy used for new layers.
+    ///
+    /// Chainable variant.
+    pub fn layer_policy(self, policy: LayerPolicy) -> Self {
+        self.with(|s| s.set_layer_policy(policy))
+    }
+
     /// Returns the number of layers in this `StackView`.
     pub fn len(&self) -> usize {
         self.layers.len()
@@ -258,18 +541,40 @@ impl StackView {
             size: Vec2::zero(),
             placement: Placement::Fullscreen,
             virgin: true,
+            events: LayerEvents::default(),
         });
+        self.pushed += 1;
     }
 
-    /// Adds new view on top of the stack in the center of the screen.
+    /// Adds new view on top of the stack.
+    ///
+    /// By default, it is placed in the center of the screen. See
+    /// [`StackView::set_layer_policy`] to change that.
     pub fn add_layer<T>(&mut self, view: T)
     where
         T: IntoBoxedView,
     {
-        self.add_layer_at(Position::center(), view);
+        let position = self.next_position();
+        self.add_layer_at(position, view);
     }
 
-    /// Adds new view on top of the stack in the center of the screen.
+    /// Returns the position for the next layer, according to the policy.
+    fn next_position(&self) -> Position {
+        let top_is_floating = self
+            .layers
+            .last()
+            .map(|child| matches!(child.placement, Placement::Floating(_)))
+            .unwrap_or(false);
+
+        match self.policy.placement {
+            LayerPlacement::Cascade(step) if top_is_floating => {
+                Position::parent(step)
+            }
+            _ => Position::center(),
+        }
+    }
+
+    /// Adds new view on top of the stack.
     ///
     /// Chainable variant.
     pub fn layer<T>(self, view: T) -> Self
@@ -365,17 +670,25 @@ impl StackView {
         T: IntoBoxedView,
     {
         let boxed = BoxedView::boxed(view);
-        self.layers.push(Child {
+        let layer = Layer::new(CircularFocus::wrap_tab(boxed));
+        let view = if self.policy.shadow {
             // Skip padding for absolute/parent-placed views
-            view: ChildWrapper::Shadow(
-                ShadowView::new(Layer::new(CircularFocus::wrap_tab(boxed)))
+            ChildWrapper::Shadow(
+                ShadowView::new(layer)
                     .top_padding(position.y == Offset::Center)
                     .left_padding(position.x == Offset::Center),
-            ),
+            )
+        } else {
+            ChildWrapper::Backfilled(layer)
+        };
+        self.layers.push(Child {
+            view,
             size: Vec2::new(0, 0),
             placement: Placement::Floating(position),
             virgin: true,
+            events: LayerEvents::default(),
         });
+        self.pushed += 1;
     }
 
     /// Adds a transparent view on top of the stack in the center of the screen.
@@ -397,7 +710,9 @@ impl StackView {
             size: Vec2::new(0, 0),
             placement: Placement::Floating(position),
             virgin: true,
+            events: LayerEvents::default(),
         });
+        self.pushed += 1;
     }
 
     /// Adds a view on top of the stack at the given position.
@@ -417,17 +732,161 @@ impl StackView {
     /// If the given position is out of bounds.
     pub fn remove_layer(&mut self, position: LayerPosition) -> Box<dyn View> {
         let i = self.get_index(position).unwrap();
+        self.popped += 1;
         self.layers.remove(i).view.unwrap().unwrap()
     }
 
     /// Remove the top-most layer.
     pub fn pop_layer(&mut self) -> Option<Box<dyn View>> {
         self.bg_dirty.set(true);
+        let child = self.layers.pop()?;
+        self.popped += 1;
+        Some(child.view.unwrap().unwrap())
+    }
+
+    /// Sets how a layer handles events.
+    ///
+    /// Layers are [`LayerEvents::Modal`] by default.
+    ///
+    /// # Panics
+    ///
+    /// If `layer` is out of bounds.
+    pub fn set_layer_events(
+        &mut self,
+        layer: LayerPosition,
+        events: LayerEvents,
+    ) {
+        let i = self.get_index(layer).unwrap();
+        self.layers[i].events = events;
+    }
+
+    /// Returns how a layer handles events.
+    ///
+    /// Returns `None` if `layer` is out of bounds.
+    pub fn get_layer_events(
+        &self,
+        layer: LayerPosition,
+    ) -> Option<LayerEvents> {
+        let i = self.get_index(layer)?;
+        self.layers.get(i).map(|child| child.events)
+    }
+
+    /// Returns the number of layers added and removed since the last call.
+    ///
+    /// This is used by `Cursive` to run the layer hooks.
+    pub(crate) fn take_layer_changes(&mut self) -> (usize, usize) {
+        let changes = (self.pushed, self.popped);
+        self.pushed = 0;
+        self.popped = 0;
+        changes
+    }
+
+    // Brings the layer under the mouse to the front, if it accepts events.
+    //
+    // The previous active layer loses the focus.
+    fn raise_clicked_layer(&mut self, position: Vec2) -> EventResult {
+        let offsets: Vec<Vec2> =
+            StackPositionIterator::new(self.layers.iter(), self.last_size)
+                .map(|(_, offset)| offset)
+                .collect();
+        let clicked =
+            self.layers
+                .iter()
+                .zip(offsets)
+                .rposition(|(child, offset)| {
+                    Rect::from_size(offset, child.size).contains(position)
+                });
+        let i = match clicked {
+            Some(i) if i + 1 < self.layers.len() => i,
+            _ => return EventResult::Ignored,
+        };
+        if self.layers[i].events == LayerEvents::Transparent {
+            return EventResult::Ignored;
+        }
+
+        let lost = match self.active_index() {
+            Some(old) => self.layers[old].view.on_event(Event::FocusLost),
+            None => EventResult::Ignored,
+        };
+        let mut child = self.layers.remove(i);
+        child.view.take_focus(Direction::none());
+        let gained = child.view.on_event(Event::FocusGained);
+        self.layers.push(child);
+        lost.and(gained)
+    }
+
+    // Applies the moves and closes asked by windows.
+    fn apply_window_requests(&mut self) {
+        let offsets: Vec<Vec2> =
+            StackPositionIterator::new(self.layers.iter(), self.last_size)
+                .map(|(_, offset)| offset)
+                .collect();
+
+        let mut closed = Vec::new();
+        for (i, (child, offset)) in
+            self.layers.iter_mut().zip(offsets).enumerate()
+        {
+            let view: &mut dyn View = &mut **child.view.get_inner_mut();
+            let request = match view.as_any_mut().downcast_mut::<Window>() {
+                Some(window) => window.take_request(),
+                None => continue,
+            };
+            match request {
+                Some(WindowRequest::Move(delta)) => {
+                    if let Placement::Floating(_) = child.placement {
+                        let offset = offset.saturating_add(delta);
+                        child.placement =
+                            Placement::Floating(Position::absolute(offset));
+                        self.bg_dirty.set(true);
+                    }
+                }
+                Some(WindowRequest::Close) => closed.push(i),
+                None => (),
+            }
+        }
+
+        for i in closed.into_iter().rev() {
+            self.remove_layer(LayerPosition::FromBack(i));
+            self.bg_dirty.set(true);
+        }
+    }
+
+    // Sends an event to the layers, starting from the top.
+    fn dispatch_event(&mut self, event: Event) -> EventResult {
+        // Only mouse events need the offset of each layer.
+        let offsets: Vec<Vec2> = match event {
+            Event::Mouse { .. } => {
+                StackPositionIterator::new(self.layers.iter(), self.last_size)
+                    .map(|(_, offset)| offset)
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+
+        // Start from the top, until a layer keeps the event.
+        for (i, v) in self.layers.iter_mut().enumerate().rev() {
+            let offset = offsets.get(i).copied().unwrap_or_else(Vec2::zero);
+            let result = match v.events {
+                LayerEvents::Transparent => continue,
+                LayerEvents::Modal => {
+                    return v.view.on_event(event.relativized(offset))
+                }
+                LayerEvents::PassThrough => {
+                    v.view.on_event(event.relativized(offset))
+                }
+            };
+            if result.is_consumed() {
+                return result;
+            }
+        }
+        EventResult::Ignored
+    }
+
+    // Returns the index of the top layer receiving events.
+    fn active_index(&self) -> Option<usize> {
         self.layers
-            .pop()
-            .map(|child| child.view)
-            .map(ChildWrapper::unwrap)
-            .map(BoxedView::unwrap)
+            .iter()
+            .rposition(|child| child.events != LayerEvents::Transparent)
     }
 
     /// Computes the offset of the current top view.
@@ -523,10 +982,15 @@ impl StackView {
     pub fn draw_bg(&self, printer: &Printer<'_, '_>) {
         // If the background is dirty draw a new background
         if self.bg_dirty.get() {
-            for y in 0..printer.size.y {
-                printer.with_color(ColorStyle::background(), |printer| {
-                    printer.print_hline((0, y), printer.size.x, " ");
-                });
+            match self.background {
+                Some(ref background) => background.draw(printer),
+                None => {
+                    printer.with_color(ColorStyle::background(), |printer| {
+                        for y in 0..printer.size.y {
+                            printer.print_hline((0, y), printer.size.x, " ");
+                        }
+                    });
+                }
             }
 
             // set background as clean, so we don't need to do this every frame
@@ -541,7 +1005,7 @@ impl StackView {
     ///
     /// You probably just want to call draw()
     pub fn draw_fg(&self, printer: &Printer<'_, '_>) {
-        let last = self.layers.len();
+        let active = self.active_index();
         printer.with_color(ColorStyle::primary(), |printer| {
             for (i, (v, offset)) in
                 StackPositionIterator::new(self.layers.iter(), printer.size)
@@ -551,7 +1015,7 @@ impl StackView {
                     &printer
                         .offset(offset)
                         .cropped(v.size)
-                        .focused(i + 1 == last),
+                        .focused(Some(i) == active),
                 );
             }
         });
@@ -617,17 +1081,28 @@ impl View for StackView {
         if event == Event::WindowResize {
             self.bg_dirty.set(true);
         }
-        // Use the stack position iterator to get the offset of the top layer.
-        // TODO: save it instead when drawing?
-        match StackPositionIterator::new(
-            self.layers.iter_mut(),
-            self.last_size,
-        )
-        .last()
+        let mut raised = EventResult::Ignored;
+        if let Event::Mouse {
+            event: MouseEvent::Press(_),
+            position,
+            offset,
+        } = event
         {
-            None => EventResult::Ignored,
-            Some((v, offset)) => v.view.on_event(event.relativized(offset)),
+            if self.window_manager {
+                if let Some(position) = position.checked_sub(offset) {
+                    raised = self.raise_clicked_layer(position);
+                }
+            }
         }
+
+        let mouse = matches!(event, Event::Mouse { .. });
+        let result = raised.and(self.dispatch_event(event));
+
+        // Windows only ask to move or close on mouse events.
+        if mouse {
+            self.apply_window_requests();
+        }
+        result
     }
 
     fn layout(&mut self, size: Vec2) {
@@ -664,9 +1139,14 @@ impl View for StackView {
     }
 
     fn take_focus(&mut self, source: Direction) -> bool {
-        match self.layers.last_mut() {
+        let source = if self.remember_focus {
+            Direction::none()
+        } else {
+            source
+        };
+        match self.active_index() {
             None => false,
-            Some(v) => v.view.take_focus(source),
+            Some(i) => self.layers[i].view.take_focus(source),
         }
     }
 
@@ -689,6 +1169,25 @@ impl View for StackView {
 
         Err(())
     }
+
+    fn remove_view(
+        &mut self,
+        selector: &Selector<'_>,
+    ) -> Option<Box<dyn View>> {
+        for layer in &mut self.layers {
+            if let Some(view) = layer.view.remove_view(selector) {
+                return Some(view);
+            }
+        }
+
+        let i = self
+            .layers
+            .iter_mut()
+            .position(|layer| view::contains(&mut layer.view, selector))?;
+        self.bg_dirty.set(true);
+        self.popped += 1;
+        Some(self.layers.remove(i).view.unwrap().unwrap())
+    }
 }
 
 #[cfg(test)]
@@ -714,6 +1213,191 @@ mod tests {
         assert_eq!(text.get_content().source(), "1");
     }
 
+    #[test]
+    fn window_manager() {
+        use crate::event::MouseButton;
+
+        let mouse = |event, position: (usize, usize)| Event::Mouse {
+            offset: Vec2::zero(),
+            position: position.into(),
+            event,
+        };
+        let press = |p| mouse(MouseEvent::Press(MouseButton::Left), p);
+        let hold = |p| mouse(MouseEvent::Hold(MouseButton::Left), p);
+        let release = |p| mouse(MouseEvent::Release(MouseButton::Left), p);
+
+        let mut stack = StackView::new()
+            .layer_policy(LayerPolicy::flat())
+            .window_manager(true);
+        stack.add_layer_at(
+            Position::absolute((0, 0)),
+            Window::new(TextView::new("A")).size((12, 4)),
+        );
+        stack.add_layer_at(
+            Position::absolute((10, 3)),
+            Window::new(TextView::new("B")).size((14, 5)),
+        );
+        stack.layout(Vec2::new(30, 10));
+
+        // Clicking the bottom window raises it.
+        stack.on_event(press((1, 1)));
+        stack.layout(Vec2::new(30, 10));
+        assert_eq!(
+            stack.layer_sizes(),
+            vec![Vec2::new(14, 5), Vec2::new(12, 4)]
+        );
+
+        // Dragging the title bar moves it.
+        stack.on_event(press((2, 0)));
+        stack.on_event(hold((5, 2)));
+        stack.on_event(release((5, 2)));
+        stack.layout(Vec2::new(30, 10));
+        assert_eq!(stack.offset(), Vec2::new(3, 2));
+
+        // Dragging the corner resizes it.
+        stack.on_event(press((14, 5)));
+        stack.on_event(hold((16, 7)));
+        stack.on_event(release((16, 7)));
+        stack.layout(Vec2::new(30, 10));
+        assert_eq!(stack.layer_sizes()[1], Vec2::new(14, 6));
+
+        // The close button removes it.
+        stack.on_event(press((12, 2)));
+        assert_eq!(stack.layer_sizes(), vec![Vec2::new(14, 5)]);
+    }
+
+    #[test]
+    fn raise_focus_events() {
+        use crate::event::MouseButton;
+        use crate::views::FocusTracker;
+
+        let tracker = |name| {
+            FocusTracker::new(TextView::new(name))
+                .on_focus(|view| {
+                    view.set_content("in");
+                    EventResult::Consumed(None)
+                })
+                .on_focus_lost(|view| {
+                    view.set_content("out");
+                    EventResult::Consumed(None)
+                })
+        };
+        let content = |stack: &StackView, i| {
+            let layer = stack.get(LayerPosition::FromBack(i)).unwrap();
+            let tracker = layer.downcast_ref::<FocusTracker<TextView>>();
+            tracker
+                .unwrap()
+                .get_inner()
+                .get_content()
+                .source()
+                .to_string()
+        };
+
+        let mut stack = StackView::new()
+            .layer_policy(LayerPolicy::flat())
+            .window_manager(true);
+        stack.add_layer_at(Position::absolute((0, 0)), tracker("A"));
+        stack.add_layer_at(Position::absolute((5, 0)), tracker("B"));
+        stack.layout(Vec2::new(10, 2));
+
+        // Clicking the bottom layer moves the focus to it.
+        let result = stack.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::zero(),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        assert!(result.is_consumed());
+        assert_eq!(content(&stack, 0), "out");
+        assert_eq!(content(&stack, 1), "in");
+    }
+
+    #[test]
+    fn backgrounds() {
+        use crate::buffer::render_to_buffer;
+
+        let mut stack = StackView::new()
+            .layer_policy(LayerPolicy::flat())
+            .background(Background::pattern("ab\ncd", ColorStyle::primary()))
+            .layer(TextView::new("x"));
+        assert!(stack.set_layer_background(
+            LayerPosition::FromFront(0),
+            Some(Background::pattern("-", ColorStyle::primary())),
+        ));
+        assert!(!stack.set_layer_background(LayerPosition::FromBack(1), None));
+
+        let buffer = render_to_buffer(&mut stack, (5, 3));
+        assert_eq!(buffer.lines(), ["ababa", "cdxdc", "ababa"]);
+    }
+
+    #[test]
+    fn layer_events() {
+        use crate::event::Key;
+        use crate::view::Nameable;
+        use crate::views::{EditView, OnEventView};
+        use crate::Cursive;
+
+        let mut siv = crate::testing::dummy((20, 5));
+        siv.set_user_data(Vec::<&str>::new());
+        siv.set_on_layer_push(|s| {
+            s.with_user_data(|log: &mut Vec<&str>| log.push("push"));
+        });
+        siv.set_on_layer_pop(|s| {
+            s.with_user_data(|log: &mut Vec<&str>| log.push("pop"));
+        });
+
+        siv.add_layer(EditView::new().with_name("edit"));
+        siv.add_layer(
+            OnEventView::new(TextView::new("popup"))
+                .on_event(Key::F1, |s: &mut Cursive| s.quit()),
+        );
+        let top = LayerPosition::FromFront(0);
+        siv.screen_mut()
+            .set_layer_events(top, LayerEvents::PassThrough);
+        siv.refresh();
+
+        // Ignored by the popup, the key goes to the layer below.
+        siv.on_event(Event::Char('a'));
+        siv.on_event(Event::Key(Key::F1));
+        assert!(!siv.is_running());
+
+        siv.screen_mut().set_layer_events(top, LayerEvents::Modal);
+        siv.on_event(Event::Char('b'));
+        siv.pop_layer();
+        siv.refresh();
+
+        let content =
+            siv.call_on_name("edit", |edit: &mut EditView| edit.get_content());
+        assert_eq!(content.unwrap().as_str(), "a");
+        assert_eq!(
+            siv.take_user_data::<Vec<&str>>().unwrap(),
+            ["push", "push", "pop"]
+        );
+    }
+
+    #[test]
+    fn remove_view() {
+        use crate::view::Nameable;
+        use crate::views::{Dialog, LinearLayout};
+
+        let mut stack = StackView::new()
+            .layer(Dialog::around(
+                LinearLayout::vertical()
+                    .child(TextView::new("1").with_name("one"))
+                    .child(TextView::new("2")),
+            ))
+            .layer(TextView::new("3").with_name("three"));
+
+        // Views are removed from the closest view group.
+        assert!(stack.remove_view(&Selector::Name("one")).is_some());
+        assert_eq!(stack.len(), 2);
+        stack.call_on_any(&Selector::Name("one"), &mut |_| panic!("removed"));
+
+        // Layers can be removed too.
+        assert!(stack.remove_view(&Selector::Name("three")).is_some());
+        assert_eq!(stack.len(), 1);
+        assert!(stack.remove_view(&Selector::Name("three")).is_none());
+    }
+
     #[test]
     fn move_layer_works() {
         let mut stack = StackView::new()
@@ -761,6 +1445,20 @@ mod tests {
         assert!(stack.pop_layer().is_none());
     }
 
+    #[test]
+    fn cascade() {
+        let mut stack = StackView::new()
+            .layer_policy(LayerPolicy::cascade((2, 1)).with_shadow(false))
+            .layer(TextView::new("1"))
+            .layer(TextView::new("2"))
+            .layer(TextView::new("3"));
+        stack.layout(Vec2::new(20, 10));
+
+        // The first layer is centered, the others follow.
+        assert_eq!(stack.layer_sizes(), vec![Vec2::new(1, 1); 3]);
+        assert_eq!(stack.offset(), Vec2::new(9 + 4, 4 + 2));
+    }
+
     #[test]
     fn get() {
         let mut stack = StackView::new()
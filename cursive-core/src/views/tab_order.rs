@@ -0,0 +1,205 @@
+use crate::event::{Event, EventResult, Key};
+use crate::printer::ViewAreas;
+use crate::view::{Selector, View, ViewWrapper};
+use crate::{Printer, With};
+use std::cell::Cell;
+
+/// Wrapper giving an explicit `Tab` order to named views.
+///
+/// `Tab` and `Shift+Tab` move the focus to the next or previous view in the
+/// list, wrapping around, instead of following the layout. Names that can't
+/// be focused are skipped. Other events are handled as usual.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Button, EditView, LinearLayout, TabOrder};
+/// use cursive_core::view::Nameable;
+///
+/// let form = TabOrder::new(
+///     LinearLayout::horizontal()
+///         .child(EditView::new().with_name("last"))
+///         .child(EditView::new().with_name("first"))
+///         .child(Button::new("Ok", |s| s.quit()).with_name("ok")),
+/// )
+/// .names(["first", "last", "ok"]);
+/// ```
+pub struct TabOrder<V> {
+    view: V,
+    names: Vec<String>,
+
+    // Areas of the named views, to find the focused one.
+    areas: ViewAreas,
+    // Index in `names` of the focused view, if any.
+    current: Cell<Option<usize>>,
+}
+
+impl<V> TabOrder<V> {
+    /// Wraps the given view, with an empty order.
+    pub fn new(view: V) -> Self {
+        TabOrder {
+            view,
+            names: Vec::new(),
+            areas: ViewAreas::default(),
+            current: Cell::new(None),
+        }
+    }
+
+    /// Sets the names of the views, in `Tab` order.
+    pub fn set_names<I, S>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.names = names.into_iter().map(Into::into).collect();
+        self.current.set(None);
+    }
+
+    /// Sets the names of the views, in `Tab` order.
+    ///
+    /// Chainable variant.
+    pub fn names<I, S>(self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.with(|s| s.set_names(names))
+    }
+
+    /// Returns the names of the views, in `Tab` order.
+    pub fn get_names(&self) -> &[String] {
+        &self.names
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> TabOrder<V> {
+    // Focuses the next view in the order that takes it.
+    fn move_focus(&mut self, forward: bool) -> EventResult {
+        let len = self.names.len();
+        let start = match self.current.get() {
+            Some(i) => i,
+            None if forward => len - 1,
+            None => 0,
+        };
+
+        let lost = self.view.on_event(Event::FocusLost);
+        let next = (1..=len)
+            .map(|step| {
+                if forward {
+                    (start + step) % len
+                } else {
+                    (start + len - step) % len
+                }
+            })
+            .find(|&i| {
+                self.view
+                    .focus_view(&Selector::Name(&self.names[i]))
+                    .is_ok()
+            });
+        let gained = self.view.on_event(Event::FocusGained);
+        let result = lost.and(gained);
+
+        match next {
+            Some(i) => {
+                self.current.set(Some(i));
+                result.and(EventResult::Consumed(None))
+            }
+            None => result,
+        }
+    }
+}
+
+impl<V: View> ViewWrapper for TabOrder<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        self.areas.borrow_mut().clear();
+        self.view.draw(&printer.track_view_areas(&self.areas));
+        printer.record_areas(&self.areas);
+
+        let areas = self.areas.borrow();
+        self.current.set(self.names.iter().position(|name| {
+            areas.get(name).is_some_and(|area| area.is_focused())
+        }));
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let forward = match event {
+            Event::Key(Key::Tab) => true,
+            Event::Shift(Key::Tab) => false,
+            _ => return self.view.on_event(event),
+        };
+        if self.names.is_empty() {
+            return self.view.on_event(event);
+        }
+        self.move_focus(forward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::Nameable;
+    use crate::views::{Button, FocusTracker, LinearLayout};
+    use crate::Cursive;
+
+    fn log(s: &mut Cursive, entry: String) {
+        s.with_user_data(|log: &mut Vec<String>| log.push(entry));
+    }
+
+    #[test]
+    fn tab_order_and_focus_events() {
+        let mut siv = crate::testing::dummy((20, 3));
+        let button = |name: &'static str| {
+            FocusTracker::new(Button::new(name, |_| ()))
+                .on_focus(move |_| {
+                    EventResult::with_cb(move |s| log(s, format!("+{}", name)))
+                })
+                .on_focus_lost(move |_| {
+                    EventResult::with_cb(move |s| log(s, format!("-{}", name)))
+                })
+                .with_name(name)
+        };
+        siv.add_layer(
+            TabOrder::new(
+                LinearLayout::horizontal()
+                    .child(button("a"))
+                    .child(button("b"))
+                    .child(button("c")),
+            )
+            .names(["c", "a"]),
+        );
+        siv.set_user_data(Vec::<String>::new());
+        siv.refresh();
+
+        // The order skips `b`, and wraps around.
+        siv.on_event(Event::Key(Key::Tab));
+        siv.on_event(Event::Key(Key::Tab));
+        // Other focus changes still send the events.
+        siv.on_event(Event::Key(Key::Right));
+        siv.refresh();
+        siv.on_event(Event::Shift(Key::Tab));
+
+        assert_eq!(
+            siv.take_user_data::<Vec<String>>().unwrap(),
+            ["-a", "+c", "-c", "+a", "-a", "+b", "-b", "+a"]
+        );
+
+        // Without explicit order, the focus wraps around the layer.
+        siv.add_layer(
+            LinearLayout::vertical()
+                .child(button("d"))
+                .child(button("e")),
+        );
+        siv.set_user_data(Vec::<String>::new());
+        siv.refresh();
+        siv.on_event(Event::Key(Key::Tab));
+        siv.on_event(Event::Key(Key::Tab));
+        assert_eq!(
+            siv.take_user_data::<Vec<String>>().unwrap(),
+            ["-d", "+e", "-e", "+d"]
+        );
+    }
+}
@@ -1,13 +1,21 @@
 use crate::direction::Direction;
 use crate::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use crate::menu::MenuTree;
 use crate::rect::Rect;
-use crate::theme::{ColorStyle, Effect};
+use crate::theme::{ColorStyle, Effect, Style};
+use crate::utils::clipboard;
 use crate::utils::lines::simple::{prefix, simple_prefix, LinesIterator, Row};
-use crate::view::{ScrollBase, SizeCache, View};
+use crate::utils::spell::SpellChecker;
+use crate::view::{Position, ScrollBase, SizeCache, View};
+use crate::views::MenuPopup;
 use crate::Vec2;
-use crate::{Printer, With, XY};
+use crate::{Cursive, Printer, With, XY};
+use crossbeam_channel::{Receiver, TryRecvError};
 use log::debug;
-use std::cmp::min;
+use std::cell::{Cell, RefCell};
+use std::cmp::{max, min};
+use std::ops::Range;
+use std::rc::Rc;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -15,7 +23,8 @@ use unicode_width::UnicodeWidthStr;
 ///
 /// A `TextArea` will attempt to grow vertically and horizontally
 /// dependent on the content.  Wrap it in a `ResizedView` to
-/// constrain its size.
+/// constrain its size, or use [`TextArea::auto_grow`] to keep its height
+/// within some bounds.
 ///
 /// # Examples
 ///
@@ -50,6 +59,105 @@ pub struct TextArea {
 
     /// Byte offset of the currently selected grapheme.
     cursor: usize,
+
+    /// Byte offset where the selection started, if any.
+    ///
+    /// The selection goes from here to the cursor.
+    selection_anchor: Option<usize>,
+
+    /// If `true`, `Home` and `End` go to the start or end of the logical
+    /// line, rather than the wrapped row.
+    logical_home_end: bool,
+
+    /// If `true`, new lines start with the indentation of the previous one.
+    auto_indent: bool,
+
+    /// Finds the grapheme paired with the one under the cursor.
+    pair_matcher: Option<Rc<PairMatcher>>,
+
+    /// Minimum and maximum height to request, if set.
+    ///
+    /// When set, the view follows the number of rows within these bounds.
+    height_bounds: Option<(usize, usize)>,
+
+    /// Event triggering `on_submit`.
+    submit_event: Event,
+
+    /// Callback when `submit_event` is received.
+    on_submit: Option<Rc<OnSubmit>>,
+
+    /// Event triggering `on_cancel`.
+    cancel_event: Event,
+
+    /// Callback when `cancel_event` is received.
+    on_cancel: Option<Rc<OnSubmit>>,
+
+    /// States to restore with `undo`, most recent last.
+    undo_stack: Vec<Snapshot>,
+
+    /// States to restore with `redo`, most recent last.
+    redo_stack: Vec<Snapshot>,
+
+    /// If `true`, the last edit was a character insertion which the next
+    /// one can join.
+    grouping: bool,
+
+    /// Finds misspelled words in the visible rows.
+    spell_checker: Option<Rc<dyn SpellChecker>>,
+
+    /// Style used for misspelled words.
+    spell_style: Style,
+
+    /// Event opening the suggestions for the word under the cursor.
+    suggestions_event: Event,
+
+    /// Suggestion chosen in the popup, to apply on the next layout.
+    replacement: Rc<RefCell<Option<Replacement>>>,
+
+    /// Clipboard content being read, to insert once known.
+    pending_paste: Option<Receiver<Option<String>>>,
+
+    /// Absolute offset of the view, from the last draw.
+    last_offset: Cell<Vec2>,
+}
+
+/// Maximum number of edits kept in the undo history.
+const HISTORY_LIMIT: usize = 100;
+
+/// Content and cursor position saved in the edit history.
+struct Snapshot {
+    content: String,
+    cursor: usize,
+}
+
+/// Closure type for callbacks when the content is submitted or cancelled.
+///
+/// Arguments are the `Cursive` and the content of the text area.
+pub type OnSubmit = dyn Fn(&mut Cursive, &str);
+
+// Range to replace, and the replacement.
+type Replacement = (Range<usize>, String);
+
+/// Closure type to find a matching pair, like brackets.
+///
+/// Arguments are the content of the text area and the cursor position. It
+/// should return the byte offset of the grapheme paired with the cursor, if
+/// any.
+pub type PairMatcher = dyn Fn(&str, usize) -> Option<usize>;
+
+/// Returns `true` if `key` moves the cursor.
+fn is_movement(key: Key) -> bool {
+    matches!(
+        key,
+        Key::Left
+            | Key::Right
+            | Key::Up
+            | Key::Down
+            | Key::Home
+            | Key::End
+            | Key::PageUp
+            | Key::PageDown
+    )
 }
 
 fn make_rows(text: &str, width: usize) -> Vec<Row> {
@@ -71,6 +179,24 @@ impl TextArea {
             size_cache: None,
             last_size: Vec2::zero(),
             cursor: 0,
+            selection_anchor: None,
+            logical_home_end: false,
+            auto_indent: false,
+            pair_matcher: None,
+            height_bounds: None,
+            submit_event: Event::Ctrl(Key::Enter),
+            on_submit: None,
+            cancel_event: Event::Key(Key::Esc),
+            on_cancel: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            grouping: false,
+            spell_checker: None,
+            spell_style: Style::from(Effect::Underline),
+            suggestions_event: Event::Key(Key::F7),
+            replacement: Rc::new(RefCell::new(None)),
+            pending_paste: None,
+            last_offset: Cell::new(Vec2::zero()),
         }
     }
 
@@ -90,20 +216,31 @@ impl TextArea {
 
     /// Moves the cursor to the given position.
     ///
+    /// This clears the selection.
+    ///
     /// # Panics
     ///
     /// This method panics if `cursor` is not the beginning of a character in
     /// the content string.
     pub fn set_cursor(&mut self, cursor: usize) {
         self.cursor = cursor;
+        self.selection_anchor = None;
 
         let focus = self.selected_row();
         self.scrollbase.scroll_to(focus);
     }
 
     /// Sets the content of the view.
+    ///
+    /// This clears the edit history.
     pub fn set_content<S: Into<String>>(&mut self, content: S) {
-        self.content = content.into();
+        self.clear_history();
+        self.replace_content(content.into());
+    }
+
+    fn replace_content(&mut self, content: String) {
+        self.content = content;
+        self.selection_anchor = None;
 
         // First, make sure we are within the bounds.
         self.cursor = min(self.cursor, self.content.len());
@@ -158,6 +295,567 @@ impl TextArea {
         self.enabled
     }
 
+    /// Makes the height of this view follow its content.
+    ///
+    /// The requested height will be the number of rows, but never less than
+    /// `min_height` nor more than `max_height`. If the content needs more
+    /// rows than that, a scrollbar will be shown.
+    ///
+    /// This is useful for chat-like input boxes in a `LinearLayout`, which
+    /// grow as the user types and shrink back when cleared.
+    ///
+    /// # Panics
+    ///
+    /// If `min_height > max_height`.
+    pub fn set_auto_grow(&mut self, min_height: usize, max_height: usize) {
+        assert!(
+            min_height <= max_height,
+            "min_height must not exceed max_height"
+        );
+        self.height_bounds = Some((min_height, max_height));
+        self.invalidate();
+    }
+
+    /// Makes the height of this view follow its content.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{LinearLayout, TextArea, TextView};
+    ///
+    /// let layout = LinearLayout::vertical()
+    ///     .child(TextView::new("Messages..."))
+    ///     .child(TextArea::new().auto_grow(1, 5));
+    /// ```
+    pub fn auto_grow(self, min_height: usize, max_height: usize) -> Self {
+        self.with(|s| s.set_auto_grow(min_height, max_height))
+    }
+
+    /// Stops following the content height.
+    ///
+    /// The view will again request as many rows as its content has.
+    pub fn disable_auto_grow(&mut self) {
+        self.height_bounds = None;
+        self.invalidate();
+    }
+
+    /// Returns the height bounds set with `set_auto_grow`, if any.
+    pub fn get_auto_grow(&self) -> Option<(usize, usize)> {
+        self.height_bounds
+    }
+
+    /// Returns the byte range of the selected text in the content string.
+    ///
+    /// Text can be selected with `Shift` and the movement keys.
+    ///
+    /// Returns `None` if nothing is selected.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.selection_anchor
+            .filter(|&anchor| anchor != self.cursor)
+            .map(|anchor| min(anchor, self.cursor)..max(anchor, self.cursor))
+    }
+
+    /// Returns the selected text, if any.
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection().map(|range| &self.content[range])
+    }
+
+    /// Clears the selection, leaving the content untouched.
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+    }
+
+    /// Inserts `text` at the cursor, replacing the selection if any.
+    ///
+    /// The cursor is moved after the inserted text.
+    pub fn insert_text(&mut self, text: &str) {
+        self.save_undo(false);
+        self.delete_selection();
+        self.content.insert_str(self.cursor, text);
+        self.cursor += text.len();
+
+        if let Some(size) = self.size_cache.map(|s| s.map(|s| s.value)) {
+            self.invalidate();
+            self.compute_rows(size);
+        }
+    }
+
+    /// Copies the selected text to the clipboard.
+    ///
+    /// Returns `false` if nothing is selected. `Ctrl+C` and `Ctrl+Insert`
+    /// call this, although `Ctrl+C` quits the application by default.
+    ///
+    /// See [`clipboard`] for how the clipboard works.
+    pub fn copy(&self) -> bool {
+        match self.selected_text() {
+            Some(text) => {
+                clipboard::set_text(text);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the selected text to the clipboard.
+    ///
+    /// Returns `false` if nothing is selected. `Ctrl+X` and `Shift+Delete`
+    /// call this.
+    pub fn cut(&mut self) -> bool {
+        if !self.copy() {
+            return false;
+        }
+        self.save_undo(false);
+        self.delete_selection();
+        true
+    }
+
+    /// Inserts the content of the clipboard at the cursor.
+    ///
+    /// Returns `false` if the clipboard is empty. `Ctrl+V` and
+    /// `Shift+Insert` call this.
+    ///
+    /// The system clipboard is read in the background: until it is known,
+    /// this returns `true`, and the text is inserted on a later layout.
+    pub fn paste(&mut self) -> bool {
+        let receiver = clipboard::request_text();
+        match receiver.try_recv() {
+            Ok(Some(text)) => {
+                self.insert_text(&text);
+                true
+            }
+            Err(TryRecvError::Empty) => {
+                self.pending_paste = Some(receiver);
+                true
+            }
+            Ok(None) | Err(TryRecvError::Disconnected) => false,
+        }
+    }
+
+    /// Inserts the clipboard content read in the background, if known.
+    fn apply_paste(&mut self) {
+        let text = match self.pending_paste {
+            Some(ref receiver) => match receiver.try_recv() {
+                Ok(text) => text,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => None,
+            },
+            None => return,
+        };
+
+        self.pending_paste = None;
+        if let Some(text) = text {
+            self.insert_text(&text);
+        }
+    }
+
+    /// Reverts the last edit.
+    ///
+    /// Consecutive characters typed as a single word are reverted together.
+    ///
+    /// Returns `false` if there was nothing to undo. `Ctrl+Z` calls this.
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.redo_stack.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Re-applies the last edit reverted by `undo`.
+    ///
+    /// Returns `false` if there was nothing to redo. `Ctrl+Y` calls this.
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.undo_stack.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    /// Returns `true` if there is an edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+
+    /// Forgets all edits, so they can't be undone or redone anymore.
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.grouping = false;
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            content: self.content.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.grouping = false;
+        self.replace_content(snapshot.content);
+        self.set_cursor(snapshot.cursor);
+    }
+
+    /// Saves the current state before an edit.
+    ///
+    /// If `group` is `true` and the previous edit was also grouped, the
+    /// saved state already covers this edit.
+    fn save_undo(&mut self, group: bool) {
+        if !(group && self.grouping) {
+            if self.undo_stack.len() == HISTORY_LIMIT {
+                self.undo_stack.remove(0);
+            }
+            let current = self.snapshot();
+            self.undo_stack.push(current);
+        }
+        self.redo_stack.clear();
+        self.grouping = group;
+    }
+
+    /// Sets whether `Home` and `End` follow logical lines.
+    ///
+    /// By default, they move to the start or end of the current row, as
+    /// wrapped on screen. When `logical` is `true`, they move to the start or
+    /// end of the line as separated by newlines in the content.
+    pub fn set_logical_home_end(&mut self, logical: bool) {
+        self.logical_home_end = logical;
+    }
+
+    /// Makes `Home` and `End` follow logical lines.
+    ///
+    /// Chainable variant.
+    pub fn logical_home_end(self) -> Self {
+        self.with(|s| s.set_logical_home_end(true))
+    }
+
+    /// Enables or disables auto-indentation.
+    ///
+    /// When enabled, pressing `Enter` copies the leading whitespace of the
+    /// current line to the new line.
+    pub fn set_auto_indent(&mut self, auto_indent: bool) {
+        self.auto_indent = auto_indent;
+    }
+
+    /// Enables auto-indentation.
+    ///
+    /// Chainable variant.
+    pub fn auto_indent(self) -> Self {
+        self.with(|s| s.set_auto_indent(true))
+    }
+
+    /// Sets a function to find the grapheme paired with the cursor.
+    ///
+    /// The paired grapheme will be highlighted. See
+    /// [`TextArea::match_brackets`] for a matcher for common brackets.
+    pub fn set_pair_matcher<F>(&mut self, matcher: F)
+    where
+        F: Fn(&str, usize) -> Option<usize> + 'static,
+    {
+        self.pair_matcher = Some(Rc::new(matcher));
+    }
+
+    /// Sets a function to find the grapheme paired with the cursor.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::TextArea;
+    ///
+    /// let text_area = TextArea::new()
+    ///     .auto_indent()
+    ///     .pair_matcher(TextArea::match_brackets);
+    /// ```
+    pub fn pair_matcher<F>(self, matcher: F) -> Self
+    where
+        F: Fn(&str, usize) -> Option<usize> + 'static,
+    {
+        self.with(|s| s.set_pair_matcher(matcher))
+    }
+
+    /// Removes the pair matcher.
+    pub fn disable_pair_matcher(&mut self) {
+        self.pair_matcher = None;
+    }
+
+    /// Finds the bracket matching the one at `cursor`.
+    ///
+    /// Handles `()`, `[]` and `{}`. If the cursor is not on a bracket, the
+    /// grapheme just before the cursor is tried instead.
+    ///
+    /// This can be used with [`TextArea::set_pair_matcher`].
+    pub fn match_brackets(content: &str, cursor: usize) -> Option<usize> {
+        const PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
+        let find = |offset: usize| {
+            let c = content[offset..].chars().next()?;
+            PAIRS.iter().find_map(|&(open, close)| {
+                if c == open {
+                    // Look forward for the closing bracket.
+                    let mut depth = 0;
+                    content[offset..].char_indices().find_map(|(i, c)| {
+                        if c == open {
+                            depth += 1;
+                        } else if c == close {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some(offset + i);
+                            }
+                        }
+                        None
+                    })
+                } else if c == close {
+                    // Look backward for the opening bracket.
+                    let mut depth = 0;
+                    content[..=offset].char_indices().rev().find_map(
+                        |(i, c)| {
+                            if c == close {
+                                depth += 1;
+                            } else if c == open {
+                                depth -= 1;
+                                if depth == 0 {
+                                    return Some(i);
+                                }
+                            }
+                            None
+                        },
+                    )
+                } else {
+                    None
+                }
+            })
+        };
+
+        find(cursor).or_else(|| {
+            let previous = content[..cursor].char_indices().next_back()?.0;
+            find(previous)
+        })
+    }
+
+    /// Sets the spell checker to use.
+    ///
+    /// Misspelled words in visible rows are drawn with the spell style, and
+    /// the suggestions event opens a popup to replace the word under the
+    /// cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::TextArea;
+    ///
+    /// // Let's say "teh" is always wrong.
+    /// let text_area = TextArea::new().spell_checker(|text: &str| {
+    ///     text.match_indices("teh")
+    ///         .map(|(i, word)| i..i + word.len())
+    ///         .collect()
+    /// });
+    /// ```
+    pub fn set_spell_checker<C>(&mut self, checker: C)
+    where
+        C: SpellChecker + 'static,
+    {
+        self.spell_checker = Some(Rc::new(checker));
+    }
+
+    /// Sets the spell checker to use.
+    ///
+    /// Chainable variant.
+    pub fn spell_checker<C>(self, checker: C) -> Self
+    where
+        C: SpellChecker + 'static,
+    {
+        self.with(|s| s.set_spell_checker(checker))
+    }
+
+    /// Removes the spell checker, if any.
+    pub fn disable_spell_checker(&mut self) {
+        self.spell_checker = None;
+    }
+
+    /// Sets the style used for misspelled words.
+    ///
+    /// Defaults to an underline.
+    pub fn set_spell_style<S: Into<Style>>(&mut self, style: S) {
+        self.spell_style = style.into();
+    }
+
+    /// Sets the style used for misspelled words.
+    ///
+    /// Chainable variant.
+    pub fn spell_style<S: Into<Style>>(self, style: S) -> Self {
+        self.with(|s| s.set_spell_style(style))
+    }
+
+    /// Sets the event opening the spelling suggestions.
+    ///
+    /// Defaults to `F7`.
+    pub fn set_suggestions_event<E: Into<Event>>(&mut self, event: E) {
+        self.suggestions_event = event.into();
+    }
+
+    /// Sets the event opening the spelling suggestions.
+    ///
+    /// Chainable variant.
+    pub fn suggestions_event<E: Into<Event>>(self, event: E) -> Self {
+        self.with(|s| s.set_suggestions_event(event))
+    }
+
+    /// Sets the event used to submit the content.
+    ///
+    /// Defaults to `Ctrl+Enter`. `Enter` alone still inserts a newline,
+    /// unless it is used here.
+    pub fn set_submit_event<E: Into<Event>>(&mut self, event: E) {
+        self.submit_event = event.into();
+    }
+
+    /// Sets the event used to submit the content.
+    ///
+    /// Chainable variant.
+    pub fn submit_event<E: Into<Event>>(self, event: E) -> Self {
+        self.with(|s| s.set_submit_event(event))
+    }
+
+    /// Sets the event used to cancel the edition.
+    ///
+    /// Defaults to `Esc`.
+    pub fn set_cancel_event<E: Into<Event>>(&mut self, event: E) {
+        self.cancel_event = event.into();
+    }
+
+    /// Sets the event used to cancel the edition.
+    ///
+    /// Chainable variant.
+    pub fn cancel_event<E: Into<Event>>(self, event: E) -> Self {
+        self.with(|s| s.set_cancel_event(event))
+    }
+
+    /// Sets a callback to be called when the content is submitted.
+    ///
+    /// `callback` will be given the content of the view.
+    ///
+    /// See [`set_submit_event`](#method.set_submit_event) to change the
+    /// event triggering this callback.
+    pub fn set_on_submit<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_submit = Some(Rc::new(callback));
+    }
+
+    /// Sets a mutable callback to be called when the content is submitted.
+    ///
+    /// *Warning*: this callback cannot be called recursively. If you somehow
+    /// trigger this callback again in the given closure, it will be ignored.
+    pub fn set_on_submit_mut<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut Cursive, &str) + 'static,
+    {
+        self.set_on_submit(crate::immut2!(callback));
+    }
+
+    /// Sets a callback to be called when the content is submitted.
+    ///
+    /// Chainable variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cursive_core::views::{Dialog, TextArea};
+    ///
+    /// let text_area = TextArea::new()
+    ///     .on_submit(|s, text| {
+    ///         s.pop_layer();
+    ///         s.add_layer(Dialog::info(text));
+    ///     })
+    ///     .on_cancel(|s, _| {
+    ///         s.pop_layer();
+    ///     });
+    /// ```
+    pub fn on_submit<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_submit(callback))
+    }
+
+    /// Sets a mutable callback to be called when the content is submitted.
+    ///
+    /// Chainable variant.
+    pub fn on_submit_mut<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_submit_mut(callback))
+    }
+
+    /// Sets a callback to be called when the edition is cancelled.
+    ///
+    /// `callback` will be given the content of the view.
+    ///
+    /// See [`set_cancel_event`](#method.set_cancel_event) to change the
+    /// event triggering this callback.
+    pub fn set_on_cancel<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_cancel = Some(Rc::new(callback));
+    }
+
+    /// Sets a mutable callback to be called when the edition is cancelled.
+    ///
+    /// *Warning*: this callback cannot be called recursively. If you somehow
+    /// trigger this callback again in the given closure, it will be ignored.
+    pub fn set_on_cancel_mut<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut Cursive, &str) + 'static,
+    {
+        self.set_on_cancel(crate::immut2!(callback));
+    }
+
+    /// Sets a callback to be called when the edition is cancelled.
+    ///
+    /// Chainable variant.
+    pub fn on_cancel<F>(self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_cancel(callback))
+    }
+
+    /// Sets a mutable callback to be called when the edition is cancelled.
+    ///
+    /// Chainable variant.
+    pub fn on_cancel_mut<F>(self, callback: F) -> Self
+    where
+        F: FnMut(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_cancel_mut(callback))
+    }
+
+    // Returns a callback running `cb` with the current content.
+    fn content_cb(&self, cb: &Rc<OnSubmit>) -> EventResult {
+        let cb = Rc::clone(cb);
+        let content = self.content.clone();
+        EventResult::with_cb(move |s| cb(s, &content))
+    }
+
     /// Finds the row containing the grapheme at the given offset
     fn row_at(&self, offset: usize) -> usize {
         debug!("Offset: {}", offset);
@@ -187,6 +885,90 @@ impl TextArea {
         self.row_at(self.cursor)
     }
 
+    /// Returns the misspelled word under the cursor, if any.
+    fn misspelled_word(&self) -> Option<Range<usize>> {
+        let checker = self.spell_checker.as_ref()?;
+        let row = &self.rows[self.selected_row()];
+        checker
+            .check(&self.content[row.start..row.end])
+            .into_iter()
+            .map(|range| row.start + range.start..row.start + range.end)
+            .find(|range| {
+                range.start <= self.cursor && self.cursor <= range.end
+            })
+    }
+
+    /// Opens a popup with replacements for the word under the cursor.
+    fn open_suggestions(&self) -> EventResult {
+        let range = match self.misspelled_word() {
+            Some(range) => range,
+            None => return EventResult::Ignored,
+        };
+        let suggestions = self
+            .spell_checker
+            .as_ref()
+            .map(|checker| checker.suggestions(&self.content[range.clone()]))
+            .unwrap_or_default();
+
+        let mut tree = MenuTree::new();
+        for suggestion in suggestions {
+            let replacement = Rc::clone(&self.replacement);
+            let range = range.clone();
+            tree.add_leaf(suggestion.clone(), move |_| {
+                // We can't reach the view from here; it will pick the
+                // replacement on the next layout.
+                *replacement.borrow_mut() =
+                    Some((range.clone(), suggestion.clone()));
+            });
+        }
+        if tree.is_empty() {
+            tree.add_leaf("(No suggestions)", |_| ());
+        }
+        let tree = Rc::new(tree);
+
+        // Show the suggestions right below the word, aligned with it.
+        let row = self.selected_row();
+        let x = self.content[self.rows[row].start..range.start].width();
+        let y = row.saturating_sub(self.scrollbase.start_line) + 1;
+        let offset = (self.last_offset.get() + (x, y)).saturating_sub((2, 0));
+
+        EventResult::with_cb(move |s| {
+            let offset = offset.signed() - s.screen().offset();
+            s.screen_mut().add_layer_at(
+                Position::parent(offset),
+                MenuPopup::new(Rc::clone(&tree)),
+            );
+        })
+    }
+
+    /// Applies the replacement chosen in the suggestions popup, if any.
+    fn apply_replacement(&mut self) {
+        let (range, word) = match self.replacement.borrow_mut().take() {
+            Some(replacement) => replacement,
+            None => return,
+        };
+        // The content may have changed in the meantime.
+        if self.content.get(range.clone()).is_none() {
+            return;
+        }
+
+        self.save_undo(false);
+        self.selection_anchor = None;
+        self.content.replace_range(range.clone(), &word);
+        self.cursor = range.start + word.len();
+        self.invalidate();
+    }
+
+    /// Returns the byte offset under the given position in the view.
+    fn offset_at(&self, position: Vec2) -> usize {
+        let y = position.y + self.scrollbase.start_line;
+        let y = min(y, self.rows.len() - 1);
+        let row = &self.rows[y];
+        let content = &self.content[row.start..row.end];
+
+        row.start + simple_prefix(content, position.x).length
+    }
+
     fn selected_col(&self) -> usize {
         self.col_at(self.cursor)
     }
@@ -261,6 +1043,95 @@ impl TextArea {
         self.cursor += len;
     }
 
+    fn move_home(&mut self) {
+        self.cursor = if self.logical_home_end {
+            self.content[..self.cursor].rfind('\n').map_or(0, |i| i + 1)
+        } else {
+            self.rows[self.selected_row()].start
+        };
+    }
+
+    fn move_end(&mut self) {
+        if self.logical_home_end {
+            self.cursor = self.content[self.cursor..]
+                .find('\n')
+                .map_or(self.content.len(), |i| self.cursor + i);
+            return;
+        }
+
+        let row = self.selected_row();
+        self.cursor = self.rows[row].end;
+        if row + 1 < self.rows.len() && self.cursor == self.rows[row + 1].start
+        {
+            self.move_left();
+        }
+    }
+
+    /// Moves the cursor to the start of the current or previous word.
+    fn move_word_left(&mut self) {
+        let cursor = self.cursor;
+        self.cursor = self
+            .content
+            .unicode_word_indices()
+            .map(|(i, _)| i)
+            .take_while(|&i| i < cursor)
+            .last()
+            .unwrap_or(0);
+    }
+
+    /// Moves the cursor to the end of the current or next word.
+    fn move_word_right(&mut self) {
+        let cursor = self.cursor;
+        self.cursor = self
+            .content
+            .unicode_word_indices()
+            .map(|(i, word)| i + word.len())
+            .find(|&end| end > cursor)
+            .unwrap_or(self.content.len());
+    }
+
+    /// Inserts a newline, with indentation if needed.
+    fn insert_newline(&mut self) {
+        let indent: String = if self.auto_indent {
+            let line_start =
+                self.content[..self.cursor].rfind('\n').map_or(0, |i| i + 1);
+            self.content[line_start..self.cursor]
+                .chars()
+                .take_while(|&c| c == ' ' || c == '\t')
+                .collect()
+        } else {
+            String::new()
+        };
+
+        self.insert('\n');
+        for c in indent.chars() {
+            self.insert(c);
+        }
+    }
+
+    /// Removes the selected text, if any.
+    ///
+    /// Returns `true` if some text was removed.
+    fn delete_selection(&mut self) -> bool {
+        let range = match self.selection() {
+            Some(range) => range,
+            None => {
+                self.selection_anchor = None;
+                return false;
+            }
+        };
+
+        self.content.replace_range(range.clone(), "");
+        self.cursor = range.start;
+        self.selection_anchor = None;
+
+        if let Some(size) = self.size_cache.map(|s| s.map(|s| s.value)) {
+            self.invalidate();
+            self.compute_rows(size);
+        }
+        true
+    }
+
     fn is_cache_valid(&self, size: Vec2) -> bool {
         match self.size_cache {
             None => false,
@@ -455,6 +1326,13 @@ impl TextArea {
 
 impl View for TextArea {
     fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        // When growing with the content, we'll never ask for more than the
+        // max height, so plan the rows (and scrollbar) accordingly.
+        let constraint = match self.height_bounds {
+            Some((_, max_height)) => constraint.map_y(|y| min(y, max_height)),
+            None => constraint,
+        };
+
         // Make sure our structure is up to date
         self.soft_compute_rows(constraint);
 
@@ -463,15 +1341,31 @@ impl View for TextArea {
         // And y = number of rows
         debug!("{:?}", self.rows);
         let scroll_width = if self.rows.len() > constraint.y { 1 } else { 0 };
+        let height = match self.height_bounds {
+            Some((min_height, max_height)) => {
+                min(max(self.rows.len(), min_height), max_height)
+            }
+            None => self.rows.len(),
+        };
         Vec2::new(
             scroll_width
                 + 1
                 + self.rows.iter().map(|r| r.width).max().unwrap_or(1),
-            self.rows.len(),
+            height,
         )
     }
 
     fn draw(&self, printer: &Printer<'_, '_>) {
+        self.last_offset.set(printer.offset);
+
+        // Come back for the background work.
+        let spell_pending = match self.spell_checker {
+            Some(ref checker) => checker.is_pending(),
+            None => false,
+        };
+        if self.pending_paste.is_some() || spell_pending {
+            printer.request_frame();
+        }
         printer.with_color(ColorStyle::secondary(), |printer| {
             let effect = if self.enabled && printer.enabled {
                 Effect::Reverse
@@ -490,6 +1384,15 @@ impl View for TextArea {
                 }
             });
 
+            let paired = if printer.focused {
+                self.pair_matcher
+                    .as_ref()
+                    .and_then(|matcher| matcher(&self.content, self.cursor))
+                    .filter(|&offset| offset < self.content.len())
+            } else {
+                None
+            };
+
             debug!("Content: `{}`", &self.content);
             self.scrollbase.draw(printer, |printer, i| {
                 debug!("Drawing row {}", i);
@@ -499,8 +1402,52 @@ impl View for TextArea {
                 debug!("row text: `{}`", text);
                 printer.with_effect(effect, |printer| {
                     printer.print((0, 0), text);
+
+                    let checker = match self.spell_checker {
+                        Some(ref checker) => checker,
+                        None => return,
+                    };
+                    printer.with_style(self.spell_style.clone(), |printer| {
+                        for range in checker.check(text) {
+                            let x = text[..range.start].width();
+                            printer.print((x, 0), &text[range]);
+                        }
+                    });
                 });
 
+                if let Some(selection) = self.selection() {
+                    let start = max(selection.start, row.start);
+                    let end = min(selection.end, row.end);
+                    if start < end {
+                        let offset = self.content[row.start..start].width();
+                        printer.with_color(
+                            ColorStyle::highlight(),
+                            |printer| {
+                                printer.print(
+                                    (offset, 0),
+                                    &self.content[start..end],
+                                );
+                            },
+                        );
+                    }
+                }
+
+                if let Some(offset) = paired {
+                    if row.start <= offset && offset < row.end {
+                        let c = text[offset - row.start..]
+                            .graphemes(true)
+                            .next()
+                            .unwrap();
+                        let x = text[..offset - row.start].width();
+                        printer.with_color(
+                            ColorStyle::highlight(),
+                            |printer| {
+                                printer.print((x, 0), c);
+                            },
+                        );
+                    }
+                }
+
                 if printer.focused && i == self.selected_row() {
                     let cursor_offset = self.cursor - row.start;
                     let c = if cursor_offset == text.len() {
@@ -519,28 +1466,109 @@ impl View for TextArea {
     }
 
     fn on_event(&mut self, event: Event) -> EventResult {
+        if event == self.submit_event {
+            if let Some(ref cb) = self.on_submit {
+                return self.content_cb(cb);
+            }
+        }
+        if event == self.cancel_event {
+            if let Some(ref cb) = self.on_cancel {
+                return self.content_cb(cb);
+            }
+        }
+        if event == self.suggestions_event && self.spell_checker.is_some() {
+            return self.open_suggestions();
+        }
+
+        // Movements with `Shift` extend the selection.
+        let (event, extend_selection) = match event {
+            Event::Shift(key) if is_movement(key) => (Event::Key(key), true),
+            Event::CtrlShift(key) if is_movement(key) => {
+                (Event::Ctrl(key), true)
+            }
+            event => (event, false),
+        };
+        let previous_anchor = self.selection_anchor;
+        match event {
+            Event::Key(key) | Event::Ctrl(key) if is_movement(key) => {
+                if !extend_selection {
+                    self.selection_anchor = None;
+                } else if self.selection_anchor.is_none() {
+                    self.selection_anchor = Some(self.cursor);
+                }
+            }
+            _ => (),
+        }
+
+        // Consecutive letters are undone together, as a single word.
+        let edit = match event {
+            Event::Char(ch) => {
+                Some(!ch.is_whitespace() && self.selection().is_none())
+            }
+            Event::Key(Key::Enter) => Some(false),
+            Event::Key(Key::Backspace)
+                if self.cursor > 0 || self.selection().is_some() =>
+            {
+                Some(false)
+            }
+            Event::Key(Key::Del)
+                if self.cursor < self.content.len()
+                    || self.selection().is_some() =>
+            {
+                Some(false)
+            }
+            _ => None,
+        };
+        match edit {
+            Some(group) => self.save_undo(group),
+            None => self.grouping = false,
+        }
+
         let mut fix_scroll = true;
         match event {
-            Event::Char(ch) => self.insert(ch),
-            Event::Key(Key::Enter) => self.insert('\n'),
+            Event::CtrlChar('z') if self.can_undo() => {
+                self.undo();
+            }
+            Event::CtrlChar('y') if self.can_redo() => {
+                self.redo();
+            }
+            Event::CtrlChar('c') | Event::Ctrl(Key::Ins)
+                if self.selection().is_some() =>
+            {
+                self.copy();
+            }
+            Event::CtrlChar('x') | Event::Shift(Key::Del)
+                if self.selection().is_some() =>
+            {
+                self.cut();
+            }
+            Event::CtrlChar('v') | Event::Shift(Key::Ins) => {
+                if !self.paste() {
+                    return EventResult::Ignored;
+                }
+            }
+            Event::Char(ch) => {
+                self.delete_selection();
+                self.insert(ch);
+            }
+            Event::Key(Key::Enter) => {
+                self.delete_selection();
+                self.insert_newline();
+            }
+            Event::Key(Key::Backspace) | Event::Key(Key::Del)
+                if self.delete_selection() => {}
             Event::Key(Key::Backspace) if self.cursor > 0 => self.backspace(),
             Event::Key(Key::Del) if self.cursor < self.content.len() => {
                 self.delete()
             }
 
-            Event::Key(Key::End) => {
-                let row = self.selected_row();
-                self.cursor = self.rows[row].end;
-                if row + 1 < self.rows.len()
-                    && self.cursor == self.rows[row + 1].start
-                {
-                    self.move_left();
-                }
-            }
+            Event::Key(Key::End) => self.move_end(),
             Event::Ctrl(Key::Home) => self.cursor = 0,
             Event::Ctrl(Key::End) => self.cursor = self.content.len(),
-            Event::Key(Key::Home) => {
-                self.cursor = self.rows[self.selected_row()].start
+            Event::Key(Key::Home) => self.move_home(),
+            Event::Ctrl(Key::Left) if self.cursor > 0 => self.move_word_left(),
+            Event::Ctrl(Key::Right) if self.cursor < self.content.len() => {
+                self.move_word_right()
             }
             Event::Key(Key::Up) if self.selected_row() > 0 => self.move_up(),
             Event::Key(Key::Down)
@@ -585,29 +1613,49 @@ impl View for TextArea {
                 event: MouseEvent::Hold(MouseButton::Left),
                 position,
                 offset,
-            } => {
+            } if self.scrollbase.is_dragging() => {
                 fix_scroll = false;
                 let position = position.saturating_sub(offset);
                 self.scrollbase.drag(position);
             }
             Event::Mouse {
-                event: MouseEvent::Press(_),
+                event: MouseEvent::Hold(MouseButton::Left),
+                position,
+                offset,
+            } if self.selection_anchor.is_some() && !self.rows.is_empty() => {
+                // Dragging extends the selection from the press position.
+                self.cursor = self.offset_at(position.saturating_sub(offset));
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } if self.scrollbase.is_dragging() => {
+                fix_scroll = false;
+                self.scrollbase.release_grab();
+            }
+            Event::Mouse {
+                event: MouseEvent::Press(button),
                 position,
                 offset,
             } if !self.rows.is_empty()
                 && position.fits_in_rect(offset, self.last_size) =>
             {
                 if let Some(position) = position.checked_sub(offset) {
-                    let y = position.y + self.scrollbase.start_line;
-                    let y = min(y, self.rows.len() - 1);
-                    let x = position.x;
-                    let row = &self.rows[y];
-                    let content = &self.content[row.start..row.end];
-
-                    self.cursor = row.start + simple_prefix(content, x).length;
+                    self.cursor = self.offset_at(position);
+                    self.selection_anchor = if button == MouseButton::Left {
+                        Some(self.cursor)
+                    } else {
+                        None
+                    };
+                }
+            }
+            _ => {
+                // A blocked movement still collapses the selection.
+                if extend_selection {
+                    self.selection_anchor = previous_anchor;
                 }
+                return EventResult::Ignored;
             }
-            _ => return EventResult::Ignored,
         }
 
         debug!("Rows: {:?}", self.rows);
@@ -624,6 +1672,8 @@ impl View for TextArea {
     }
 
     fn layout(&mut self, size: Vec2) {
+        self.apply_replacement();
+        self.apply_paste();
         self.last_size = size;
         self.compute_rows(size);
     }
@@ -648,3 +1698,205 @@ impl View for TextArea {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_area(content: &str, width: usize) -> TextArea {
+        let mut view = TextArea::new().content(content);
+        view.layout(Vec2::new(width, 10));
+        view
+    }
+
+    #[test]
+    fn word_navigation() {
+        let mut view = text_area("hello big, world", 20);
+        view.set_cursor(0);
+
+        view.on_event(Event::Ctrl(Key::Right));
+        assert_eq!(view.cursor(), 5);
+        view.on_event(Event::Ctrl(Key::Right));
+        assert_eq!(view.cursor(), 9);
+        view.on_event(Event::Ctrl(Key::Left));
+        assert_eq!(view.cursor(), 6);
+    }
+
+    #[test]
+    fn logical_home_end() {
+        // "aaaa bbbb" is wrapped on two rows.
+        let mut view = text_area("aaaa bbbb\ncc", 6);
+        view.set_cursor(7);
+
+        view.on_event(Event::Key(Key::Home));
+        assert_eq!(view.cursor(), 5);
+
+        view.set_logical_home_end(true);
+        view.on_event(Event::Key(Key::Home));
+        assert_eq!(view.cursor(), 0);
+        view.on_event(Event::Key(Key::End));
+        assert_eq!(view.cursor(), 9);
+    }
+
+    #[test]
+    fn shift_selection() {
+        let mut view = text_area("hello world", 20);
+        view.set_cursor(0);
+
+        view.on_event(Event::CtrlShift(Key::Right));
+        view.on_event(Event::Shift(Key::Right));
+        assert_eq!(view.selected_text(), Some("hello "));
+
+        // Typing replaces the selection.
+        view.on_event(Event::Char('X'));
+        assert_eq!(view.get_content(), "Xworld");
+        assert_eq!(view.selection(), None);
+
+        // Moving without shift clears the selection.
+        view.on_event(Event::Shift(Key::Left));
+        view.on_event(Event::Key(Key::Left));
+        assert_eq!(view.selection(), None);
+    }
+
+    #[test]
+    fn auto_indent() {
+        let mut view = text_area("    foo", 20).auto_indent();
+        view.set_cursor(7);
+        view.on_event(Event::Key(Key::Enter));
+        view.on_event(Event::Char('x'));
+        assert_eq!(view.get_content(), "    foo\n    x");
+    }
+
+    #[test]
+    fn match_brackets() {
+        let text = "f(a[0], {b})";
+        assert_eq!(TextArea::match_brackets(text, 1), Some(11));
+        assert_eq!(TextArea::match_brackets(text, 11), Some(1));
+        assert_eq!(TextArea::match_brackets(text, 3), Some(5));
+        // Right after a bracket.
+        assert_eq!(TextArea::match_brackets(text, 6), Some(3));
+        assert_eq!(TextArea::match_brackets(text, 0), None);
+        assert_eq!(TextArea::match_brackets("(()", 0), None);
+    }
+
+    #[test]
+    fn mouse_selection() {
+        let mut view = text_area("hello world", 20);
+        let mouse = |event, x| Event::Mouse {
+            event,
+            position: Vec2::new(x, 0),
+            offset: Vec2::zero(),
+        };
+
+        view.on_event(mouse(MouseEvent::Press(MouseButton::Left), 2));
+        view.on_event(mouse(MouseEvent::Hold(MouseButton::Left), 7));
+        view.on_event(mouse(MouseEvent::Release(MouseButton::Left), 7));
+        assert_eq!(view.selected_text(), Some("llo w"));
+
+        view.on_event(Event::CtrlChar('x'));
+        assert_eq!(view.get_content(), "heorld");
+        view.on_event(Event::Key(Key::End));
+        // Tests only use the application buffer, so this is immediate.
+        view.on_event(Event::CtrlChar('v'));
+        assert_eq!(view.get_content(), "heorldllo w");
+    }
+
+    #[test]
+    fn spell_check() {
+        struct Checker;
+
+        impl SpellChecker for Checker {
+            fn check(&self, text: &str) -> Vec<Range<usize>> {
+                text.match_indices("teh").map(|(i, _)| i..i + 3).collect()
+            }
+
+            fn suggestions(&self, _: &str) -> Vec<String> {
+                vec!["the".into()]
+            }
+        }
+
+        let mut view = text_area("fix teh typo", 20).spell_checker(Checker);
+        view.set_cursor(5);
+        assert_eq!(view.misspelled_word(), Some(4..7));
+
+        // Pretend the suggestion was picked in the popup.
+        assert!(view.on_event(Event::Key(Key::F7)).has_callback());
+        *view.replacement.borrow_mut() = Some((4..7, "the".into()));
+        view.layout(Vec2::new(20, 10));
+        assert_eq!(view.get_content(), "fix the typo");
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut view = text_area("", 20);
+        for ch in "hello world".chars() {
+            view.on_event(Event::Char(ch));
+        }
+        view.on_event(Event::Key(Key::Backspace));
+        assert_eq!(view.get_content(), "hello worl");
+
+        view.on_event(Event::CtrlChar('z'));
+        assert_eq!(view.get_content(), "hello world");
+        // The whole word goes away at once.
+        view.on_event(Event::CtrlChar('z'));
+        assert_eq!(view.get_content(), "hello ");
+        assert_eq!(view.cursor(), 6);
+
+        view.on_event(Event::CtrlChar('y'));
+        assert_eq!(view.get_content(), "hello world");
+
+        // A new edit drops the redo history.
+        view.undo();
+        view.on_event(Event::Char('!'));
+        assert!(!view.can_redo());
+        assert_eq!(view.get_content(), "hello !");
+    }
+
+    #[test]
+    fn auto_grow() {
+        let mut view = TextArea::new().auto_grow(2, 4);
+        assert_eq!(view.required_size(Vec2::new(10, 10)).y, 2);
+
+        view.set_content("a\nb\nc");
+        assert_eq!(view.required_size(Vec2::new(10, 10)).y, 3);
+
+        // Past the max height, a scrollbar takes a column.
+        view.set_content("a\nb\nc\nd\ne\nf");
+        assert_eq!(view.required_size(Vec2::new(10, 10)), Vec2::new(3, 4));
+
+        view.disable_auto_grow();
+        assert_eq!(view.required_size(Vec2::new(10, 10)).y, 6);
+    }
+
+    #[test]
+    fn submit_cancel() {
+        let mut siv = crate::testing::dummy((10, 5));
+        siv.set_user_data(Vec::<String>::new());
+        let record = |name: &'static str| {
+            move |s: &mut Cursive, text: &str| {
+                s.with_user_data(|events: &mut Vec<String>| {
+                    events.push(format!("{} {}", name, text))
+                });
+            }
+        };
+        let mut view = text_area("hi", 10)
+            .on_submit(record("submit"))
+            .on_cancel(record("cancel"));
+
+        // Enter still inserts a newline.
+        view.on_event(Event::Key(Key::Enter));
+        assert_eq!(view.get_content(), "\nhi");
+
+        view.on_event(Event::Ctrl(Key::Enter)).process(&mut siv);
+        view.on_event(Event::Key(Key::Esc)).process(&mut siv);
+
+        view.set_submit_event(Key::Enter);
+        view.on_event(Event::Key(Key::Enter)).process(&mut siv);
+        assert_eq!(view.get_content(), "\nhi");
+
+        assert_eq!(
+            siv.user_data::<Vec<String>>().unwrap(),
+            &vec!["submit \nhi", "cancel \nhi", "submit \nhi"]
+        );
+    }
+}
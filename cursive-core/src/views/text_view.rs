@@ -1,4 +1,6 @@
-use std::ops::Deref;
+use std::cell::Cell;
+use std::ops::{Deref, Range};
+use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::{Mutex, MutexGuard};
 
@@ -6,15 +8,23 @@ use owning_ref::{ArcRef, OwningHandle};
 use unicode_width::UnicodeWidthStr;
 
 use crate::align::*;
-use crate::theme::Effect;
+use crate::direction::Direction;
+use crate::event::{Event, EventResult, MouseButton, MouseEvent};
+use crate::rect::Rect;
+use crate::theme::{ColorStyle, Effect, Link};
 use crate::utils::lines::spans::{LinesIterator, Row};
 use crate::utils::markup::StyledString;
-use crate::view::{SizeCache, View};
-use crate::{Printer, Vec2, With, XY};
+use crate::view::{self, SearchStep, Searchable, SizeCache, View};
+use crate::{Cursive, Printer, Vec2, With, XY};
 
 // Content type used internally for caching and storage
 type InnerContentType = Arc<StyledString>;
 
+type LinkCallback = Rc<dyn Fn(&mut Cursive, &str)>;
+
+/// Maximum number of changes remembered by a `TextContent`.
+const MAX_CHANGES: usize = 256;
+
 /// Provides access to the content of a [`TextView`].
 ///
 /// Cloning this object will still point to the same content.
@@ -50,6 +60,9 @@ impl TextContent {
                 content_value: content,
                 content_cache: Arc::new(StyledString::default()),
                 size_cache: None,
+                track_changes: false,
+                changes: Vec::new(),
+                generation: 0,
             })),
         }
     }
@@ -80,12 +93,28 @@ impl Deref for TextContentRef {
 
 impl TextContent {
     /// Replaces the content with the given value.
+    ///
+    /// If a view highlights changes, lines that differ from the previous
+    /// content are recorded as changed.
     pub fn set_content<S>(&self, content: S)
     where
         S: Into<StyledString>,
     {
         self.with_content(|c| {
-            *Arc::make_mut(&mut c.content_value) = content.into();
+            let content = content.into();
+            let changes = if c.track_changes {
+                changed_lines(c.content_value.source(), content.source())
+            } else {
+                Vec::new()
+            };
+            *Arc::make_mut(&mut c.content_value) = content;
+
+            c.generation += 1;
+            let generation = c.generation;
+            c.changes = changes
+                .into_iter()
+                .map(|range| (generation, range))
+                .collect();
         });
     }
 
@@ -97,7 +126,21 @@ impl TextContent {
         self.with_content(|c| {
             // This will only clone content if content_cached and content_value
             // are sharing the same underlying Rc.
-            Arc::make_mut(&mut c.content_value).append(content);
+            let value = Arc::make_mut(&mut c.content_value);
+
+            // The last line changes too, unless it was complete.
+            let start = value.source().rfind('\n').map_or(0, |i| i + 1);
+            value.append(content);
+            let end = value.source().len();
+
+            c.generation += 1;
+            if !c.track_changes {
+                return;
+            }
+            c.changes.push((c.generation, start..end));
+            if c.changes.len() > MAX_CHANGES {
+                c.changes.remove(0);
+            }
         })
     }
 
@@ -109,6 +152,11 @@ impl TextContent {
         TextContentInner::get_content(&self.content)
     }
 
+    /// Returns the current content, without locking it afterward.
+    pub(crate) fn snapshot(&self) -> Arc<StyledString> {
+        Arc::clone(&self.content.lock().unwrap().content_value)
+    }
+
     /// Apply the given closure to the inner content, and bust the cache afterward.
     fn with_content<F, O>(&self, f: F) -> O
     where
@@ -136,6 +184,37 @@ struct TextContentInner {
 
     // We keep the cache here so it can be busted when we change the content.
     size_cache: Option<XY<SizeCache>>,
+
+    // If true, changes are recorded for `TextView::highlight_changes`.
+    track_changes: bool,
+
+    // Byte ranges of the changed lines, with the generation of the change.
+    changes: Vec<(usize, Range<usize>)>,
+
+    // Incremented on every change.
+    generation: usize,
+}
+
+// Returns the byte ranges of the lines in `new` which are not in `old` at
+// the same place.
+fn changed_lines(old: &str, new: &str) -> Vec<Range<usize>> {
+    let mut old_lines = old.split('\n');
+    let mut changes: Vec<Range<usize>> = Vec::new();
+    let mut start = 0;
+
+    for line in new.split('\n') {
+        let end = start + line.len();
+        if old_lines.next() != Some(line) {
+            match changes.last_mut() {
+                // Merge consecutive lines.
+                Some(last) if last.end + 1 == start => last.end = end,
+                _ => changes.push(start..end),
+            }
+        }
+        start = end + 1;
+    }
+
+    changes
 }
 
 impl TextContentInner {
@@ -190,6 +269,28 @@ pub struct TextView {
     // ScrollBase make many scrolling-related things easier
     last_size: Vec2,
     width: Option<usize>,
+
+    // Current search query, with the index of the current match.
+    search: Option<(String, usize)>,
+    // Row of the current match, if any.
+    search_row: Option<usize>,
+
+    // Highlighted byte ranges in the source, sorted by start.
+    highlights: Vec<Range<usize>>,
+
+    // Number of frames changed lines stay highlighted. 0 disables it.
+    change_frames: usize,
+    change_style: ColorStyle,
+
+    // Generation of the content when it was last drawn.
+    seen_generation: Cell<usize>,
+    // Changes after this generation are highlighted.
+    changes_since: Cell<usize>,
+    // Frames left before the changes stop being highlighted.
+    frames_left: Cell<usize>,
+
+    // Called when a link is clicked.
+    on_link: Option<LinkCallback>,
 }
 
 impl TextView {
@@ -218,6 +319,7 @@ impl TextView {
     /// assert!(view.get_content().source().contains("new"));
     /// ```
     pub fn new_with_content(content: TextContent) -> Self {
+        let generation = content.content.lock().unwrap().generation;
         TextView {
             content,
             effect: Effect::Simple,
@@ -226,6 +328,15 @@ impl TextView {
             align: Align::top_left(),
             last_size: Vec2::zero(),
             width: None,
+            search: None,
+            search_row: None,
+            highlights: Vec::new(),
+            change_frames: 0,
+            change_style: ColorStyle::highlight_inactive(),
+            seen_generation: Cell::new(generation),
+            changes_since: Cell::new(generation),
+            frames_left: Cell::new(0),
+            on_link: None,
         }
     }
 
@@ -246,6 +357,148 @@ impl TextView {
         self.with(|s| s.set_effect(effect))
     }
 
+    /// Sets a callback to run when a link is clicked.
+    ///
+    /// It is given the URL of the link, set with a
+    /// [`Link`](crate::theme::Link) style on the content. With a callback,
+    /// this view accepts the focus, so it can receive mouse clicks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::theme::Link;
+    /// use cursive_core::utils::markup::StyledString;
+    /// use cursive_core::views::TextView;
+    ///
+    /// let mut text = StyledString::plain("Read ");
+    /// text.append_styled("the manual", Link::new("https://example.com"));
+    ///
+    /// let view = TextView::new(text).on_link(|s, url| {
+    ///     s.add_layer(TextView::new(format!("Opening {}", url)));
+    /// });
+    /// ```
+    pub fn set_on_link<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.on_link = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when a link is clicked.
+    ///
+    /// Chainable variant.
+    pub fn on_link<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, &str) + 'static,
+    {
+        self.with(|s| s.set_on_link(cb))
+    }
+
+    /// Returns the link at the given position, if any.
+    ///
+    /// The position is relative to this view, as last laid out.
+    pub fn link_at(&self, position: Vec2) -> Option<Link> {
+        let y_offset =
+            self.align.v.get_offset(self.rows.len(), self.last_size.y);
+        let row = self.rows.get(position.y.checked_sub(y_offset)?)?;
+        let mut x = self.align.h.get_offset(row.width, self.last_size.x);
+
+        let content = self.content.content.lock().unwrap();
+        for span in row.resolve(content.get_cache().as_ref()) {
+            let width = span.content.width();
+            if position.x >= x && position.x < x + width {
+                return span.attr.link.clone();
+            }
+            x += width;
+        }
+        None
+    }
+
+    /// Highlights the given byte ranges of the content.
+    ///
+    /// Ranges are byte offsets in the source of the content, as returned by
+    /// [`utils::search`](crate::utils::search). They replace any previous
+    /// highlight.
+    pub fn set_highlights(&mut self, highlights: Vec<Range<usize>>) {
+        self.highlights = highlights;
+        self.highlights.sort_by_key(|range| range.start);
+    }
+
+    /// Highlights more byte ranges of the content.
+    ///
+    /// Previous highlights are kept.
+    pub fn add_highlights<I>(&mut self, highlights: I)
+    where
+        I: IntoIterator<Item = Range<usize>>,
+    {
+        self.highlights.extend(highlights);
+        self.highlights.sort_by_key(|range| range.start);
+    }
+
+    /// Highlights changed lines for the given number of frames.
+    ///
+    /// Lines changed by `set_content` or added by `append` stand out until
+    /// they were drawn `frames` times. This only fades if the screen is
+    /// redrawn regularly, for example with
+    /// [`Cursive::set_autorefresh`](crate::Cursive::set_autorefresh).
+    ///
+    /// 0 disables it, which is the default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cursive_core::views::{TextContent, TextView};
+    ///
+    /// let mut siv = cursive_core::Cursive::dummy();
+    /// siv.set_fps(10);
+    ///
+    /// let status = TextContent::new("CPU: 12%\nRAM: 40%");
+    /// siv.add_layer(
+    ///     TextView::new_with_content(status.clone()).highlight_changes(20),
+    /// );
+    ///
+    /// // Only the first line will be highlighted, for two seconds.
+    /// status.set_content("CPU: 97%\nRAM: 40%");
+    /// ```
+    pub fn set_highlight_changes(&mut self, frames: usize) {
+        self.change_frames = frames;
+        if frames > 0 {
+            // Changes are only recorded once someone needs them.
+            self.content.content.lock().unwrap().track_changes = true;
+        }
+    }
+
+    /// Highlights changed lines for the given number of frames.
+    ///
+    /// Chainable variant.
+    pub fn highlight_changes(self, frames: usize) -> Self {
+        self.with(|s| s.set_highlight_changes(frames))
+    }
+
+    /// Sets the color of changed lines.
+    ///
+    /// Defaults to `ColorStyle::highlight_inactive()`.
+    pub fn set_change_style(&mut self, style: ColorStyle) {
+        self.change_style = style;
+    }
+
+    /// Sets the color of changed lines.
+    ///
+    /// Chainable variant.
+    pub fn change_style(self, style: ColorStyle) -> Self {
+        self.with(|s| s.set_change_style(style))
+    }
+
+    /// Removes all highlights.
+    pub fn clear_highlights(&mut self) {
+        self.highlights.clear();
+    }
+
+    /// Returns the highlighted byte ranges, sorted by start.
+    pub fn highlights(&self) -> &[Range<usize>] {
+        &self.highlights
+    }
+
     /// Disables content wrap for this view.
     ///
     /// This may be useful if you want horizontal scrolling.
@@ -355,6 +608,189 @@ impl TextView {
     }
 }
 
+impl Searchable for TextView {
+    fn search(&mut self, query: &str, step: SearchStep) -> EventResult {
+        let current = match self.search {
+            Some((_, current)) => current,
+            None => 0,
+        };
+
+        // Row of each match, in order.
+        let rows: Vec<usize> = self
+            .row_texts()
+            .iter()
+            .enumerate()
+            .flat_map(|(y, text)| {
+                view::find_matches(text, query).into_iter().map(move |_| y)
+            })
+            .collect();
+
+        if rows.is_empty() {
+            self.search = Some((query.to_string(), 0));
+            self.search_row = None;
+            return EventResult::Ignored;
+        }
+
+        let n = rows.len();
+        let current = match step {
+            SearchStep::Current if current < n => current,
+            SearchStep::Current => 0,
+            SearchStep::Next => (current + 1) % n,
+            SearchStep::Previous => (current + n - 1) % n,
+        };
+
+        self.search = Some((query.to_string(), current));
+        self.search_row = Some(rows[current]);
+        EventResult::Consumed(None)
+    }
+
+    fn clear_search(&mut self) {
+        self.search = None;
+        self.search_row = None;
+    }
+}
+
+impl TextView {
+    // Returns the text displayed on each row.
+    fn row_texts(&self) -> Vec<String> {
+        let content = self.content.content.lock().unwrap();
+        self.rows
+            .iter()
+            .map(|row| {
+                row.resolve(content.get_cache().as_ref())
+                    .into_iter()
+                    .map(|span| span.content)
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Draws the highlighted ranges on top of the text.
+    fn draw_highlights(
+        &self,
+        printer: &Printer<'_, '_>,
+        content: &StyledString,
+    ) {
+        if self.highlights.is_empty() {
+            return;
+        }
+
+        for (y, row) in self.rows.iter().enumerate() {
+            let mut x = self.align.h.get_offset(row.width, printer.size.x);
+            for segment in &row.segments {
+                let text = segment.resolve_plain(&content);
+                // Owned segments (like hyphens) are not part of the source.
+                if let Some((start, end)) =
+                    segment.source_indices(content.spans_raw())
+                {
+                    let first = self
+                        .highlights
+                        .partition_point(|range| range.end <= start);
+                    for range in self.highlights[first..]
+                        .iter()
+                        .take_while(|range| range.start < end)
+                    {
+                        let from = range.start.max(start) - start;
+                        let to = range.end.min(end) - start;
+                        let offset = x + text[..from].width();
+                        printer.with_color(
+                            ColorStyle::highlight_inactive(),
+                            |printer| {
+                                printer.print((offset, y), &text[from..to])
+                            },
+                        );
+                    }
+                }
+                x += segment.width;
+            }
+        }
+    }
+
+    // Highlights the lines changed recently.
+    fn draw_changes(
+        &self,
+        printer: &Printer<'_, '_>,
+        content: &TextContentInner,
+    ) {
+        if self.change_frames == 0 {
+            return;
+        }
+
+        // Start highlighting new changes.
+        if content.generation != self.seen_generation.get() {
+            self.changes_since.set(self.seen_generation.get());
+            self.seen_generation.set(content.generation);
+            self.frames_left.set(self.change_frames);
+        }
+
+        let frames_left = self.frames_left.get();
+        if frames_left == 0 {
+            return;
+        }
+        self.frames_left.set(frames_left - 1);
+
+        let since = self.changes_since.get();
+        let changes: Vec<&Range<usize>> = content
+            .changes
+            .iter()
+            .filter(|(generation, _)| *generation > since)
+            .map(|(_, range)| range)
+            .collect();
+
+        let cache = content.get_cache().as_ref();
+        for (y, row) in self.rows.iter().enumerate() {
+            let changed = row
+                .segments
+                .iter()
+                .filter_map(|segment| {
+                    segment.source_indices(cache.spans_raw())
+                })
+                .any(|(start, end)| {
+                    changes
+                        .iter()
+                        .any(|range| range.start < end && start < range.end)
+                });
+            if !changed {
+                continue;
+            }
+
+            printer.with_color(self.change_style, |printer| {
+                printer.print_hline((0, y), printer.size.x, " ");
+                let mut x = self.align.h.get_offset(row.width, printer.size.x);
+                for span in row.resolve(cache) {
+                    printer.print((x, y), span.content);
+                    x += span.content.width();
+                }
+            });
+        }
+    }
+
+    // Highlights search matches on top of the text.
+    fn draw_search(&self, printer: &Printer<'_, '_>) {
+        let (query, current) = match self.search {
+            Some((ref query, current)) => (query, current),
+            None => return,
+        };
+
+        let mut i = 0;
+        for (y, text) in self.row_texts().iter().enumerate() {
+            let x = self.align.h.get_offset(text.width(), printer.size.x);
+            for range in view::find_matches(text, query) {
+                let color = if i == current {
+                    ColorStyle::highlight()
+                } else {
+                    ColorStyle::highlight_inactive()
+                };
+                let offset = x + text[..range.start].width();
+                printer.with_color(color, |printer| {
+                    printer.print((offset, y), &text[range]);
+                });
+                i += 1;
+            }
+        }
+    }
+}
+
 impl View for TextView {
     fn draw(&self, printer: &Printer<'_, '_>) {
         let h = self.rows.len();
@@ -366,17 +802,60 @@ impl View for TextView {
 
         printer.with_effect(self.effect, |printer| {
             for (y, row) in self.rows.iter().enumerate() {
+                if !printer.is_visible((0, y), (printer.size.x, 1)) {
+                    continue;
+                }
                 let l = row.width;
                 let mut x = self.align.h.get_offset(l, printer.size.x);
 
                 for span in row.resolve(content.get_cache().as_ref()) {
-                    printer.with_style(*span.attr, |printer| {
+                    printer.with_style(span.attr.clone(), |printer| {
                         printer.print((x, y), span.content);
                         x += span.content.width();
                     });
                 }
             }
         });
+
+        self.draw_changes(printer, &content);
+        self.draw_highlights(printer, content.get_cache());
+        self.draw_search(printer);
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let cb = match self.on_link {
+            Some(ref cb) => Rc::clone(cb),
+            None => return EventResult::Ignored,
+        };
+        match event {
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            } => {
+                let link = position
+                    .checked_sub(offset)
+                    .and_then(|position| self.link_at(position));
+                match link {
+                    Some(link) => EventResult::with_cb(move |s| {
+                        cb(s, link.url());
+                    }),
+                    None => EventResult::Ignored,
+                }
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn take_focus(&mut self, _: Direction) -> bool {
+        self.on_link.is_some()
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        match self.search_row {
+            Some(y) => Rect::from_size((0, y), (size.x, 1)),
+            None => Rect::from_size((0, 0), size),
+        }
     }
 
     fn needs_relayout(&self) -> bool {
@@ -390,6 +869,19 @@ impl View for TextView {
         Vec2::new(self.width.unwrap_or(0), self.rows.len())
     }
 
+    fn measure(&mut self, size: Vec2) -> Vec2 {
+        let size = if self.wrap { size } else { Vec2::max_value() };
+        if size.x == 0 {
+            return Vec2::zero();
+        }
+
+        let content = self.content.content.lock().unwrap();
+        LinesIterator::new(content.content_value.as_ref(), size.x)
+            .fold(Vec2::zero(), |total, row| {
+                Vec2::new(total.x.max(row.width), total.y + 1)
+            })
+    }
+
     fn layout(&mut self, size: Vec2) {
         // Compute the text rows.
         self.last_size = size;
@@ -403,3 +895,92 @@ impl View for TextView {
         content.size_cache = Some(SizeCache::build(my_size, size));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure() {
+        let mut view = TextView::new("Some text that needs wrapping.");
+
+        // Measuring doesn't touch the rows.
+        assert_eq!(view.measure(Vec2::new(10, 10)), Vec2::new(10, 3));
+        assert!(view.rows.is_empty());
+
+        assert_eq!(
+            view.measure(Vec2::new(10, 10)),
+            view.required_size(Vec2::new(10, 10))
+        );
+    }
+
+    #[test]
+    fn highlight_changes() {
+        use crate::buffer::render_to_buffer;
+
+        let content = TextContent::new("a\nb\nc");
+        let mut view =
+            TextView::new_with_content(content.clone()).highlight_changes(2);
+
+        // Colors at the end of each row.
+        let mut render = || {
+            let buffer = render_to_buffer(&mut view, (3, 3));
+            (0..3)
+                .map(|y| buffer.get(Vec2::new(2, y)).unwrap().colors)
+                .collect::<Vec<_>>()
+        };
+        let plain = render()[0];
+
+        content.set_content("a\nB\nc");
+        for _ in 0..2 {
+            let colors = render();
+            assert_eq!(colors[0], plain);
+            assert_ne!(colors[1], plain);
+        }
+
+        // The highlight faded.
+        assert_eq!(render()[1], plain);
+
+        // Appended text is highlighted from the last line.
+        content.append("d\ne");
+        let colors = render();
+        assert_eq!(colors[1], plain);
+        assert_ne!(colors[2], plain);
+
+        assert_eq!(changed_lines("a\nb\nc", "x\nb\ny"), [0..1, 4..5]);
+
+        // Nothing is recorded without a view highlighting changes.
+        let content = TextContent::new("a");
+        let _view = TextView::new_with_content(content.clone());
+        content.set_content("b");
+        content.append("c");
+        assert!(content.content.lock().unwrap().changes.is_empty());
+    }
+
+    #[test]
+    fn on_link() {
+        use crate::theme::Link;
+
+        let mut text = StyledString::plain("See ");
+        text.append_styled("docs", Link::new("https://example.com"));
+
+        let mut view = TextView::new(text).on_link(|s, url| {
+            s.set_user_data(url.to_string());
+        });
+        crate::buffer::render_to_buffer(&mut view, (10, 1));
+
+        let click = |x| Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(x, 0),
+            event: MouseEvent::Press(MouseButton::Left),
+        };
+        assert!(!view.on_event(click(1)).is_consumed());
+
+        let mut siv = Cursive::dummy();
+        view.on_event(click(5)).process(&mut siv);
+        assert_eq!(
+            siv.user_data::<String>().map(|s| &s[..]),
+            Some("https://example.com")
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::theme::{BorderStyle, Color, PaletteColor, Theme};
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+use crate::With;
+
+/// Wrapper drawing a view with a different theme.
+///
+/// Only the given parts override the theme of the parent view; the rest is
+/// inherited. Themed views can be nested, each one building on its parent.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::theme::{BaseColor, Color, PaletteColor};
+/// use cursive_core::views::{Dialog, TextView, Themed};
+///
+/// // A dark pane, whatever the global theme.
+/// let pane = Themed::new(Dialog::around(TextView::new("$ cargo build")))
+///     .color(PaletteColor::View, Color::Dark(BaseColor::Black))
+///     .color(PaletteColor::Primary, Color::Light(BaseColor::Green))
+///     .shadow(false);
+/// ```
+pub struct Themed<V> {
+    view: V,
+    namespace: Option<String>,
+    colors: Vec<(PaletteColor, Color)>,
+    borders: Option<BorderStyle>,
+    shadow: Option<bool>,
+}
+
+impl<V> Themed<V> {
+    /// Wraps the given view, without any override yet.
+    pub fn new(view: V) -> Self {
+        Themed {
+            view,
+            namespace: None,
+            colors: Vec::new(),
+            borders: None,
+            shadow: None,
+        }
+    }
+
+    /// Overrides a color of the palette.
+    pub fn set_color(&mut self, key: PaletteColor, color: Color) {
+        self.colors.retain(|&(k, _)| k != key);
+        self.colors.push((key, color));
+    }
+
+    /// Overrides a color of the palette.
+    ///
+    /// Chainable variant.
+    pub fn color(self, key: PaletteColor, color: Color) -> Self {
+        self.with(|s| s.set_color(key, color))
+    }
+
+    /// Merges a namespace of the palette, before other colors.
+    ///
+    /// This uses a section of the theme file, like `[colors.terminal]`.
+    /// See [`Palette::merge`](crate::theme::Palette::merge).
+    pub fn set_namespace<S: Into<String>>(&mut self, namespace: S) {
+        self.namespace = Some(namespace.into());
+    }
+
+    /// Merges a namespace of the palette, before other colors.
+    ///
+    /// Chainable variant.
+    pub fn namespace<S: Into<String>>(self, namespace: S) -> Self {
+        self.with(|s| s.set_namespace(namespace))
+    }
+
+    /// Overrides the border style.
+    pub fn set_borders(&mut self, borders: BorderStyle) {
+        self.borders = Some(borders);
+    }
+
+    /// Overrides the border style.
+    ///
+    /// Chainable variant.
+    pub fn borders(self, borders: BorderStyle) -> Self {
+        self.with(|s| s.set_borders(borders))
+    }
+
+    /// Overrides whether layers have shadows.
+    pub fn set_shadow(&mut self, shadow: bool) {
+        self.shadow = Some(shadow);
+    }
+
+    /// Overrides whether layers have shadows.
+    ///
+    /// Chainable variant.
+    pub fn shadow(self, shadow: bool) -> Self {
+        self.with(|s| s.set_shadow(shadow))
+    }
+
+    /// Returns the theme used for the wrapped view, given the parent one.
+    pub fn resolve_theme(&self, parent: &Theme) -> Theme {
+        let mut palette = match self.namespace {
+            Some(ref namespace) => parent.palette.merge(namespace),
+            None => parent.palette.clone(),
+        };
+        if !self.colors.is_empty() {
+            palette.extend(self.colors.iter().copied());
+            palette.update_relative_colors();
+        }
+
+        Theme {
+            shadow: self.shadow.unwrap_or(parent.shadow),
+            borders: self.borders.unwrap_or(parent.borders),
+            palette,
+            styles: parent.styles.clone(),
+        }
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for Themed<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        let theme = self.resolve_theme(printer.theme);
+        self.view.draw(&printer.theme(&theme));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+    use crate::theme::{BaseColor, ColorStyle};
+    use crate::utils::markup::StyledString;
+    use crate::views::{LinearLayout, TextView};
+    use crate::Vec2;
+
+    #[test]
+    fn cascade() {
+        let text =
+            |s| TextView::new(StyledString::styled(s, ColorStyle::primary()));
+        let green = Color::Light(BaseColor::Green);
+        let mut view = LinearLayout::vertical().child(text("a")).child(
+            Themed::new(
+                Themed::new(text("b"))
+                    .color(PaletteColor::View, Color::Rgb(1, 2, 3)),
+            )
+            .color(PaletteColor::Primary, green)
+            .color(PaletteColor::View, Color::Rgb(4, 5, 6)),
+        );
+        let buffer = render_to_buffer(&mut view, (1, 2));
+        let colors = |y| buffer.get(Vec2::new(0, y)).unwrap().colors;
+
+        let palette = crate::theme::load_default().palette;
+        assert_eq!(colors(0).front, palette[PaletteColor::Primary]);
+        // The inner override wins, the rest is inherited.
+        assert_eq!(colors(1).front, green);
+        assert_eq!(colors(1).back, Color::Rgb(1, 2, 3));
+    }
+}
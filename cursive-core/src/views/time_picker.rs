@@ -0,0 +1,317 @@
+use crate::direction::{Absolute, Direction};
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::rect::Rect;
+use crate::view::View;
+use crate::{Cursive, Printer, Vec2, With};
+use chrono::{Local, NaiveTime, Timelike};
+use std::rc::Rc;
+
+type TimeCallback = Rc<dyn Fn(&mut Cursive, NaiveTime)>;
+
+/// Number of values for the hours, minutes and seconds.
+const RANGES: [u32; 3] = [24, 60, 60];
+
+/// Input to pick a time of the day.
+///
+/// The hours, minutes and optionally seconds are edited one at a time:
+/// `Left` and `Right` move between them, `Up` and `Down` or the mouse wheel
+/// change the current one, and digits can be typed. `Enter` submits the
+/// time.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use cursive_core::views::{Dialog, TimePicker};
+///
+/// let picker = TimePicker::new()
+///     .time(NaiveTime::from_hms_opt(9, 30, 0).unwrap())
+///     .on_submit(|s, time| {
+///         s.pop_layer();
+///         s.add_layer(Dialog::info(format!("Alarm set at {}", time)));
+///     });
+/// ```
+pub struct TimePicker {
+    time: NaiveTime,
+    show_seconds: bool,
+
+    // Index of the selected field: hours, minutes or seconds.
+    focus: usize,
+
+    // First digit typed in the selected field, waiting for the second one.
+    typed: Option<u32>,
+
+    on_submit: Option<TimeCallback>,
+}
+
+new_default!(TimePicker);
+
+impl TimePicker {
+    /// Creates a new time picker, on the current time to the minute.
+    pub fn new() -> Self {
+        let now = Local::now().time();
+        TimePicker {
+            time: NaiveTime::from_hms_opt(now.hour(), now.minute(), 0)
+                .unwrap(),
+            show_seconds: false,
+            focus: 0,
+            typed: None,
+            on_submit: None,
+        }
+    }
+
+    /// Sets the time.
+    ///
+    /// Seconds are dropped if they are not shown.
+    pub fn set_time(&mut self, time: NaiveTime) {
+        let second = if self.show_seconds { time.second() } else { 0 };
+        self.time =
+            NaiveTime::from_hms_opt(time.hour(), time.minute(), second)
+                .unwrap();
+    }
+
+    /// Sets the time.
+    ///
+    /// Chainable variant.
+    pub fn time(self, time: NaiveTime) -> Self {
+        self.with(|s| s.set_time(time))
+    }
+
+    /// Returns the time.
+    pub fn get_time(&self) -> NaiveTime {
+        self.time
+    }
+
+    /// Shows the seconds, so they can be picked too.
+    ///
+    /// Defaults to `false`.
+    pub fn set_show_seconds(&mut self, show_seconds: bool) {
+        self.show_seconds = show_seconds;
+        if !show_seconds {
+            self.time = self.time.with_second(0).unwrap();
+            self.focus = self.focus.min(1);
+        }
+    }
+
+    /// Shows the seconds, so they can be picked too.
+    ///
+    /// Chainable variant.
+    pub fn show_seconds(self, show_seconds: bool) -> Self {
+        self.with(|s| s.set_show_seconds(show_seconds))
+    }
+
+    /// Sets a callback to run when the time is submitted.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, NaiveTime) + 'static,
+    {
+        self.on_submit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when the time is submitted.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, NaiveTime) + 'static,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    fn fields(&self) -> usize {
+        if self.show_seconds {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn get_field(&self, field: usize) -> u32 {
+        match field {
+            0 => self.time.hour(),
+            1 => self.time.minute(),
+            _ => self.time.second(),
+        }
+    }
+
+    fn set_field(&mut self, field: usize, value: u32) {
+        let time = match field {
+            0 => self.time.with_hour(value),
+            1 => self.time.with_minute(value),
+            _ => self.time.with_second(value),
+        };
+        self.time = time.unwrap();
+    }
+
+    // Changes the selected field by `delta`, wrapping around.
+    fn add(&mut self, delta: i64) -> EventResult {
+        let range = i64::from(RANGES[self.focus]);
+        let value = i64::from(self.get_field(self.focus)) + delta;
+        self.set_field(self.focus, value.rem_euclid(range) as u32);
+        self.typed = None;
+        EventResult::Consumed(None)
+    }
+
+    fn move_focus(&mut self, focus: usize) -> EventResult {
+        if focus >= self.fields() || focus == self.focus {
+            return EventResult::Ignored;
+        }
+        self.focus = focus;
+        self.typed = None;
+        EventResult::Consumed(None)
+    }
+
+    fn type_digit(&mut self, digit: u32) -> EventResult {
+        let range = RANGES[self.focus];
+        match self.typed.take() {
+            Some(first) if first * 10 + digit < range => {
+                self.set_field(self.focus, first * 10 + digit);
+                self.move_focus(self.focus + 1);
+            }
+            _ => {
+                self.set_field(self.focus, digit);
+                self.typed = Some(digit);
+            }
+        }
+        EventResult::Consumed(None)
+    }
+
+    fn submit(&self) -> EventResult {
+        let time = self.time;
+        EventResult::Consumed(
+            self.on_submit
+                .clone()
+                .map(|cb| Callback::from_fn(move |s| cb(s, time))),
+        )
+    }
+
+    // Returns the field drawn at the given column, if any.
+    fn field_at(&self, x: usize) -> Option<usize> {
+        Some(x / 3).filter(|&field| x % 3 != 2 && field < self.fields())
+    }
+}
+
+impl View for TimePicker {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        for field in 0..self.fields() {
+            let x = field * 3;
+            if field > 0 {
+                printer.print((x - 1, 0), ":");
+            }
+
+            let text = format!("{:02}", self.get_field(field));
+            if field == self.focus && printer.focused {
+                printer.with_selection(true, |printer| {
+                    printer.print((x, 0), &text);
+                });
+            } else {
+                printer.print((x, 0), &text);
+            }
+        }
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        Vec2::new(self.fields() * 3 - 1, 1)
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        // Entering from the right selects the last field.
+        self.focus = match source {
+            Direction::Abs(Absolute::Right) => self.fields() - 1,
+            _ => 0,
+        };
+        self.typed = None;
+        true
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Left) => {
+                self.move_focus(self.focus.wrapping_sub(1))
+            }
+            Event::Key(Key::Right) => self.move_focus(self.focus + 1),
+            Event::Key(Key::Up) => self.add(1),
+            Event::Key(Key::Down) => self.add(-1),
+            Event::Key(Key::PageUp) => self.add(10),
+            Event::Key(Key::PageDown) => self.add(-10),
+            Event::Char(c) if c.is_ascii_digit() => {
+                self.type_digit(c.to_digit(10).unwrap())
+            }
+            Event::Key(Key::Enter) => self.submit(),
+            Event::Mouse {
+                event,
+                position,
+                offset,
+            } => {
+                let field = position
+                    .checked_sub(offset)
+                    .filter(|position| position.y == 0)
+                    .and_then(|position| self.field_at(position.x));
+                let field = match field {
+                    Some(field) => field,
+                    None => return EventResult::Ignored,
+                };
+                match event {
+                    MouseEvent::Press(MouseButton::Left) => {
+                        self.move_focus(field);
+                        EventResult::Consumed(None)
+                    }
+                    MouseEvent::WheelUp => {
+                        self.move_focus(field);
+                        self.add(1)
+                    }
+                    MouseEvent::WheelDown => {
+                        self.move_focus(field);
+                        self.add(-1)
+                    }
+                    _ => EventResult::Ignored,
+                }
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn important_area(&self, _: Vec2) -> Rect {
+        Rect::from_size((self.focus * 3, 0), (2, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+
+    #[test]
+    fn edit_fields() {
+        let mut picker = TimePicker::new()
+            .time(NaiveTime::from_hms_opt(23, 59, 0).unwrap())
+            .show_seconds(true);
+        assert_eq!(
+            render_to_buffer(&mut picker, (8, 1)).lines(),
+            ["23:59:00"]
+        );
+
+        // Values wrap around.
+        picker.on_event(Event::Key(Key::Up));
+        assert_eq!(picker.get_time().hour(), 0);
+
+        // Typing two digits moves to the next field.
+        picker.on_event(Event::Char('0'));
+        picker.on_event(Event::Char('7'));
+        picker.on_event(Event::Char('4'));
+        picker.on_event(Event::Char('5'));
+        assert_eq!(
+            picker.get_time(),
+            NaiveTime::from_hms_opt(7, 45, 0).unwrap()
+        );
+
+        // Invalid values start over.
+        picker.on_event(Event::Char('9'));
+        picker.on_event(Event::Char('9'));
+        assert_eq!(picker.get_time().second(), 9);
+
+        assert!(!picker.on_event(Event::Key(Key::Right)).is_consumed());
+    }
+}
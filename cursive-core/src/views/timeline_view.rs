@@ -0,0 +1,105 @@
+use crate::event::{Event, EventResult, Key};
+use crate::theme::ColorStyle;
+use crate::utils::timeline::TimelineFrame;
+use crate::view::View;
+use crate::Printer;
+use crate::Vec2;
+
+/// Overlay stepping through frames captured in a [`Timeline`].
+///
+/// Usually opened with [`Cursive::show_timeline`]. The last line shows the
+/// frame number and the events received just before it.
+///
+/// `Left` and `Right` go to the previous and next frames, `Home` and `End`
+/// to the first and last ones, and `Esc` closes the overlay.
+///
+/// [`Timeline`]: crate::utils::timeline::Timeline
+/// [`Cursive::show_timeline`]: crate::Cursive::show_timeline
+pub struct TimelineView {
+    frames: Vec<TimelineFrame>,
+    current: usize,
+}
+
+impl TimelineView {
+    /// Creates a new view, showing the last of the given frames.
+    pub fn new(frames: Vec<TimelineFrame>) -> Self {
+        let current = frames.len().saturating_sub(1);
+        TimelineView { frames, current }
+    }
+
+    /// Returns the index of the frame shown.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Shows the frame at the given index.
+    ///
+    /// The index is clamped to the number of frames.
+    pub fn set_current(&mut self, i: usize) {
+        self.current = i.min(self.frames.len().saturating_sub(1));
+    }
+
+    // Describes the current frame.
+    fn status(&self, frame: &TimelineFrame) -> String {
+        let events: Vec<String> = frame
+            .events()
+            .iter()
+            .map(|event| format!("{:?}", event))
+            .collect();
+        format!(
+            " {}/{} · frame #{} · {} · ←→ Home End Esc",
+            self.current + 1,
+            self.frames.len(),
+            frame.number(),
+            if events.is_empty() {
+                "no event".to_string()
+            } else {
+                events.join(" ")
+            },
+        )
+    }
+}
+
+impl View for TimelineView {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        let frame = match self.frames.get(self.current) {
+            Some(frame) => frame,
+            None => return,
+        };
+
+        frame.screen().draw(printer);
+
+        let y = printer.size.y.saturating_sub(1);
+        printer.with_color(ColorStyle::highlight(), |printer| {
+            printer.print_hline((0, y), printer.size.x, " ");
+            printer.print((0, y), &self.status(frame));
+        });
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        constraint
+    }
+
+    fn take_focus(&mut self, _: crate::direction::Direction) -> bool {
+        true
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Left) => {
+                self.set_current(self.current.saturating_sub(1))
+            }
+            Event::Key(Key::Right) => self.set_current(self.current + 1),
+            Event::Key(Key::Home) => self.set_current(0),
+            Event::Key(Key::End) => self.set_current(usize::MAX),
+            Event::Key(Key::Esc) => {
+                return EventResult::with_cb(|s| {
+                    s.pop_layer();
+                    s.resume_timeline();
+                });
+            }
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Consumed(None)
+    }
+}
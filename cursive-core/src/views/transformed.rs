@@ -0,0 +1,290 @@
+use crate::buffer::BufferBackend;
+use crate::event::{Event, EventResult, Key};
+use crate::rect::Rect;
+use crate::view::{View, ViewWrapper};
+use crate::Printer;
+use crate::Vec2;
+
+/// Geometric transformation applied by a [`Transformed`] view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transformation {
+    /// Mirrors the view horizontally: the left side ends up on the right.
+    FlipHorizontal,
+
+    /// Mirrors the view vertically: the top ends up at the bottom.
+    FlipVertical,
+
+    /// Rotates the view by 180 degrees.
+    Rotate180,
+
+    /// Rotates the view by 90 degrees clockwise.
+    ///
+    /// The top row of the view ends up as the right column.
+    RotateClockwise,
+
+    /// Rotates the view by 90 degrees counter-clockwise.
+    ///
+    /// The top row of the view ends up as the left column.
+    RotateCounterClockwise,
+}
+
+impl Transformation {
+    /// Returns `true` if this transformation swaps the X and Y axis.
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Transformation::RotateClockwise
+                | Transformation::RotateCounterClockwise
+        )
+    }
+
+    /// Transforms a size from the inner view to the outer space.
+    pub fn size(self, size: Vec2) -> Vec2 {
+        if self.swaps_axes() {
+            size.swap()
+        } else {
+            size
+        }
+    }
+
+    /// Transforms a cell position from the inner view to the outer space.
+    ///
+    /// * `inner_size` is the size of the inner view.
+    /// * `width` is the width of the grapheme starting at `pos`.
+    pub fn apply(self, inner_size: Vec2, pos: Vec2, width: usize) -> Vec2 {
+        let max = inner_size.saturating_sub((1, 1));
+        let flip_x = inner_size.x.saturating_sub(pos.x + width);
+        match self {
+            Transformation::FlipHorizontal => Vec2::new(flip_x, pos.y),
+            Transformation::FlipVertical => Vec2::new(pos.x, max.y - pos.y),
+            Transformation::Rotate180 => Vec2::new(flip_x, max.y - pos.y),
+            Transformation::RotateClockwise => Vec2::new(max.y - pos.y, pos.x),
+            Transformation::RotateCounterClockwise => {
+                Vec2::new(pos.y, max.x - pos.x)
+            }
+        }
+    }
+
+    /// Transforms a cell position from the outer space back to the inner view.
+    ///
+    /// `inner_size` is the size of the inner view.
+    pub fn invert(self, inner_size: Vec2, pos: Vec2) -> Vec2 {
+        let max = inner_size.saturating_sub((1, 1));
+        let pos = Vec2::min(pos, self.size(max));
+        match self {
+            Transformation::FlipHorizontal => Vec2::new(max.x - pos.x, pos.y),
+            Transformation::FlipVertical => Vec2::new(pos.x, max.y - pos.y),
+            Transformation::Rotate180 => max - pos,
+            Transformation::RotateClockwise => Vec2::new(pos.y, max.y - pos.x),
+            Transformation::RotateCounterClockwise => {
+                Vec2::new(max.x - pos.y, pos.x)
+            }
+        }
+    }
+
+    /// Returns the inner arrow key matching the given visual arrow key.
+    ///
+    /// Non-arrow keys are returned unchanged.
+    pub fn invert_key(self, key: Key) -> Key {
+        use self::Transformation::*;
+
+        match (self, key) {
+            (FlipHorizontal, Key::Left) | (Rotate180, Key::Left) => Key::Right,
+            (FlipHorizontal, Key::Right) | (Rotate180, Key::Right) => {
+                Key::Left
+            }
+            (FlipVertical, Key::Up) | (Rotate180, Key::Up) => Key::Down,
+            (FlipVertical, Key::Down) | (Rotate180, Key::Down) => Key::Up,
+            (RotateClockwise, Key::Up) => Key::Left,
+            (RotateClockwise, Key::Down) => Key::Right,
+            (RotateClockwise, Key::Left) => Key::Down,
+            (RotateClockwise, Key::Right) => Key::Up,
+            (RotateCounterClockwise, Key::Up) => Key::Right,
+            (RotateCounterClockwise, Key::Down) => Key::Left,
+            (RotateCounterClockwise, Key::Left) => Key::Up,
+            (RotateCounterClockwise, Key::Right) => Key::Down,
+            (_, key) => key,
+        }
+    }
+}
+
+/// Wrapper view that flips or rotates the output of its child.
+///
+/// The child is drawn on an intermediate buffer, which is then copied with
+/// the requested transformation. Mouse events and arrow keys are converted
+/// back, so the child keeps working as expected.
+///
+/// This can be used to mirror a layout for right-to-left languages, or for
+/// unusual dashboard arrangements.
+///
+/// Note that text is transformed as well: mirrored text will read backward,
+/// and rotated text will be printed one grapheme per row.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{Button, LinearLayout, Transformation, Transformed};
+///
+/// // The ">" button will now appear on the left.
+/// let buttons = Transformed::new(
+///     Transformation::FlipHorizontal,
+///     LinearLayout::horizontal()
+///         .child(Button::new("<", |_| ()))
+///         .child(Button::new(">", |_| ())),
+/// );
+/// ```
+pub struct Transformed<V> {
+    view: V,
+    transformation: Transformation,
+
+    /// Size given to the inner view in the last layout.
+    inner_size: Vec2,
+}
+
+impl<V> Transformed<V> {
+    /// Wraps `view` in a new `Transformed` view.
+    pub fn new(transformation: Transformation, view: V) -> Self {
+        Transformed {
+            view,
+            transformation,
+            inner_size: Vec2::zero(),
+        }
+    }
+
+    /// Returns the transformation currently applied.
+    pub fn get_transformation(&self) -> Transformation {
+        self.transformation
+    }
+
+    /// Sets the transformation to apply.
+    pub fn set_transformation(&mut self, transformation: Transformation) {
+        self.transformation = transformation;
+    }
+
+    inner_getters!(self.view: V);
+}
+
+impl<V: View> ViewWrapper for Transformed<V> {
+    wrap_impl!(self.view: V);
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let t = self.transformation;
+        t.size(self.view.required_size(t.size(req)))
+    }
+
+    fn wrap_measure(&mut self, req: Vec2) -> Vec2 {
+        let t = self.transformation;
+        t.size(self.view.measure(t.size(req)))
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.inner_size = self.transformation.size(size);
+        self.view.layout(self.inner_size);
+    }
+
+    fn wrap_draw(&self, printer: &Printer<'_, '_>) {
+        let backend = BufferBackend::new(self.inner_size);
+        {
+            let inner_printer =
+                Printer::new(self.inner_size, printer.theme, &backend)
+                    .focused(printer.focused)
+                    .enabled(printer.enabled);
+            self.view.draw(&inner_printer);
+        }
+
+        let buffer = backend.into_buffer();
+        for (pos, cell) in buffer.cells() {
+            let width = cell.width();
+            if width == 0 {
+                continue;
+            }
+            let pos = self.transformation.apply(self.inner_size, pos, width);
+            cell.print(printer, pos);
+        }
+    }
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        let t = self.transformation;
+        let event = match event {
+            Event::Mouse {
+                offset,
+                position,
+                event,
+            } => match position.checked_sub(offset) {
+                Some(position) => Event::Mouse {
+                    offset: Vec2::zero(),
+                    position: t.invert(self.inner_size, position),
+                    event,
+                },
+                None => Event::Mouse {
+                    offset,
+                    position,
+                    event,
+                },
+            },
+            Event::Key(key) => Event::Key(t.invert_key(key)),
+            Event::Shift(key) => Event::Shift(t.invert_key(key)),
+            Event::Ctrl(key) => Event::Ctrl(t.invert_key(key)),
+            Event::Alt(key) => Event::Alt(t.invert_key(key)),
+            event => event,
+        };
+        self.view.on_event(event)
+    }
+
+    fn wrap_important_area(&self, size: Vec2) -> Rect {
+        let t = self.transformation;
+        let inner_size = t.size(size);
+        let area = self.view.important_area(inner_size);
+        Rect::from_corners(
+            t.apply(inner_size, area.top_left(), 1),
+            t.apply(inner_size, area.bottom_right(), 1),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_then_invert() {
+        let inner = Vec2::new(4, 3);
+        let all = [
+            Transformation::FlipHorizontal,
+            Transformation::FlipVertical,
+            Transformation::Rotate180,
+            Transformation::RotateClockwise,
+            Transformation::RotateCounterClockwise,
+        ];
+        for &t in &all {
+            for x in 0..inner.x {
+                for y in 0..inner.y {
+                    let pos = Vec2::new(x, y);
+                    let outer = t.apply(inner, pos, 1);
+                    assert!(outer.strictly_lt(t.size(inner)));
+                    assert_eq!(t.invert(inner, outer), pos, "{:?}", t);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_clockwise() {
+        let t = Transformation::RotateClockwise;
+        let inner = Vec2::new(4, 3);
+        // Top-left goes to top-right.
+        assert_eq!(t.apply(inner, Vec2::new(0, 0), 1), Vec2::new(2, 0));
+        // Top-right goes to bottom-right.
+        assert_eq!(t.apply(inner, Vec2::new(3, 0), 1), Vec2::new(2, 3));
+    }
+
+    #[test]
+    fn flip_wide_grapheme() {
+        let t = Transformation::FlipHorizontal;
+        // A 2-wide grapheme at the start ends up at the end.
+        assert_eq!(
+            t.apply(Vec2::new(5, 1), Vec2::new(0, 0), 2),
+            Vec2::new(3, 0)
+        );
+    }
+}
@@ -0,0 +1,357 @@
+use crate::direction::Direction;
+use crate::event::{
+    Callback, Event, EventResult, Key, MouseButton, MouseEvent,
+};
+use crate::rect::Rect;
+use crate::theme::ColorStyle;
+use crate::utils::markup::StyledString;
+use crate::view::View;
+use crate::{Cursive, Printer, Vec2, With};
+use std::cmp::min;
+use std::rc::Rc;
+
+type RowFn = Rc<dyn Fn(usize) -> StyledString>;
+type CountFn = Rc<dyn Fn() -> usize>;
+type IndexCallback = Rc<dyn Fn(&mut Cursive, usize)>;
+
+/// List of rows built on demand.
+///
+/// Unlike [`SelectView`](crate::views::SelectView), rows are not stored:
+/// they are given by a closure from their index, and only the visible rows
+/// are ever built. The number of rows is given by another closure, checked
+/// on every layout, so the data can grow while the list is displayed.
+///
+/// This makes it suitable for huge datasets, like a million-line log.
+///
+/// The list scrolls by itself, and should not be put in a `ScrollView`.
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::VirtualList;
+///
+/// let list = VirtualList::new(|| 1_000_000, |i| format!("Line {}", i).into())
+///     .on_submit(|s, i| {
+///         s.add_layer(cursive_core::views::Dialog::info(format!("Row {}", i)));
+///     });
+/// ```
+pub struct VirtualList {
+    row: RowFn,
+    count: CountFn,
+
+    // Index of the selected row.
+    focus: usize,
+
+    // Index of the first visible row.
+    offset: usize,
+
+    last_size: Vec2,
+
+    on_select: Option<IndexCallback>,
+    on_submit: Option<IndexCallback>,
+}
+
+impl VirtualList {
+    /// Creates a new list.
+    ///
+    /// `count` returns the number of rows, and `row` builds the row at the
+    /// given index, from `0` to `count() - 1`.
+    pub fn new<C, R>(count: C, row: R) -> Self
+    where
+        C: Fn() -> usize + 'static,
+        R: Fn(usize) -> StyledString + 'static,
+    {
+        VirtualList {
+            row: Rc::new(row),
+            count: Rc::new(count),
+            focus: 0,
+            offset: 0,
+            last_size: Vec2::zero(),
+            on_select: None,
+            on_submit: None,
+        }
+    }
+
+    /// Returns the number of rows, as given by the count closure.
+    pub fn len(&self) -> usize {
+        (self.count)()
+    }
+
+    /// Returns `true` if the list has no row.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sets a callback to run when the selection changes.
+    ///
+    /// It is given the index of the new selected row.
+    pub fn set_on_select<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.on_select = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when the selection changes.
+    ///
+    /// Chainable variant.
+    pub fn on_select<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.with(|s| s.set_on_select(cb))
+    }
+
+    /// Sets a callback to run when a row is submitted with `Enter` or a
+    /// click.
+    pub fn set_on_submit<F>(&mut self, cb: F)
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.on_submit = Some(Rc::new(cb));
+    }
+
+    /// Sets a callback to run when a row is submitted.
+    ///
+    /// Chainable variant.
+    pub fn on_submit<F>(self, cb: F) -> Self
+    where
+        F: Fn(&mut Cursive, usize) + 'static,
+    {
+        self.with(|s| s.set_on_submit(cb))
+    }
+
+    /// Returns the index of the selected row.
+    ///
+    /// Returns `None` if the list is empty.
+    pub fn selected_id(&self) -> Option<usize> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.focus)
+        }
+    }
+
+    /// Selects the row at the given index, and scrolls to it.
+    ///
+    /// The index is clamped to the last row. Returns the `on_select`
+    /// callback, if any.
+    pub fn set_selection(&mut self, i: usize) -> Callback {
+        self.focus = min(i, self.len().saturating_sub(1));
+        self.scroll_to_focus();
+
+        let focus = self.focus;
+        match self.on_select.clone() {
+            Some(cb) => Callback::from_fn(move |s| cb(s, focus)),
+            None => Callback::dummy(),
+        }
+    }
+
+    /// Selects the row at the given index.
+    ///
+    /// Chainable variant. Does not run the `on_select` callback.
+    pub fn selected(self, i: usize) -> Self {
+        self.with(|s| {
+            s.set_selection(i);
+        })
+    }
+
+    /// Returns the range of rows currently visible.
+    pub fn visible_rows(&self) -> std::ops::Range<usize> {
+        let end = min(self.offset + self.last_size.y, self.len());
+        self.offset..end
+    }
+
+    fn is_scrolling(&self, size: Vec2) -> bool {
+        self.len() > size.y
+    }
+
+    // Adjusts the offset so the selected row is visible.
+    fn scroll_to_focus(&mut self) {
+        let height = self.last_size.y;
+        if self.focus < self.offset {
+            self.offset = self.focus;
+        } else if height > 0 && self.focus >= self.offset + height {
+            self.offset = self.focus + 1 - height;
+        }
+    }
+
+    // Keeps the offset and the selection in the list.
+    fn clamp(&mut self) {
+        let len = self.len();
+        self.focus = min(self.focus, len.saturating_sub(1));
+        self.offset = min(self.offset, len.saturating_sub(self.last_size.y));
+    }
+
+    fn move_focus(&mut self, n: isize) -> EventResult {
+        let len = self.len();
+        if len == 0 {
+            return EventResult::Ignored;
+        }
+
+        let focus = if n < 0 {
+            self.focus.saturating_sub(n.unsigned_abs())
+        } else {
+            min(self.focus.saturating_add(n as usize), len - 1)
+        };
+        if focus == self.focus {
+            return EventResult::Ignored;
+        }
+
+        EventResult::Consumed(Some(self.set_selection(focus)))
+    }
+
+    fn scroll(&mut self, n: isize) -> EventResult {
+        let max = self.len().saturating_sub(self.last_size.y);
+        let offset = if n < 0 {
+            self.offset.saturating_sub(n.unsigned_abs())
+        } else {
+            min(self.offset + n as usize, max)
+        };
+        if offset == self.offset {
+            return EventResult::Ignored;
+        }
+        self.offset = offset;
+        EventResult::Consumed(None)
+    }
+
+    fn submit(&self) -> EventResult {
+        let focus = self.focus;
+        EventResult::Consumed(
+            self.on_submit
+                .clone()
+                .map(|cb| Callback::from_fn(move |s| cb(s, focus))),
+        )
+    }
+
+    fn draw_scrollbar(&self, printer: &Printer<'_, '_>) {
+        let height = printer.size.y;
+        let len = self.len();
+        if height == 0 {
+            return;
+        }
+        let x = printer.size.x.saturating_sub(1);
+
+        let thumb = (height * height / len).max(1);
+        let max_offset = len - height;
+        let thumb_offset = (height - thumb) * self.offset / max_offset;
+
+        let color = if printer.focused {
+            ColorStyle::highlight()
+        } else {
+            ColorStyle::highlight_inactive()
+        };
+
+        printer.print_vline((x, 0), height, "|");
+        printer.with_color(color, |printer| {
+            printer.print_vline((x, thumb_offset), thumb, "▒");
+        });
+    }
+}
+
+impl View for VirtualList {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        let scrolling = self.is_scrolling(printer.size);
+        let width = if scrolling {
+            printer.size.x.saturating_sub(1)
+        } else {
+            printer.size.x
+        };
+
+        for (y, i) in self.visible_rows().enumerate() {
+            let printer = printer.offset((0, y)).cropped((width, 1));
+            printer.with_selection(i == self.focus, |printer| {
+                printer.print_hline((0, 0), width, " ");
+                printer.print_styled((0, 0), (&(self.row)(i)).into());
+            });
+        }
+
+        if scrolling {
+            self.draw_scrollbar(printer);
+        }
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        // Measuring every row would defeat the purpose.
+        Vec2::new(req.x, min(req.y, self.len()))
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        self.clamp();
+    }
+
+    fn take_focus(&mut self, _: Direction) -> bool {
+        !self.is_empty()
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        let page = self.last_size.y.max(1) as isize;
+
+        match event {
+            Event::Key(Key::Up) => self.move_focus(-1),
+            Event::Key(Key::Down) => self.move_focus(1),
+            Event::Key(Key::PageUp) => self.move_focus(-page),
+            Event::Key(Key::PageDown) => self.move_focus(page),
+            Event::Key(Key::Home) => self.move_focus(isize::MIN),
+            Event::Key(Key::End) => self.move_focus(isize::MAX),
+            Event::Key(Key::Enter) if !self.is_empty() => self.submit(),
+            Event::Mouse {
+                event: MouseEvent::WheelUp,
+                ..
+            } => self.scroll(-3),
+            Event::Mouse {
+                event: MouseEvent::WheelDown,
+                ..
+            } => self.scroll(3),
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                position,
+                offset,
+            } => match position.checked_sub(offset) {
+                Some(position)
+                    if position.fits_in_rect(Vec2::zero(), self.last_size) =>
+                {
+                    let i = self.offset + position.y;
+                    if i >= self.len() {
+                        return EventResult::Ignored;
+                    }
+                    if i == self.focus {
+                        self.submit()
+                    } else {
+                        EventResult::Consumed(Some(self.set_selection(i)))
+                    }
+                }
+                _ => EventResult::Ignored,
+            },
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        let y = self.focus.saturating_sub(self.offset);
+        Rect::from_size((0, y), (size.x, 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::render_to_buffer;
+
+    #[test]
+    fn visible_rows() {
+        let mut list =
+            VirtualList::new(|| 1_000_000, |i| i.to_string().into());
+
+        let lines = render_to_buffer(&mut list, (8, 3)).lines();
+        assert_eq!(lines, ["0      ▒", "1      |", "2      |"]);
+
+        list.on_event(Event::Key(Key::End));
+        assert_eq!(list.selected_id(), Some(999_999));
+        assert_eq!(list.visible_rows(), 999_997..1_000_000);
+
+        let lines = render_to_buffer(&mut list, (8, 3)).lines();
+        assert_eq!(lines[2], "999999 ▒");
+    }
+}
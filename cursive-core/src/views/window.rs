@@ -0,0 +1,318 @@
+use crate::direction::Direction;
+use crate::event::{AnyCb, Event, EventResult, MouseButton, MouseEvent};
+use crate::rect::Rect;
+use crate::theme::ColorStyle;
+use crate::view::{IntoBoxedView, Selector, View};
+use crate::views::BoxedView;
+use crate::{Printer, Vec2, With, XY};
+
+// Smallest size a window can be resized to.
+const MIN_SIZE: (usize, usize) = (12, 3);
+
+// Buttons drawn on the title bar, from the right border.
+const CLOSE_BUTTON: &str = "[×]";
+const MAXIMIZE_BUTTON: &str = "[□]";
+const RESTORE_BUTTON: &str = "[▫]";
+const BUTTON_WIDTH: usize = 3;
+
+/// Change a window asks from the `StackView` holding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WindowRequest {
+    /// Move the window by this many cells.
+    Move(XY<isize>),
+    /// Remove the window from the stack.
+    Close,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Drag {
+    // Dragging the title bar, holding the window at this point.
+    Move(Vec2),
+    // Dragging the bottom-right corner.
+    Resize,
+}
+
+/// Window with a title bar, which the mouse can move and resize.
+///
+/// The title bar has buttons to maximize and close the window. Dragging the
+/// title bar moves the window, and dragging the right or bottom border
+/// resizes it.
+///
+/// Moving and closing are done by the [`StackView`] holding the window, so
+/// the window must be added directly as a layer. See
+/// [`StackView::set_window_manager`] to raise windows when clicked.
+///
+/// [`StackView`]: crate::views::StackView
+/// [`StackView::set_window_manager`]: crate::views::StackView::set_window_manager
+///
+/// # Examples
+///
+/// ```rust
+/// use cursive_core::views::{TextView, Window};
+///
+/// let mut siv = cursive_core::Cursive::dummy();
+/// siv.screen_mut().set_window_manager(true);
+/// siv.add_layer(Window::new(TextView::new("Hello!")).title("Greeting"));
+/// siv.add_layer(Window::new(TextView::new("World!")).title("Other"));
+/// ```
+pub struct Window {
+    content: BoxedView,
+    title: String,
+
+    // Size set by resizing, used instead of the content's.
+    size: Option<Vec2>,
+
+    // When maximized, the size to restore.
+    maximized: Option<Option<Vec2>>,
+
+    drag: Option<Drag>,
+    request: Option<WindowRequest>,
+    last_size: Vec2,
+    invalidated: bool,
+}
+
+impl Window {
+    /// Creates a new window around the given view.
+    pub fn new<V: IntoBoxedView>(view: V) -> Self {
+        Window {
+            content: BoxedView::boxed(view),
+            title: String::new(),
+            size: None,
+            maximized: None,
+            drag: None,
+            request: None,
+            last_size: Vec2::zero(),
+            invalidated: true,
+        }
+    }
+
+    /// Sets the title of the window.
+    pub fn set_title<S: Into<String>>(&mut self, title: S) {
+        self.title = title.into();
+    }
+
+    /// Sets the title of the window.
+    ///
+    /// Chainable variant.
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        self.with(|s| s.set_title(title))
+    }
+
+    /// Returns the title of the window.
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    /// Sets the size of the window, borders included.
+    ///
+    /// `None` fits the window to its content. Resizing the window with the
+    /// mouse changes this size.
+    pub fn set_size(&mut self, size: Option<Vec2>) {
+        self.size = size.map(|size| size.or_max(MIN_SIZE));
+        self.invalidated = true;
+    }
+
+    /// Sets the size of the window, borders included.
+    ///
+    /// Chainable variant.
+    pub fn size<S: Into<Vec2>>(self, size: S) -> Self {
+        self.with(|s| s.set_size(Some(size.into())))
+    }
+
+    /// Returns the size set for the window, if any.
+    pub fn get_size(&self) -> Option<Vec2> {
+        self.size
+    }
+
+    /// Returns `true` if the window fills the stack.
+    pub fn is_maximized(&self) -> bool {
+        self.maximized.is_some()
+    }
+
+    /// Maximizes the window, or restores its previous size.
+    pub fn set_maximized(&mut self, maximized: bool) {
+        if maximized == self.is_maximized() {
+            return;
+        }
+        if maximized {
+            self.maximized = Some(self.size);
+        } else if let Some(size) = self.maximized.take() {
+            self.size = size;
+        }
+        self.drag = None;
+        self.invalidated = true;
+    }
+
+    /// Returns the view inside the window.
+    pub fn get_content(&self) -> &dyn View {
+        &*self.content
+    }
+
+    /// Returns the view inside the window.
+    pub fn get_content_mut(&mut self) -> &mut dyn View {
+        &mut *self.content
+    }
+
+    pub(crate) fn take_request(&mut self) -> Option<WindowRequest> {
+        self.request.take()
+    }
+
+    // Returns the x coordinate of the given title bar button.
+    //
+    // Buttons are numbered from the right, and are hidden if too narrow.
+    fn button_x(&self, i: usize) -> Option<usize> {
+        let right = self.last_size.x.checked_sub(2 + BUTTON_WIDTH * i)?;
+        right.checked_sub(BUTTON_WIDTH).filter(|&x| x >= 2)
+    }
+
+    fn on_button(&self, i: usize, pos: Vec2) -> bool {
+        matches!(self.button_x(i), Some(x) if pos.y == 0 && pos.x >= x && pos.x < x + BUTTON_WIDTH)
+    }
+
+    fn on_mouse(
+        &mut self,
+        event: MouseEvent,
+        position: Vec2,
+        offset: Vec2,
+    ) -> Option<EventResult> {
+        match (event, self.drag) {
+            (MouseEvent::Hold(MouseButton::Left), Some(Drag::Move(grab))) => {
+                let delta =
+                    position.signed() - grab.signed() - offset.signed();
+                if delta != XY::new(0, 0) {
+                    self.request = Some(WindowRequest::Move(delta));
+                }
+                return Some(EventResult::Consumed(None));
+            }
+            (MouseEvent::Hold(MouseButton::Left), Some(Drag::Resize)) => {
+                let size = position.saturating_sub(offset) + (1, 1);
+                self.set_size(Some(size));
+                return Some(EventResult::Consumed(None));
+            }
+            (MouseEvent::Release(_), Some(_)) => {
+                self.drag = None;
+                return Some(EventResult::Consumed(None));
+            }
+            _ => (),
+        }
+
+        let pos = position.checked_sub(offset)?;
+        if event != MouseEvent::Press(MouseButton::Left)
+            || !pos.fits_in(self.last_size.saturating_sub((1, 1)))
+        {
+            return None;
+        }
+
+        let corner = self.last_size.saturating_sub((1, 1));
+        if self.on_button(0, pos) {
+            self.request = Some(WindowRequest::Close);
+        } else if self.on_button(1, pos) {
+            self.set_maximized(!self.is_maximized());
+        } else if self.is_maximized() {
+            // A maximized window stays in place.
+            if pos.y != 0 && pos.x != corner.x && pos.y != corner.y {
+                return None;
+            }
+        } else if pos.y == 0 {
+            self.drag = Some(Drag::Move(pos));
+        } else if pos.x == corner.x || pos.y == corner.y {
+            self.drag = Some(Drag::Resize);
+        } else {
+            return None;
+        }
+        Some(EventResult::Consumed(None))
+    }
+}
+
+impl View for Window {
+    fn draw(&self, printer: &Printer<'_, '_>) {
+        printer.print_box((0, 0), printer.size, true);
+
+        let style = if printer.focused {
+            ColorStyle::title_primary()
+        } else {
+            ColorStyle::title_secondary()
+        };
+
+        let buttons = [
+            CLOSE_BUTTON,
+            if self.is_maximized() {
+                RESTORE_BUTTON
+            } else {
+                MAXIMIZE_BUTTON
+            },
+        ];
+        let mut title_end = printer.size.x.saturating_sub(2);
+        for (i, button) in buttons.iter().enumerate() {
+            if let Some(x) = self.button_x(i) {
+                printer.with_color(style, |p| p.print((x, 0), button));
+                title_end = x.saturating_sub(1);
+            }
+        }
+
+        if !self.title.is_empty() {
+            printer.cropped((title_end, 1)).with_color(style, |p| {
+                p.print((2, 0), &self.title);
+            });
+        }
+
+        let printer = printer.offset((1, 1)).shrinked((1, 1));
+        self.content.draw(&printer);
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.last_size = size;
+        self.invalidated = false;
+        self.content.layout(size.saturating_sub((2, 2)));
+    }
+
+    fn needs_relayout(&self) -> bool {
+        self.invalidated || self.content.needs_relayout()
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        if self.is_maximized() {
+            return constraint;
+        }
+        if let Some(size) = self.size {
+            return size;
+        }
+        let constraint = constraint.saturating_sub((2, 2));
+        self.content.required_size(constraint) + (2, 2)
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        if let Event::Mouse {
+            event: mouse,
+            position,
+            offset,
+        } = event
+        {
+            if let Some(result) = self.on_mouse(mouse, position, offset) {
+                return result;
+            }
+        }
+        self.content.on_event(event.relativized((1, 1)))
+    }
+
+    fn take_focus(&mut self, source: Direction) -> bool {
+        self.content.take_focus(source)
+    }
+
+    fn call_on_any<'a>(
+        &mut self,
+        selector: &Selector<'_>,
+        callback: AnyCb<'a>,
+    ) {
+        self.content.call_on_any(selector, callback);
+    }
+
+    fn focus_view(&mut self, selector: &Selector<'_>) -> Result<(), ()> {
+        self.content.focus_view(selector)
+    }
+
+    fn important_area(&self, size: Vec2) -> Rect {
+        let inner_size = size.saturating_sub((2, 2));
+        self.content.important_area(inner_size) + (1, 1)
+    }
+}
@@ -0,0 +1,484 @@
+//! Declarative view trees.
+//!
+//! Instead of mutating views directly, an application can describe the
+//! tree it wants with [`Node`]s, and give the description to a
+//! [`VirtualView`]. When the description changes, the view compares it with
+//! the previous one and only applies the differences to the real views.
+//!
+//! Views that are kept retain their state: the focus, the scroll offset or
+//! the cursor position in an edit field are not reset.
+//!
+//! Children of a layout are matched by their key when they have one, and
+//! by their position otherwise. Keys should be used when children can be
+//! added, removed or moved.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cursive_core::virtual_dom::{Node, VirtualView};
+//!
+//! fn render(items: &[&str]) -> Node {
+//!     Node::vertical(
+//!         items
+//!             .iter()
+//!             .map(|item| Node::text(*item).key(*item))
+//!             .collect(),
+//!     )
+//! }
+//!
+//! let mut view = VirtualView::new(render(&["a", "b"]));
+//!
+//! // Only the new item is created.
+//! view.update(render(&["a", "b", "c"]));
+//! ```
+use crate::direction::Orientation;
+use crate::utils::markup::StyledString;
+use crate::view::{View, ViewWrapper};
+use crate::views::{
+    BoxedView, Button, Checkbox, EditView, LinearLayout, Panel, ScrollView,
+    TextView,
+};
+use crate::Cursive;
+use std::mem;
+use std::rc::Rc;
+
+type ChangeCallback = Rc<dyn Fn(&mut Cursive, bool)>;
+type EditCallback = Rc<dyn Fn(&mut Cursive, &str, usize)>;
+
+/// Description of a view.
+///
+/// A node is cheap to build: no view is created until it is given to a
+/// [`VirtualView`].
+pub struct Node {
+    key: Option<String>,
+    kind: Kind,
+}
+
+enum Kind {
+    Text(StyledString),
+    Button {
+        label: String,
+        cb: Rc<dyn Fn(&mut Cursive)>,
+    },
+    Checkbox {
+        checked: bool,
+        on_change: ChangeCallback,
+    },
+    Edit {
+        content: String,
+        on_edit: EditCallback,
+    },
+    Layout {
+        orientation: Orientation,
+        children: Vec<Node>,
+    },
+    Panel {
+        title: String,
+        child: Box<Node>,
+    },
+    Scroll(Box<Node>),
+}
+
+impl Node {
+    fn new(kind: Kind) -> Self {
+        Node { key: None, kind }
+    }
+
+    /// Describes a [`TextView`].
+    pub fn text<S: Into<StyledString>>(content: S) -> Self {
+        Self::new(Kind::Text(content.into()))
+    }
+
+    /// Describes a [`Button`].
+    pub fn button<S, F>(label: S, cb: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&mut Cursive) + 'static,
+    {
+        Self::new(Kind::Button {
+            label: label.into(),
+            cb: Rc::new(cb),
+        })
+    }
+
+    /// Describes a [`Checkbox`].
+    ///
+    /// `on_change` is called when the user toggles the checkbox.
+    pub fn checkbox<F>(checked: bool, on_change: F) -> Self
+    where
+        F: Fn(&mut Cursive, bool) + 'static,
+    {
+        Self::new(Kind::Checkbox {
+            checked,
+            on_change: Rc::new(on_change),
+        })
+    }
+
+    /// Describes an [`EditView`].
+    ///
+    /// `on_edit` is called when the user edits the content.
+    pub fn edit<S, F>(content: S, on_edit: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&mut Cursive, &str, usize) + 'static,
+    {
+        Self::new(Kind::Edit {
+            content: content.into(),
+            on_edit: Rc::new(on_edit),
+        })
+    }
+
+    /// Describes a vertical [`LinearLayout`].
+    pub fn vertical(children: Vec<Node>) -> Self {
+        Self::new(Kind::Layout {
+            orientation: Orientation::Vertical,
+            children,
+        })
+    }
+
+    /// Describes a horizontal [`LinearLayout`].
+    pub fn horizontal(children: Vec<Node>) -> Self {
+        Self::new(Kind::Layout {
+            orientation: Orientation::Horizontal,
+            children,
+        })
+    }
+
+    /// Describes a [`Panel`] around `child`.
+    pub fn panel<S: Into<String>>(title: S, child: Node) -> Self {
+        Self::new(Kind::Panel {
+            title: title.into(),
+            child: Box::new(child),
+        })
+    }
+
+    /// Describes a [`ScrollView`] around `child`.
+    pub fn scroll(child: Node) -> Self {
+        Self::new(Kind::Scroll(Box::new(child)))
+    }
+
+    /// Sets the key identifying this node among its siblings.
+    ///
+    /// Chainable variant.
+    pub fn key<S: Into<String>>(mut self, key: S) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Returns `true` if a view built for `self` can be patched into `other`.
+    fn same_kind(&self, other: &Node) -> bool {
+        match (&self.kind, &other.kind) {
+            (
+                Kind::Layout { orientation: a, .. },
+                Kind::Layout { orientation: b, .. },
+            ) => a == b,
+            (a, b) => mem::discriminant(a) == mem::discriminant(b),
+        }
+    }
+}
+
+/// View built from a [`Node`] description.
+///
+/// Call [`update`](#method.update) with a new description to change the
+/// view.
+pub struct VirtualView {
+    node: Node,
+    view: BoxedView,
+}
+
+impl VirtualView {
+    /// Creates a new `VirtualView` from the given description.
+    pub fn new(node: Node) -> Self {
+        let view = BoxedView::new(build(&node));
+        VirtualView { node, view }
+    }
+
+    /// Changes the description of the view.
+    ///
+    /// Only the differences with the previous description are applied.
+    pub fn update(&mut self, node: Node) {
+        if !patch(&self.node, &node, &mut *self.view) {
+            self.view = BoxedView::new(build(&node));
+        }
+        self.node = node;
+    }
+}
+
+impl ViewWrapper for VirtualView {
+    wrap_impl!(self.view: BoxedView);
+}
+
+/// Creates a new view from its description.
+fn build(node: &Node) -> Box<dyn View> {
+    match node.kind {
+        Kind::Text(ref content) => Box::new(TextView::new(content.clone())),
+        Kind::Button { ref label, ref cb } => {
+            let cb = Rc::clone(cb);
+            Box::new(Button::new(label.clone(), move |s| cb(s)))
+        }
+        Kind::Checkbox {
+            checked,
+            ref on_change,
+        } => {
+            let mut checkbox = Checkbox::new();
+            checkbox.set_checked(checked);
+            let on_change = Rc::clone(on_change);
+            checkbox.set_on_change(move |s, checked| on_change(s, checked));
+            Box::new(checkbox)
+        }
+        Kind::Edit {
+            ref content,
+            ref on_edit,
+        } => {
+            let mut edit = EditView::new();
+            edit.set_content(content.clone());
+            let on_edit = Rc::clone(on_edit);
+            edit.set_on_edit(move |s, text, cursor| on_edit(s, text, cursor));
+            Box::new(edit)
+        }
+        Kind::Layout {
+            orientation,
+            ref children,
+        } => {
+            let mut layout = LinearLayout::new(orientation);
+            for child in children {
+                layout.add_child(build(child));
+            }
+            Box::new(layout)
+        }
+        Kind::Panel {
+            ref title,
+            ref child,
+        } => Box::new(
+            Panel::new(BoxedView::new(build(child))).title(title.clone()),
+        ),
+        Kind::Scroll(ref child) => {
+            Box::new(ScrollView::new(BoxedView::new(build(child))))
+        }
+    }
+}
+
+/// Applies the differences between `old` and `new` to `view`.
+///
+/// `view` must have been built from `old`.
+///
+/// Returns `false` if `view` cannot be patched, and must be rebuilt.
+fn patch(old: &Node, new: &Node, view: &mut dyn View) -> bool {
+    if !old.same_kind(new) {
+        return false;
+    }
+
+    match (&old.kind, &new.kind) {
+        (Kind::Text(old_content), Kind::Text(new_content)) => {
+            let text = match view.downcast_mut::<TextView>() {
+                Some(text) => text,
+                None => return false,
+            };
+            if old_content != new_content {
+                text.set_content(new_content.clone());
+            }
+        }
+        (
+            Kind::Button {
+                label: old_label, ..
+            },
+            Kind::Button { label, cb },
+        ) => {
+            let button = match view.downcast_mut::<Button>() {
+                Some(button) => button,
+                None => return false,
+            };
+            if old_label != label {
+                button.set_label(label.clone());
+            }
+            // Callbacks can't be compared, so they are always replaced.
+            let cb = Rc::clone(cb);
+            button.set_callback(move |s| cb(s));
+        }
+        (Kind::Checkbox { .. }, Kind::Checkbox { checked, on_change }) => {
+            let checkbox = match view.downcast_mut::<Checkbox>() {
+                Some(checkbox) => checkbox,
+                None => return false,
+            };
+            // Set the callback afterwards, so it doesn't see the update.
+            checkbox.set_checked(*checked);
+            let on_change = Rc::clone(on_change);
+            checkbox.set_on_change(move |s, checked| on_change(s, checked));
+        }
+        (Kind::Edit { .. }, Kind::Edit { content, on_edit }) => {
+            let edit = match view.downcast_mut::<EditView>() {
+                Some(edit) => edit,
+                None => return false,
+            };
+            // Compare with the actual content, which the user may have
+            // edited: setting it would move the cursor.
+            if *edit.get_content() != *content {
+                edit.set_content(content.clone());
+            }
+            let on_edit = Rc::clone(on_edit);
+            edit.set_on_edit(move |s, text, cursor| on_edit(s, text, cursor));
+        }
+        (
+            Kind::Layout {
+                children: old_children,
+                ..
+            },
+            Kind::Layout { children, .. },
+        ) => {
+            let layout = match view.downcast_mut::<LinearLayout>() {
+                Some(layout) => layout,
+                None => return false,
+            };
+            patch_children(old_children, children, layout);
+        }
+        (
+            Kind::Panel {
+                title: old_title,
+                child: old_child,
+            },
+            Kind::Panel { title, child },
+        ) => {
+            let panel = match view.downcast_mut::<Panel<BoxedView>>() {
+                Some(panel) => panel,
+                None => return false,
+            };
+            if old_title != title {
+                panel.set_title(title.clone());
+            }
+            patch_boxed(old_child, child, panel.get_inner_mut());
+        }
+        (Kind::Scroll(old_child), Kind::Scroll(child)) => {
+            let scroll = match view.downcast_mut::<ScrollView<BoxedView>>() {
+                Some(scroll) => scroll,
+                None => return false,
+            };
+            // Patching the content in place keeps the scroll offset.
+            patch_boxed(old_child, child, scroll.get_inner_mut());
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+/// Patches `view`, or replaces it if it cannot be patched.
+fn patch_boxed(old: &Node, new: &Node, view: &mut BoxedView) {
+    if !patch(old, new, &mut **view) {
+        *view = BoxedView::new(build(new));
+    }
+}
+
+/// Finds, for each new child, the old child it replaces.
+fn match_children(old: &[Node], new: &[Node]) -> Vec<Option<usize>> {
+    let mut used = vec![false; old.len()];
+
+    new.iter()
+        .enumerate()
+        .map(|(i, child)| {
+            let found = match child.key {
+                Some(ref key) => old.iter().enumerate().position(|(j, o)| {
+                    !used[j] && o.key.as_ref() == Some(key)
+                }),
+                None => Some(i).filter(|&j| {
+                    j < old.len() && !used[j] && old[j].key.is_none()
+                }),
+            };
+            let found = found.filter(|&j| old[j].same_kind(child));
+            if let Some(j) = found {
+                used[j] = true;
+            }
+            found
+        })
+        .collect()
+}
+
+fn patch_children(old: &[Node], new: &[Node], layout: &mut LinearLayout) {
+    let matches = match_children(old, new);
+
+    let unchanged = old.len() == new.len()
+        && matches.iter().enumerate().all(|(i, &j)| j == Some(i));
+
+    if unchanged {
+        // Same children in the same order: patch them in place.
+        for (i, (old, new)) in old.iter().zip(new).enumerate() {
+            let patched = layout
+                .get_child_mut(i)
+                .is_some_and(|view| patch(old, new, view));
+            if !patched {
+                layout.remove_child(i);
+                layout.insert_child(i, build(new));
+            }
+        }
+        return;
+    }
+
+    // Take all the children out, and put them back in the new order.
+    let focus = layout.get_focus_index();
+    let mut views: Vec<Option<Box<dyn View>>> = Vec::with_capacity(old.len());
+    while let Some(view) = layout.remove_child(0) {
+        views.push(Some(view));
+    }
+
+    for (child, &j) in new.iter().zip(&matches) {
+        let kept = j.and_then(|j| Some((j, views.get_mut(j)?.take()?)));
+        let view = match kept {
+            Some((j, mut view)) => {
+                if patch(&old[j], child, &mut *view) {
+                    view
+                } else {
+                    build(child)
+                }
+            }
+            None => build(child),
+        };
+        layout.add_child(view);
+    }
+
+    // Keep the focus on the same child, if it is still there.
+    if let Some(i) = matches.iter().position(|&j| j == Some(focus)) {
+        layout.set_focus_index(i).ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(items: &[&str]) -> Node {
+        Node::vertical(
+            items
+                .iter()
+                .map(|item| Node::edit(*item, |_, _, _| ()).key(*item))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn keyed_reorder() {
+        let mut view = VirtualView::new(render(&["a", "b", "c"]));
+
+        let layout = view.view.downcast_mut::<LinearLayout>().unwrap();
+        layout.set_focus_index(1).unwrap();
+        // Edit the content, as a user would.
+        let edit = layout.get_child_mut(1).unwrap();
+        edit.downcast_mut::<EditView>().unwrap().set_content("b!");
+
+        view.update(render(&["c", "b", "d"]));
+
+        let layout = view.view.downcast_mut::<LinearLayout>().unwrap();
+        assert_eq!(layout.len(), 3);
+        assert_eq!(layout.get_focus_index(), 1);
+
+        let contents: Vec<String> = (0..3)
+            .map(|i| {
+                let edit = layout.get_child_mut(i).unwrap();
+                edit.downcast_mut::<EditView>()
+                    .unwrap()
+                    .get_content()
+                    .to_string()
+            })
+            .collect();
+        // The edited view was kept, but its content updated.
+        assert_eq!(contents, vec!["c", "b", "d"]);
+    }
+}
@@ -65,14 +65,14 @@ impl Backend {
                 // TODO: mouse support
                 BltEvent::MouseMove { x, y } => {
                     self.mouse_position = Vec2::new(x as usize, y as usize);
-                    // TODO: find out if a button is pressed?
-                    match self.buttons_pressed.iter().next() {
-                        None => Event::Refresh,
-                        Some(btn) => Event::Mouse {
-                            event: MouseEvent::Hold(*btn),
-                            position: self.mouse_position,
-                            offset: Vec2::zero(),
-                        },
+                    let event = match self.buttons_pressed.iter().next() {
+                        None => MouseEvent::Move,
+                        Some(btn) => MouseEvent::Hold(*btn),
+                    };
+                    Event::Mouse {
+                        event,
+                        position: self.mouse_position,
+                        offset: Vec2::zero(),
                     }
                 }
                 BltEvent::MouseScroll { delta } => Event::Mouse {
@@ -300,6 +300,10 @@ impl backend::Backend for Backend {
         true
     }
 
+    fn has_true_colors(&self) -> bool {
+        true
+    }
+
     fn screen_size(&self) -> Vec2 {
         let Size { width, height } = terminal::state::size();
         (width, height).into()
@@ -502,7 +506,8 @@ fn blt_keycode_to_key(kc: KeyCode) -> Key {
         KeyCode::F10 => Key::F10,
         KeyCode::F11 => Key::F11,
         KeyCode::F12 => Key::F12,
-        KeyCode::NumEnter | KeyCode::Enter => Key::Enter,
+        KeyCode::NumEnter => Key::NumpadEnter,
+        KeyCode::Enter => Key::Enter,
         KeyCode::Escape => Key::Esc,
         KeyCode::Backspace => Key::Backspace,
         KeyCode::Tab => Key::Tab,
@@ -44,6 +44,7 @@ type Stdout = File;
 /// Backend using crossterm
 pub struct Backend {
     current_style: Cell<theme::ColorPair>,
+    hyperlinks: bool,
 
     stdout: RefCell<BufWriter<Stdout>>,
 }
@@ -91,6 +92,12 @@ fn translate_event(event: CKeyEvent) -> Event {
     );
 
     match event {
+        // Function keys after F24 are not supported.
+        CKeyEvent {
+            code: KeyCode::F(n),
+            ..
+        } if n > 24 => Event::Unknown(vec![n]),
+
         // Handle Char + modifier.
         CKeyEvent {
             modifiers: KeyModifiers::CONTROL,
@@ -179,7 +186,10 @@ fn translate_color(base_color: theme::Color) -> Color {
                               "Blue color fragment (b = {}) is out of bound. Make sure b ≤ 5.",
                               b);
 
-            Color::AnsiValue(16 + 36 * r + 6 * g + b)
+            let strategy = theme::downsample::Strategy::Nearest;
+            Color::AnsiValue(
+                theme::downsample::to_256(base_color, strategy).unwrap(),
+            )
         }
         theme::Color::TerminalDefault => Color::Reset,
     }
@@ -207,6 +217,7 @@ impl Backend {
 
         Ok(Box::new(Backend {
             current_style: Cell::new(theme::ColorPair::from_256colors(0, 0)),
+            hyperlinks: super::terminal_has_hyperlinks(),
             stdout,
         }))
     }
@@ -305,6 +316,10 @@ impl backend::Backend for Backend {
         true
     }
 
+    fn has_true_colors(&self) -> bool {
+        super::terminal_has_true_colors()
+    }
+
     fn screen_size(&self) -> Vec2 {
         let size = terminal::size().unwrap_or((1, 1));
         Vec2::from(size)
@@ -381,6 +396,19 @@ impl backend::Backend for Backend {
         }
     }
 
+    fn set_link(&self, url: Option<&str>) {
+        if self.hyperlinks {
+            queue!(self.stdout_mut(), Print(backend::hyperlink_sequence(url)))
+                .unwrap();
+        }
+    }
+
+    fn beep(&self) {
+        let mut stdout = self.stdout_mut();
+        queue!(stdout, Print("\x07")).unwrap();
+        stdout.flush().unwrap();
+    }
+
     fn name(&self) -> &str {
         "crossterm"
     }
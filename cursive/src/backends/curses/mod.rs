@@ -4,7 +4,7 @@
 #![cfg(any(feature = "ncurses-backend", feature = "pancurses-backend"))]
 
 use crate::event::{Event, Key};
-use crate::theme::{BaseColor, Color, ColorPair};
+use crate::theme::{downsample, Color, ColorPair};
 use maplit::hashmap;
 
 #[cfg(feature = "ncurses-backend")]
@@ -77,63 +77,14 @@ fn find_closest_pair(pair: ColorPair, max_colors: i16) -> (i16, i16) {
 /// If `max_colors` is less than 256 (like 8 or 16), the color will be
 /// downgraded to the closest one available.
 fn find_closest(color: Color, max_colors: i16) -> i16 {
-    match color {
-        Color::TerminalDefault => -1,
-        Color::Dark(BaseColor::Black) => 0,
-        Color::Dark(BaseColor::Red) => 1,
-        Color::Dark(BaseColor::Green) => 2,
-        Color::Dark(BaseColor::Yellow) => 3,
-        Color::Dark(BaseColor::Blue) => 4,
-        Color::Dark(BaseColor::Magenta) => 5,
-        Color::Dark(BaseColor::Cyan) => 6,
-        Color::Dark(BaseColor::White) => 7,
-        Color::Light(BaseColor::Black) => 8 % max_colors,
-        Color::Light(BaseColor::Red) => 9 % max_colors,
-        Color::Light(BaseColor::Green) => 10 % max_colors,
-        Color::Light(BaseColor::Yellow) => 11 % max_colors,
-        Color::Light(BaseColor::Blue) => 12 % max_colors,
-        Color::Light(BaseColor::Magenta) => 13 % max_colors,
-        Color::Light(BaseColor::Cyan) => 14 % max_colors,
-        Color::Light(BaseColor::White) => 15 % max_colors,
-        Color::Rgb(r, g, b) if max_colors >= 256 => {
-            // If r = g = b, it may be a grayscale value!
-            // Grayscale colors have a bit higher resolution than the rest of
-            // the palette, so if we can use it we should!
-            //
-            // r=g=b < 8 should go to pure black instead.
-            // r=g=b >= 247 should go to pure white.
-
-            // TODO: project almost-gray colors as well?
-            if r == g && g == b && r >= 8 && r < 247 {
-                // The grayscale palette says the colors 232+n are:
-                // (r = g = b) = 8 + 10 * n
-                // With 0 <= n <= 23. This gives:
-                // (r - 8) / 10 = n
-                let n = (r - 8) / 10;
-                i16::from(232 + n)
-            } else {
-                // Generic RGB
-                let r = 6 * u16::from(r) / 256;
-                let g = 6 * u16::from(g) / 256;
-                let b = 6 * u16::from(b) / 256;
-                (16 + 36 * r + 6 * g + b) as i16
-            }
-        }
-        Color::Rgb(r, g, b) => {
-            // Have to hack it down to 8 colors.
-            let r = if r > 127 { 1 } else { 0 };
-            let g = if g > 127 { 1 } else { 0 };
-            let b = if b > 127 { 1 } else { 0 };
-            (r + 2 * g + 4 * b) as i16
-        }
-        Color::RgbLowRes(r, g, b) if max_colors >= 256 => {
-            i16::from(16 + 36 * r + 6 * g + b)
-        }
-        Color::RgbLowRes(r, g, b) => {
-            let r = if r > 2 { 1 } else { 0 };
-            let g = if g > 2 { 1 } else { 0 };
-            let b = if b > 2 { 1 } else { 0 };
-            (r + 2 * g + 4 * b) as i16
-        }
-    }
+    let strategy = downsample::Strategy::Nearest;
+    let index = if max_colors >= 256 {
+        downsample::to_256(color, strategy)
+    } else if max_colors >= 16 {
+        downsample::to_16(color, strategy)
+    } else {
+        downsample::to_8(color, strategy)
+    };
+    // The terminal default color is -1.
+    index.map_or(-1, i16::from)
 }
@@ -114,6 +114,7 @@ impl Backend {
         // This asks the terminal to provide us with mouse drag events
         // (Mouse move when a button is pressed).
         // Replacing 1002 with 1003 would give us ANY mouse move.
+        // See `set_mouse_motion`.
         write_to_tty(b"\x1B[?1002h")?;
 
         let c = Backend {
@@ -238,7 +239,7 @@ impl Backend {
             };
 
             if mevent.bstate == ncurses::REPORT_MOUSE_POSITION as mmask_t {
-                // The event is either a mouse drag event,
+                // The event is either a mouse drag event, a mouse move,
                 // or a weird double-release event. :S
 
                 self.last_mouse_button
@@ -251,7 +252,7 @@ impl Backend {
                         {
                             Some(MouseEvent::WheelDown)
                         } else {
-                            None
+                            Some(MouseEvent::Move)
                         }
                     })
                     .map(&make_event)
@@ -305,6 +306,10 @@ impl backend::Backend for Backend {
         "ncurses"
     }
 
+    fn beep(&self) {
+        ncurses::beep();
+    }
+
     fn screen_size(&self) -> Vec2 {
         let mut x: i32 = 0;
         let mut y: i32 = 0;
@@ -320,8 +325,22 @@ impl backend::Backend for Backend {
         self.parse_next()
     }
 
+    fn set_mouse_motion(&self, enabled: bool) {
+        // Legacy mouse mode can't tell mouse moves apart from the wheel.
+        if ncurses::NCURSES_MOUSE_VERSION < 2 {
+            return;
+        }
+
+        // Disabling 1003 turns mouse tracking off, so go back to 1002.
+        if enabled {
+            write_to_tty(b"\x1B[?1003h").unwrap();
+        } else {
+            write_to_tty(b"\x1B[?1003l\x1B[?1002h").unwrap();
+        }
+    }
+
     fn finish(&mut self) {
-        write_to_tty(b"\x1B[?1002l").unwrap();
+        write_to_tty(b"\x1B[?1003l\x1B[?1002l").unwrap();
         ncurses::endwin();
     }
 
@@ -467,9 +486,9 @@ fn initialize_keymap() -> HashMap<i32, Event> {
     // Values under 256 are chars and control values
     // Tab is '\t'
     map.insert(9, Event::Key(Key::Tab));
-    // Treat '\n' and the numpad Enter the same
     map.insert(10, Event::Key(Key::Enter));
-    map.insert(ncurses::KEY_ENTER, Event::Key(Key::Enter));
+    // Ignored keypad keys are sent again as regular keys.
+    map.insert(ncurses::KEY_ENTER, Event::Key(Key::NumpadEnter));
     // This is the escape key when pressed by itself.
     // When used for control sequences,
     // it should have been caught earlier.
@@ -480,7 +499,11 @@ fn initialize_keymap() -> HashMap<i32, Event> {
 
     map.insert(410, Event::WindowResize);
 
+    map.insert(ncurses::KEY_A1, Event::Key(Key::NumpadHome));
+    map.insert(ncurses::KEY_A3, Event::Key(Key::NumpadPageUp));
     map.insert(ncurses::KEY_B2, Event::Key(Key::NumpadCenter));
+    map.insert(ncurses::KEY_C1, Event::Key(Key::NumpadEnd));
+    map.insert(ncurses::KEY_C3, Event::Key(Key::NumpadPageDown));
     map.insert(ncurses::KEY_DC, Event::Key(Key::Del));
     map.insert(ncurses::KEY_IC, Event::Key(Key::Ins));
     map.insert(ncurses::KEY_BTAB, Event::Shift(Key::Tab));
@@ -72,6 +72,7 @@ impl Backend {
         // This asks the terminal to provide us with mouse drag events
         // (Mouse move when a button is pressed).
         // Replacing 1002 with 1003 would give us ANY mouse move.
+        // See `set_mouse_motion`.
         #[cfg(not(windows))]
         print!("\x1B[?1002h");
         stdout().flush()?;
@@ -206,7 +207,7 @@ impl Backend {
                 pancurses::Input::KeySTab => Event::Shift(Key::Tab),
                 pancurses::Input::KeyCTab => Event::Ctrl(Key::Tab),
                 pancurses::Input::KeyCATab => Event::CtrlAlt(Key::Tab),
-                pancurses::Input::KeyEnter => Event::Key(Key::Enter),
+                pancurses::Input::KeyEnter => Event::Key(Key::NumpadEnter),
                 pancurses::Input::KeySReset => Event::Refresh,
                 pancurses::Input::KeyReset => Event::Refresh,
                 pancurses::Input::KeyPrint => Event::Refresh,
@@ -282,11 +283,11 @@ impl Backend {
                 pancurses::Input::KeyEvent => Event::Refresh,
                 // TODO: mouse support
                 pancurses::Input::KeyMouse => self.parse_mouse_event(),
-                pancurses::Input::KeyA1 => Event::Refresh,
-                pancurses::Input::KeyA3 => Event::Refresh,
+                pancurses::Input::KeyA1 => Event::Key(Key::NumpadHome),
+                pancurses::Input::KeyA3 => Event::Key(Key::NumpadPageUp),
                 pancurses::Input::KeyB2 => Event::Key(Key::NumpadCenter),
-                pancurses::Input::KeyC1 => Event::Refresh,
-                pancurses::Input::KeyC3 => Event::Refresh,
+                pancurses::Input::KeyC1 => Event::Key(Key::NumpadEnd),
+                pancurses::Input::KeyC3 => Event::Key(Key::NumpadPageDown),
             })
         } else {
             None
@@ -314,15 +315,13 @@ impl Backend {
         };
 
         if mevent.bstate == pancurses::REPORT_MOUSE_POSITION as mmask_t {
-            // The event is either a mouse drag event,
+            // The event is either a mouse drag event, a mouse move,
             // or a weird double-release event. :S
-            self.last_mouse_button
-                .map(MouseEvent::Hold)
-                .map(&make_event)
-                .unwrap_or_else(|| {
-                    debug!("We got a mouse drag, but no last mouse pressed?");
-                    Event::Unknown(vec![])
-                })
+            make_event(
+                self.last_mouse_button
+                    .map(MouseEvent::Hold)
+                    .unwrap_or(MouseEvent::Move),
+            )
         } else {
             // Identify the button
             let mut bare_event = mevent.bstate & ((1 << 25) - 1);
@@ -359,6 +358,10 @@ impl backend::Backend for Backend {
         "pancurses"
     }
 
+    fn beep(&self) {
+        pancurses::beep();
+    }
+
     fn screen_size(&self) -> Vec2 {
         // Coordinates are reversed here
         let (y, x) = self.window.get_max_yx();
@@ -369,8 +372,23 @@ impl backend::Backend for Backend {
         pancurses::has_colors()
     }
 
+    fn set_mouse_motion(&self, enabled: bool) {
+        // Disabling 1003 turns mouse tracking off, so go back to 1002.
+        #[cfg(not(windows))]
+        {
+            if enabled {
+                print!("\x1B[?1003h");
+            } else {
+                print!("\x1B[?1003l\x1B[?1002h");
+            }
+            stdout().flush().expect("could not flush stdout");
+        }
+        #[cfg(windows)]
+        let _ = enabled;
+    }
+
     fn finish(&mut self) {
-        print!("\x1B[?1002l");
+        print!("\x1B[?1003l\x1B[?1002l");
         stdout().flush().expect("could not flush stdout");
         pancurses::endwin();
     }
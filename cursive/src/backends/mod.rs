@@ -16,3 +16,22 @@ pub mod crossterm;
 pub mod curses;
 pub mod puppet;
 pub mod termion;
+
+/// Checks whether the terminal advertises support for 24-bit colors.
+#[cfg(any(feature = "termion", feature = "crossterm"))]
+fn terminal_has_true_colors() -> bool {
+    std::env::var("COLORTERM")
+        .map(|value| value == "truecolor" || value == "24bit")
+        .unwrap_or(false)
+}
+
+/// Checks whether the terminal is likely to understand OSC 8 hyperlinks.
+///
+/// Terminals that don't know them usually ignore them, except for the Linux
+/// console and dumb terminals.
+#[cfg(any(feature = "termion", feature = "crossterm"))]
+fn terminal_has_hyperlinks() -> bool {
+    std::env::var("TERM")
+        .map(|term| term != "linux" && term != "dumb")
+        .unwrap_or(false)
+}
@@ -98,6 +98,10 @@ impl backend::Backend for Backend {
         true
     }
 
+    fn has_true_colors(&self) -> bool {
+        true
+    }
+
     fn screen_size(&self) -> Vec2 {
         self.size.get()
     }
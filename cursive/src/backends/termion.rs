@@ -34,6 +34,7 @@ pub struct Backend {
     terminal:
         RefCell<AlternateScreen<MouseTerminal<RawTerminal<BufWriter<File>>>>>,
     current_style: Cell<theme::ColorPair>,
+    hyperlinks: bool,
 
     // Inner state required to parse input
     last_button: Option<MouseButton>,
@@ -87,6 +88,7 @@ impl Backend {
         let c = Backend {
             terminal,
             current_style: Cell::new(theme::ColorPair::from_256colors(0, 0)),
+            hyperlinks: super::terminal_has_hyperlinks(),
 
             last_button: None,
             input_receiver,
@@ -103,7 +105,9 @@ impl Backend {
 
     fn map_key(&mut self, event: TEvent) -> Event {
         match event {
-            TEvent::Unsupported(bytes) => Event::Unknown(bytes),
+            TEvent::Unsupported(bytes) => {
+                parse_unsupported(&bytes).unwrap_or(Event::Unknown(bytes))
+            }
             TEvent::Key(TKey::Esc) => Event::Key(Key::Esc),
             TEvent::Key(TKey::Backspace) => Event::Key(Key::Backspace),
             TEvent::Key(TKey::Left) => Event::Key(Key::Left),
@@ -116,7 +120,7 @@ impl Backend {
             TEvent::Key(TKey::PageDown) => Event::Key(Key::PageDown),
             TEvent::Key(TKey::Delete) => Event::Key(Key::Del),
             TEvent::Key(TKey::Insert) => Event::Key(Key::Ins),
-            TEvent::Key(TKey::F(i)) if i < 12 => Event::Key(Key::from_f(i)),
+            TEvent::Key(TKey::F(i)) if i <= 24 => Event::Key(Key::from_f(i)),
             TEvent::Key(TKey::F(j)) => Event::Unknown(vec![j]),
             TEvent::Key(TKey::Char('\n')) => Event::Key(Key::Enter),
             TEvent::Key(TKey::Char('\t')) => Event::Key(Key::Tab),
@@ -239,11 +243,27 @@ impl backend::Backend for Backend {
         }
     }
 
+    fn set_link(&self, url: Option<&str>) {
+        if self.hyperlinks {
+            self.write(backend::hyperlink_sequence(url));
+        }
+    }
+
+    fn beep(&self) {
+        let mut terminal = self.terminal.borrow_mut();
+        write!(terminal, "\x07").unwrap();
+        terminal.flush().unwrap();
+    }
+
     fn has_colors(&self) -> bool {
         // TODO: color support detection?
         true
     }
 
+    fn has_true_colors(&self) -> bool {
+        super::terminal_has_true_colors()
+    }
+
     fn screen_size(&self) -> Vec2 {
         // TODO: termion::terminal_size currently requires stdout.
         // When available, we should try to use /dev/tty instead.
@@ -332,8 +352,77 @@ where
         theme::Color::Light(theme::BaseColor::White) => f(&tcolor::LightWhite),
 
         theme::Color::Rgb(r, g, b) => f(&tcolor::Rgb(r, g, b)),
-        theme::Color::RgbLowRes(r, g, b) => {
-            f(&tcolor::AnsiValue::rgb(r, g, b))
+        theme::Color::RgbLowRes(..) => {
+            let strategy = theme::downsample::Strategy::Nearest;
+            let index = theme::downsample::to_256(clr, strategy).unwrap();
+            f(&tcolor::AnsiValue(index))
         }
     }
 }
+
+/// Parses escape sequences unknown to termion.
+///
+/// This covers the keypad in application mode (`ESC O p` for 0, ...), and
+/// F13 to F20 as sent by rxvt-like terminals (`ESC [ 25 ~`, ...).
+fn parse_unsupported(bytes: &[u8]) -> Option<Event> {
+    match *bytes {
+        [0x1b, b'O', c] => Some(Event::Key(match c {
+            b'p'..=b'y' => Key::from_numpad(c - b'p'),
+            b'M' => Key::NumpadEnter,
+            b'n' => Key::NumpadDecimal,
+            b'k' => Key::NumpadAdd,
+            b'm' => Key::NumpadSubtract,
+            b'j' => Key::NumpadMultiply,
+            b'o' => Key::NumpadDivide,
+            _ => return None,
+        })),
+        [0x1b, b'[', ref code @ .., b'~'] => {
+            let code: u8 = std::str::from_utf8(code).ok()?.parse().ok()?;
+            let n = match code {
+                25 => 13,
+                26 => 14,
+                28 => 15,
+                29 => 16,
+                31 => 17,
+                32 => 18,
+                33 => 19,
+                34 => 20,
+                _ => return None,
+            };
+            Some(Event::Key(Key::from_f(n)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keypad_sequences() {
+        let key = |bytes: &[u8]| parse_unsupported(bytes);
+
+        assert_eq!(key(b"\x1bOp"), Some(Event::Key(Key::Numpad0)));
+        assert_eq!(key(b"\x1bOy"), Some(Event::Key(Key::Numpad9)));
+        assert_eq!(key(b"\x1bOM"), Some(Event::Key(Key::NumpadEnter)));
+        assert_eq!(key(b"\x1bOn"), Some(Event::Key(Key::NumpadDecimal)));
+        assert_eq!(key(b"\x1bOo"), Some(Event::Key(Key::NumpadDivide)));
+        assert_eq!(key(b"\x1bOz"), None);
+    }
+
+    #[test]
+    fn extended_function_keys() {
+        let key = |bytes: &[u8]| parse_unsupported(bytes);
+
+        assert_eq!(key(b"\x1b[25~"), Some(Event::Key(Key::F13)));
+        assert_eq!(key(b"\x1b[28~"), Some(Event::Key(Key::F15)));
+        assert_eq!(key(b"\x1b[34~"), Some(Event::Key(Key::F20)));
+        // 27 and 30 are not function keys.
+        assert_eq!(key(b"\x1b[27~"), None);
+        assert_eq!(key(b"\x1b[30~"), None);
+        assert_eq!(key(b"\x1b[~"), None);
+        assert_eq!(key(b"\x1b[999~"), None);
+        assert_eq!(key(b"\x1b"), None);
+    }
+}